@@ -4,6 +4,28 @@ This package re-exports the public API implemented in the compiled
 ``xlsxturbo`` extension module.
 """
 
-from .xlsxturbo import __version__, csv_to_xlsx, df_to_xlsx, dfs_to_xlsx, version
+from .xlsxturbo import (
+    __version__,
+    csv_to_xlsx,
+    csvs_to_xlsx,
+    df_sections_to_xlsx,
+    df_to_xlsx,
+    df_to_xlsx_bytes,
+    dfs_to_xlsx,
+    dfs_to_xlsx_bytes,
+    read_xlsx,
+    version,
+)
 
-__all__ = ["__version__", "csv_to_xlsx", "df_to_xlsx", "dfs_to_xlsx", "version"]
+__all__ = [
+    "__version__",
+    "csv_to_xlsx",
+    "csvs_to_xlsx",
+    "df_sections_to_xlsx",
+    "df_to_xlsx",
+    "df_to_xlsx_bytes",
+    "dfs_to_xlsx",
+    "dfs_to_xlsx_bytes",
+    "read_xlsx",
+    "version",
+]
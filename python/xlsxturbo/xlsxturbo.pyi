@@ -1,11 +1,34 @@
 """Type stubs for the xlsxturbo compiled (Rust) extension module."""
 
+from collections.abc import Callable
+from datetime import date, datetime, time
 from os import PathLike
-from typing import Literal, TypedDict
+from typing import Any, Literal, Protocol, TypedDict
 
 PathArg = str | PathLike[str]
 
+class BinaryWriter(Protocol):
+    """Any object with a binary write() method: io.BytesIO, an open 'wb' file, a cloud storage stream."""
+
+    def write(self, data: bytes, /) -> object: ...
+
+OutputArg = PathArg | BinaryWriter
+
 DateOrder = Literal["auto", "mdy", "us", "dmy", "eu", "european"]
+NanPolicy = Literal["empty", "string", "error"]
+LargeIntMode = Literal["string", "number", "text"]
+LongStringMode = Literal["error", "truncate", "split"]
+BoolMode = Literal["boolean", "yes_no", "one_zero"] | tuple[str, str]  # A tuple is (true_text, false_text)
+ConditionalFormatMode = Literal["all_matches", "first_match_wins"]
+Autofit = bool | list[str | int]  # A list autofits only those columns
+SheetView = Literal["normal", "page_layout", "page_break"]
+SummaryFunction = Literal["sum", "mean", "min", "max", "count"]
+UnderlineStyle = Literal["single", "double", "single_accounting", "double_accounting"]
+FontScript = Literal["superscript", "subscript", "none"]
+FreezePanes = bool | str | tuple[int, int] | FreezeSplit
+OutlineGroup = tuple[int, int, int] | tuple[int, int, int, bool]  # (first, last, level[, collapsed])
+# (cell, url[, display_text[, tooltip]]); None skips the display text
+HyperlinkSpec = tuple[str, str] | tuple[str, str, str | None] | tuple[str, str, str | None, str | None]
 ValidationType = Literal[
     "list",
     "whole_number",
@@ -16,8 +39,16 @@ ValidationType = Literal[
     "text_length",
     "textlength",
     "length",
+    "date",
+    "time",
 ]
 
+class FreezeSplit(TypedDict, total=False):
+    """Freeze leading rows and columns, counted from start_row/start_col."""
+
+    rows: int  # Rows kept in view (default: 0)
+    cols: int  # Columns kept in view (default: 0)
+
 class HeaderFormat(TypedDict, total=False):
     """Header cell formatting options. All fields are optional."""
 
@@ -26,16 +57,32 @@ class HeaderFormat(TypedDict, total=False):
     font_color: str  # '#RRGGBB' or named color (white, black, red, blue, etc.)
     bg_color: str  # '#RRGGBB' or named color
     font_size: float
-    underline: bool
+    font_name: str  # Typeface, e.g. 'Arial'; replaces the theme font
+    font_family: int  # Font family class 0-5 (1 Roman, 2 Swiss, 3 Modern, 4 Script, 5 Decorative)
+    font_scheme: Literal["minor", "major", "none"]  # Theme body font, theme heading font, or none
+    underline: bool | UnderlineStyle  # True = single
+    font_strikethrough: bool  # Strike through the text
+    font_script: FontScript  # Raise or lower the text
     border: bool | str  # True = thin all sides, str = named style all sides
     border_left: bool | str  # True = thin, or named style (thin, medium, thick, dashed, dotted, double, hair, etc.)
     border_right: bool | str  # True = thin, or named style for right side only
     border_top: bool | str  # True = thin, or named style for top side only
     border_bottom: bool | str  # True = thin, or named style for bottom side only
     border_color: str  # Color for all borders. Requires a border to be set for a visible effect
+    border_style: str  # Style drawn by True border flags (default 'thin')
+    border_left_color: str  # Left border color; overrides border_color
+    border_right_color: str  # Right border color; overrides border_color
+    border_top_color: str  # Top border color; overrides border_color
+    border_bottom_color: str  # Bottom border color; overrides border_color
+    diagonal_border: bool | str  # Corner-to-corner line; True = border_style (default thin), or named style
+    diagonal_type: Literal["up", "down", "both"]  # Diagonal direction (default 'up'); needs diagonal_border
+    diagonal_color: str  # Diagonal line color
     align_horizontal: str  # 'left', 'center', 'right', 'fill', 'justify', 'center_across', 'distributed'
-    align_vertical: str  # 'top', 'center', 'bottom', 'justify', 'distributed'
+    align_vertical: str  # 'top', 'center' (or 'middle'), 'bottom', 'justify', 'distributed'
     wrap_text: bool  # Enable text wrapping within cell
+    align: str  # Short for align_horizontal
+    valign: str  # Short for align_vertical
+    text_wrap: bool  # Short for wrap_text
 
 class ColumnFormat(TypedDict, total=False):
     """Column cell formatting options. All fields are optional."""
@@ -45,7 +92,12 @@ class ColumnFormat(TypedDict, total=False):
     font_color: str  # '#RRGGBB' or named color (white, black, red, blue, etc.)
     bg_color: str  # '#RRGGBB' or named color
     font_size: float
-    underline: bool
+    font_name: str  # Typeface, e.g. 'Arial'; replaces the theme font
+    font_family: int  # Font family class 0-5 (1 Roman, 2 Swiss, 3 Modern, 4 Script, 5 Decorative)
+    font_scheme: Literal["minor", "major", "none"]  # Theme body font, theme heading font, or none
+    underline: bool | UnderlineStyle  # True = single
+    font_strikethrough: bool  # Strike through the text
+    font_script: FontScript  # Raise or lower the text
     num_format: str  # Excel number format string, e.g. '0.00', '#,##0', '0.00%'
     border: bool | str  # True = thin all sides (backward compat), str = named style all sides
     border_left: bool | str  # True = thin, or named style (thin, medium, thick, dashed, dotted, double, hair, etc.)
@@ -53,9 +105,24 @@ class ColumnFormat(TypedDict, total=False):
     border_top: bool | str  # True = thin, or named style for top side only
     border_bottom: bool | str  # True = thin, or named style for bottom side only
     border_color: str  # Color for all borders. Requires a border to be set for a visible effect
+    border_style: str  # Style drawn by True border flags (default 'thin')
+    border_left_color: str  # Left border color; overrides border_color
+    border_right_color: str  # Right border color; overrides border_color
+    border_top_color: str  # Top border color; overrides border_color
+    border_bottom_color: str  # Bottom border color; overrides border_color
+    diagonal_border: bool | str  # Corner-to-corner line; True = border_style (default thin), or named style
+    diagonal_type: Literal["up", "down", "both"]  # Diagonal direction (default 'up'); needs diagonal_border
+    diagonal_color: str  # Diagonal line color
     align_horizontal: str  # 'left', 'center', 'right', 'fill', 'justify', 'center_across', 'distributed'
-    align_vertical: str  # 'top', 'center', 'bottom', 'justify', 'distributed'
+    align_vertical: str  # 'top', 'center' (or 'middle'), 'bottom', 'justify', 'distributed'
     wrap_text: bool  # Enable text wrapping within cell
+    align: str  # Short for align_horizontal
+    valign: str  # Short for align_vertical
+    text_wrap: bool  # Short for wrap_text
+
+# (range, value[, format]); a non-string value is written with its type into the top-left cell
+MergedValue = str | int | float | bool | date | datetime
+MergedRangeSpec = tuple[str, MergedValue] | tuple[str, MergedValue, ColumnFormat | None]
 
 class ConditionalFormat(TypedDict, total=False):
     """Conditional formatting options for a column. 'type' is required.
@@ -66,16 +133,23 @@ class ConditionalFormat(TypedDict, total=False):
     - 'data_bar': In-cell bar chart
     - 'icon_set': Traffic lights, arrows, or other icons
     - 'cell': Rule-based formatting (highlight cells matching a condition)
+    - 'text': Highlight text cells containing, beginning with, or ending with a string
 
-    For 'cell' type, use 'criteria' to specify the condition and 'format' for styling.
+    For 'cell' type, use 'criteria' (or its alias 'rule') to specify the condition and
+    'format' for styling. 'min'/'max' are accepted as aliases of 'min_value'/'max_value'.
     Multiple rules on one column: pass a list of ConditionalFormat dicts instead of a single dict.
     """
 
-    type: str  # Required: '2_color_scale', '3_color_scale', 'data_bar', 'icon_set', 'cell'
+    type: str  # Required: '2_color_scale', '3_color_scale', 'data_bar', 'icon_set', 'cell', 'text'
     # For color scales:
     min_color: str  # '#RRGGBB' or named color for minimum value
     mid_color: str  # '#RRGGBB' or named color for midpoint (3_color_scale only)
     max_color: str  # '#RRGGBB' or named color for maximum value
+    min_type: str  # 'number', 'percent', 'percentile', or 'min' (default: lowest value)
+    mid_type: str  # 'number', 'percent', or 'percentile' (3_color_scale only; default: 50th percentile)
+    max_type: str  # 'number', 'percent', 'percentile', or 'max' (default: highest value)
+    mid_value: int | float  # Midpoint for mid_type (3_color_scale only)
+    # min_value / max_value (below) set the scale ends for a numeric min_type / max_type
     # For data bars:
     bar_color: str  # '#RRGGBB' or named color for the bar fill
     border_color: str  # '#RRGGBB' or named color for bar border
@@ -88,8 +162,13 @@ class ConditionalFormat(TypedDict, total=False):
     # For cell rules (type='cell'):
     criteria: str  # 'equal_to', 'not_equal_to', 'greater_than', 'less_than', 'between', 'containing', etc.
     value: str | int | float  # Target value for comparison criteria
-    min_value: int | float  # Min value for 'between'/'not_between' criteria
-    max_value: int | float  # Max value for 'between'/'not_between' criteria
+    min_value: int | float  # Min value for 'between'/'not_between' criteria, or the color-scale minimum
+    max_value: int | float  # Max value for 'between'/'not_between' criteria, or the color-scale maximum
+    rule: str  # Alias of 'criteria'; for type='text': 'contains', 'not_contains', 'begins_with', 'ends_with'
+    min: int | float  # Alias of 'min_value'
+    max: int | float  # Alias of 'max_value'
+    # For text rules (type='text'):
+    text: str  # String the cell text is matched against
     format: ColumnFormat  # Format to apply when condition is met (bg_color, font_color, bold, etc.)
 
 class CommentOptions(TypedDict, total=False):
@@ -100,15 +179,35 @@ class CommentOptions(TypedDict, total=False):
 
     text: str  # The comment text (required at runtime)
     author: str  # Author name for the comment
+    width: int  # Note box width in pixels (default 128)
+    height: int  # Note box height in pixels (default 74)
+    bg_color: str  # Note box fill, '#RRGGBB' or named color (default pale yellow)
+    font_size: float  # Note text size in points (default 8)
+    visible: bool  # Always show the note instead of only on hover
+
+class FormulaColumnOptions(TypedDict, total=False):
+    """Options for a formula column given as a dict instead of a template string.
+
+    Note: 'formula' is required at runtime but TypedDict doesn't enforce this.
+    """
+
+    formula: str  # Formula template with {row}/{col_name} placeholders (required at runtime)
+    formula_type: Literal["regular", "dynamic", "array"]  # Default: "regular" (one formula per row)
+    range: str  # Target range for an "array" formula, e.g. 'D2:D10' (default: the column's data rows)
+    position: int | str  # Insert before this 0-based column index, or 'after:col_name' (default: append)
+    # Cached value shown before recalculation: a constant, or a callable taking the 0-based row index
+    formula_result: float | str | bool | Callable[[int], float | str | bool | None]
 
 class ValidationOptions(TypedDict, total=False):
     """Data validation options for a column. 'type' is required.
 
     Supported types:
-    - 'list': Dropdown with specified values
+    - 'list': Dropdown with specified values, or the cells of 'source_range'
     - 'whole_number': Integer between min and max
     - 'decimal': Decimal number between min and max
     - 'text_length': Text length between min and max
+    - 'date': Date between min and max, given as ISO strings or dates
+    - 'time': Time of day between min and max, given as ISO strings or times
 
     For 'whole_number', 'min'/'max' are bounded to the i32 range
     (-2147483648..=2147483647); a value outside that range raises ValueError
@@ -117,12 +216,16 @@ class ValidationOptions(TypedDict, total=False):
 
     type: ValidationType  # Required: validation type
     values: list[str]  # For 'list' type: dropdown options
-    min: int | float  # For number/text_length: minimum value (defaults to type minimum if omitted)
-    max: int | float  # For number/text_length: maximum value (defaults to type maximum if omitted)
+    source_range: str  # For 'list' type, instead of values: cell range such as '=Lists!$A$1:$A$100'
+    show_dropdown: bool  # For 'list' type: show the in-cell dropdown arrow (default True)
+    ignore_blank: bool  # Allow blank cells (default True)
+    min: int | float | str | date | time  # Minimum value (defaults to type minimum if omitted)
+    max: int | float | str | date | time  # Maximum value (defaults to type maximum if omitted)
     input_title: str  # Title for input prompt
     input_message: str  # Message for input prompt
     error_title: str  # Title for error message
     error_message: str  # Message for error message
+    error_style: Literal["stop", "warning", "information"]  # Alert for invalid input (default 'stop' blocks it)
 
 class RichTextFormat(TypedDict, total=False):
     """Format options for a rich text segment."""
@@ -132,18 +235,28 @@ class RichTextFormat(TypedDict, total=False):
     font_color: str  # '#RRGGBB' or named color
     bg_color: str  # '#RRGGBB' or named color
     font_size: float
-    underline: bool
+    font_name: str
+    underline: bool | UnderlineStyle  # True = single
+    font_strikethrough: bool
+    font_script: FontScript  # 'superscript' for footnote markers, 'subscript' for chemical formulas
 
 class ImageOptions(TypedDict, total=False):
     """Options for embedding images.
 
-    Note: 'path' is required at runtime but TypedDict doesn't enforce this.
+    Note: exactly one of 'path' or 'data' is required at runtime but TypedDict
+    doesn't enforce this.
     """
 
-    path: str  # Path to image file - PNG, JPEG, GIF, BMP (required at runtime)
+    path: str  # Path to image file - PNG, JPEG, GIF, BMP
+    data: bytes  # In-memory image file contents, instead of a path
+    image_type: Literal["png", "jpeg", "jpg", "gif", "bmp"]  # Expected format of 'data', checked on load
     scale_width: float  # Scale factor for width (1.0 = original)
     scale_height: float  # Scale factor for height (1.0 = original)
     alt_text: str  # Alternative text for accessibility
+    x_offset: int  # Horizontal offset in pixels from the cell's top-left corner
+    y_offset: int  # Vertical offset in pixels from the cell's top-left corner
+    anchor: Literal["move_and_size", "move", "absolute", "move_and_size_after"]  # How the image follows cell resizing
+    dpi: float  # Resolution to size the image by, instead of the one stored in the file
 
 class CheckboxOptions(TypedDict, total=False):
     """Options for interactive cell checkboxes.
@@ -214,7 +327,8 @@ class ChartSeriesOptions(TypedDict, total=False):
 class ChartOptions(TypedDict, total=False):
     """Options for native Excel charts.
 
-    Note: 'type' and either 'data_range'/'values_range' or 'series' are required at runtime.
+    Note: 'type' and either 'data_range'/'values_range', 'series', or 'value_cols'
+    are required at runtime.
     Note: 'values_range'/'values'/'data_range' and 'categories_range'/'categories'
     must include a sheet name (e.g. 'Sheet1!$B$2:$B$10'); a bare range raises
     ValueError. This also applies to a chart-level 'categories_range'/'categories'
@@ -228,6 +342,8 @@ class ChartOptions(TypedDict, total=False):
     categories_range: str  # Excel range for categories/X values
     categories: str  # Alias for categories_range
     series: list[ChartSeriesOptions]  # Multiple series
+    categories_col: str  # Written column supplying the categories (with value_cols)
+    value_cols: str | list[str]  # Written column(s) to plot, one series each, named by header
     name: str  # Single-series name or formula reference
     series_name: str  # Alias for name
     title: str  # Chart title
@@ -289,48 +405,208 @@ class CellValueOptions(TypedDict, total=False):
     align_vertical: str  # 'top', 'center', 'bottom', 'justify', 'distributed'
     wrap_text: bool  # Enable text wrapping within cell
 
+class CellOverride(TypedDict, total=False):
+    """A cell_overrides entry. At least one of 'value' and 'format' is required."""
+
+    value: Any  # Written like a cells value (str, number, bool, date, datetime, None)
+    format: ColumnFormat  # Full format for the cell, as for column_formats
+
+class PageMargins(TypedDict, total=False):
+    """Printed page margins in inches. Omitted edges keep Excel's default."""
+
+    left: float  # Excel default 0.7
+    right: float  # Excel default 0.7
+    top: float  # Excel default 0.75
+    bottom: float  # Excel default 0.75
+    header: float  # Excel default 0.3
+    footer: float  # Excel default 0.3
+
+class HeaderFooterImages(TypedDict, total=False):
+    """Image paths for the sections of a printed header or footer."""
+
+    left: str
+    center: str
+    right: str
+
+class PageSetup(TypedDict, total=False):
+    """Print layout settings. These only affect printing, not the data."""
+
+    orientation: Literal["portrait", "landscape"]
+    fit_to_pages: tuple[int, int]  # (width, height) in pages; 0 = as many as needed
+    paper_size: int  # Excel paper index, e.g. 1 = Letter, 9 = A4
+    margins: PageMargins
+    header: str  # Printed page header, an Excel format string like '&CPage &P of &N'
+    footer: str  # Printed page footer, same format as header
+    header_images: HeaderFooterImages  # Image paths shown at &[Picture] placeholders in header
+    footer_images: HeaderFooterImages  # Image paths shown at &[Picture] placeholders in footer
+    header_footer_scale_with_doc: bool  # Scale header/footer with the page scaling (Excel default: True)
+
+class ProtectOptions(TypedDict, total=False):
+    """Sheet protection settings. All fields are optional."""
+
+    password: str  # Password needed to unprotect the sheet in Excel
+    unlocked_columns: list[str]  # Column names or wildcard patterns whose data cells stay editable
+
+class DocumentProperties(TypedDict, total=False):
+    """Workbook document properties (File > Info in Excel). All fields are optional."""
+
+    title: str
+    subject: str
+    author: str
+    manager: str
+    company: str
+    keywords: str
+    comments: str
+    category: str
+    created: str  # ISO 8601 date or datetime, e.g. "2024-01-15T09:30:00"; taken as UTC without an offset
+
+class TableColumnOptions(TypedDict, total=False):
+    """Per-column overrides for a table_style table. Any total adds a totals row."""
+
+    header: str  # Replacement header text (default: the DataFrame column name)
+    total_function: Literal["sum", "average", "count", "count_numbers", "max", "min", "std_dev", "var"]
+    total_label: str  # Text shown in the totals row; exclusive with total_function
+
+class TableOptions(TypedDict, total=False):
+    """Look of a table_style table. Omitted keys keep Excel's defaults."""
+
+    banded_rows: bool  # Stripe alternate rows (default: True)
+    banded_columns: bool  # Stripe alternate columns (default: False)
+    first_column: bool  # Emphasize the first column (default: False)
+    last_column: bool  # Emphasize the last column (default: False)
+
+class TableSpec(TypedDict, total=False):
+    """An extra table over an explicit range, for the tables option.
+
+    Note: 'range' is required at runtime but TypedDict doesn't enforce this.
+    """
+
+    range: str  # Cell range like "A1:C10"; its first row is the header row (required at runtime)
+    style: str  # As for table_style (default: "Medium9")
+    name: str  # Table name, sanitized as for table_name (default: auto-generated)
+    total_row: bool  # The range's last row is the totals row (default: False)
+
 class SheetOptions(TypedDict, total=False):
     """Per-sheet options for dfs_to_xlsx. All fields are optional.
 
     For the dict/list-valued options below (column_widths, header_format,
     column_formats, conditional_formats, formula_columns, merged_ranges,
-    hyperlinks, comments, validations, rich_text, images, checkboxes,
-    textboxes, charts, sparklines, cells): passing an explicitly empty dict/list for a sheet
+    hyperlinks, row_groups, col_groups, comments, validations, rich_text, images, checkboxes,
+    textboxes, charts, sparklines, cells, cell_overrides, link_format, page_setup, table_columns): passing an explicitly empty dict/list for a sheet
     disables that global default for that sheet rather than falling back to
     it. Omitting the key entirely (or passing None) still falls back to the
     global default as before.
 
     A per-sheet `column_widths` dict combined with `autofit=True` (global or
-    per-sheet) no longer suppresses autofit: explicit widths win for the
-    columns they name, and every other column is still autofitted. An
-    `'_all'` entry caps the autofit width for those other columns instead of
-    overriding it. An explicitly empty `column_widths: {}` for a sheet has no
-    explicit widths to apply, so the sheet is autofitted exactly as if
-    `column_widths` had been omitted.
+    per-sheet) does not suppress autofit: every column is still autofitted,
+    capped at its own width, else at the `'_all'` entry. An explicitly empty
+    `column_widths: {}` for a sheet has no caps to apply, so the sheet is
+    autofitted exactly as if `column_widths` had been omitted.
     """
 
     header: bool
-    autofit: bool
+    autofit: Autofit  # True, or a list of column names/indices to autofit
     table_style: str | None
-    freeze_panes: bool
+    freeze_panes: FreezePanes  # True, 'C2', (row, col), or {'rows': N, 'cols': M}
+    freeze_top_cell: str | None  # Top-left cell of the scrollable pane, e.g. 'A100'
+    split_panes: str | None  # Resizable, unfrozen split at a cell, e.g. 'B3'
     column_widths: dict[int | str, int | float] | None  # Keys: int index or '_all'
     row_heights: dict[int, int | float] | None
     table_name: str | None
+    table_columns: list[TableColumnOptions] | None  # One entry per table column, from the left
+    table_options: TableOptions | None  # Table banding and first/last column emphasis
+    tables: list[TableSpec] | None  # Extra tables over explicit ranges
     header_format: HeaderFormat | None
-    column_formats: dict[str, ColumnFormat] | None  # Pattern -> format ('prefix*', '*suffix', '*contains*', exact)
+    column_formats: dict[str | int, ColumnFormat] | None  # Pattern ('prefix*', '*suffix', '*contains*', exact) or column index -> format
     conditional_formats: dict[str, ConditionalFormat | list[ConditionalFormat]] | None  # Column/pattern -> config
-    formula_columns: dict[str, str] | None  # Column name -> Excel formula template with {row} placeholder
-    merged_ranges: list[tuple[str, str] | tuple[str, str, HeaderFormat]] | None  # (range, text[, format])
-    hyperlinks: list[tuple[str, str] | tuple[str, str, str]] | None  # (cell, url[, display_text])
+    formula_columns: dict[str, str | FormulaColumnOptions] | None  # Column name -> formula template or options
+    merged_ranges: list[MergedRangeSpec] | None  # (range, value[, format])
+    hyperlinks: list[HyperlinkSpec] | None  # (cell, url[, display_text[, tooltip]])
+    row_groups: list[OutlineGroup] | None  # (first_row, last_row, level[, collapsed])
+    col_groups: list[OutlineGroup] | None  # (first_col, last_col, level[, collapsed])
     comments: dict[str, str | CommentOptions] | None  # Cell ref -> comment text or options
     validations: dict[str, ValidationOptions] | None  # Column name/pattern -> validation options
     rich_text: dict[str, list[tuple[str, RichTextFormat] | str]] | None  # Cell ref -> segments
-    images: dict[str, str | ImageOptions] | None  # Cell ref -> image path or options
+    images: dict[str, str | bytes | ImageOptions] | None  # Cell ref -> image path, bytes, or options
     checkboxes: dict[str, bool | CheckboxOptions] | None  # Cell ref -> checked state or options
     textboxes: dict[str, str | TextboxOptions] | None  # Cell ref -> text or textbox options
     charts: dict[str, ChartOptions] | None  # Cell ref -> native Excel chart options
     sparklines: dict[str, SparklineOptions] | None  # Location ref -> sparkline options
     cells: dict[str, str | int | float | bool | CellValueOptions] | None  # Cell ref -> value or options
+    cell_overrides: dict[str, CellOverride] | None  # Cell ref -> value and/or format, written last
+    date_format: str | None  # Excel number format for date cells
+    datetime_format: str | None  # Excel number format for datetime cells
+    time_format: str | None  # Excel number format for datetime.time cells
+    nan_policy: NanPolicy  # How NaN/Inf values are written
+    large_int_mode: LargeIntMode  # How integers beyond 2^53 are written
+    long_string_mode: LongStringMode  # How strings over Excel's 32,767-character cell limit are written
+    start_row: int  # Zero-based sheet row where the data begins
+    start_col: int  # Zero-based sheet column where the data begins
+    autofilter: bool  # Autofilter dropdowns over the data without a table
+    conditional_format_mode: ConditionalFormatMode  # How overlapping conditional_formats patterns combine
+    show_gridlines: bool  # Show worksheet gridlines on screen
+    show_row_col_headers: bool  # Show row numbers and column letters on screen
+    zoom: int  # Zoom percentage, 10 to 400
+    view: SheetView  # 'normal', 'page_layout', or 'page_break'
+    right_to_left: bool  # Mirror the sheet view; data order is unchanged
+    outline_below: bool  # Row group buttons below (True) or above (False) each group
+    outline_right: bool  # Column group buttons right (True) or left (False) of each group
+    tab_color: str | None  # Sheet tab color, '#RRGGBB' or named color
+    page_setup: PageSetup | None  # Print orientation, fit-to-pages, paper size, margins
+    print_area: str | None  # Cell range to print, e.g. 'A1:F50'
+    background_image: str | bytes | None  # Image path or bytes tiled behind the cells
+    categorical_validation: bool  # Dropdown of its categories over each categorical column
+    integer_like_floats: bool  # Whole values of float columns shown without decimals
+    columns: list[str | int]  # Column names/0-based positions to write, in output order
+    skip_rows: int  # Leading DataFrame rows to leave out
+    max_rows: int | None  # Write at most this many DataFrame rows, after skip_rows
+    transpose: bool  # Column names down the first column, each DataFrame row as a column
+    include_index: bool  # pandas index as the leading column(s)
+    cell_format: ColumnFormat  # Base style of every data cell; column_formats take precedence
+    banded_rows: tuple[str, str] | list[str]  # Two fill colors alternated across data rows
+    summary_row: dict[str, SummaryFunction]  # Bold row of aggregates below the data
+    default_col_width: float  # Width of every column on the sheet; column_widths/autofit override it
+    default_row_height: float  # Height of every row on the sheet; row_heights overrides it
+    protect: bool | ProtectOptions  # Lock the sheet; False leaves this sheet unprotected
+    auto_hyperlinks: bool  # Write http(s):// and mailto: string values as hyperlinks
+    auto_rich_text_columns: list[str]  # Columns whose **bold**/*italic* markup is written as rich text
+    infer_string_types: bool  # Write strings that read as numbers, booleans, or dates as those types
+    bool_mode: BoolMode  # 'boolean', 'yes_no', 'one_zero', or (true_text, false_text)
+    text_columns: list[str | int]  # Column names/0-based positions written as '@' text, e.g. zip codes
+    sanitize_strings: bool  # Strip a leading BOM and illegal control characters from strings
+    link_format: ColumnFormat  # Style of the auto_hyperlinks links
+    visible: bool | Literal["very_hidden"]  # False hides the sheet tab; at least one sheet must stay visible
+    active_cell: str  # Cell selected when the sheet is opened, e.g. 'B2'
+
+class CsvSheetOptions(TypedDict, total=False):
+    """Per-sheet options for csvs_to_xlsx. All fields are optional and fall
+    back to the csvs_to_xlsx keyword arguments; each has the same meaning as
+    the csv_to_xlsx parameter of that name.
+    """
+
+    date_order: DateOrder
+    date_format: str | None
+    datetime_format: str | None
+    time_format: str | None
+    nan_policy: NanPolicy
+    large_int_mode: LargeIntMode
+    long_string_mode: LongStringMode
+    constant_memory: bool
+    smart_numeric: bool
+    delimiter: str | None
+    quote: str | None
+    escape: str | None
+    has_header: bool
+    header_format: HeaderFormat | None  # Requires has_header for this sheet
+    encoding: str
+    comment: str | None
+    skip_empty_rows: bool
+    null_values: list[str] | None
+    decimal_separator: str | None
+    thousands_separator: str | None
+    scientific_format: str | None
+    text_columns: list[int] | None
+    sanitize_strings: bool
 
 def csv_to_xlsx(
     input_path: PathArg,
@@ -338,6 +614,28 @@ def csv_to_xlsx(
     sheet_name: str = "Sheet1",
     parallel: bool = False,
     date_order: DateOrder = "auto",
+    date_format: str | None = None,
+    datetime_format: str | None = None,
+    time_format: str | None = None,
+    nan_policy: NanPolicy = "empty",
+    large_int_mode: LargeIntMode = "string",
+    long_string_mode: LongStringMode = "error",
+    constant_memory: bool = False,
+    smart_numeric: bool = False,
+    delimiter: str | None = None,
+    quote: str | None = None,
+    escape: str | None = None,
+    has_header: bool = False,
+    header_format: HeaderFormat | None = None,
+    encoding: str = "utf-8",
+    comment: str | None = None,
+    skip_empty_rows: bool = False,
+    null_values: list[str] | None = None,
+    decimal_separator: str | None = None,
+    thousands_separator: str | None = None,
+    scientific_format: str | None = None,
+    text_columns: list[int] | None = None,
+    sanitize_strings: bool = False,
 ) -> tuple[int, int]:
     """Convert a CSV file to XLSX format with automatic type detection.
 
@@ -347,10 +645,81 @@ def csv_to_xlsx(
         sheet_name: Name of the worksheet (default: "Sheet1").
         parallel: Use multi-core parallel processing (default: False).
             Faster for large files (100K+ rows) but uses more memory.
+            Cannot be combined with constant_memory.
         date_order: Date parsing order for ambiguous dates like "01-02-2024".
             "auto" - ISO first, then European (DMY), then US (MDY).
             "mdy" or "us" - US format: 01-02-2024 = January 2nd.
             "dmy" or "eu" - European format: 01-02-2024 = February 1st.
+        date_format: Excel number format for date cells (default: "yyyy-mm-dd").
+            Example: "dd/mm/yyyy".
+        datetime_format: Excel number format for datetime cells
+            (default: "yyyy-mm-dd hh:mm:ss"). Example: "dd/mm/yyyy hh:mm".
+        time_format: Excel number format for time-of-day cells such as "14:30:00"
+            (default: "hh:mm:ss"). Example: "h:mm AM/PM".
+        nan_policy: How NaN/Inf values are written (default: "empty").
+            "empty" - an empty cell
+            "string" - the text "NaN", "Inf", or "-Inf"
+            "error" - the Excel error #N/A, so dependent formulas see an error
+        large_int_mode: How integers beyond 2^53 (which an Excel number can't
+            hold exactly) are written (default: "string").
+            "string" - a text cell holding every digit
+            "number" - an Excel number, accepting float rounding of the low digits
+            "text" - a text cell with the "@" (Text) number format
+        long_string_mode: How strings longer than Excel's 32,767-character
+            cell limit are written (default: "error").
+            "error" - raise ValueError naming the cell
+            "truncate" - keep the first 32,767 characters
+            "split" - keep the first 32,767 characters in the cell and the
+                rest (up to 32,713 more) in a note on it
+        constant_memory: Stream rows to disk as they are written instead of
+            holding the worksheet in memory (default: False). Use for very
+            large files. Cannot be combined with parallel.
+        smart_numeric: Detect formatted numbers that would otherwise stay text
+            (default: False). "45%" is written as 0.45 with a percent format,
+            "$1,234.50" (also €, £, ¥) as 1234.5 with a "#,##0.00" format, and
+            "1,234,567" as a number.
+        delimiter: Field separator, a single ASCII character such as ";" or
+            "\t" (default: ",").
+        quote: Character that quotes a field containing the delimiter or line
+            breaks (default: '"'). A doubled quote inside a quoted field is a
+            literal quote.
+        escape: Character that escapes a quote inside a quoted field, e.g.
+            "\\" for files written as \" (default: None).
+        has_header: Treat the first row as a header (default: False). Its
+            fields are written as text even when they look like numbers or
+            dates, and the panes are frozen below it, matching df_to_xlsx.
+        header_format: Format dict for the header row, e.g.
+            {"bold": True, "bg_color": "#DDEBF7"}. Requires has_header=True.
+        encoding: Text encoding of the input file (default: "utf-8"), e.g.
+            "windows-1252", "latin-1", "iso-8859-15", "shift_jis", or
+            "utf-16". A byte-order mark in the file takes precedence.
+        comment: Character that marks a comment line, e.g. "#" (default:
+            None). Lines starting with it are skipped.
+        skip_empty_rows: Skip rows whose fields are all empty or whitespace,
+            such as ",,," separator lines (default: False). Lines with no
+            content at all are always skipped. Skipped rows are not counted
+            or written.
+        null_values: Field values written as empty cells, such as
+            ["NA", "N/A", "NULL", "-"] (default: None). Matching ignores case
+            and surrounding whitespace; the header row is not affected.
+        decimal_separator: Decimal separator of numbers in the file, e.g.
+            "," for European files (default: None, meaning ".").
+        thousands_separator: Thousands separator of numbers in the file,
+            e.g. "." or " " (default: None). With decimal_separator=",",
+            "1.234,56" is written as 1234.56. Groups must be well-formed
+            ("12.34,5" stays text), and the two separators must differ.
+        scientific_format: Excel number format for numbers written in
+            scientific notation, such as "1.5e10" (default: None, General
+            format). For example, "0.00E+00" shows 1.50E+10; the full value
+            is still stored.
+        text_columns: 0-based column positions whose fields are written as
+            text as-is, with Excel's "@" format, skipping type detection
+            (default: None). "01234" keeps its leading zero. Empty fields and
+            null_values still become empty cells.
+        sanitize_strings: Strip a leading byte order mark (U+FEFF) and
+            replace control characters XML forbids (below U+0020 except tab,
+            line feed, and carriage return) with spaces in every field before
+            it is parsed (default: False).
 
     Note:
         String cells preserve surrounding whitespace (e.g. " padded " is
@@ -366,73 +735,202 @@ def csv_to_xlsx(
         Tuple of (rows, columns) written to the Excel file.
 
     Raises:
-        ValueError: If the conversion fails.
+        ValueError: If the conversion fails or a number format is invalid.
+    """
+
+def csvs_to_xlsx(
+    inputs: list[tuple[PathArg, str] | tuple[PathArg, str, CsvSheetOptions]],
+    output_path: PathArg,
+    parallel: bool = False,
+    date_order: DateOrder = "auto",
+    date_format: str | None = None,
+    datetime_format: str | None = None,
+    time_format: str | None = None,
+    nan_policy: NanPolicy = "empty",
+    large_int_mode: LargeIntMode = "string",
+    long_string_mode: LongStringMode = "error",
+    constant_memory: bool = False,
+    smart_numeric: bool = False,
+    delimiter: str | None = None,
+    quote: str | None = None,
+    escape: str | None = None,
+    has_header: bool = False,
+    header_format: HeaderFormat | None = None,
+    encoding: str = "utf-8",
+    comment: str | None = None,
+    skip_empty_rows: bool = False,
+    null_values: list[str] | None = None,
+    decimal_separator: str | None = None,
+    thousands_separator: str | None = None,
+    scientific_format: str | None = None,
+    text_columns: list[int] | None = None,
+    sanitize_strings: bool = False,
+) -> list[tuple[int, int]]:
+    """Convert several CSV files to one XLSX workbook, one sheet per file.
+
+    Sheets are written in list order. Every csv_to_xlsx option can be given
+    as a keyword argument (the default for every sheet) or in a sheet's
+    options dict (that sheet only).
+
+    Args:
+        inputs: List of (csv_path, sheet_name) or
+            (csv_path, sheet_name, options_dict) tuples. See CsvSheetOptions
+            for the options dict keys.
+        output_path: Path for the output XLSX file.
+        parallel: Parse each file's rows in parallel, as in csv_to_xlsx
+            (default: False). Cannot be combined with constant_memory.
+        date_order, date_format, datetime_format, time_format, nan_policy,
+        large_int_mode, long_string_mode, constant_memory, smart_numeric,
+        delimiter, quote, escape, has_header, encoding, comment,
+        skip_empty_rows, null_values, decimal_separator, thousands_separator,
+        scientific_format, text_columns, sanitize_strings: Defaults for every sheet, with the same meaning and defaults as in csv_to_xlsx.
+        header_format: Default format dict for the header row. Applies to
+            the sheets with has_header; a header_format in a sheet's options
+            dict requires has_header for that sheet.
+
+    Returns:
+        List of (rows, columns) tuples, one per sheet in input order.
+
+    Raises:
+        ValueError: If inputs is empty, an option is unknown or invalid, or a
+            conversion fails (the message names the sheet).
     """
 
 def df_to_xlsx(
     df: object,
-    output_path: PathArg,
+    output_path: OutputArg,
     sheet_name: str = "Sheet1",
     header: bool = True,
-    autofit: bool = False,
+    autofit: Autofit = False,
     table_style: str | None = None,
-    freeze_panes: bool = False,
+    freeze_panes: FreezePanes = False,
     column_widths: dict[int | str, int | float] | None = None,
     table_name: str | None = None,
     header_format: HeaderFormat | None = None,
     row_heights: dict[int, int | float] | None = None,
     constant_memory: bool = False,
-    column_formats: dict[str, ColumnFormat] | None = None,
+    column_formats: dict[str | int, ColumnFormat] | None = None,
     conditional_formats: dict[str, ConditionalFormat | list[ConditionalFormat]] | None = None,
-    formula_columns: dict[str, str] | None = None,
-    merged_ranges: list[tuple[str, str] | tuple[str, str, HeaderFormat]] | None = None,
-    hyperlinks: list[tuple[str, str] | tuple[str, str, str]] | None = None,
+    formula_columns: dict[str, str | FormulaColumnOptions] | None = None,
+    merged_ranges: list[MergedRangeSpec] | None = None,
+    hyperlinks: list[HyperlinkSpec] | None = None,
     comments: dict[str, str | CommentOptions] | None = None,
     validations: dict[str, ValidationOptions] | None = None,
     rich_text: dict[str, list[tuple[str, RichTextFormat] | str]] | None = None,
-    images: dict[str, str | ImageOptions] | None = None,
+    images: dict[str, str | bytes | ImageOptions] | None = None,
     checkboxes: dict[str, bool | CheckboxOptions] | None = None,
     textboxes: dict[str, str | TextboxOptions] | None = None,
     charts: dict[str, ChartOptions] | None = None,
     defined_names: dict[str, str] | None = None,
     cells: dict[str, str | int | float | bool | CellValueOptions] | None = None,
+    cell_overrides: dict[str, CellOverride] | None = None,
     sparklines: dict[str, SparklineOptions] | None = None,
+    date_format: str | None = None,
+    datetime_format: str | None = None,
+    time_format: str | None = None,
+    nan_policy: NanPolicy = "empty",
+    large_int_mode: LargeIntMode = "string",
+    long_string_mode: LongStringMode = "error",
+    start_row: int = 0,
+    start_col: int = 0,
+    autofilter: bool = False,
+    conditional_format_mode: ConditionalFormatMode = "all_matches",
+    freeze_top_cell: str | None = None,
+    split_panes: str | None = None,
+    show_gridlines: bool = True,
+    show_row_col_headers: bool = True,
+    zoom: int | None = None,
+    view: SheetView = "normal",
+    right_to_left: bool = False,
+    outline_below: bool = True,
+    outline_right: bool = True,
+    tab_color: str | None = None,
+    page_setup: PageSetup | None = None,
+    print_area: str | None = None,
+    background_image: str | bytes | None = None,
+    row_groups: list[OutlineGroup] | None = None,
+    col_groups: list[OutlineGroup] | None = None,
+    table_columns: list[TableColumnOptions] | None = None,
+    table_options: TableOptions | None = None,
+    tables: list[TableSpec] | None = None,
+    categorical_validation: bool = False,
+    integer_like_floats: bool = False,
+    columns: list[str | int] | None = None,
+    skip_rows: int = 0,
+    max_rows: int | None = None,
+    transpose: bool = False,
+    include_index: bool = False,
+    cell_format: ColumnFormat | None = None,
+    banded_rows: tuple[str, str] | list[str] | None = None,
+    summary_row: dict[str, SummaryFunction] | None = None,
+    default_col_width: float | None = None,
+    default_row_height: float | None = None,
+    protect: bool | ProtectOptions | None = None,
+    auto_hyperlinks: bool = False,
+    auto_rich_text_columns: list[str] | None = None,
+    infer_string_types: bool = False,
+    bool_mode: BoolMode = "boolean",
+    text_columns: list[str | int] | None = None,
+    sanitize_strings: bool = False,
+    link_format: ColumnFormat | None = None,
+    calc_mode: Literal["auto", "manual", "auto_except_tables"] = "auto",
+    force_recalc: bool = True,
+    properties: DocumentProperties | None = None,
+    custom_properties: dict[str, str | int | float | bool | date | datetime] | None = None,
+    deterministic: bool = False,
+    progress_callback: Callable[[int], object] | None = None,
+    progress_every: int = 100_000,
 ) -> tuple[int, int]:
     """Convert a pandas or polars DataFrame to XLSX format.
 
     Args:
-        df: pandas DataFrame or polars DataFrame to export.
-        output_path: Path for the output XLSX file.
+        df: pandas DataFrame, polars DataFrame, or polars LazyFrame to export.
+            A LazyFrame is collected before writing.
+        output_path: Path for the output XLSX file, or a binary file-like
+            object with a write() method, which receives the whole workbook.
         sheet_name: Name of the worksheet (default: "Sheet1").
-        header: Include column names as header row (default: True).
+        header: Include column names as header row (default: True). A
+            pandas column MultiIndex gets one header row per level, with
+            repeated upper-level labels merged across their columns.
         autofit: Automatically adjust column widths to fit content (default: False).
-            Combined with column_widths: explicit widths win for the columns
-            they name; every other column is still autofitted (rather than
-            left at Excel's default width). Add an '_all' entry in
-            column_widths to cap the autofit width instead of overriding it.
+            Combined with column_widths: each autofitted column is capped at
+            its own column_widths entry, else at '_all', rather than set to it.
+            A list of column names and/or 0-based positions autofits only
+            those columns; the rest keep column_widths or Excel's default.
+            An autofitted column is at least as wide as its header label
+            plus two characters, so bold headers are not clipped.
         table_style: Apply Excel table formatting (default: None).
             Styles: "Light1"-"Light21", "Medium1"-"Medium28", "Dark1"-"Dark11", "None".
-        freeze_panes: Freeze the header row for easier scrolling (default: False).
+            With header=False the table is created without a header row.
+        freeze_panes: Freeze panes for easier scrolling (default: False). True
+            freezes the header row; a cell reference like "C2" or a 0-based
+            (row, col) tuple freezes everything above and left of that cell.
+            Positions are absolute, not offset by start_row/start_col. A
+            {"rows": N, "cols": M} dict freezes the first N rows and M
+            columns of the written block, counting from start_row/start_col.
         column_widths: Dict mapping column index to width. Use '_all' to cap all columns.
             An integer key must be a non-negative index within Excel's column range
             (0..=16383); a negative key, a key beyond 16383, or a non-integer/non-'_all'
             key raises. A key beyond the DataFrame's column count is applied to that
-            column anyway (it is no longer silently ignored). With autofit=True and
-            no '_all' key: listed columns get the explicit width, unlisted columns
-            are autofitted. With autofit=True and an '_all' key: '_all' caps the
-            autofit width for unlisted columns instead of overriding it.
+            column anyway (it is no longer silently ignored). With autofit, the
+            widths of autofitted columns are maximums: each is fitted to its
+            content, then capped at its own entry, else at '_all'.
         table_name: Custom name for the Excel table (requires table_style).
             Effective table names must be unique across the workbook after sanitization.
         header_format: Dict of header cell formatting options.
         row_heights: Dict mapping row index to height in points.
         constant_memory: Use streaming mode for minimal RAM usage (default: False).
             When enabled, emits RuntimeWarning and disables: table_style, freeze_panes,
-            row_heights, autofit, column_widths with autofit cap, conditional_formats,
-            formula_columns, merged_ranges, hyperlinks, comments, validations, rich_text,
-            images, checkboxes, textboxes, charts, sparklines, and cells. Plain column_widths,
+            autofilter, row_heights, autofit, column_widths with autofit cap, conditional_formats,
+            formula_columns, merged_ranges, hyperlinks, row_groups, col_groups, comments,
+            validations, categorical_validation, rich_text, images, checkboxes, textboxes,
+            charts, sparklines, cells, and cell_overrides. Plain column_widths,
             header_format, and column_formats remain supported.
-        column_formats: Dict mapping column name patterns to format options.
+        column_formats: Dict mapping column name patterns or column indices to format options.
             Patterns: 'prefix*', '*suffix', '*contains*', or exact match.
+            An int key (e.g. 2) targets that DataFrame column by position, for
+            duplicate or numeric column names, and takes precedence over patterns.
+            An out-of-range index raises ValueError.
             First matching pattern wins (order preserved).
             Every pattern must match at least one column or ValueError is raised.
         conditional_formats: Dict mapping column names to conditional format configs.
@@ -440,16 +938,34 @@ def df_to_xlsx(
             Every name or pattern must match at least one column.
             Example: {'score': {'type': '2_color_scale', 'min_color': '#FF0000', 'max_color': '#00FF00'}}
         formula_columns: Dict mapping new column names to Excel formula templates.
-            Use {row} placeholder for the current row number (1-based Excel row).
-            Example: {'Total': '=A{row}+B{row}', 'Percentage': '=C{row}/D{row}*100'}
-        merged_ranges: List of (range, text) or (range, text, format) tuples to merge cells.
-            Range uses Excel notation (e.g., 'A1:D1'). Format uses HeaderFormat options.
+            Use {row} placeholder for the current row number (1-based Excel row),
+            and {col_name} for that column's cell in the current row (data or
+            formula columns; an unknown name raises ValueError).
+            Example: {'Total': '=A{row}+B{row}', 'Percentage': '={Total}/{budget}*100'}
+            A dict value {'formula': ..., 'formula_type': ..., 'range': ...} picks
+            'regular' (default), 'dynamic' (one spilling formula in the first data
+            row; needs Excel 365 or 2021+), or 'array' (one CSE formula over
+            'range', default the column's data rows). A 'position' (a 0-based
+            column index, or 'after:col_name') inserts the column among the data
+            columns, shifting the later ones right; a table then includes it.
+            A 'formula_result' (a constant, or a callable taking the 0-based
+            DataFrame row index) is cached as the formula's value, for readers
+            that don't recalculate.
+        merged_ranges: List of (range, value) or (range, value, format) tuples to merge cells.
+            Range uses Excel notation (e.g., 'A1:D1'). Format uses ColumnFormat options.
+            A number, bool, date, or datetime value keeps its type (dates get
+            date_format/datetime_format unless the format sets num_format).
             Example: [('A1:B1', 'Title'), ('C1:D1', 'Subtitle', {'bold': True})]
-        hyperlinks: List of (cell, url) or (cell, url, display_text) tuples for clickable links.
-            Cell uses Excel notation (e.g., 'A1'). Display text is optional.
+        hyperlinks: List of (cell, url), (cell, url, display_text), or
+            (cell, url, display_text, tooltip) tuples for clickable links.
+            Cell uses Excel notation (e.g., 'A1'). Display text is optional;
+            the tooltip (at most 255 characters) is shown on hover.
             Example: [('A2', 'https://example.com'), ('B2', 'https://google.com', 'Google')]
+            A url like 'internal:Summary!A1' jumps to a cell in the workbook;
+            the sheet must exist, or ValueError is raised.
         comments: Dict mapping cell refs to comment text or CommentOptions.
             Example: {'A1': 'Simple note'} or {'A1': {'text': 'Note', 'author': 'John'}}
+            The dict form also takes width, height, bg_color, font_size, and visible.
         validations: Dict mapping column name/pattern to data validation config.
             Every name or pattern must match at least one column.
             Types: 'list' (dropdown), 'whole_number', 'decimal', 'text_length'.
@@ -459,7 +975,7 @@ def df_to_xlsx(
             instead of a misleading generic type error.
         rich_text: Dict mapping cell refs to list of (text, format) tuples or plain strings.
             Example: {'A1': [('Bold', {'bold': True}), ' normal text']}
-        images: Dict mapping cell refs to image path or ImageOptions.
+        images: Dict mapping cell refs to image path, image bytes, or ImageOptions.
             Example: {'B5': 'logo.png'} or {'B5': {'path': 'logo.png', 'scale_width': 0.5}}
         checkboxes: Dict mapping cell refs to interactive checkboxes.
             Simple form: {'A1': True, 'A2': False}
@@ -477,6 +993,9 @@ def df_to_xlsx(
             must include a sheet name (e.g. 'Sheet1!$B$2:$B$10'); a bare range raises ValueError.
             Example: {'D2': {'type': 'bar', 'data_range': 'Sheet1!$B$2:$B$10',
                       'categories_range': 'Sheet1!$A$2:$A$10', 'title': 'Monthly Activity'}}
+            Or name written columns instead of ranges: 'value_cols' (a column
+            name or list, one series each) with an optional 'categories_col',
+            e.g. {'D2': {'type': 'column', 'categories_col': 'Month', 'value_cols': ['Sales']}}
         sparklines: Dict mapping a location ref to a sparkline (mini in-cell chart) config.
             A single-cell key (e.g. 'D2') places one sparkline; a range key (e.g. 'D2:D10')
             places a grouped sparkline, one per row of the data range. 'range' is required and
@@ -488,6 +1007,243 @@ def df_to_xlsx(
             Values can be simple (str, int, float, bool) or dicts with 'value' and optional 'num_format'.
             Cells are written after DataFrame data, so they can overwrite existing values.
             Example: {'B9': 'Label', 'D6': {'value': '934728173849', 'num_format': '@'}}
+        cell_overrides: Dict mapping cell refs to a dict with 'value' and/or
+            'format' (a full format dict, as for column_formats). Written after
+            the DataFrame data and cells, so they can override any cell; a
+            'format' without 'value' restyles the cell, keeping its value
+            (default: None). Example: {'D2': {'value': 'Check', 'format': {'bg_color': '#FFFF00'}}}
+        date_format: Excel number format for date cells (default: 'yyyy-mm-dd').
+            A column_formats 'num_format' still takes precedence for its columns.
+            An invalid format raises ValueError. Example: 'dd/mm/yyyy'.
+        datetime_format: Excel number format for datetime cells
+            (default: 'yyyy-mm-dd hh:mm:ss'). Example: 'dd/mm/yyyy hh:mm'.
+        time_format: Excel number format for datetime.time cells
+            (default: 'hh:mm:ss'). Example: 'h:mm AM/PM'.
+        nan_policy: How NaN/Inf values are written (default: 'empty').
+            'empty' - an empty cell
+            'string' - the text 'NaN', 'Inf', or '-Inf'
+            'error' - the Excel error #N/A, so dependent formulas see an error
+        large_int_mode: How integers beyond 2^53 (which an Excel number can't
+            hold exactly) are written (default: 'string').
+            'string' - a text cell holding every digit
+            'number' - an Excel number, accepting float rounding of the low digits
+            'text' - a text cell with the '@' (Text) number format
+        long_string_mode: How strings longer than Excel's 32,767-character
+            cell limit are written (default: 'error').
+            'error' - raise ValueError naming the cell
+            'truncate' - keep the first 32,767 characters
+            'split' - keep the first 32,767 characters in the cell and the
+                rest (up to 32,713 more) in a note on it
+        start_row: Zero-based sheet row for the header (or first data row)
+            (default: 0).
+        start_col: Zero-based sheet column for the first DataFrame column
+            (default: 0). The data, table, formula columns, conditional
+            formats, validations, and freeze panes move with the offset.
+            Cell-ref features (cells, merged_ranges, hyperlinks, ...),
+            column_widths keys, and row_heights keys stay absolute.
+        autofilter: Add autofilter dropdowns over the written data (header
+            row through the last data row, formula columns included) without
+            creating a table (default: False). Ignored when a table_style table
+            is created, since tables have their own filter. With header=False
+            the first data row holds the dropdowns.
+        conditional_format_mode: How conditional_formats patterns that match
+            the same column combine (default: 'all_matches'). 'all_matches'
+            stacks every matching pattern's rules on the column;
+            'first_match_wins' applies only the first matching pattern (in
+            dict order), like column_formats.
+        freeze_top_cell: Top-left cell of the scrollable pane when panes are
+            frozen or split, e.g. 'A100' opens the area below a frozen header
+            scrolled to row 100 (default: None). Has no effect without
+            freeze_panes or split_panes.
+        split_panes: Cell reference like 'B3' where the window is split into
+            resizable, unfrozen panes (default: None). The dividers start at
+            the given cell for default row heights and column widths and can
+            be dragged. Mutually exclusive with freeze_panes.
+        show_gridlines: Show the worksheet gridlines on screen (default:
+            True). False gives a clean dashboard look; printing is unaffected.
+        show_row_col_headers: Show the row numbers and column letters on
+            screen (default: True). False hides them for a dashboard look;
+            printing is unaffected.
+        zoom: Zoom level as a percentage from 10 to 400 (default: None,
+            Excel's 100).
+        view: View the sheet opens in (default: "normal"): 'normal' for the
+            ordinary grid, 'page_layout' for pages as printed with headers and
+            footers, 'page_break' for the grid with page breaks marked.
+        right_to_left: Show the sheet right to left, with column A at the
+            right edge, for Arabic or Hebrew reports (default: False). Only
+            the view is mirrored; the data keeps its column order and cell
+            references.
+        outline_below: Put the row_groups expand/collapse buttons below each
+            group, where a summary row follows its details (default: True).
+            False puts them above, for summaries that head their group.
+        outline_right: Put the col_groups expand/collapse buttons right of
+            each group (default: True). False puts them on the left.
+        tab_color: Color of the sheet tab, as '#RRGGBB' or a named color
+            (default: None).
+        page_setup: Print layout settings (default: None): orientation
+            ('portrait' or 'landscape'), fit_to_pages ((width, height) in
+            pages, 0 meaning as many as needed), paper_size (Excel paper
+            index, e.g. 1 = Letter, 9 = A4), and margins (dict of left,
+            right, top, bottom, header, footer in inches), header and footer
+            (Excel header/footer format strings such as '&CPage &P of &N',
+            at most 255 characters), header_images and footer_images (dicts
+            of 'left'/'center'/'right' to an image path, each needing a
+            &[Picture] placeholder in that section), and
+            header_footer_scale_with_doc (bool). Only affects printing, not
+            the data.
+        print_area: Cell range to print, e.g. 'A1:F50' (default: None, the
+            whole used range). Honored as given, even when it extends past
+            the written data.
+        background_image: Image path or bytes shown tiled behind the cells,
+            e.g. a "DRAFT" watermark (default: None). Excel doesn't print
+            background images, so this is for on-screen viewing only.
+        row_groups: List of (first_row, last_row, level) or (first_row,
+            last_row, level, collapsed) tuples outlining absolute 0-based
+            sheet rows as collapsible groups (default: None). level is 1-7;
+            overlapping groups keep the deepest level per row.
+            collapsed=True starts the group collapsed.
+        col_groups: List of (first_col, last_col, level[, collapsed]) tuples
+            outlining absolute 0-based sheet columns, as for row_groups
+            (default: None).
+        table_columns: List of per-column dicts for the table_style table,
+            matched to the columns from the left (default: None). Keys:
+            header, total_function or total_label. Any total adds a totals
+            row below the data, counted in the returned rows. Ignored
+            without table_style.
+        table_options: Dict of table style toggles (default: None). Keys:
+            banded_rows (default True), banded_columns, first_column and
+            last_column (default False). Ignored without table_style.
+        tables: List of extra tables over explicit ranges (default: None).
+            Keys: range (required, e.g. "A1:C10"; its first row is the
+            header row), style (default "Medium9"), name, and total_row (the
+            range's last row is the totals row). Ranges must not overlap each
+            other or the table_style table or autofilter range.
+        categorical_validation: Add a dropdown list validation over each
+            categorical column (polars Categorical/Enum, pandas category)
+            listing its categories (default: False). Columns already covered
+            by validations keep those. Categorical values are always written
+            as their label text.
+        integer_like_floats: Write whole-number values of float columns with
+            an integer number format ("0"), so e.g. 3.0 displays as 3
+            (default: False). Fractional values, other dtypes, and columns
+            with a column_formats entry are unaffected.
+        columns: List of column names and/or 0-based positions to write, in
+            output order (default: None, all columns). Every other option
+            (column_formats int keys, formula_columns, conditional_formats,
+            table_columns, charts, ...) sees only the selected columns, in
+            the selected order. An empty list writes all columns; an unknown
+            name, an out-of-range index, or a column selected twice raises
+            ValueError.
+        skip_rows: Number of leading DataFrame rows to leave out (default: 0).
+        max_rows: Write at most this many DataFrame rows, after skip_rows
+            (default: None, no limit). Tables, autofilters, conditional
+            formats, validations, and the returned row count all cover only
+            the written rows.
+        transpose: Write the DataFrame transposed (default: False): column
+            names go down the first column and each DataFrame row becomes a
+            sheet column. Cell types are still detected per value and
+            column_formats still apply to each original column's cells.
+            Features keyed by column name (table_style, autofilter,
+            table_columns, formula_columns, conditional_formats, validations,
+            categorical_validation, summary_row) don't map onto transposed
+            data and are skipped with a RuntimeWarning. Cannot be combined
+            with constant_memory.
+        include_index: Write the pandas index as the leading column(s),
+            headed by the index name ("index" when unnamed), as
+            DataFrame.reset_index() would (default: False). A MultiIndex
+            becomes one column per level. Options that address written
+            columns (column_formats index keys, column_widths, charts) count
+            the index columns; name-keyed options can target them by name.
+            Ignored for polars, which has no index.
+        cell_format: Format dict applied to every data cell without a
+            column_formats entry (default: None), e.g. a font and borders
+            for the whole sheet. Same keys as column_formats. Date, time,
+            and other typed cells keep their number format on top of it; a
+            matching column_formats entry replaces it for that column.
+            Header cells use header_format instead.
+        banded_rows: Pair of fill colors, e.g. ("#FFFFFF", "#F2F2F2"),
+            alternated across data rows starting with the first (default:
+            None). Works without a table; cell_format and column_formats
+            keep their other properties on top of the fill, and a column's
+            own bg_color wins. Formula columns are not banded. With
+            transpose=True the bands run across columns.
+        summary_row: Dict mapping column names to "sum", "mean", "min",
+            "max", or "count" (default: None). Writes a bold row of
+            SUM/AVERAGE/MIN/MAX/COUNTA formulas over each column's data
+            directly below the written block (after a table's totals row),
+            with the value computed from the DataFrame cached for readers
+            that don't recalculate. The returned row count includes it.
+            Needs at least one data row.
+        default_col_width: Width of every column on the sheet, in characters
+            (default: None, Excel's 8.43). column_widths and autofit override
+            it for the columns they set.
+        default_row_height: Height of every row on the sheet, in points
+            (default: None, Excel's 15). row_heights overrides it for the
+            rows it sets.
+        protect: Protect the sheet against edits (default: None). True
+            locks every cell; a dict takes an optional "password" and
+            "unlocked_columns", a list of column names or wildcard patterns
+            whose data cells stay editable. A pattern matching no column
+            raises ValueError. Validations still apply to the unlocked cells.
+        auto_hyperlinks: Write string values starting with "http://",
+            "https://", or "mailto:" as clickable hyperlinks (default: False).
+            Values with whitespace or longer than Excel's 2079-character URL
+            limit stay plain text.
+        link_format: Format dict for the cells auto_hyperlinks turns into
+            links (default: None, Excel's blue underlined hyperlink style over
+            the cell's usual format). Same keys as column_formats.
+        auto_rich_text_columns: List of column names or wildcard patterns
+            whose string values are parsed for inline markup, **bold** and
+            *italic*, and written as rich text (default: None). An unpaired
+            marker or a backslash-escaped \\* stays literal. A pattern
+            matching no column raises ValueError.
+        infer_string_types: Write string values that read as numbers,
+            booleans, dates, datetimes, or times as those types, with the same
+            detection csv_to_xlsx uses (default: False). Useful for all-string
+            DataFrames such as read_csv(dtype=str) output. Leading zeros are
+            not kept: "007" becomes 7.
+        bool_mode: How booleans are written (default: "boolean", Excel
+            TRUE/FALSE): "yes_no" writes the text "Yes"/"No", "one_zero" the
+            numbers 1/0, and a (true_text, false_text) pair of strings writes
+            custom labels. Column formats and cell_format still apply.
+        text_columns: Column names and/or 0-based positions among the written
+            columns whose values are written as text with Excel's "@" format
+            (default: None). Each value is written as its str(), skipping type
+            detection and infer_string_types, so "01234" keeps its leading
+            zero. Nulls stay empty cells. An unknown name or out-of-range
+            position raises ValueError.
+        sanitize_strings: Strip a leading byte order mark (U+FEFF) and
+            replace control characters XML forbids (below U+0020 except tab,
+            line feed, and carriage return) with spaces in string values and
+            header labels before writing (default: False). This keeps stray
+            NUL or other control bytes from leaving Excel with a file it
+            offers to repair.
+        calc_mode: Workbook calculation mode: "auto" (default), "manual", or
+            "auto_except_tables" (automatic except for data tables).
+        force_recalc: Have Excel recalculate every formula when the file is
+            opened (default: True). False makes Excel trust the cached formula
+            values, such as those set by formula_result.
+        properties: Document properties dict with any of "title", "subject",
+            "author", "manager", "company", "keywords", "comments",
+            "category", and "created" (an ISO 8601 date or datetime, taken as
+            UTC without an offset) (default: None). An unknown key or an
+            unparseable "created" raises ValueError.
+        custom_properties: Dict of custom document properties, mapping a name
+            to a str, int, float, bool, date, or datetime value (default:
+            None). Datetimes are stored in UTC (a naive one is taken as UTC).
+            Any other value type raises TypeError; a non-finite float or an
+            int beyond 2**53 raises ValueError.
+        deterministic: Write byte-identical output for identical inputs
+            (default: False), e.g. for artifact caching. The created
+            timestamp is fixed at 1980-01-01 instead of the time of writing,
+            unless properties sets "created".
+        progress_callback: Callable invoked with the number of data rows
+            written so far after every progress_every rows (default: None).
+            It runs with the GIL held; an exception it raises is reported as
+            a RuntimeWarning and the write continues. Returning False cancels
+            the write: nothing is saved and RuntimeError is raised.
+        progress_every: Rows between progress_callback calls (default:
+            100000). Must be at least 1.
 
     Note:
         date/datetime values before 1900-03-01 cannot be represented as a
@@ -499,80 +1255,156 @@ def df_to_xlsx(
         real Excel datetimes/dates, not as their str() representation.
 
     Returns:
-        Tuple of (rows, columns) written to the Excel file.
+        Tuple of (rows, columns): one past the last written row and column,
+        counted from A1, so a following block can start at start_row=rows.
+        Without an offset this is the number of rows and columns written.
     """
 
 def dfs_to_xlsx(
     sheets: list[tuple[object, str] | tuple[object, str, SheetOptions]],
-    output_path: PathArg,
+    output_path: OutputArg,
     header: bool = True,
-    autofit: bool = False,
+    autofit: Autofit = False,
     table_style: str | None = None,
-    freeze_panes: bool = False,
+    freeze_panes: FreezePanes = False,
     column_widths: dict[int | str, int | float] | None = None,
     table_name: str | None = None,
     header_format: HeaderFormat | None = None,
     row_heights: dict[int, int | float] | None = None,
     constant_memory: bool = False,
-    column_formats: dict[str, ColumnFormat] | None = None,
+    column_formats: dict[str | int, ColumnFormat] | None = None,
     conditional_formats: dict[str, ConditionalFormat | list[ConditionalFormat]] | None = None,
-    formula_columns: dict[str, str] | None = None,
-    merged_ranges: list[tuple[str, str] | tuple[str, str, HeaderFormat]] | None = None,
-    hyperlinks: list[tuple[str, str] | tuple[str, str, str]] | None = None,
+    formula_columns: dict[str, str | FormulaColumnOptions] | None = None,
+    merged_ranges: list[MergedRangeSpec] | None = None,
+    hyperlinks: list[HyperlinkSpec] | None = None,
     comments: dict[str, str | CommentOptions] | None = None,
     validations: dict[str, ValidationOptions] | None = None,
     rich_text: dict[str, list[tuple[str, RichTextFormat] | str]] | None = None,
-    images: dict[str, str | ImageOptions] | None = None,
+    images: dict[str, str | bytes | ImageOptions] | None = None,
     checkboxes: dict[str, bool | CheckboxOptions] | None = None,
     textboxes: dict[str, str | TextboxOptions] | None = None,
     charts: dict[str, ChartOptions] | None = None,
     defined_names: dict[str, str] | None = None,
     cells: dict[str, str | int | float | bool | CellValueOptions] | None = None,
+    cell_overrides: dict[str, CellOverride] | None = None,
     sparklines: dict[str, SparklineOptions] | None = None,
+    date_format: str | None = None,
+    datetime_format: str | None = None,
+    time_format: str | None = None,
+    nan_policy: NanPolicy = "empty",
+    large_int_mode: LargeIntMode = "string",
+    long_string_mode: LongStringMode = "error",
+    start_row: int = 0,
+    start_col: int = 0,
+    autofilter: bool = False,
+    conditional_format_mode: ConditionalFormatMode = "all_matches",
+    active_sheet: int | str | None = None,
+    freeze_top_cell: str | None = None,
+    split_panes: str | None = None,
+    show_gridlines: bool = True,
+    show_row_col_headers: bool = True,
+    zoom: int | None = None,
+    view: SheetView = "normal",
+    right_to_left: bool = False,
+    outline_below: bool = True,
+    outline_right: bool = True,
+    tab_color: str | None = None,
+    page_setup: PageSetup | None = None,
+    print_area: str | None = None,
+    background_image: str | bytes | None = None,
+    row_groups: list[OutlineGroup] | None = None,
+    col_groups: list[OutlineGroup] | None = None,
+    table_columns: list[TableColumnOptions] | None = None,
+    table_options: TableOptions | None = None,
+    tables: list[TableSpec] | None = None,
+    categorical_validation: bool = False,
+    integer_like_floats: bool = False,
+    columns: list[str | int] | None = None,
+    skip_rows: int = 0,
+    max_rows: int | None = None,
+    transpose: bool = False,
+    include_index: bool = False,
+    cell_format: ColumnFormat | None = None,
+    banded_rows: tuple[str, str] | list[str] | None = None,
+    summary_row: dict[str, SummaryFunction] | None = None,
+    default_col_width: float | None = None,
+    default_row_height: float | None = None,
+    protect: bool | ProtectOptions | None = None,
+    auto_hyperlinks: bool = False,
+    auto_rich_text_columns: list[str] | None = None,
+    infer_string_types: bool = False,
+    bool_mode: BoolMode = "boolean",
+    text_columns: list[str | int] | None = None,
+    sanitize_strings: bool = False,
+    link_format: ColumnFormat | None = None,
+    calc_mode: Literal["auto", "manual", "auto_except_tables"] = "auto",
+    force_recalc: bool = True,
+    properties: DocumentProperties | None = None,
+    custom_properties: dict[str, str | int | float | bool | date | datetime] | None = None,
+    deterministic: bool = False,
 ) -> list[tuple[int, int]]:
     """Write multiple DataFrames to separate sheets in a single workbook.
 
     Args:
         sheets: List of (DataFrame, sheet_name) or (DataFrame, sheet_name, options) tuples.
-        output_path: Path for the output XLSX file.
+            A polars LazyFrame is collected before writing.
+        output_path: Path for the output XLSX file, or a binary file-like
+            object with a write() method, which receives the whole workbook.
         header: Include column names as header row (default: True).
         autofit: Automatically adjust column widths (default: False).
-            Combined with column_widths: explicit widths win for the columns
-            they name; every other column is still autofitted (rather than
-            left at Excel's default width). Add an '_all' entry in
-            column_widths to cap the autofit width instead of overriding it.
-        table_style: Apply Excel table formatting (default: None).
-        freeze_panes: Freeze the header row (default: False).
+            Combined with column_widths: each autofitted column is capped at
+            its own column_widths entry, else at '_all', rather than set to it.
+            A list of column names and/or 0-based positions autofits only
+            those columns on every sheet; usually set per sheet instead.
+            An autofitted column is at least as wide as its header label
+            plus two characters.
+        table_style: Apply Excel table formatting (default: None). With
+            header=False the table is created without a header row.
+        freeze_panes: Freeze the header row (True), everything above and
+            left of a cell given as "C2" or a 0-based (row, col) tuple, or
+            the first rows/cols of the block given as a dict (default: False).
         column_widths: Dict mapping column index to width. Use '_all' to cap all columns.
             An integer key must be a non-negative index within Excel's column range
             (0..=16383); a negative key, a key beyond 16383, or a non-integer/non-'_all'
             key raises. A key beyond the DataFrame's column count is applied to that
-            column anyway (it is no longer silently ignored). With autofit=True and
-            no '_all' key: listed columns get the explicit width, unlisted columns
-            are autofitted. With autofit=True and an '_all' key: '_all' caps the
-            autofit width for unlisted columns instead of overriding it.
+            column anyway (it is no longer silently ignored). With autofit, the
+            widths of autofitted columns are maximums: each is fitted to its
+            content, then capped at its own entry, else at '_all'.
         table_name: Custom name for Excel tables (requires table_style). Effective
             table names must be unique across the workbook after sanitization.
         header_format: Dict of header cell formatting options.
         row_heights: Dict mapping row index to height in points.
         constant_memory: Use streaming mode (default: False).
             When enabled, emits RuntimeWarning and disables: table_style, freeze_panes,
-            row_heights, autofit, column_widths with autofit cap, conditional_formats,
-            formula_columns, merged_ranges, hyperlinks, comments, validations, rich_text,
-            images, checkboxes, textboxes, charts, sparklines, and cells. Plain column_widths,
+            autofilter, row_heights, autofit, column_widths with autofit cap, conditional_formats,
+            formula_columns, merged_ranges, hyperlinks, row_groups, col_groups, comments,
+            validations, categorical_validation, rich_text, images, checkboxes, textboxes,
+            charts, sparklines, cells, and cell_overrides. Plain column_widths,
             header_format, and column_formats remain supported.
-        column_formats: Dict mapping column name patterns to format options.
+        column_formats: Dict mapping column name patterns or column indices to format options.
             Patterns: 'prefix*', '*suffix', '*contains*', or exact match.
+            An int key (e.g. 2) targets that DataFrame column by position, for
+            duplicate or numeric column names, and takes precedence over patterns.
+            An out-of-range index raises ValueError.
             Every pattern must match at least one column or ValueError is raised.
         conditional_formats: Dict mapping column names to conditional format configs.
             Supported types: '2_color_scale', '3_color_scale', 'data_bar', 'icon_set', 'cell'.
             Every name or pattern must match at least one column.
         formula_columns: Dict mapping new column names to Excel formula templates.
-            Use {row} placeholder for the current row number (1-based Excel row).
-        merged_ranges: List of (range, text) or (range, text, format) tuples to merge cells.
-            Range uses Excel notation (e.g., 'A1:D1'). Format uses HeaderFormat options.
-        hyperlinks: List of (cell, url) or (cell, url, display_text) tuples for clickable links.
-            Cell uses Excel notation (e.g., 'A1'). Display text is optional.
+            Use {row} placeholder for the current row number (1-based Excel row),
+            and {col_name} for that column's cell in the current row.
+            A dict value adds 'formula_type' ('regular', 'dynamic', or 'array')
+            and, for arrays, a target 'range'. Dynamic formulas need Excel 365/2021+.
+            A 'position' (index or 'after:col_name') inserts it among the data columns,
+            and a 'formula_result' (constant or callable(row_index)) caches its value.
+        merged_ranges: List of (range, value) or (range, value, format) tuples to merge cells.
+            Range uses Excel notation (e.g., 'A1:D1'). Format uses ColumnFormat options.
+            Non-string values keep their type, as for df_to_xlsx.
+        hyperlinks: List of (cell, url), (cell, url, display_text), or
+            (cell, url, display_text, tooltip) tuples for clickable links.
+            Cell uses Excel notation (e.g., 'A1'). Display text is optional;
+            the tooltip (at most 255 characters) is shown on hover.
+            An 'internal:Sheet!A1' url may target any sheet in the list.
         comments: Dict mapping cell refs to comment text or CommentOptions.
         validations: Dict mapping column name/pattern to data validation config.
             Every name or pattern must match at least one column.
@@ -580,7 +1412,7 @@ def dfs_to_xlsx(
             a value outside that range raises ValueError naming the field and range,
             instead of a misleading generic type error.
         rich_text: Dict mapping cell refs to list of (text, format) tuples or plain strings.
-        images: Dict mapping cell refs to image path or ImageOptions.
+        images: Dict mapping cell refs to image path, image bytes, or ImageOptions.
         checkboxes: Dict mapping cell refs to interactive checkboxes.
             Simple form: {'A1': True}
             Dict form: {'A1': {'checked': True, 'format': {'bg_color': '#C6EFCE'}}}
@@ -591,6 +1423,8 @@ def dfs_to_xlsx(
             'data_range' and 'categories_range'/'categories' (including a chart-level
             fallback used by series without their own) must include a sheet name
             (e.g. 'Sheet1!$B$2:$B$10'); a bare range raises ValueError.
+            'categories_col'/'value_cols' name columns of the sheet's own
+            DataFrame instead.
         sparklines: Dict mapping a location ref to a sparkline (mini in-cell chart) config.
             Range key (e.g. 'D2:D10') makes a grouped sparkline; single cell makes one.
             'range' must be sheet-qualified, e.g. 'Sheet1!A2:C10'.
@@ -600,6 +1434,125 @@ def dfs_to_xlsx(
         cells: Dict mapping cell refs to values for arbitrary cell writes.
             Values can be simple (str, int, float, bool) or dicts with 'value' and optional 'num_format'.
             Example: {'B9': 'Label', 'D6': {'value': '934728173849', 'num_format': '@'}}
+        cell_overrides: Dict mapping cell refs to a value and/or format written
+            after the data, as for df_to_xlsx (default: None).
+        date_format: Excel number format for date cells (default: 'yyyy-mm-dd').
+        datetime_format: Excel number format for datetime cells
+            (default: 'yyyy-mm-dd hh:mm:ss').
+        time_format: Excel number format for datetime.time cells
+            (default: 'hh:mm:ss').
+        nan_policy: How NaN/Inf values are written: 'empty', 'string', or
+            'error' (default: 'empty').
+        large_int_mode: How integers beyond 2^53 are written: 'string',
+            'number', or 'text' (default: 'string').
+        long_string_mode: How strings over Excel's 32,767-character cell
+            limit are written: 'error', 'truncate', or 'split'
+            (default: 'error').
+        start_row: Zero-based sheet row where each sheet's data begins
+            (default: 0).
+        start_col: Zero-based sheet column where each sheet's data begins
+            (default: 0).
+        autofilter: Add autofilter dropdowns over each sheet's data without
+            creating a table (default: False).
+        conditional_format_mode: How conditional_formats patterns that match
+            the same column combine: 'all_matches' (rules stack) or
+            'first_match_wins' (first pattern in dict order only)
+            (default: 'all_matches').
+        active_sheet: Sheet shown when the workbook opens, as a 0-based index
+            into sheets or a sheet name (default: None, the first visible
+            sheet). Must not be a hidden sheet.
+        freeze_top_cell: Top-left cell of the scrollable pane when panes are
+            frozen or split, e.g. 'A100' opens the area below a frozen header
+            scrolled to row 100 (default: None). Has no effect without
+            freeze_panes or split_panes.
+        split_panes: Cell reference like 'B3' where the window is split into
+            resizable, unfrozen panes (default: None). The dividers start at
+            the given cell for default row heights and column widths and can
+            be dragged. Mutually exclusive with freeze_panes.
+        show_gridlines: Show the worksheet gridlines on screen (default:
+            True). False gives a clean dashboard look; printing is unaffected.
+        show_row_col_headers: Show the row numbers and column letters on
+            screen (default: True).
+        zoom: Zoom level of every sheet as a percentage from 10 to 400
+            (default: None).
+        view: View every sheet opens in: 'normal', 'page_layout', or
+            'page_break' (default: "normal"). Usually set per sheet instead.
+        right_to_left: Show every sheet right to left (default: False). Only
+            the view is mirrored; the data keeps its column order.
+        outline_below: Put row group buttons below each group (default:
+            True); False puts them above.
+        outline_right: Put column group buttons right of each group
+            (default: True); False puts them on the left.
+        tab_color: Color of every sheet tab, as '#RRGGBB' or a named color
+            (default: None). Usually set per sheet instead.
+        page_setup: Print layout settings for every sheet, as for df_to_xlsx
+            (default: None).
+        print_area: Cell range to print on every sheet, e.g. 'A1:F50'
+            (default: None). Usually set per sheet instead.
+        background_image: Image path or bytes tiled behind the cells of every
+            sheet, as for df_to_xlsx (default: None).
+        row_groups: Row outline groups for every sheet, as for df_to_xlsx
+            (default: None).
+        col_groups: Column outline groups for every sheet, as for df_to_xlsx
+            (default: None).
+        table_columns: Per-column table overrides for every sheet, as for
+            df_to_xlsx (default: None).
+        table_options: Table banding and first/last column emphasis for
+            every sheet, as for df_to_xlsx (default: None).
+        tables: Extra tables for every sheet, as for df_to_xlsx (default:
+            None). Table names must be unique across the workbook.
+        categorical_validation: Add a dropdown of its categories over each
+            categorical column on every sheet, as for df_to_xlsx
+            (default: False).
+        integer_like_floats: Write whole-number values of float columns with
+            an integer number format on every sheet, as for df_to_xlsx
+            (default: False).
+        columns: List of column names and/or 0-based positions to write on
+            every sheet, as for df_to_xlsx (default: None, all columns).
+        skip_rows: Number of leading DataFrame rows to leave out on every
+            sheet (default: 0).
+        max_rows: Write at most this many DataFrame rows per sheet, after
+            skip_rows (default: None, no limit).
+        transpose: Write every sheet transposed, as for df_to_xlsx
+            (default: False).
+        include_index: Write each pandas DataFrame's index as its leading
+            column(s), as for df_to_xlsx (default: False).
+        cell_format: Format dict applied to every data cell without a
+            column_formats entry, as for df_to_xlsx (default: None).
+        banded_rows: Pair of fill colors alternated across data rows, as for
+            df_to_xlsx (default: None).
+        summary_row: Dict mapping column names to an aggregate written in a
+            bold row below the data, as for df_to_xlsx (default: None).
+        default_col_width: Width of every column on each sheet, as for
+            df_to_xlsx (default: None).
+        default_row_height: Height of every row on each sheet, as for
+            df_to_xlsx (default: None).
+        protect: Protect each sheet against edits, as for df_to_xlsx
+            (default: None). A per-sheet False leaves that sheet unprotected.
+        auto_hyperlinks: Write URL and mailto: string values as hyperlinks on
+            every sheet, as for df_to_xlsx (default: False).
+        link_format: Format dict for auto_hyperlinks links, as for df_to_xlsx
+            (default: None).
+        auto_rich_text_columns: Columns whose **bold**/*italic* markup is
+            written as rich text on every sheet, as for df_to_xlsx (default: None).
+        infer_string_types: Write string values that read as numbers,
+            booleans, or dates as those types on every sheet, as for
+            df_to_xlsx (default: False).
+        bool_mode: How booleans are written on every sheet, as for
+            df_to_xlsx (default: "boolean").
+        text_columns: Columns written as text on every sheet, as for
+            df_to_xlsx (default: None).
+        sanitize_strings: Strip byte order marks and illegal control
+            characters from string values on every sheet, as for df_to_xlsx
+            (default: False).
+        calc_mode: Workbook calculation mode, as for df_to_xlsx (default: "auto").
+        force_recalc: Recalculate every formula when the file is opened, as
+            for df_to_xlsx (default: True).
+        properties: Document properties dict, as for df_to_xlsx (default: None).
+        custom_properties: Custom document properties dict, as for df_to_xlsx
+            (default: None).
+        deterministic: Write byte-identical output for identical inputs, as
+            for df_to_xlsx (default: False).
 
     Note:
         date/datetime values before 1900-03-01 cannot be represented as a
@@ -614,6 +1567,100 @@ def dfs_to_xlsx(
         List of (rows, columns) tuples, one per written sheet.
     """
 
+def df_sections_to_xlsx(
+    sections: list[tuple[object, str, int] | tuple[object, str, int, str | None]],
+    output_path: OutputArg,
+    header: bool = True,
+    autofit: bool = False,
+    table_style: str | None = None,
+    header_format: HeaderFormat | None = None,
+    title_format: HeaderFormat | None = None,
+) -> list[tuple[int, int]]:
+    """Write several DataFrames as stacked sections, sharing worksheets by name.
+
+    Args:
+        sections: List of (DataFrame, sheet_name, start_row) or
+            (DataFrame, sheet_name, start_row, title) tuples. start_row is
+            zero-based. A title is written in column A at start_row and the
+            DataFrame starts on the next row. Sections naming the same sheet
+            are written into one worksheet and must be listed top to bottom
+            without overlapping. A polars LazyFrame is collected before writing.
+        output_path: Path for the output XLSX file, or a binary file-like
+            object with a write() method, which receives the whole workbook.
+        header: Include column names as each section's header row (default: True).
+        autofit: Automatically adjust column widths to fit content (default: False).
+        table_style: Format each section as an Excel table with this style (default: None).
+        header_format: Header cell styles, as for df_to_xlsx (default: None).
+        title_format: Title cell styles, same keys as header_format (default: None).
+
+    Returns:
+        List of (rows, columns) tuples, one per section: one past its last
+        written row and column.
+
+    Raises:
+        ValueError: If sections on a sheet overlap or the conversion fails.
+    """
+
+def df_to_xlsx_bytes(df: object, **kwargs: Any) -> bytes:
+    """Convert a DataFrame to XLSX and return the workbook as bytes.
+
+    Args:
+        df: pandas DataFrame, polars DataFrame, or polars LazyFrame to export.
+        **kwargs: Any df_to_xlsx keyword argument except output_path
+            (sheet_name, autofit, table_style, ...).
+
+    Returns:
+        The complete .xlsx file contents.
+
+    Raises:
+        TypeError: If an unknown keyword argument (or output_path) is passed.
+        ValueError: If the conversion fails.
+    """
+
+def dfs_to_xlsx_bytes(
+    sheets: list[tuple[object, str] | tuple[object, str, SheetOptions]],
+    **kwargs: Any,
+) -> bytes:
+    """Write multiple DataFrames to one workbook and return it as bytes.
+
+    Args:
+        sheets: List of (DataFrame, sheet_name[, options]) tuples, as for dfs_to_xlsx.
+        **kwargs: Any dfs_to_xlsx keyword argument except output_path
+            (header, autofit, table_style, ...).
+
+    Returns:
+        The complete .xlsx file contents.
+
+    Raises:
+        TypeError: If an unknown keyword argument (or output_path) is passed.
+        ValueError: If the conversion fails.
+    """
+
+def read_xlsx(path: PathArg, sheet_name: str | None = None) -> list[list[Any]]:
+    """Read a worksheet of an XLSX file back into rows of typed values.
+
+    Useful for checking what a write produced. Row 0 is Excel row 1 and
+    column 0 is column A, so rows[r][c] is the cell at that position; empty
+    cells, including leading empty rows and columns, are None. Every row has
+    the same length, up to the last column that holds a value.
+
+    Args:
+        path: Path to the XLSX file.
+        sheet_name: Name of the worksheet to read (default: the first sheet).
+
+    Returns:
+        List of rows, each a list of cell values: float for numbers (Excel
+        stores every number as a float, so 1 reads back as 1.0), bool, str,
+        datetime.datetime for dates and datetimes, datetime.time for a time
+        of day, datetime.timedelta for a duration, None for an empty cell,
+        and the error text (e.g. "#N/A") for an error cell. Formula cells
+        read as their cached result.
+
+    Raises:
+        ValueError: If the file can't be read as XLSX or sheet_name matches
+            no sheet.
+    """
+
 def version() -> str:
     """Return the version of the xlsxturbo library."""
 
@@ -10,17 +10,28 @@ mod conditional_formats;
 mod dimensions;
 mod formulas;
 mod media;
+mod outlines;
+mod printing;
 mod rich_text;
 mod sparklines;
+mod tables;
 mod validations;
 
 pub(crate) use annotations::{apply_comments, apply_hyperlinks, apply_merged_ranges};
-pub(crate) use cells::apply_cells;
-pub(crate) use charts::apply_charts;
+pub(crate) use cells::{apply_cell_overrides, apply_cells};
+pub(crate) use charts::{apply_charts, ChartDataLayout};
 pub(crate) use conditional_formats::apply_conditional_formats;
-pub(crate) use dimensions::{apply_column_widths, apply_column_widths_with_autofit_cap};
-pub(crate) use formulas::apply_formula_columns;
-pub(crate) use media::{apply_checkboxes, apply_images, apply_textboxes};
+pub(crate) use dimensions::{
+    apply_column_widths, apply_column_widths_with_autofit_cap,
+    apply_column_widths_with_selected_autofit,
+};
+pub(crate) use formulas::{
+    apply_formula_columns, apply_summary_row, formula_column_layout, ColumnLayout,
+};
+pub(crate) use media::{apply_background_image, apply_checkboxes, apply_images, apply_textboxes};
+pub(crate) use outlines::{apply_col_groups, apply_row_groups};
+pub(crate) use printing::{apply_page_setup, apply_print_area};
 pub(crate) use rich_text::apply_rich_text;
 pub(crate) use sparklines::apply_sparklines;
-pub(crate) use validations::apply_validations;
+pub(crate) use tables::apply_tables;
+pub(crate) use validations::{apply_categorical_validations, apply_validations};
@@ -1,81 +1,247 @@
 //! Cell annotations, hyperlinks, and merged ranges.
 
-use crate::parse::{parse_cell_range, parse_cell_ref, parse_header_format};
-use crate::types::{Comment, Hyperlink, MergedRange};
+use crate::parse::{
+    parse_cell_range, parse_cell_ref, parse_color, parse_column_format, ranges_intersect, CellRange,
+};
+use crate::types::{Comment, Hyperlink, MergedRange, OptionMap, WriteConfig};
+use crate::write::{write_py_value_with_format, CellWriteOptions};
 use indexmap::IndexMap;
 use pyo3::prelude::*;
-use rust_xlsxwriter::{Format, Note, Worksheet};
+use pyo3::types::PyString;
+use rust_xlsxwriter::utility::cell_range;
+use rust_xlsxwriter::{Format, Note, Url, Worksheet};
 
-/// Apply merged ranges to worksheet
+/// Check merged ranges before any is written: none may overlap another or
+/// intersect `filter_region` (the labelled table or autofilter range). Excel
+/// silently "repairs" such files, so every offending range is reported in one
+/// error instead.
+fn validate_merged_ranges(
+    merged_ranges: &[MergedRange],
+    filter_region: Option<(&str, CellRange)>,
+) -> Result<(), String> {
+    let parsed = merged_ranges
+        .iter()
+        .map(|(range_str, _, _)| Ok((range_str, parse_cell_range(range_str)?)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut problems = Vec::new();
+    for (i, &(range_str, range)) in parsed.iter().enumerate() {
+        for &(other_str, other) in &parsed[i + 1..] {
+            if ranges_intersect(range, other) {
+                problems.push(format!("'{}' overlaps '{}'", range_str, other_str));
+            }
+        }
+        if let Some((label, region)) = filter_region {
+            if ranges_intersect(range, region) {
+                problems.push(format!(
+                    "'{}' intersects the {} range {}",
+                    range_str,
+                    label,
+                    cell_range(region.0, region.1, region.2, region.3)
+                ));
+            }
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("merged_ranges: {}", problems.join("; ")))
+    }
+}
+
+/// Apply merged ranges to worksheet. A string value is the merged text; any
+/// other value is written into the top-left cell after the merge, with its
+/// type detected as for `cells`. Unless the range's format sets `num_format`,
+/// date/time values layer the sheet's date/datetime/time format over it.
+/// Overlapping ranges, or ranges intersecting `filter_region`, are rejected
+/// before anything is merged.
 pub(crate) fn apply_merged_ranges(
     py: Python<'_>,
     worksheet: &mut Worksheet,
     merged_ranges: &[MergedRange],
+    config: &WriteConfig<'_>,
+    filter_region: Option<(&str, CellRange)>,
 ) -> Result<(), String> {
-    for (range_str, text, format_dict) in merged_ranges {
+    validate_merged_ranges(merged_ranges, filter_region)?;
+
+    for (range_str, value, format_dict) in merged_ranges {
         let (first_row, first_col, last_row, last_col) = parse_cell_range(range_str)?;
+        let context = format!("merged_ranges['{}']", range_str);
 
-        // Build format if provided
-        let format = if let Some(fmt_dict) = format_dict {
-            let parsed =
-                parse_header_format(py, fmt_dict, &format!("merged_ranges['{}']", range_str))?;
-            Some(parsed)
-        } else {
-            None
+        // Build format if provided, else a default center-aligned format
+        let format = match format_dict {
+            Some(fmt_dict) => parse_column_format(py, fmt_dict, &context)?,
+            None => Format::new().set_align(rust_xlsxwriter::FormatAlign::Center),
         };
 
-        // Apply merge with or without format
-        if let Some(ref fmt) = format {
-            worksheet
-                .merge_range(first_row, first_col, last_row, last_col, text, fmt)
-                .map_err(|e| format!("Failed to merge range '{}': {}", range_str, e))?;
-        } else {
-            // Create default center-aligned format for merged cells
-            let default_fmt = Format::new().set_align(rust_xlsxwriter::FormatAlign::Center);
-            worksheet
-                .merge_range(first_row, first_col, last_row, last_col, text, &default_fmt)
-                .map_err(|e| format!("Failed to merge range '{}': {}", range_str, e))?;
+        let value = value.bind(py);
+        let text = value.cast::<PyString>().map(|s| s.to_string());
+        worksheet
+            .merge_range(
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+                text.as_deref().unwrap_or(""),
+                &format,
+            )
+            .map_err(|e| format!("Failed to merge range '{}': {}", range_str, e))?;
+        if text.is_ok() {
+            continue;
         }
+
+        // merge_range wrote an empty string to the top-left cell; overwrite it
+        // with the typed value in the merge's format
+        let has_num_format = format_dict
+            .as_ref()
+            .is_some_and(|dict| dict.contains_key("num_format"));
+        let column_format = has_num_format.then(|| format.clone());
+        let options = CellWriteOptions::from_config(config, Some(format))?;
+        write_py_value_with_format(
+            worksheet,
+            first_row,
+            first_col,
+            value,
+            &options,
+            column_format.as_ref(),
+        )
+        .map_err(|e| format!("{}: {}", context, e))?;
     }
 
     Ok(())
 }
 
-/// Apply hyperlinks to worksheet
+/// Resolve an `internal:Sheet!A1` hyperlink target to rust_xlsxwriter's
+/// quoted `internal:'Sheet'!A1` form. The sheet (matched case-insensitively,
+/// like Excel, and optionally already quoted) must be one of `sheet_names`,
+/// and the cell part must be a cell or range reference. Internal targets
+/// without a sheet (`internal:A1`, a defined name) are returned as given;
+/// `None` means `url` is not an internal link.
+fn resolve_internal_link(
+    url: &str,
+    sheet_names: &[String],
+    context: &str,
+) -> Result<Option<String>, String> {
+    let Some(target) = url.strip_prefix("internal:") else {
+        return Ok(None);
+    };
+    let Some((sheet, cell)) = target.rsplit_once('!') else {
+        return Ok(Some(url.to_string()));
+    };
+    let sheet = match sheet.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        Some(quoted) => quoted.replace("''", "'"),
+        None => sheet.to_string(),
+    };
+    let name = sheet_names
+        .iter()
+        .find(|name| name.to_lowercase() == sheet.to_lowercase())
+        .ok_or_else(|| {
+            format!(
+                "{}: internal link target sheet '{}' does not exist. Sheets: {}",
+                context,
+                sheet,
+                sheet_names.join(", ")
+            )
+        })?;
+    let unanchored = cell.replace('$', "");
+    let cell_check = if unanchored.contains(':') {
+        parse_cell_range(&unanchored).map(|_| ())
+    } else {
+        parse_cell_ref(&unanchored).map(|_| ())
+    };
+    cell_check.map_err(|e| format!("{}: invalid internal link target: {}", context, e))?;
+    Ok(Some(format!(
+        "internal:'{}'!{}",
+        name.replace('\'', "''"),
+        cell
+    )))
+}
+
+/// Apply hyperlinks to worksheet. `sheet_names` lists every sheet of the
+/// workbook, the valid targets of `internal:` links.
 pub(crate) fn apply_hyperlinks(
     worksheet: &mut Worksheet,
     hyperlinks: &[Hyperlink],
+    sheet_names: &[String],
 ) -> Result<(), String> {
-    for (cell_ref, url, display_text) in hyperlinks {
+    for (cell_ref, url, display_text, tooltip) in hyperlinks {
         let (row, col) = parse_cell_ref(cell_ref)?;
+        let context = format!("hyperlinks['{}']", cell_ref);
+
+        // An internal link shows its target as written unless display text is
+        // given, not the quoted form it is stored in
+        let (link, text) = match resolve_internal_link(url, sheet_names, &context)? {
+            Some(link) => {
+                let text = display_text.as_deref().or(url.strip_prefix("internal:"));
+                (link, text)
+            }
+            None => (url.clone(), display_text.as_deref()),
+        };
 
-        if let Some(text) = display_text {
-            worksheet
-                .write_url_with_text(row, col, url.as_str(), text.as_str())
-                .map_err(|e| format!("Failed to write hyperlink at '{}': {}", cell_ref, e))?;
-        } else {
-            worksheet
-                .write_url(row, col, url.as_str())
-                .map_err(|e| format!("Failed to write hyperlink at '{}': {}", cell_ref, e))?;
+        let mut link = Url::new(link);
+        if let Some(text) = text {
+            link = link.set_text(text);
         }
+        if let Some(tip) = tooltip {
+            link = link.set_tip(tip);
+        }
+        worksheet
+            .write_url(row, col, link)
+            .map_err(|e| format!("Failed to write hyperlink at '{}': {}", cell_ref, e))?;
     }
 
     Ok(())
 }
 
-/// Apply comments/notes to worksheet
+/// Apply comments/notes to worksheet. The dict form can also size the note
+/// box (`width`/`height` in pixels), color it (`bg_color`), set its
+/// `font_size`, and keep it shown rather than shown on hover (`visible`).
 pub(crate) fn apply_comments(
+    py: Python<'_>,
     worksheet: &mut Worksheet,
     comments: &IndexMap<String, Comment>,
 ) -> Result<(), String> {
-    for (cell_ref, (text, author)) in comments {
+    for (cell_ref, comment) in comments {
         let (row, col) = parse_cell_ref(cell_ref)?;
 
-        let mut note = Note::new(text);
-        if let Some(auth) = author {
+        let mut note = Note::new(&comment.text);
+        if let Some(auth) = &comment.author {
             note = note.set_author(auth);
         }
 
+        if let Some(opts) = &comment.options {
+            let view = OptionMap::new(py, opts, format!("comments['{}']", cell_ref));
+            for key in ["width", "height"] {
+                if view.u32(key)? == Some(0) {
+                    return Err(format!("{}: '{}' must be positive", view.context(), key));
+                }
+            }
+            if let Some(width) = view.u32("width")? {
+                note = note.set_width(width);
+            }
+            if let Some(height) = view.u32("height")? {
+                note = note.set_height(height);
+            }
+            if let Some(color) = view.string("bg_color")? {
+                let color =
+                    parse_color(&color).map_err(|e| format!("{}: {}", view.context(), e))?;
+                note = note.set_background_color(color);
+            }
+            if let Some(size) = view.f64("font_size")? {
+                if !(size.is_finite() && size > 0.0) {
+                    return Err(format!(
+                        "{}: 'font_size' must be a positive number, got {}",
+                        view.context(),
+                        size
+                    ));
+                }
+                note = note.set_font_size(size);
+            }
+            if let Some(visible) = view.bool("visible")? {
+                note = note.set_visible(visible);
+            }
+        }
+
         worksheet
             .insert_note(row, col, &note)
             .map_err(|e| format!("Failed to insert note at '{}': {}", cell_ref, e))?;
@@ -1,20 +1,21 @@
 //! Arbitrary cell write application helpers.
 
-use crate::parse::{parse_horizontal_alignment, parse_vertical_alignment};
-use crate::types::CellWrite;
-use crate::write::{write_py_value_with_format, DATETIME_NUM_FORMAT, DATE_NUM_FORMAT};
+use crate::parse::{parse_column_format, parse_horizontal_alignment, parse_vertical_alignment};
+use crate::types::{CellOverride, CellWrite, WriteConfig};
+use crate::write::{write_py_value_with_format, CellWriteOptions};
 use pyo3::prelude::*;
 use rust_xlsxwriter::{Format, Worksheet};
 
-/// Apply arbitrary cell writes to a worksheet
+/// Apply arbitrary cell writes to a worksheet.
+/// Date/datetime values without an explicit `num_format` use the sheet's
+/// `date_format`/`datetime_format`/`time_format`, and NaN/Inf floats follow its
+/// `nan_policy`, same as DataFrame cells.
 pub(crate) fn apply_cells(
     py: Python<'_>,
     worksheet: &mut Worksheet,
     cells: &[CellWrite],
+    options: &CellWriteOptions,
 ) -> Result<(), String> {
-    let date_format = Format::new().set_num_format(DATE_NUM_FORMAT);
-    let datetime_format = Format::new().set_num_format(DATETIME_NUM_FORMAT);
-
     for cell in cells {
         let value = cell.value.bind(py);
         let has_formatting = cell.num_format.is_some()
@@ -39,15 +40,58 @@ pub(crate) fn apply_cells(
         } else {
             None
         };
+        write_py_value_with_format(worksheet, cell.row, cell.col, value, options, fmt.as_ref())?;
+    }
+    Ok(())
+}
+
+/// Apply `cell_overrides`, after the DataFrame data and `cells`.
+///
+/// A value is written like a `cells` value, styled with the entry's format.
+/// Unless that format sets `num_format`, date/time values layer the sheet's
+/// date/datetime/time format over it, as `cell_format` does for data cells.
+/// A format without a value restyles the cell in place, replacing its format.
+pub(crate) fn apply_cell_overrides(
+    py: Python<'_>,
+    worksheet: &mut Worksheet,
+    overrides: &[CellOverride],
+    config: &WriteConfig<'_>,
+) -> Result<(), String> {
+    for cell in overrides {
+        let context = || {
+            format!(
+                "cell_overrides['{}']",
+                rust_xlsxwriter::utility::row_col_to_cell(cell.row, cell.col)
+            )
+        };
+        let format = cell
+            .format
+            .as_ref()
+            .map(|dict| parse_column_format(py, dict, &format!("{}: format", context())))
+            .transpose()?;
+        let Some(value) = &cell.value else {
+            if let Some(format) = &format {
+                worksheet
+                    .set_cell_format(cell.row, cell.col, format)
+                    .map_err(|e| format!("{}: {}", context(), e))?;
+            }
+            continue;
+        };
+        let has_num_format = cell
+            .format
+            .as_ref()
+            .is_some_and(|dict| dict.contains_key("num_format"));
+        let column_format = if has_num_format { format.clone() } else { None };
+        let options = CellWriteOptions::from_config(config, format)?;
         write_py_value_with_format(
             worksheet,
             cell.row,
             cell.col,
-            value,
-            &date_format,
-            &datetime_format,
-            fmt.as_ref(),
-        )?;
+            value.bind(py),
+            &options,
+            column_format.as_ref(),
+        )
+        .map_err(|e| format!("{}: {}", context(), e))?;
     }
     Ok(())
 }
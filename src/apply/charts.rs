@@ -6,6 +6,110 @@ use indexmap::IndexMap;
 use pyo3::prelude::*;
 use rust_xlsxwriter::{Chart, ChartDataTable, ChartLegendPosition, ChartType, Worksheet};
 
+/// Where the DataFrame landed on the sheet, so `categories_col` / `value_cols`
+/// can name columns instead of spelling out sheet ranges.
+pub(crate) struct ChartDataLayout<'a> {
+    pub(crate) sheet_name: &'a str,
+    /// Written column names in sheet order (DataFrame columns, then formula columns)
+    pub(crate) columns: Vec<&'a str>,
+    pub(crate) start_col: u16,
+    pub(crate) header_row: Option<u32>,
+    pub(crate) first_data_row: u32,
+    pub(crate) last_data_row: u32,
+}
+
+impl ChartDataLayout<'_> {
+    fn column_index(&self, cell_ref: &str, key: &str, name: &str) -> Result<u16, String> {
+        let position = self
+            .columns
+            .iter()
+            .position(|c| *c == name)
+            .ok_or_else(|| {
+                format!(
+                    "charts['{}']: {} '{}' is not a written column. Columns: {}",
+                    cell_ref,
+                    key,
+                    name,
+                    self.columns.join(", ")
+                )
+            })?;
+        Ok(self.start_col + position as u16)
+    }
+
+    fn data_range(&self, col: u16) -> (&str, u32, u16, u32, u16) {
+        (
+            self.sheet_name,
+            self.first_data_row,
+            col,
+            self.last_data_row,
+            col,
+        )
+    }
+}
+
+/// Whether `key` is present with a non-`None` value.
+fn has_value(view: &OptionMap<'_, '_>, key: &str) -> bool {
+    view.get(key)
+        .is_some_and(|obj| !obj.bind(view.py()).is_none())
+}
+
+/// Add one series per `value_cols` entry, with `categories_col` (if given) as
+/// every series' categories and each column's header cell as its name.
+fn add_column_series(
+    chart: &mut Chart,
+    cell_ref: &str,
+    view: &OptionMap<'_, '_>,
+    layout: &ChartDataLayout<'_>,
+) -> Result<(), String> {
+    for key in ["values_range", "values", "data_range", "series"] {
+        if has_value(view, key) {
+            return Err(format!(
+                "charts['{}']: 'value_cols' cannot be combined with '{}'",
+                cell_ref, key
+            ));
+        }
+    }
+    let value_cols: Vec<String> = match view.get("value_cols") {
+        Some(obj) if has_value(view, "value_cols") => {
+            let bound = obj.bind(view.py());
+            if let Ok(name) = bound.extract::<String>() {
+                vec![name]
+            } else {
+                bound.extract::<Vec<String>>().map_err(|_| {
+                    format!(
+                        "charts['{}']: 'value_cols' must be a column name or a list of column names",
+                        cell_ref
+                    )
+                })?
+            }
+        }
+        _ => Vec::new(),
+    };
+    if value_cols.is_empty() {
+        return Err(format!(
+            "charts['{}']: 'value_cols' must not be empty",
+            cell_ref
+        ));
+    }
+
+    let categories_col = match view.string("categories_col")? {
+        Some(name) => Some(layout.column_index(cell_ref, "categories_col", &name)?),
+        None => None,
+    };
+    for name in &value_cols {
+        let col = layout.column_index(cell_ref, "value_cols", name)?;
+        let series = chart.add_series().set_values(layout.data_range(col));
+        if let Some(cat_col) = categories_col {
+            series.set_categories(layout.data_range(cat_col));
+        }
+        match layout.header_row {
+            Some(header_row) => series.set_name((layout.sheet_name, header_row, col)),
+            None => series.set_name(name.as_str()),
+        };
+    }
+    Ok(())
+}
+
 fn parse_chart_type(chart_type: &str) -> Result<ChartType, String> {
     match chart_type.to_lowercase().as_str() {
         "area" => Ok(ChartType::Area),
@@ -136,11 +240,13 @@ fn add_chart_series(
     Ok(())
 }
 
-/// Apply native Excel charts to worksheet.
+/// Apply native Excel charts to worksheet. `layout` resolves the
+/// `categories_col` / `value_cols` column names to the written data ranges.
 pub(crate) fn apply_charts(
     py: Python<'_>,
     worksheet: &mut Worksheet,
     charts: &IndexMap<String, ChartConfig>,
+    layout: &ChartDataLayout<'_>,
 ) -> Result<(), String> {
     const CHART_KEYS: &[&str] = &[
         "type",
@@ -149,6 +255,8 @@ pub(crate) fn apply_charts(
         "values",
         "categories_range",
         "categories",
+        "categories_col",
+        "value_cols",
         "series",
         "series_name",
         "name",
@@ -185,7 +293,22 @@ pub(crate) fn apply_charts(
             .ok_or_else(|| format!("charts['{}']: missing 'type' key", cell_ref))?;
         let mut chart = Chart::new(parse_chart_type(&chart_type)?);
 
-        if config
+        if has_value(&view, "value_cols") {
+            for key in ["categories_range", "categories"] {
+                if has_value(&view, "categories_col") && has_value(&view, key) {
+                    return Err(format!(
+                        "charts['{}']: 'categories_col' cannot be combined with '{}'",
+                        cell_ref, key
+                    ));
+                }
+            }
+            add_column_series(&mut chart, cell_ref, &view, layout)?;
+        } else if has_value(&view, "categories_col") {
+            return Err(format!(
+                "charts['{}']: 'categories_col' requires 'value_cols'",
+                cell_ref
+            ));
+        } else if config
             .get("series")
             .is_some_and(|series_obj| !series_obj.bind(py).is_none())
         {
@@ -1,15 +1,15 @@
 //! Conditional formatting application helpers.
 
 use crate::parse::{matches_pattern, parse_color, parse_column_format, parse_icon_type};
-use crate::types::{ConditionalFormatConfigs, OptionMap};
+use crate::types::{ConditionalFormatConfigs, ConditionalFormatMode, OptionMap};
 use pyo3::prelude::*;
 use rust_xlsxwriter::{
     ConditionalFormat2ColorScale, ConditionalFormat3ColorScale, ConditionalFormatBlank,
     ConditionalFormatCell, ConditionalFormatCellRule, ConditionalFormatDataBar,
     ConditionalFormatDataBarDirection, ConditionalFormatIconSet, ConditionalFormatText,
-    ConditionalFormatTextRule, Format, Worksheet,
+    ConditionalFormatTextRule, ConditionalFormatType, Format, Worksheet,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Add a cell-rule conditional format (Blank/Text/Cell) with an optional `format` applied first.
 macro_rules! add_cell_cf {
@@ -41,6 +41,73 @@ fn cf_optional_color(view: &OptionMap<'_, '_>, key: &str) -> Result<Option<u32>,
     parse_color(&color_str).map(Some)
 }
 
+/// Extract one color-scale point (`point` is "min", "mid", or "max") from its
+/// `<point>_type` and `<point>_value` keys. `None` keeps Excel's default for
+/// that point: lowest/highest value for the ends, 50th percentile for the
+/// midpoint. The "min"/"max" types name those defaults explicitly on the
+/// matching end and take no value.
+fn cf_scale_point(
+    view: &OptionMap<'_, '_>,
+    point: &str,
+) -> Result<Option<(ConditionalFormatType, f64)>, String> {
+    let type_key = format!("{}_type", point);
+    let value_key = format!("{}_value", point);
+    let Some(type_name) = view.string(&type_key)? else {
+        if view.get(&value_key).is_some() {
+            return Err(format!(
+                "{}: '{}' requires '{}'",
+                view.context(),
+                value_key,
+                type_key
+            ));
+        }
+        return Ok(None);
+    };
+    let rule_type = match type_name.to_lowercase().as_str() {
+        "number" => ConditionalFormatType::Number,
+        "percent" => ConditionalFormatType::Percent,
+        "percentile" => ConditionalFormatType::Percentile,
+        bound @ ("min" | "max") if bound == point => {
+            if view.get(&value_key).is_some() {
+                return Err(format!(
+                    "{}: '{}' is not used with {} '{}'",
+                    view.context(),
+                    value_key,
+                    type_key,
+                    type_name
+                ));
+            }
+            return Ok(None);
+        }
+        _ => {
+            let valid = match point {
+                "min" => "number, percent, percentile, min",
+                "max" => "number, percent, percentile, max",
+                _ => "number, percent, percentile",
+            };
+            return Err(format!(
+                "{}: unknown {} '{}'. Valid: {}",
+                view.context(),
+                type_key,
+                type_name,
+                valid
+            ));
+        }
+    };
+    let value = view.required_f64(&value_key)?;
+    if rule_type != ConditionalFormatType::Number && !(0.0..=100.0).contains(&value) {
+        return Err(format!(
+            "{}: '{}' must be between 0 and 100 for {} '{}', got {}",
+            view.context(),
+            value_key,
+            type_key,
+            type_name,
+            value
+        ));
+    }
+    Ok(Some((rule_type, value)))
+}
+
 /// Parse the optional `format` dict on a cell-rule conditional format config.
 fn parse_cf_format(view: &OptionMap<'_, '_>) -> Result<Option<Format>, String> {
     match view.dict("format")? {
@@ -49,6 +116,26 @@ fn parse_cf_format(view: &OptionMap<'_, '_>) -> Result<Option<Format>, String> {
     }
 }
 
+/// Resolve a config key that may also be spelled `alias` (e.g. `rule` for
+/// `criteria`), returning whichever one is present. Passing both is an error
+/// rather than a silent pick.
+fn cf_aliased_key<'k>(
+    view: &OptionMap<'_, '_>,
+    key: &'k str,
+    alias: &'k str,
+) -> Result<&'k str, String> {
+    match (view.get(key).is_some(), view.get(alias).is_some()) {
+        (true, true) => Err(format!(
+            "{}: '{}' and '{}' are the same option; pass only one",
+            view.context(),
+            key,
+            alias
+        )),
+        (false, true) => Ok(alias),
+        _ => Ok(key),
+    }
+}
+
 /// Apply a 2-color-scale conditional format.
 fn apply_2_color_scale(
     view: &OptionMap<'_, '_>,
@@ -58,6 +145,12 @@ fn apply_2_color_scale(
     data_end_row: u32,
 ) -> Result<(), String> {
     let mut cf = ConditionalFormat2ColorScale::new();
+    if let Some((rule_type, value)) = cf_scale_point(view, "min")? {
+        cf = cf.set_minimum(rule_type, value);
+    }
+    if let Some((rule_type, value)) = cf_scale_point(view, "max")? {
+        cf = cf.set_maximum(rule_type, value);
+    }
     if let Some(c) = cf_optional_color(view, "min_color")? {
         cf = cf.set_minimum_color(c);
     }
@@ -84,6 +177,15 @@ fn apply_3_color_scale(
     data_end_row: u32,
 ) -> Result<(), String> {
     let mut cf = ConditionalFormat3ColorScale::new();
+    if let Some((rule_type, value)) = cf_scale_point(view, "min")? {
+        cf = cf.set_minimum(rule_type, value);
+    }
+    if let Some((rule_type, value)) = cf_scale_point(view, "mid")? {
+        cf = cf.set_midpoint(rule_type, value);
+    }
+    if let Some((rule_type, value)) = cf_scale_point(view, "max")? {
+        cf = cf.set_maximum(rule_type, value);
+    }
     if let Some(c) = cf_optional_color(view, "min_color")? {
         cf = cf.set_minimum_color(c);
     }
@@ -176,6 +278,63 @@ fn apply_icon_set(
     Ok(())
 }
 
+/// Map a lowercased text-rule name to its rule, reading the string to match
+/// from `text_key`. Returns `Ok(None)` when `name` isn't a text rule, so the
+/// `cell` type can fall through to its other criteria families.
+fn parse_text_rule(
+    view: &OptionMap<'_, '_>,
+    name: &str,
+    text_key: &str,
+) -> Result<Option<ConditionalFormatTextRule>, String> {
+    let rule: fn(String) -> ConditionalFormatTextRule = match name {
+        "containing" | "contains" | "text_contains" => ConditionalFormatTextRule::Contains,
+        "not_containing" | "not containing" | "not_contains" | "does_not_contain"
+        | "does not contain" => ConditionalFormatTextRule::DoesNotContain,
+        "begins_with" | "begins with" | "starts_with" | "starts with" => {
+            ConditionalFormatTextRule::BeginsWith
+        }
+        "ends_with" | "ends with" => ConditionalFormatTextRule::EndsWith,
+        _ => return Ok(None),
+    };
+    Ok(Some(rule(view.required_string(text_key)?)))
+}
+
+/// Apply a text conditional format: highlight cells whose text matches `rule`
+/// against the `text` string.
+fn apply_text_conditional(
+    view: &OptionMap<'_, '_>,
+    worksheet: &mut Worksheet,
+    col_pattern: &str,
+    col_idx: u16,
+    data_start_row: u32,
+    data_end_row: u32,
+) -> Result<(), String> {
+    let rule_name = view.string("rule")?.ok_or_else(|| {
+        format!(
+            "conditional_formats['{}']: 'text' type requires 'rule' key",
+            col_pattern
+        )
+    })?;
+    let rule = parse_text_rule(view, &rule_name.to_lowercase(), "text")?.ok_or_else(|| {
+        format!(
+            "conditional_formats['{}']: Unknown text rule '{}'. \
+             Valid: contains, not_contains, begins_with, ends_with",
+            col_pattern, rule_name
+        )
+    })?;
+    let fmt = parse_cf_format(view)?;
+    add_cell_cf!(
+        worksheet,
+        data_start_row,
+        col_idx,
+        data_end_row,
+        ConditionalFormatText::new().set_rule(rule),
+        fmt,
+        "text format"
+    );
+    Ok(())
+}
+
 /// Apply a cell-rule conditional format, dispatching by criteria family.
 fn apply_cell_conditional(
     view: &OptionMap<'_, '_>,
@@ -186,10 +345,10 @@ fn apply_cell_conditional(
     data_end_row: u32,
 ) -> Result<(), String> {
     let criteria: String = view
-        .get("criteria")
+        .get(cf_aliased_key(view, "criteria", "rule")?)
         .ok_or_else(|| {
             format!(
-                "conditional_formats['{}']: 'cell' type requires 'criteria' key",
+                "conditional_formats['{}']: 'cell' type requires 'criteria' (or 'rule') key",
                 col_pattern
             )
         })?
@@ -235,21 +394,7 @@ fn apply_cell_conditional(
     }
 
     // Text rules (require string 'value')
-    if let Some(rule) = match criteria_lower.as_str() {
-        "containing" | "contains" | "text_contains" => Some(ConditionalFormatTextRule::Contains(
-            view.required_string("value")?,
-        )),
-        "not_containing" | "not containing" | "does_not_contain" | "does not contain" => Some(
-            ConditionalFormatTextRule::DoesNotContain(view.required_string("value")?),
-        ),
-        "begins_with" | "begins with" | "starts_with" | "starts with" => Some(
-            ConditionalFormatTextRule::BeginsWith(view.required_string("value")?),
-        ),
-        "ends_with" | "ends with" => Some(ConditionalFormatTextRule::EndsWith(
-            view.required_string("value")?,
-        )),
-        _ => None,
-    } {
+    if let Some(rule) = parse_text_rule(view, &criteria_lower, "value")? {
         add_cell_cf!(
             worksheet,
             data_start_row,
@@ -262,15 +407,15 @@ fn apply_cell_conditional(
         return Ok(());
     }
 
-    // Range rules (require numeric min_value/max_value)
+    // Range rules (require numeric min_value/max_value, or min/max as in validations)
     if let Some(rule) = match criteria_lower.as_str() {
         "between" => Some(ConditionalFormatCellRule::Between(
-            view.required_f64("min_value")?,
-            view.required_f64("max_value")?,
+            view.required_f64(cf_aliased_key(view, "min_value", "min")?)?,
+            view.required_f64(cf_aliased_key(view, "max_value", "max")?)?,
         )),
         "not_between" | "not between" => Some(ConditionalFormatCellRule::NotBetween(
-            view.required_f64("min_value")?,
-            view.required_f64("max_value")?,
+            view.required_f64(cf_aliased_key(view, "min_value", "min")?)?,
+            view.required_f64(cf_aliased_key(view, "max_value", "max")?)?,
         )),
         _ => None,
     } {
@@ -312,7 +457,7 @@ fn apply_cell_conditional(
     }
 
     let cf = match criteria_lower.as_str() {
-        "equal_to" | "equal to" | "==" | "eq" => make_rule!(EqualTo),
+        "equal_to" | "equal to" | "equal" | "==" | "eq" => make_rule!(EqualTo),
         "not_equal_to" | "not equal to" | "!=" | "ne" => make_rule!(NotEqualTo),
         "greater_than" | "greater than" | ">" | "gt" => make_rule!(GreaterThan),
         "less_than" | "less than" | "<" | "lt" => make_rule!(LessThan),
@@ -348,7 +493,7 @@ fn apply_cell_conditional(
 
 /// Apply a single conditional format config to a column range.
 /// Dispatches by `type` to a family-specific helper. Unlike `validations`,
-/// conditional format configs are dispatched to one of five type-specific
+/// conditional format configs are dispatched to one of six type-specific
 /// families with disjoint key sets, so the valid-keys list — and thus the
 /// unknown-key error — is per-type; the resolved format type is passed to
 /// `OptionMap::reject_unknown_for` as its qualifier.
@@ -381,13 +526,35 @@ fn apply_single_conditional_format(
 
     match format_type.to_lowercase().as_str() {
         "2_color_scale" | "2colorscale" | "two_color_scale" => {
-            view.reject_unknown_for(&format_type, &["type", "min_color", "max_color"])?;
+            view.reject_unknown_for(
+                &format_type,
+                &[
+                    "type",
+                    "min_color",
+                    "max_color",
+                    "min_type",
+                    "min_value",
+                    "max_type",
+                    "max_value",
+                ],
+            )?;
             apply_2_color_scale(&view, worksheet, col_idx, data_start_row, data_end_row)
         }
         "3_color_scale" | "3colorscale" | "three_color_scale" => {
             view.reject_unknown_for(
                 &format_type,
-                &["type", "min_color", "mid_color", "max_color"],
+                &[
+                    "type",
+                    "min_color",
+                    "mid_color",
+                    "max_color",
+                    "min_type",
+                    "min_value",
+                    "mid_type",
+                    "mid_value",
+                    "max_type",
+                    "max_value",
+                ],
             )?;
             apply_3_color_scale(&view, worksheet, col_idx, data_start_row, data_end_row)
         }
@@ -411,10 +578,13 @@ fn apply_single_conditional_format(
                 &[
                     "type",
                     "criteria",
+                    "rule",
                     "format",
                     "value",
                     "min_value",
                     "max_value",
+                    "min",
+                    "max",
                 ],
             )?;
             apply_cell_conditional(
@@ -426,40 +596,65 @@ fn apply_single_conditional_format(
                 data_end_row,
             )
         }
+        "text" => {
+            view.reject_unknown_for(&format_type, &["type", "rule", "text", "format"])?;
+            apply_text_conditional(
+                &view,
+                worksheet,
+                col_pattern,
+                col_idx,
+                data_start_row,
+                data_end_row,
+            )
+        }
         _ => Err(format!(
             "Unknown conditional format type '{}'. Valid types: \
-             2_color_scale, 3_color_scale, data_bar, icon_set, cell",
+             2_color_scale, 3_color_scale, data_bar, icon_set, cell, text",
             format_type
         )),
     }
 }
 
 /// Apply conditional formats to a worksheet
-/// Supports: 2_color_scale, 3_color_scale, data_bar, icon_set, cell
-/// Uses IndexMap to preserve pattern order (first match wins for overlapping patterns)
+/// Supports: 2_color_scale, 3_color_scale, data_bar, icon_set, cell, text
+/// Uses IndexMap to preserve pattern order, so under
+/// `ConditionalFormatMode::FirstMatchWins` the earliest matching pattern
+/// claims a column and later overlapping patterns skip it
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn apply_conditional_formats(
     py: Python<'_>,
     worksheet: &mut Worksheet,
     columns: &[String],
+    data_cols: &[u16],
     data_start_row: u32,
     data_end_row: u32,
     cond_formats: &ConditionalFormatConfigs,
+    mode: ConditionalFormatMode,
 ) -> Result<(), String> {
+    let mut formatted_cols: HashSet<usize> = HashSet::new();
     for (col_pattern, configs) in cond_formats {
-        let col_indices: Vec<u16> = columns
+        let matched: Vec<usize> = columns
             .iter()
             .enumerate()
             .filter(|(_, name)| matches_pattern(name, col_pattern))
-            .map(|(idx, _)| idx as u16) // safe: col_count already validated via u16::try_from
+            .map(|(idx, _)| idx)
             .collect();
 
-        if col_indices.is_empty() {
+        // A pattern whose columns were all claimed earlier still matched, so
+        // only an empty match is an error.
+        if matched.is_empty() {
             return Err(format!(
                 "conditional_formats['{}']: pattern matched no columns",
                 col_pattern
             ));
         }
 
+        let col_indices: Vec<u16> = matched
+            .into_iter()
+            .filter(|&idx| mode == ConditionalFormatMode::AllMatches || formatted_cols.insert(idx))
+            .map(|idx| data_cols[idx])
+            .collect();
+
         for config in configs {
             for &col_idx in &col_indices {
                 apply_single_conditional_format(
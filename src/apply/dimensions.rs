@@ -4,15 +4,15 @@ use rust_xlsxwriter::Worksheet;
 use std::collections::HashMap;
 
 /// Apply an explicitly-given column width for every key in `widths` that
-/// names a column index at or beyond `col_count` (i.e. outside the data
-/// range the `0..col_count` loops in this module cover). `"_all"` is a
-/// global cap over the data columns only, so it never applies here.
+/// names a column index outside the data columns (`data_cols`) the loops in
+/// this module cover. `"_all"` is a global cap over
+/// the data columns only, so it never applies here.
 /// Extraction (`extract_column_widths`) already validated every integer key
 /// fits Excel's column range, so a stray non-integer/"_all" key is just
 /// skipped rather than erroring again here.
 fn apply_out_of_range_column_widths(
     worksheet: &mut Worksheet,
-    col_count: u16,
+    data_cols: &[u16],
     widths: &HashMap<String, f64>,
 ) -> Result<(), String> {
     for (key, &width) in widths {
@@ -20,7 +20,7 @@ fn apply_out_of_range_column_widths(
             continue;
         }
         if let Ok(col_idx) = key.parse::<u16>() {
-            if col_idx >= col_count {
+            if !data_cols.contains(&col_idx) {
                 worksheet
                     .set_column_width(col_idx, width)
                     .map_err(|e| format!("Failed to set column width: {}", e))?;
@@ -30,15 +30,17 @@ fn apply_out_of_range_column_widths(
     Ok(())
 }
 
-/// Apply column widths to worksheet, supporting '_all' global cap
+/// Apply column widths to worksheet, supporting '_all' global cap.
+/// Keys are absolute sheet column indices; `"_all"` covers the data columns,
+/// whose sheet columns are `data_cols`.
 pub(crate) fn apply_column_widths(
     worksheet: &mut Worksheet,
-    col_count: u16,
+    data_cols: &[u16],
     widths: &HashMap<String, f64>,
 ) -> Result<(), String> {
     let global_width = widths.get("_all").copied();
 
-    for col_idx in 0..col_count {
+    for &col_idx in data_cols {
         let col_key = col_idx.to_string();
         // Specific column overrides '_all'
         if let Some(width) = widths.get(&col_key) {
@@ -51,39 +53,79 @@ pub(crate) fn apply_column_widths(
                 .map_err(|e| format!("Failed to set column width: {}", e))?;
         }
     }
-    apply_out_of_range_column_widths(worksheet, col_count, widths)
+    apply_out_of_range_column_widths(worksheet, data_cols, widths)
 }
 
-/// Apply column widths with autofit and cap: autofit each column to content, then cap at '_all'.
-/// Uses pre-computed content widths to apply min(autofit, cap) per column.
+/// Apply column widths with autofit and caps: autofit each column to content,
+/// then cap it at its own width, else at '_all'.
+/// Uses pre-computed content widths, one per entry of `data_cols`, to apply
+/// min(autofit, cap) per column.
 ///
 /// Caller must ensure this is NOT called in constant_memory mode (autofit is unsupported).
 pub(crate) fn apply_column_widths_with_autofit_cap(
     worksheet: &mut Worksheet,
-    col_count: u16,
+    data_cols: &[u16],
     widths: &HashMap<String, f64>,
     content_widths: &[f64],
 ) -> Result<(), String> {
-    let global_cap = widths.get("_all").copied().unwrap_or(f64::MAX);
+    for (idx, &col_idx) in data_cols.iter().enumerate() {
+        let capped = autofit_width_with_cap(widths, col_idx, content_widths.get(idx).copied());
+        worksheet
+            .set_column_width(col_idx, capped)
+            .map_err(|e| format!("Failed to set column width: {}", e))?;
+    }
+    apply_out_of_range_column_widths(worksheet, data_cols, widths)
+}
 
-    for col_idx in 0..col_count {
-        let col_key = col_idx.to_string();
-        if let Some(width) = widths.get(&col_key) {
-            // Specific width overrides autofit and cap
-            worksheet
-                .set_column_width(col_idx, *width)
-                .map_err(|e| format!("Failed to set column width: {}", e))?;
+/// The autofit width of sheet column `col_idx` (Excel's default when its
+/// content width is unknown), capped at its own entry in `widths`, else at
+/// '_all'.
+fn autofit_width_with_cap(
+    widths: &HashMap<String, f64>,
+    col_idx: u16,
+    content_width: Option<f64>,
+) -> f64 {
+    let cap = widths
+        .get(&col_idx.to_string())
+        .or_else(|| widths.get("_all"))
+        .copied()
+        .unwrap_or(f64::MAX);
+    content_width.unwrap_or(8.43).min(cap) // 8.43: Excel default
+}
+
+/// Apply column widths with autofit limited to the `selected` data columns,
+/// one flag per entry of `data_cols`. A selected column gets its pre-computed
+/// content width, capped at its own width, else at '_all'; every other column
+/// is sized as by `apply_column_widths`.
+///
+/// Caller must ensure this is NOT called in constant_memory mode (autofit is unsupported).
+pub(crate) fn apply_column_widths_with_selected_autofit(
+    worksheet: &mut Worksheet,
+    data_cols: &[u16],
+    widths: Option<&HashMap<String, f64>>,
+    content_widths: &[f64],
+    selected: &[bool],
+) -> Result<(), String> {
+    let no_widths = HashMap::new();
+    let widths = widths.unwrap_or(&no_widths);
+    let global_width = widths.get("_all").copied();
+
+    for (idx, &col_idx) in data_cols.iter().enumerate() {
+        let width = if selected.get(idx).copied().unwrap_or(false) {
+            Some(autofit_width_with_cap(
+                widths,
+                col_idx,
+                content_widths.get(idx).copied(),
+            ))
         } else {
-            // Autofit capped at '_all'
-            let autofit_width = content_widths
-                .get(col_idx as usize)
-                .copied()
-                .unwrap_or(8.43); // Excel default
-            let capped = autofit_width.min(global_cap);
+            // Specific column overrides '_all'
+            widths.get(&col_idx.to_string()).copied().or(global_width)
+        };
+        if let Some(width) = width {
             worksheet
-                .set_column_width(col_idx, capped)
+                .set_column_width(col_idx, width)
                 .map_err(|e| format!("Failed to set column width: {}", e))?;
         }
     }
-    apply_out_of_range_column_widths(worksheet, col_count, widths)
+    apply_out_of_range_column_widths(worksheet, data_cols, widths)
 }
@@ -1,56 +1,476 @@
-//! Formula column application helpers.
+//! Formula column and summary row application helpers.
 
+use crate::types::{pytype_name, FormulaColumn, FormulaPosition, FormulaType, SummaryFunction};
 use indexmap::IndexMap;
-use rust_xlsxwriter::{Format, Worksheet};
+use pyo3::prelude::*;
+use pyo3::types::PyBool;
+use rust_xlsxwriter::utility::{cell_range, column_number_to_name};
+use rust_xlsxwriter::{Format, Formula, Worksheet};
+use std::collections::HashMap;
+
+/// Where each DataFrame column and formula column lands on the sheet. Formula
+/// columns with a `position` sit among the DataFrame columns and shift the
+/// ones after them right; the rest are appended after the data.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ColumnLayout {
+    /// Sheet column of each DataFrame column
+    pub(crate) data: Vec<u16>,
+    /// Sheet column of each formula column, in `formula_columns` order
+    pub(crate) formulas: Vec<u16>,
+    /// Width of the block a table covers: the DataFrame columns plus the
+    /// formula columns inserted among them
+    pub(crate) block_width: u16,
+}
+
+impl ColumnLayout {
+    /// `col_count` DataFrame columns side by side from `start_col`, with no
+    /// formula columns.
+    pub(crate) fn contiguous(start_col: u16, col_count: u16) -> Self {
+        Self {
+            data: (start_col..start_col + col_count).collect(),
+            formulas: Vec::new(),
+            block_width: col_count,
+        }
+    }
+
+    /// Column names in sheet order, formula columns included.
+    pub(crate) fn sheet_order<'a>(
+        &self,
+        columns: &'a [String],
+        formula_columns: Option<&'a IndexMap<String, FormulaColumn>>,
+    ) -> Vec<&'a str> {
+        let formula_names = formula_columns.into_iter().flat_map(|f| f.keys());
+        let mut named: Vec<(u16, &str)> = self
+            .data
+            .iter()
+            .copied()
+            .zip(columns.iter().map(String::as_str))
+            .chain(
+                self.formulas
+                    .iter()
+                    .copied()
+                    .zip(formula_names.map(String::as_str)),
+            )
+            .collect();
+        named.sort_unstable_by_key(|&(col, _)| col);
+        named.into_iter().map(|(_, name)| name).collect()
+    }
+}
+
+/// Lay out `columns` from `start_col` with `formula_columns` inserted at their
+/// `position` or appended after the data. A position past the last column or
+/// naming no DataFrame column is an error.
+pub(crate) fn formula_column_layout(
+    formula_columns: &IndexMap<String, FormulaColumn>,
+    columns: &[String],
+    start_col: u16,
+) -> Result<ColumnLayout, String> {
+    // Index of the DataFrame column each formula column goes before;
+    // columns.len() for after the last one, None to append
+    let mut anchors = Vec::with_capacity(formula_columns.len());
+    for (name, formula_column) in formula_columns {
+        let anchor = match &formula_column.position {
+            None => None,
+            Some(FormulaPosition::Index(index)) if *index <= columns.len() => Some(*index),
+            Some(FormulaPosition::Index(index)) => {
+                return Err(format!(
+                    "formula_columns['{}']: position {} is past the last of {} columns",
+                    name,
+                    index,
+                    columns.len()
+                ))
+            }
+            Some(FormulaPosition::After(after)) => Some(
+                columns
+                    .iter()
+                    .position(|c| c == after)
+                    .map(|idx| idx + 1)
+                    .ok_or_else(|| {
+                        format!(
+                            "formula_columns['{}']: position 'after:{}' names no DataFrame column",
+                            name, after
+                        )
+                    })?,
+            ),
+        };
+        anchors.push(anchor);
+    }
+
+    let mut data = Vec::with_capacity(columns.len());
+    let mut formulas = vec![0u16; formula_columns.len()];
+    let mut col = start_col;
+    for idx in 0..=columns.len() {
+        for (slot, _) in anchors.iter().enumerate().filter(|(_, a)| **a == Some(idx)) {
+            formulas[slot] = next_col(&mut col)?;
+        }
+        if idx < columns.len() {
+            data.push(next_col(&mut col)?);
+        }
+    }
+    let block_width = col - start_col;
+    for (slot, _) in anchors.iter().enumerate().filter(|(_, a)| a.is_none()) {
+        formulas[slot] = next_col(&mut col)?;
+    }
+    Ok(ColumnLayout {
+        data,
+        formulas,
+        block_width,
+    })
+}
+
+/// Return `*col` and advance it to the next sheet column.
+fn next_col(col: &mut u16) -> Result<u16, String> {
+    let current = *col;
+    *col = col
+        .checked_add(1)
+        .ok_or("Formula column index exceeds u16 limit")?;
+    Ok(current)
+}
+
+/// One piece of a compiled formula template.
+#[derive(Debug, PartialEq)]
+enum TemplatePart {
+    /// Text copied as-is
+    Text(String),
+    /// `{row}`: the Excel row number
+    Row,
+    /// `{col_name}`: this column letter followed by the Excel row number
+    Column(String),
+}
+
+/// True for the contents of an Excel array constant such as `{1,2,3}` or
+/// `{"a";"b"}`, which a template passes through instead of treating as a
+/// column name.
+fn is_array_constant(inner: &str) -> bool {
+    inner.contains([',', ';'])
+        || inner.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '.' | '"'))
+        || inner.eq_ignore_ascii_case("true")
+        || inner.eq_ignore_ascii_case("false")
+}
+
+/// Split a formula template into text, `{row}`, and `{col_name}` parts,
+/// resolving each column name to its letter through `letters`. A `{...}` that
+/// is neither `row`, a known column, nor an array constant is an error.
+fn compile_template(
+    template: &str,
+    letters: &HashMap<&str, String>,
+    context: &str,
+) -> Result<Vec<TemplatePart>, String> {
+    let mut parts = Vec::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let Some(len) = rest[open..].find('}') else {
+            break;
+        };
+        let inner = &rest[open + 1..open + len];
+        let part = if inner == "row" {
+            TemplatePart::Row
+        } else if let Some(letter) = letters.get(inner) {
+            TemplatePart::Column(letter.clone())
+        } else if is_array_constant(inner) {
+            TemplatePart::Text(rest[open..=open + len].to_string())
+        } else {
+            return Err(format!(
+                "{}: unknown column '{}' in formula template '{}'",
+                context, inner, template
+            ));
+        };
+        parts.push(TemplatePart::Text(rest[..open].to_string()));
+        parts.push(part);
+        rest = &rest[open + len + 1..];
+    }
+    parts.push(TemplatePart::Text(rest.to_string()));
+    Ok(parts)
+}
+
+/// Render a compiled template for the 1-based Excel row `excel_row`.
+fn render_template(parts: &[TemplatePart], excel_row: u32) -> String {
+    let mut formula = String::new();
+    for part in parts {
+        match part {
+            TemplatePart::Text(text) => formula.push_str(text),
+            TemplatePart::Row => formula.push_str(&excel_row.to_string()),
+            TemplatePart::Column(letter) => {
+                formula.push_str(letter);
+                formula.push_str(&excel_row.to_string());
+            }
+        }
+    }
+    formula
+}
+
+/// The cached result text for `formula_result` (or a value its callable
+/// returned): `TRUE`/`FALSE` for a bool, the number or string as given, and
+/// `None` for `None`.
+fn formula_result_text(value: &Bound<'_, PyAny>, context: &str) -> Result<Option<String>, String> {
+    if value.is_none() {
+        return Ok(None);
+    }
+    if let Ok(flag) = value.cast::<PyBool>() {
+        return Ok(Some(
+            if flag.is_true() { "TRUE" } else { "FALSE" }.to_string(),
+        ));
+    }
+    if let Ok(int) = value.extract::<i64>() {
+        return Ok(Some(int.to_string()));
+    }
+    if let Ok(float) = value.extract::<f64>() {
+        if !float.is_finite() {
+            return Err(format!(
+                "{}: formula_result must be finite, got {}",
+                context, float
+            ));
+        }
+        return Ok(Some(float.to_string()));
+    }
+    value.extract::<String>().map(Some).map_err(|_| {
+        format!(
+            "{}: formula_result must be a number, string, bool, or callable, got {}",
+            context,
+            pytype_name(value)
+        )
+    })
+}
 
 /// Apply formula columns to worksheet
-/// Formula templates can use {row} which is replaced with the actual row number (1-based)
+/// Formula templates can use {row}, which is replaced with the actual row
+/// number (1-based), and {col_name}, which is replaced with that column's
+/// cell in the same row (e.g. {price} -> A2). `columns` are the written data
+/// columns and `layout` places them and the formula columns, which can be
+/// referenced by name too.
+/// `header_row` is the sheet row for the formula column headers, or `None` when
+/// headers are disabled.
+/// A dynamic formula is written once in the first data row and spills; an
+/// array formula is written once over its `range` (default: the column's data
+/// rows). Both render their template for the first row they cover.
+/// A `formula_result` is cached as each formula's value, for readers that
+/// don't recalculate; a callable gets the 0-based DataFrame row index (0 for
+/// dynamic and array formulas).
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn apply_formula_columns(
+    py: Python<'_>,
     worksheet: &mut Worksheet,
-    formula_columns: &IndexMap<String, String>,
-    start_col: u16,
+    formula_columns: &IndexMap<String, FormulaColumn>,
+    columns: &[String],
+    layout: &ColumnLayout,
     data_start_row: u32,
     data_end_row: u32,
-    include_header: bool,
+    header_row: Option<u32>,
     header_format: Option<&Format>,
-) -> Result<u16, String> {
-    let mut col_offset = 0u16;
+) -> Result<(), String> {
+    // Name -> column letter; a data column wins over a formula column of the
+    // same name, and the first of duplicate names wins
+    let mut letters: HashMap<&str, String> = HashMap::new();
+    let data_cols = columns.iter().zip(&layout.data);
+    let formula_cols = formula_columns.keys().zip(&layout.formulas);
+    for (name, &col) in data_cols.chain(formula_cols) {
+        letters
+            .entry(name.as_str())
+            .or_insert_with(|| column_number_to_name(col));
+    }
 
-    for (col_name, formula_template) in formula_columns {
-        let col_idx = start_col
-            .checked_add(col_offset)
-            .ok_or("Formula column index exceeds u16 limit")?;
+    for ((col_name, formula_column), &col_idx) in formula_columns.iter().zip(&layout.formulas) {
+        let template = compile_template(
+            &formula_column.template,
+            &letters,
+            &format!("formula_columns['{}']", col_name),
+        )?;
 
         // Write header for formula column (only when headers are enabled)
-        if include_header {
+        if let Some(header_row) = header_row {
             if let Some(fmt) = header_format {
                 worksheet
-                    .write_string_with_format(0, col_idx, col_name, fmt)
+                    .write_string_with_format(header_row, col_idx, col_name, fmt)
                     .map_err(|e| format!("Failed to write formula column header: {}", e))?;
             } else {
                 worksheet
-                    .write_string(0, col_idx, col_name)
+                    .write_string(header_row, col_idx, col_name)
                     .map_err(|e| format!("Failed to write formula column header: {}", e))?;
             }
         }
 
-        // Write formula for each data row
-        for row in data_start_row..=data_end_row {
-            // Replace {row} with actual row number (Excel is 1-based)
+        // Fill in {row} and {col_name} for a sheet row (Excel is 1-based),
+        // caching the result for DataFrame row `index` when one is given
+        let context = format!("formula_columns['{}']", col_name);
+        let formula_at = |row: u32, index: u32| -> Result<Formula, String> {
             let excel_row = row
                 .checked_add(1)
                 .ok_or("Formula row index exceeds u32 limit")?;
-            let formula = formula_template.replace("{row}", &excel_row.to_string());
+            let formula = Formula::new(render_template(&template, excel_row));
+            let Some(result) = &formula_column.result else {
+                return Ok(formula);
+            };
+            let result = result.bind(py);
+            let text = if result.is_callable() {
+                let value = result.call1((index,)).map_err(|e| {
+                    format!("{}: formula_result raised at row {}: {}", context, index, e)
+                })?;
+                formula_result_text(&value, &context)?
+            } else {
+                formula_result_text(result, &context)?
+            };
+            Ok(match text {
+                Some(text) => formula.set_result(text),
+                None => formula,
+            })
+        };
+
+        match formula_column.formula_type {
+            FormulaType::Regular => {
+                // Write formula for each data row
+                for row in data_start_row..=data_end_row {
+                    let formula = formula_at(row, row - data_start_row)?;
+                    worksheet
+                        .write_formula(row, col_idx, formula)
+                        .map_err(|e| format!("Failed to write formula at row {}: {}", row, e))?;
+                }
+            }
+            FormulaType::Dynamic => {
+                let formula = formula_at(data_start_row, 0)?;
+                worksheet
+                    .write_dynamic_formula(data_start_row, col_idx, formula)
+                    .map_err(|e| {
+                        format!("Failed to write dynamic formula for '{}': {}", col_name, e)
+                    })?;
+            }
+            FormulaType::Array => {
+                let (first_row, first_col, last_row, last_col) = formula_column.range.unwrap_or((
+                    data_start_row,
+                    col_idx,
+                    data_end_row,
+                    col_idx,
+                ));
+                let formula = formula_at(first_row, 0)?;
+                worksheet
+                    .write_array_formula(first_row, first_col, last_row, last_col, formula)
+                    .map_err(|e| {
+                        format!("Failed to write array formula for '{}': {}", col_name, e)
+                    })?;
+            }
+        }
+    }
+
+    Ok(())
+}
 
-            worksheet
-                .write_formula(row, col_idx, formula.as_str())
-                .map_err(|e| format!("Failed to write formula at row {}: {}", row, e))?;
+/// Write a bold summary row at `row`: for each `(col, function, result)`, an
+/// aggregate formula over the column's data rows, with `result` cached as the
+/// formula's value when known so readers that don't recalculate still see it.
+pub(crate) fn apply_summary_row(
+    worksheet: &mut Worksheet,
+    row: u32,
+    data_start_row: u32,
+    data_end_row: u32,
+    cells: &[(u16, SummaryFunction, Option<f64>)],
+) -> Result<(), String> {
+    let bold = Format::new().set_bold();
+    for &(col, function, result) in cells {
+        // COUNTA counts non-empty cells, like a DataFrame's count of non-nulls
+        let name = match function {
+            SummaryFunction::Sum => "SUM",
+            SummaryFunction::Mean => "AVERAGE",
+            SummaryFunction::Min => "MIN",
+            SummaryFunction::Max => "MAX",
+            SummaryFunction::Count => "COUNTA",
+        };
+        let range = cell_range(data_start_row, col, data_end_row, col);
+        let mut formula = Formula::new(format!("={}({})", name, range));
+        if let Some(value) = result {
+            formula = formula.set_result(value.to_string());
         }
+        worksheet
+            .write_formula_with_format(row, col, formula, &bold)
+            .map_err(|e| format!("Failed to write summary row formula: {}", e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compile_template, formula_column_layout, render_template};
+    use crate::types::{FormulaColumn, FormulaPosition, FormulaType};
+    use indexmap::IndexMap;
+    use std::collections::HashMap;
+
+    fn render(template: &str, excel_row: u32) -> Result<String, String> {
+        let letters = HashMap::from([("price", "A".to_string()), ("qty", "B".to_string())]);
+        compile_template(template, &letters, "formula_columns['total']")
+            .map(|parts| render_template(&parts, excel_row))
+    }
 
-        col_offset = col_offset
-            .checked_add(1)
-            .ok_or("Formula column count exceeds u16 limit")?;
+    /// Column names resolve to the column letter plus the current row, next
+    /// to the plain `{row}` placeholder.
+    #[test]
+    fn resolves_column_names_and_row() {
+        assert_eq!(render("={price} * {qty}", 2).unwrap(), "=A2 * B2");
+        assert_eq!(render("=A{row}+{qty}", 7).unwrap(), "=A7+B7");
+        assert_eq!(render("=1+2", 3).unwrap(), "=1+2");
     }
 
-    Ok(col_offset)
+    /// Array constants pass through untouched; unknown names are errors.
+    #[test]
+    fn keeps_array_constants_and_rejects_unknown_names() {
+        assert_eq!(render("=SUM({price}*{1,2})", 2).unwrap(), "=SUM(A2*{1,2})");
+        let err = render("={cost}*2", 2).unwrap_err();
+        assert!(err.contains("formula_columns['total']: unknown column 'cost'"));
+    }
+
+    fn formula(position: Option<FormulaPosition>) -> FormulaColumn {
+        FormulaColumn {
+            template: "=1".to_string(),
+            formula_type: FormulaType::Regular,
+            range: None,
+            position,
+            result: None,
+        }
+    }
+
+    /// Positioned formula columns shift the data columns after them and widen
+    /// the table block; unpositioned ones are appended after it.
+    #[test]
+    fn inserts_positioned_formula_columns() {
+        let columns = ["a".to_string(), "b".to_string(), "c".to_string()];
+        let formulas = IndexMap::from([
+            ("end".to_string(), formula(None)),
+            (
+                "mid".to_string(),
+                formula(Some(FormulaPosition::After("a".to_string()))),
+            ),
+            (
+                "first".to_string(),
+                formula(Some(FormulaPosition::Index(0))),
+            ),
+        ]);
+        let layout = formula_column_layout(&formulas, &columns, 2).unwrap();
+        assert_eq!(layout.data, vec![3, 5, 6]);
+        assert_eq!(layout.formulas, vec![7, 4, 2]);
+        assert_eq!(layout.block_width, 5);
+        assert_eq!(
+            layout.sheet_order(&columns, Some(&formulas)),
+            vec!["first", "a", "mid", "b", "c", "end"]
+        );
+    }
+
+    /// A position past the last column or naming no column is an error.
+    #[test]
+    fn rejects_unknown_positions() {
+        let columns = ["a".to_string()];
+        let past = IndexMap::from([("f".to_string(), formula(Some(FormulaPosition::Index(2))))]);
+        let err = formula_column_layout(&past, &columns, 0).unwrap_err();
+        assert!(
+            err.contains("position 2 is past the last of 1 columns"),
+            "{}",
+            err
+        );
+        let unknown = IndexMap::from([(
+            "f".to_string(),
+            formula(Some(FormulaPosition::After("z".to_string()))),
+        )]);
+        let err = formula_column_layout(&unknown, &columns, 0).unwrap_err();
+        assert!(
+            err.contains("'after:z' names no DataFrame column"),
+            "{}",
+            err
+        );
+    }
 }
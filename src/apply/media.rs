@@ -1,49 +1,187 @@
 //! Image, checkbox, and textbox application helpers.
 
 use crate::parse::{parse_cell_ref, parse_color_enum, parse_column_format};
-use crate::types::{pydict_to_hashmap, CheckboxConfig, ImageConfig, OptionMap, TextboxConfig};
+use crate::types::{
+    pydict_to_hashmap, CheckboxConfig, ImageConfig, ImageSource, OptionMap, TextboxConfig,
+};
 use indexmap::IndexMap;
 use pyo3::prelude::*;
-use rust_xlsxwriter::{Image, Shape, ShapeFont, ShapeFormat, ShapeLine, ShapeSolidFill, Worksheet};
+use rust_xlsxwriter::{
+    Image, ObjectMovement, Shape, ShapeFont, ShapeFormat, ShapeLine, ShapeSolidFill, Worksheet,
+};
 use std::collections::HashMap;
 
-/// Apply images to worksheet
+fn parse_image_anchor(anchor: &str) -> Result<ObjectMovement, String> {
+    match anchor.to_lowercase().as_str() {
+        "move_and_size" => Ok(ObjectMovement::MoveAndSizeWithCells),
+        "move" => Ok(ObjectMovement::MoveButDontSizeWithCells),
+        "absolute" => Ok(ObjectMovement::DontMoveOrSizeWithCells),
+        "move_and_size_after" => Ok(ObjectMovement::MoveAndSizeWithCellsAfter),
+        _ => Err(format!(
+            "Unknown anchor '{}'. Valid: move_and_size, move, absolute, move_and_size_after",
+            anchor
+        )),
+    }
+}
+
+/// The image format named by the data's leading bytes, as rust_xlsxwriter
+/// detects it. Checked before loading, since the library indexes into the
+/// header unguarded and panics on a buffer shorter than four bytes.
+fn detect_image_type(data: &[u8]) -> Option<&'static str> {
+    if data.len() < 4 {
+        None
+    } else if &data[1..4] == b"PNG" {
+        Some("png")
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        Some("jpeg")
+    } else if data.starts_with(b"BM") {
+        Some("bmp")
+    } else if data.starts_with(b"GIF8") {
+        Some("gif")
+    } else {
+        None
+    }
+}
+
+/// Load an image from its path or bytes. The format always comes from the
+/// data itself; a declared `image_type` is checked against it. `context`
+/// names the image in error messages.
+fn load_image(
+    context: &str,
+    source: &ImageSource,
+    image_type: Option<&str>,
+) -> Result<Image, String> {
+    let data = match source {
+        ImageSource::Path(path) => {
+            if image_type.is_some() {
+                return Err(format!(
+                    "{}: 'image_type' only applies to 'data' images",
+                    context
+                ));
+            }
+            return Image::new(path).map_err(|e| format!("Failed to load image '{}': {}", path, e));
+        }
+        ImageSource::Data(data) => data,
+    };
+
+    let detected = detect_image_type(data).ok_or_else(|| {
+        format!(
+            "{}: unrecognized image data; expected PNG, JPEG, GIF, or BMP",
+            context
+        )
+    })?;
+    if let Some(expected) = image_type {
+        let expected = match expected.to_lowercase().as_str() {
+            "jpg" => "jpeg".to_string(),
+            other => other.to_string(),
+        };
+        if !["png", "jpeg", "gif", "bmp"].contains(&expected.as_str()) {
+            return Err(format!(
+                "{}: Unknown image_type '{}'. Valid: png, jpeg, gif, bmp",
+                context, expected
+            ));
+        }
+        if expected != detected {
+            return Err(format!(
+                "{}: image_type is '{}' but the data is {}",
+                context, expected, detected
+            ));
+        }
+    }
+    Image::new_from_buffer(data)
+        .map_err(|e| format!("{}: failed to load image data: {}", context, e))
+}
+
+/// Apply images to worksheet, each at its cell plus an optional pixel offset
 pub(crate) fn apply_images(
     py: Python<'_>,
     worksheet: &mut Worksheet,
     images: &IndexMap<String, ImageConfig>,
 ) -> Result<(), String> {
-    const IMAGE_KEYS: &[&str] = &["scale_width", "scale_height", "alt_text"];
+    const IMAGE_KEYS: &[&str] = &[
+        "scale_width",
+        "scale_height",
+        "alt_text",
+        "x_offset",
+        "y_offset",
+        "anchor",
+        "image_type",
+        "dpi",
+    ];
 
     for (cell_ref, config) in images {
         let (row, col) = parse_cell_ref(cell_ref)?;
+        let context = format!("images['{}']", cell_ref);
 
-        let mut image = Image::new(&config.path)
-            .map_err(|e| format!("Failed to load image '{}': {}", config.path, e))?;
+        let view = config
+            .options
+            .as_ref()
+            .map(|opts| OptionMap::new(py, opts, context.clone()));
+        if let Some(view) = &view {
+            view.reject_unknown(IMAGE_KEYS)?;
+        }
+        let image_type = match &view {
+            Some(view) => view.string("image_type")?,
+            None => None,
+        };
+        let mut image = load_image(&context, &config.source, image_type.as_deref())?;
 
         // Apply options if provided
-        if let Some(opts) = &config.options {
-            let view = OptionMap::new(py, opts, format!("images['{}']", cell_ref));
-            view.reject_unknown(IMAGE_KEYS)?;
-            if let Some(scale) = view.f64("scale_width")? {
+        let (x_offset, y_offset) = if let Some(view) = &view {
+            let mut scale_width = view.f64("scale_width")?;
+            let mut scale_height = view.f64("scale_height")?;
+            // rust_xlsxwriter sizes an image from the DPI stored in its file
+            // and has no setter, so an explicit DPI rescales against that one
+            if let Some(dpi) = view.f64("dpi")? {
+                if !dpi.is_finite() || dpi <= 0.0 {
+                    return Err(format!(
+                        "images['{}']: 'dpi' must be a positive number, got {}",
+                        cell_ref, dpi
+                    ));
+                }
+                scale_width = Some(scale_width.unwrap_or(1.0) * image.width_dpi() / dpi);
+                scale_height = Some(scale_height.unwrap_or(1.0) * image.height_dpi() / dpi);
+            }
+            if let Some(scale) = scale_width {
                 image = image.set_scale_width(scale);
             }
-            if let Some(scale) = view.f64("scale_height")? {
+            if let Some(scale) = scale_height {
                 image = image.set_scale_height(scale);
             }
             if let Some(alt) = view.string("alt_text")? {
                 image = image.set_alt_text(&alt);
             }
-        }
+            if let Some(anchor) = view.string("anchor")? {
+                let movement = parse_image_anchor(&anchor)
+                    .map_err(|e| format!("images['{}']: {}", cell_ref, e))?;
+                image = image.set_object_movement(movement);
+            }
+
+            let x = view.u32("x_offset")?.unwrap_or(0);
+            let y = view.u32("y_offset")?.unwrap_or(0);
+            (x, y)
+        } else {
+            (0, 0)
+        };
 
         worksheet
-            .insert_image(row, col, &image)
+            .insert_image_with_offset(row, col, &image, x_offset, y_offset)
             .map_err(|e| format!("Failed to insert image at '{}': {}", cell_ref, e))?;
     }
 
     Ok(())
 }
 
+/// Set the worksheet's background image, which Excel tiles behind the cells
+pub(crate) fn apply_background_image(
+    worksheet: &mut Worksheet,
+    source: &ImageSource,
+) -> Result<(), String> {
+    let image = load_image("background_image", source, None)?;
+    worksheet.insert_background_image(&image);
+    Ok(())
+}
+
 /// Apply checkboxes to worksheet
 pub(crate) fn apply_checkboxes(
     py: Python<'_>,
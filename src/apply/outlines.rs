@@ -0,0 +1,150 @@
+//! Row and column outline (grouping) application helpers.
+//!
+//! rust_xlsxwriter has no "set outline level" call: every `group_rows` /
+//! `group_columns` call raises the covered indices by one level. The explicit
+//! `(first, last, level)` groups are therefore resolved to a target level per
+//! index first, then emitted level by level as one group per contiguous run,
+//! so each index ends up at exactly its requested level.
+
+use crate::types::OutlineGroup;
+use rust_xlsxwriter::Worksheet;
+use std::collections::BTreeMap;
+
+/// A contiguous run of indices at or above an outline level, and whether a
+/// collapsed group at that level asked for it to start collapsed.
+struct OutlineRun {
+    first: u32,
+    last: u32,
+    collapsed: bool,
+}
+
+/// Resolve `groups` into the runs to group at each level, outermost level
+/// first. An index covered by several groups takes the deepest level. Adjacent
+/// groups at the same level form a single run, which is also how Excel shows
+/// them.
+fn outline_runs(groups: &[OutlineGroup]) -> Vec<OutlineRun> {
+    let mut levels: BTreeMap<u32, u8> = BTreeMap::new();
+    for group in groups {
+        for index in group.first..=group.last {
+            let level = levels.entry(index).or_insert(0);
+            *level = (*level).max(group.level);
+        }
+    }
+    let max_level = levels.values().copied().max().unwrap_or(0);
+
+    let mut runs = Vec::new();
+    for level in 1..=max_level {
+        let mut current: Option<(u32, u32)> = None;
+        let close = |run: (u32, u32), runs: &mut Vec<OutlineRun>| {
+            let collapsed = groups
+                .iter()
+                .any(|g| g.collapsed && g.level == level && g.first >= run.0 && g.last <= run.1);
+            runs.push(OutlineRun {
+                first: run.0,
+                last: run.1,
+                collapsed,
+            });
+        };
+        for (&index, &index_level) in &levels {
+            if index_level < level {
+                continue;
+            }
+            current = match current {
+                Some((first, last)) if last + 1 == index => Some((first, index)),
+                Some(run) => {
+                    close(run, &mut runs);
+                    Some((index, index))
+                }
+                None => Some((index, index)),
+            };
+        }
+        if let Some(run) = current {
+            close(run, &mut runs);
+        }
+    }
+    runs
+}
+
+/// Apply `row_groups`: outline each group of absolute sheet rows at its level,
+/// hiding the rows of collapsed groups.
+pub(crate) fn apply_row_groups(
+    worksheet: &mut Worksheet,
+    groups: &[OutlineGroup],
+) -> Result<(), String> {
+    for run in outline_runs(groups) {
+        let result = if run.collapsed {
+            worksheet.group_rows_collapsed(run.first, run.last)
+        } else {
+            worksheet.group_rows(run.first, run.last)
+        };
+        result.map_err(|e| {
+            format!(
+                "row_groups: failed to group rows {}-{}: {}",
+                run.first, run.last, e
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Apply `col_groups`: outline each group of absolute sheet columns at its
+/// level, hiding the columns of collapsed groups.
+pub(crate) fn apply_col_groups(
+    worksheet: &mut Worksheet,
+    groups: &[OutlineGroup],
+) -> Result<(), String> {
+    for run in outline_runs(groups) {
+        // Extraction bounds every index to Excel's column range
+        let (first, last) = (run.first as u16, run.last as u16);
+        let result = if run.collapsed {
+            worksheet.group_columns_collapsed(first, last)
+        } else {
+            worksheet.group_columns(first, last)
+        };
+        result.map_err(|e| {
+            format!(
+                "col_groups: failed to group columns {}-{}: {}",
+                run.first, run.last, e
+            )
+        })?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::outline_runs;
+    use crate::types::OutlineGroup;
+
+    fn group(first: u32, last: u32, level: u8, collapsed: bool) -> OutlineGroup {
+        OutlineGroup {
+            first,
+            last,
+            level,
+            collapsed,
+        }
+    }
+
+    /// Nested and deeper-only groups resolve to one run per level, so each
+    /// index is grouped exactly `level` times.
+    #[test]
+    fn runs_reach_each_explicit_level() {
+        let groups = [
+            group(1, 10, 1, false),
+            group(2, 4, 2, false),
+            group(6, 9, 2, true),
+        ];
+        let runs: Vec<_> = outline_runs(&groups)
+            .iter()
+            .map(|r| (r.first, r.last, r.collapsed))
+            .collect();
+        assert_eq!(runs, vec![(1, 10, false), (2, 4, false), (6, 9, true)]);
+
+        // A level-2 group with no enclosing level-1 group is still grouped twice
+        let runs: Vec<_> = outline_runs(&[group(3, 4, 2, false)])
+            .iter()
+            .map(|r| (r.first, r.last))
+            .collect();
+        assert_eq!(runs, vec![(3, 4), (3, 4)]);
+    }
+}
@@ -0,0 +1,94 @@
+//! Print layout application helpers.
+//!
+//! These settings only change how Excel prints the sheet; they touch no cells,
+//! so they are applied during the data write and also work under
+//! `constant_memory`.
+
+use crate::parse::parse_cell_range;
+use crate::types::{HeaderFooter, PageOrientation, PageSetup};
+use rust_xlsxwriter::{HeaderImagePosition, Image, Worksheet};
+
+/// Apply a `page_setup` option: orientation, fit-to-pages scaling, paper size,
+/// margins, and the printed header and footer. Margins left unset keep
+/// Excel's default for that edge.
+pub(crate) fn apply_page_setup(worksheet: &mut Worksheet, setup: &PageSetup) -> Result<(), String> {
+    match setup.orientation {
+        Some(PageOrientation::Portrait) => {
+            worksheet.set_portrait();
+        }
+        Some(PageOrientation::Landscape) => {
+            worksheet.set_landscape();
+        }
+        None => {}
+    }
+    if let Some((width, height)) = setup.fit_to_pages {
+        worksheet.set_print_fit_to_pages(width, height);
+    }
+    if let Some(paper_size) = setup.paper_size {
+        worksheet.set_paper_size(paper_size);
+    }
+    if let Some(margins) = setup.margins {
+        // rust_xlsxwriter treats a negative margin as "keep the default"
+        let edge = |inches: Option<f64>| inches.unwrap_or(-1.0);
+        worksheet.set_margins(
+            edge(margins.left),
+            edge(margins.right),
+            edge(margins.top),
+            edge(margins.bottom),
+            edge(margins.header),
+            edge(margins.footer),
+        );
+    }
+    if let Some(header) = &setup.header {
+        worksheet.set_header(&header.text);
+        apply_header_footer_images(worksheet, header, "header")?;
+    }
+    if let Some(footer) = &setup.footer {
+        worksheet.set_footer(&footer.text);
+        apply_header_footer_images(worksheet, footer, "footer")?;
+    }
+    if let Some(enable) = setup.header_footer_scale_with_doc {
+        worksheet.set_header_footer_scale_with_doc(enable);
+    }
+    Ok(())
+}
+
+/// Insert the images of a header (`kind` "header") or footer already set on
+/// the worksheet. rust_xlsxwriter rejects an image whose section of the text
+/// has no `&[Picture]` placeholder.
+fn apply_header_footer_images(
+    worksheet: &mut Worksheet,
+    header_footer: &HeaderFooter,
+    kind: &str,
+) -> Result<(), String> {
+    let positions = [
+        HeaderImagePosition::Left,
+        HeaderImagePosition::Center,
+        HeaderImagePosition::Right,
+    ];
+    for (path, position) in header_footer.images.iter().zip(positions) {
+        let Some(path) = path else {
+            continue;
+        };
+        let image = Image::new(path)
+            .map_err(|e| format!("Failed to load {} image '{}': {}", kind, path, e))?;
+        let result = if kind == "header" {
+            worksheet.set_header_image(&image, position)
+        } else {
+            worksheet.set_footer_image(&image, position)
+        };
+        result.map_err(|e| format!("Failed to set {} image '{}': {}", kind, path, e))?;
+    }
+    Ok(())
+}
+
+/// Apply a `print_area` range like `"A1:F50"`. The range is honored as given,
+/// even when it extends past the written data.
+pub(crate) fn apply_print_area(worksheet: &mut Worksheet, range: &str) -> Result<(), String> {
+    let (first_row, first_col, last_row, last_col) =
+        parse_cell_range(range).map_err(|e| format!("Invalid print_area: {}", e))?;
+    worksheet
+        .set_print_area(first_row, first_col, last_row, last_col)
+        .map_err(|e| format!("Failed to set print_area '{}': {}", range, e))?;
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Extra Excel tables over explicit cell ranges.
+
+use crate::parse::{
+    parse_cell_range, parse_table_style, ranges_intersect, sanitize_table_name, CellRange,
+};
+use crate::types::TableSpec;
+use rust_xlsxwriter::utility::cell_range;
+use rust_xlsxwriter::{Table, Worksheet};
+use std::collections::HashMap;
+
+/// Check the `tables` entries before any is added: no range may overlap
+/// another or intersect `filter_region` (the labelled `table_style` table or
+/// autofilter range), and no name may repeat one in the list or
+/// `main_table_name`. Every problem is reported in one error.
+fn validate_tables(
+    tables: &[TableSpec],
+    main_table_name: Option<&str>,
+    filter_region: Option<(&str, CellRange)>,
+) -> Result<Vec<CellRange>, String> {
+    let ranges = tables
+        .iter()
+        .map(|spec| parse_cell_range(&spec.range))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let mut problems = Vec::new();
+    for (i, (spec, &range)) in tables.iter().zip(&ranges).enumerate() {
+        for (other, &other_range) in tables[i + 1..].iter().zip(&ranges[i + 1..]) {
+            if ranges_intersect(range, other_range) {
+                problems.push(format!("'{}' overlaps '{}'", spec.range, other.range));
+            }
+        }
+        if let Some((label, region)) = filter_region {
+            if ranges_intersect(range, region) {
+                problems.push(format!(
+                    "'{}' intersects the {} range {}",
+                    spec.range,
+                    label,
+                    cell_range(region.0, region.1, region.2, region.3)
+                ));
+            }
+        }
+    }
+
+    // Excel table names are case-insensitive
+    let mut owners: HashMap<String, String> = HashMap::new();
+    if let Some(name) = main_table_name {
+        owners.insert(
+            sanitize_table_name(name).to_ascii_lowercase(),
+            "table_name".to_string(),
+        );
+    }
+    for spec in tables {
+        let Some(name) = &spec.name else { continue };
+        let sanitized = sanitize_table_name(name);
+        let owner = format!("'{}'", spec.range);
+        if let Some(previous) = owners.insert(sanitized.to_ascii_lowercase(), owner) {
+            problems.push(format!(
+                "table name '{}' for '{}' is already used by {}",
+                sanitized, spec.range, previous
+            ));
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(ranges)
+    } else {
+        Err(format!("tables: {}", problems.join("; ")))
+    }
+}
+
+/// Add each `tables` entry as an Excel table over its range. The range's first
+/// row is the header row, taking its names from the strings already written
+/// there, and with `total_row` its last row is the totals row. Unnamed tables
+/// get Excel's default names. Overlapping ranges, or ranges intersecting
+/// `filter_region`, are rejected before any table is added.
+pub(crate) fn apply_tables(
+    worksheet: &mut Worksheet,
+    tables: &[TableSpec],
+    main_table_name: Option<&str>,
+    filter_region: Option<(&str, CellRange)>,
+) -> Result<(), String> {
+    let ranges = validate_tables(tables, main_table_name, filter_region)?;
+
+    for (spec, (first_row, first_col, last_row, last_col)) in tables.iter().zip(ranges) {
+        let mut table = Table::new().set_total_row(spec.total_row);
+        if let Some(style) = &spec.style {
+            table = table.set_style(parse_table_style(style)?);
+        }
+        if let Some(name) = &spec.name {
+            table = table.set_name(sanitize_table_name(name));
+        }
+        worksheet
+            .add_table(first_row, first_col, last_row, last_col, &table)
+            .map_err(|e| format!("tables['{}']: Failed to add table: {}", spec.range, e))?;
+    }
+    Ok(())
+}
@@ -1,23 +1,33 @@
 //! Data validation application helpers.
 
-use crate::parse::matches_pattern;
+use crate::parse::{matches_pattern, naive_datetime_to_excel, naive_time_to_excel};
 use crate::types::{pytype_name, OptionMap, ValidationConfig};
+use crate::workbook::parse_iso_datetime;
 use indexmap::IndexMap;
 use pyo3::prelude::*;
-use rust_xlsxwriter::{DataValidation, DataValidationErrorStyle, Worksheet};
+use rust_xlsxwriter::{
+    DataValidation, DataValidationErrorStyle, ExcelDateTime, Formula, Worksheet,
+};
 
-/// Every validation type accepts `type` plus the shared input/error
-/// title/message keys; only the type-specific keys (e.g. `values`, or
-/// `min`/`max`) differ. Combine them here instead of indexing into a shared
+/// Excel serials spanning the `date` and `time` validation types, used for
+/// an omitted `min`/`max`: 1900-01-01 to 9999-12-31, and 00:00:00 to 23:59:59.
+const DATE_SERIAL_RANGE: (f64, f64) = (1.0, 2_958_465.0);
+const TIME_SERIAL_RANGE: (f64, f64) = (0.0, 86_399.0 / 86_400.0);
+
+/// Every validation type accepts `type`, `ignore_blank`, and the shared
+/// input/error title/message keys; only the type-specific keys (e.g.
+/// `values`, or `min`/`max`) differ. Combine them here instead of indexing into a shared
 /// keys array positionally at each call site.
 fn keys_with(extra: &[&'static str]) -> Vec<&'static str> {
     let mut keys = vec!["type"];
     keys.extend_from_slice(extra);
     keys.extend_from_slice(&[
+        "ignore_blank",
         "input_title",
         "input_message",
         "error_title",
         "error_message",
+        "error_style",
     ]);
     keys
 }
@@ -74,6 +84,87 @@ fn validation_i32_field(view: &OptionMap<'_, '_>, key: &str, default: i32) -> Re
     ))
 }
 
+/// Read a `date`/`time` validation bound as ISO text: a string as given, or
+/// the `isoformat()` of a Python date, datetime, or time. Missing or `None`
+/// yields `Ok(None)`.
+fn validation_iso_field(view: &OptionMap<'_, '_>, key: &str) -> Result<Option<String>, String> {
+    let Some(entry) = view.get(key) else {
+        return Ok(None);
+    };
+    let bound = entry.bind(view.py());
+    if bound.is_none() {
+        return Ok(None);
+    }
+    if let Ok(text) = bound.extract::<String>() {
+        return Ok(Some(text));
+    }
+    if bound.cast::<pyo3::types::PyDate>().is_ok() || bound.cast::<pyo3::types::PyTime>().is_ok() {
+        return bound
+            .call_method0("isoformat")
+            .and_then(|iso| iso.extract::<String>())
+            .map(Some)
+            .map_err(|e| format!("{}: '{}': {}", view.context(), key, e));
+    }
+    Err(format!(
+        "{}: '{}' must be an ISO string, date, datetime, or time, got {}",
+        view.context(),
+        key,
+        pytype_name(bound)
+    ))
+}
+
+/// Parse a `date` validation bound, an ISO date (`"2024-01-31"`) or datetime
+/// (`"2024-01-31T09:30:00"`), into an Excel serial. Dates before 1900-03-01 are
+/// rejected: Excel's serials there count a phantom 1900-02-29.
+fn validation_date_field(view: &OptionMap<'_, '_>, key: &str, default: f64) -> Result<f64, String> {
+    let Some(text) = validation_iso_field(view, key)? else {
+        return Ok(default);
+    };
+    let datetime = parse_iso_datetime(&text).ok_or_else(|| {
+        format!(
+            "{}: invalid '{}' date '{}'. Use an ISO 8601 date or datetime, e.g. '2024-01-31'",
+            view.context(),
+            key,
+            text
+        )
+    })?;
+    let serial = naive_datetime_to_excel(datetime);
+    if serial < 61.0 {
+        return Err(format!(
+            "{}: '{}' date '{}' is before 1900-03-01, which Excel can't validate reliably",
+            view.context(),
+            key,
+            text
+        ));
+    }
+    Ok(serial)
+}
+
+/// Parse a `time` validation bound, an ISO time (`"09:30"` or
+/// `"17:45:30"`), into an Excel serial: the fraction of the day.
+fn validation_time_field(view: &OptionMap<'_, '_>, key: &str, default: f64) -> Result<f64, String> {
+    let Some(text) = validation_iso_field(view, key)? else {
+        return Ok(default);
+    };
+    ["%H:%M:%S%.f", "%H:%M"]
+        .iter()
+        .find_map(|pattern| chrono::NaiveTime::parse_from_str(text.trim(), pattern).ok())
+        .map(naive_time_to_excel)
+        .ok_or_else(|| {
+            format!(
+                "{}: invalid '{}' time '{}'. Use an ISO 8601 time, e.g. '09:30' or '17:45:30'",
+                view.context(),
+                key,
+                text
+            )
+        })
+}
+
+/// A `date`/`time` bound for rust_xlsxwriter, which takes them as `ExcelDateTime`
+fn serial_to_datetime(view: &OptionMap<'_, '_>, serial: f64) -> Result<ExcelDateTime, String> {
+    ExcelDateTime::from_serial_datetime(serial).map_err(|e| format!("{}: {}", view.context(), e))
+}
+
 /// Build the `DataValidation` for a single validation config: rejects unknown
 /// keys, dispatches by `type` to the type-specific rule, and layers on the
 /// optional input/error message. Called once per `(col_pattern, config)`
@@ -87,38 +178,53 @@ fn build_validation(view: &OptionMap<'_, '_>, col_pattern: &str) -> Result<DataV
         .extract()
         .map_err(|e| format!("validations['{}']: invalid 'type': {}", col_pattern, e))?;
 
-    let validation = match val_type.to_lowercase().as_str() {
+    let mut validation = match val_type.to_lowercase().as_str() {
         "list" => {
-            view.reject_unknown(&keys_with(&["values"]))?;
-            // List validation: dropdown with values
-            let values: Vec<String> = view
-                .get("values")
-                .ok_or_else(|| {
-                    format!(
-                        "validations['{}']: list type requires 'values'",
+            view.reject_unknown(&keys_with(&["values", "source_range", "show_dropdown"]))?;
+            // A dropdown sourced from a cell range (e.g. "=Lists!$A$1:$A$100")
+            // is stored as a formula, so the inline 255 character limit below
+            // doesn't apply
+            if let Some(source) = view.string("source_range")? {
+                if view.get("values").is_some() {
+                    return Err(format!(
+                        "validations['{}']: list type takes 'values' or 'source_range', not both",
                         col_pattern
-                    )
-                })?
-                .bind(view.py())
-                .extract()
-                .map_err(|e| format!("validations['{}']: invalid 'values': {}", col_pattern, e))?;
-
-            // Check Excel's 255 character limit for list validation.
-            // Count characters, not bytes — Excel's limit is on characters.
-            let total_chars: usize = values.iter().map(|s| s.chars().count()).sum::<usize>()
-                + values.len().saturating_sub(1); // commas between items
-            if total_chars > 255 {
-                return Err(format!(
-                    "validations['{}']: list values exceed Excel's 255 character limit ({} chars). \
-                     Use fewer or shorter values.",
-                    col_pattern, total_chars
-                ));
-            }
+                    ));
+                }
+                DataValidation::new().allow_list_formula(Formula::new(source))
+            } else {
+                // List validation: dropdown with values
+                let values: Vec<String> = view
+                    .get("values")
+                    .ok_or_else(|| {
+                        format!(
+                            "validations['{}']: list type requires 'values' or 'source_range'",
+                            col_pattern
+                        )
+                    })?
+                    .bind(view.py())
+                    .extract()
+                    .map_err(|e| {
+                        format!("validations['{}']: invalid 'values': {}", col_pattern, e)
+                    })?;
 
-            let values_refs: Vec<&str> = values.iter().map(|s| s.as_str()).collect();
-            DataValidation::new()
-                .allow_list_strings(&values_refs)
-                .map_err(|e| format!("Failed to create list validation: {}", e))?
+                // Check Excel's 255 character limit for list validation.
+                // Count characters, not bytes — Excel's limit is on characters.
+                let total_chars: usize = values.iter().map(|s| s.chars().count()).sum::<usize>()
+                    + values.len().saturating_sub(1); // commas between items
+                if total_chars > 255 {
+                    return Err(format!(
+                        "validations['{}']: list values exceed Excel's 255 character limit ({} chars). \
+                         Use fewer or shorter values, or a 'source_range'.",
+                        col_pattern, total_chars
+                    ));
+                }
+
+                let values_refs: Vec<&str> = values.iter().map(|s| s.as_str()).collect();
+                DataValidation::new()
+                    .allow_list_strings(&values_refs)
+                    .map_err(|e| format!("Failed to create list validation: {}", e))?
+            }
         }
         "whole_number" | "whole" | "integer" => {
             view.reject_unknown(&keys_with(&["min", "max"]))?;
@@ -144,14 +250,41 @@ fn build_validation(view: &OptionMap<'_, '_>, col_pattern: &str) -> Result<DataV
             DataValidation::new()
                 .allow_text_length(rust_xlsxwriter::DataValidationRule::Between(min, max))
         }
+        "date" => {
+            view.reject_unknown(&keys_with(&["min", "max"]))?;
+            let min = validation_date_field(view, "min", DATE_SERIAL_RANGE.0)?;
+            let max = validation_date_field(view, "max", DATE_SERIAL_RANGE.1)?;
+            DataValidation::new().allow_date(rust_xlsxwriter::DataValidationRule::Between(
+                serial_to_datetime(view, min)?,
+                serial_to_datetime(view, max)?,
+            ))
+        }
+        "time" => {
+            view.reject_unknown(&keys_with(&["min", "max"]))?;
+            let min = validation_time_field(view, "min", TIME_SERIAL_RANGE.0)?;
+            let max = validation_time_field(view, "max", TIME_SERIAL_RANGE.1)?;
+            DataValidation::new().allow_time(rust_xlsxwriter::DataValidationRule::Between(
+                serial_to_datetime(view, min)?,
+                serial_to_datetime(view, max)?,
+            ))
+        }
         _ => {
             return Err(format!(
-                "Unknown validation type '{}'. Valid types: list, whole_number, decimal, text_length",
+                "Unknown validation type '{}'. Valid types: list, whole_number, decimal, text_length, date, time",
                 val_type
             ));
         }
     };
 
+    // Blank cells pass by default; the dropdown arrow (lists only, as the
+    // other types reject the key above) is shown by default
+    if let Some(ignore) = view.bool("ignore_blank")? {
+        validation = validation.ignore_blank(ignore);
+    }
+    if let Some(show) = view.bool("show_dropdown")? {
+        validation = validation.show_dropdown(show);
+    }
+
     // Add optional input message
     let validation = if let Some(msg) = view.string("input_message")? {
         let title = view.string("input_title")?.unwrap_or_default();
@@ -172,19 +305,40 @@ fn build_validation(view: &OptionMap<'_, '_>, col_pattern: &str) -> Result<DataV
             .map_err(|e| format!("Failed to set error title: {}", e))?
             .set_error_message(&msg)
             .map_err(|e| format!("Failed to set error message: {}", e))?
-            .set_error_style(DataValidationErrorStyle::Stop)
     } else {
         validation
     };
 
+    // Stop (Excel's default) blocks invalid entries; warning and information
+    // alerts let the user keep them
+    let validation = match view.string("error_style")? {
+        Some(style) => validation.set_error_style(
+            parse_error_style(&style).map_err(|e| format!("{}: {}", view.context(), e))?,
+        ),
+        None => validation,
+    };
+
     Ok(validation)
 }
 
+fn parse_error_style(style: &str) -> Result<DataValidationErrorStyle, String> {
+    match style.to_lowercase().as_str() {
+        "stop" => Ok(DataValidationErrorStyle::Stop),
+        "warning" => Ok(DataValidationErrorStyle::Warning),
+        "information" | "info" => Ok(DataValidationErrorStyle::Information),
+        _ => Err(format!(
+            "Unknown error_style '{}'. Valid: stop, warning, information",
+            style
+        )),
+    }
+}
+
 /// Apply data validations to worksheet
 pub(crate) fn apply_validations(
     py: Python<'_>,
     worksheet: &mut Worksheet,
     columns: &[String],
+    data_cols: &[u16],
     data_start_row: u32,
     data_end_row: u32,
     validations: &IndexMap<String, ValidationConfig>,
@@ -195,7 +349,7 @@ pub(crate) fn apply_validations(
             .iter()
             .enumerate()
             .filter(|(_, name)| matches_pattern(name, col_pattern))
-            .map(|(idx, _)| idx as u16) // safe: col_count already validated via u16::try_from
+            .map(|(idx, _)| data_cols[idx])
             .collect();
 
         if col_indices.is_empty() {
@@ -222,3 +376,49 @@ pub(crate) fn apply_validations(
 
     Ok(())
 }
+
+/// Add a dropdown list validation over each categorical column, listing its
+/// categories in order (`categorical_validation`). A column matched by any
+/// `validations` pattern keeps that validation instead.
+pub(crate) fn apply_categorical_validations(
+    worksheet: &mut Worksheet,
+    columns: &[String],
+    categories: &[Option<Vec<String>>],
+    data_cols: &[u16],
+    data_start_row: u32,
+    data_end_row: u32,
+    validations: Option<&IndexMap<String, ValidationConfig>>,
+) -> Result<(), String> {
+    for (idx, (name, labels)) in columns.iter().zip(categories).enumerate() {
+        let Some(labels) = labels else {
+            continue;
+        };
+        let has_validation =
+            validations.is_some_and(|v| v.keys().any(|pattern| matches_pattern(name, pattern)));
+        if has_validation || labels.is_empty() {
+            continue;
+        }
+
+        // Same 255 character limit as a `list` validation's values
+        let total_chars: usize = labels.iter().map(|s| s.chars().count()).sum::<usize>()
+            + labels.len().saturating_sub(1);
+        if total_chars > 255 {
+            return Err(format!(
+                "categorical_validation: categories of column '{}' exceed Excel's 255 character \
+                 list limit ({} chars)",
+                name, total_chars
+            ));
+        }
+
+        let label_refs: Vec<&str> = labels.iter().map(String::as_str).collect();
+        let validation = DataValidation::new()
+            .allow_list_strings(&label_refs)
+            .map_err(|e| format!("Failed to create list validation: {}", e))?;
+        let col = data_cols[idx];
+        worksheet
+            .add_data_validation(data_start_row, col, data_end_row, col, &validation)
+            .map_err(|e| format!("Failed to add validation: {}", e))?;
+    }
+
+    Ok(())
+}
@@ -1,24 +1,41 @@
 //! Core conversion functions for CSV and DataFrame to XLSX
 
 use crate::apply::{
-    apply_cells, apply_charts, apply_checkboxes, apply_column_widths,
-    apply_column_widths_with_autofit_cap, apply_comments, apply_conditional_formats,
-    apply_formula_columns, apply_hyperlinks, apply_images, apply_merged_ranges, apply_rich_text,
-    apply_sparklines, apply_textboxes, apply_validations,
+    apply_background_image, apply_categorical_validations, apply_cell_overrides, apply_cells,
+    apply_charts, apply_checkboxes, apply_col_groups, apply_column_widths,
+    apply_column_widths_with_autofit_cap, apply_column_widths_with_selected_autofit,
+    apply_comments, apply_conditional_formats, apply_formula_columns, apply_hyperlinks,
+    apply_images, apply_merged_ranges, apply_page_setup, apply_print_area, apply_rich_text,
+    apply_row_groups, apply_sparklines, apply_summary_row, apply_tables, apply_textboxes,
+    apply_validations, formula_column_layout, ChartDataLayout, ColumnLayout,
 };
 use crate::parse::{
-    build_column_formats, parse_header_format, parse_table_style, parse_value, sanitize_table_name,
+    build_column_formats, epoch_days_to_date, epoch_micros_to_datetime, matches_pattern,
+    parse_color, parse_column_format_over, parse_csv_field, parse_header_format, parse_num_format,
+    parse_table_style, python_datetime_str, sanitize_string, sanitize_table_name,
 };
 use crate::types::{
-    extract_columns, is_polars_dataframe, CellValue, DateOrder, EffectiveOpts, ExtractedOptions,
-    WriteConfig,
+    extract_columns, is_polars_dataframe, pytype_name, Autofit, CalcSettings, CellValue,
+    ColumnSelector, CsvOptions, EffectiveOpts, ExtractedOptions, FreezePanes, Progress, SheetView,
+    SummaryFunction, TableColumnConfig, TableTotal, WriteConfig,
+};
+use crate::workbook::{apply_defined_names, save_workbook, OutputTarget};
+use crate::write::{
+    write_category, write_cell, write_integer_like_float, write_markup_string, write_naive_date,
+    write_naive_datetime, write_py_value_with_format, write_text_value, CellWriteOptions,
+    DATETIME_NUM_FORMAT, DATE_NUM_FORMAT, TIME_NUM_FORMAT,
 };
-use crate::workbook::apply_defined_names;
-use crate::write::{write_cell, write_py_value_with_format, DATETIME_NUM_FORMAT, DATE_NUM_FORMAT};
 use csv::ReaderBuilder;
+use encoding_rs::{Encoding, UTF_8};
+use encoding_rs_io::{DecodeReaderBytes, DecodeReaderBytesBuilder};
+use indexmap::IndexMap;
 use pyo3::prelude::*;
+use pyo3::types::PyBool;
 use rayon::prelude::*;
-use rust_xlsxwriter::{Format, Table, Workbook, Worksheet};
+use rust_xlsxwriter::{
+    DocProperties, Format, Table, TableColumn, TableFunction, Workbook, Worksheet,
+};
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fs::File;
 
@@ -28,7 +45,9 @@ use std::fs::File;
 /// * `input_path` - Path to the input CSV file
 /// * `output_path` - Path for the output XLSX file
 /// * `sheet_name` - Name of the worksheet (default: "Sheet1")
-/// * `date_order` - Date parsing order for ambiguous dates (default: Auto)
+/// * `options` - Date order, number formats, NaN/large-int handling,
+///   constant-memory mode, smart numeric detection, CSV dialect, input
+///   encoding, and header row styling (see `CsvOptions`)
 ///
 /// # Returns
 /// * `Ok((rows, cols))` - Number of rows and columns written
@@ -37,52 +56,115 @@ pub fn convert_csv_to_xlsx(
     input_path: &str,
     output_path: &str,
     sheet_name: &str,
-    date_order: DateOrder,
+    options: &CsvOptions,
+) -> Result<(u32, u16), String> {
+    let mut workbook = Workbook::new();
+    let dims = write_csv_sheet(&mut workbook, input_path, sheet_name, options, false)?;
+    workbook
+        .save(output_path)
+        .map_err(|e| format!("Failed to save workbook to '{}': {}", output_path, e))?;
+    Ok(dims)
+}
+
+/// Convert several CSV files into one workbook, one sheet per file in the
+/// given order. Each input is `(input_path, sheet_name, options)`; with
+/// `parallel`, each file is parsed as in `convert_csv_to_xlsx_parallel`.
+/// Errors name the sheet they occurred on.
+///
+/// # Returns
+/// * `Ok(dims)` - `(rows, cols)` written to each sheet, in input order
+/// * `Err(message)` - Error description if any conversion fails
+pub fn convert_csvs_to_xlsx(
+    inputs: &[(String, String, CsvOptions)],
+    output_path: &str,
+    parallel: bool,
+) -> Result<Vec<(u32, u16)>, String> {
+    let mut workbook = Workbook::new();
+    let mut dims = Vec::with_capacity(inputs.len());
+    for (input_path, sheet_name, options) in inputs {
+        dims.push(
+            write_csv_sheet(&mut workbook, input_path, sheet_name, options, parallel)
+                .map_err(|e| format!("sheet '{}': {}", sheet_name, e))?,
+        );
+    }
+    workbook
+        .save(output_path)
+        .map_err(|e| format!("Failed to save workbook to '{}': {}", output_path, e))?;
+    Ok(dims)
+}
+
+/// Add a worksheet named `sheet_name` to `workbook` and write the CSV file at
+/// `input_path` into it, parsing chunks of rows in parallel when `parallel`
+/// is set. Returns the rows and columns written.
+fn write_csv_sheet(
+    workbook: &mut Workbook,
+    input_path: &str,
+    sheet_name: &str,
+    options: &CsvOptions,
+    parallel: bool,
 ) -> Result<(u32, u16), String> {
+    if parallel && options.constant_memory {
+        return Err(
+            "constant_memory cannot be combined with parallel: parallel parsing buffers \
+             chunks of rows in memory"
+                .to_string(),
+        );
+    }
+    // Validate formats before touching the filesystem
+    let cell_options = csv_cell_options(options)?;
+
     // Open CSV file (csv::ReaderBuilder handles buffering internally)
-    let file = File::open(input_path).map_err(|e| format!("Failed to open input file: {}", e))?;
-    let mut csv_reader = ReaderBuilder::new()
-        .has_headers(false)
-        .flexible(true)
-        .buffer_capacity(1024 * 1024)
-        .from_reader(file);
+    let mut csv_reader = open_csv_reader(input_path, options)?;
 
-    // Create workbook and worksheet
-    let mut workbook = rust_xlsxwriter::Workbook::new();
-    let worksheet = workbook.add_worksheet();
+    // Records are written as they're read, so with a constant-memory
+    // worksheet (each finished row is flushed to a temp file) peak memory
+    // stays flat regardless of file size.
+    let worksheet = if options.constant_memory {
+        workbook.add_worksheet_with_constant_memory()
+    } else {
+        workbook.add_worksheet()
+    };
     worksheet
         .set_name(sheet_name)
         .map_err(|e| format!("Failed to set sheet name: {}", e))?;
 
-    // Create formats for dates and datetimes
-    let date_format = Format::new().set_num_format(DATE_NUM_FORMAT);
-    let datetime_format = Format::new().set_num_format(DATETIME_NUM_FORMAT);
+    if parallel {
+        write_csv_records_parallel(worksheet, &mut csv_reader, options, &cell_options)
+    } else {
+        write_csv_records(worksheet, &mut csv_reader, options, &cell_options)
+    }
+}
 
+/// Parse and write each record as it is read.
+fn write_csv_records(
+    worksheet: &mut Worksheet,
+    csv_reader: &mut csv::Reader<DecodeReaderBytes<File, Vec<u8>>>,
+    options: &CsvOptions,
+    cell_options: &CellWriteOptions,
+) -> Result<(u32, u16), String> {
     let mut row_count: u32 = 0;
     let mut col_count: u16 = 0;
 
-    // Process records
     for result in csv_reader.records() {
         let record = result.map_err(|e| format!("CSV parse error at row {}: {}", row_count, e))?;
+        if options.skip_empty_rows && is_blank_record(&record) {
+            continue;
+        }
         let num_cols = u16::try_from(record.len())
             .map_err(|_| format!("Column count {} exceeds u16 limit", record.len()))?;
         if num_cols > col_count {
             col_count = num_cols;
         }
 
-        for (col_idx, value) in record.iter().enumerate() {
-            let cell_value = parse_value(value, date_order);
-            let col = u16::try_from(col_idx)
-                .map_err(|_| format!("Column index {} exceeds u16 limit", col_idx))?;
-            write_cell(
-                worksheet,
-                row_count,
-                col,
-                cell_value,
-                &date_format,
-                &datetime_format,
-            )
-            .map_err(|e| format!("Write error at ({}, {}): {}", row_count, col_idx, e))?;
+        if row_count == 0 && options.has_header {
+            write_csv_header(worksheet, &record, options)?;
+        } else {
+            for (col_idx, value) in record.iter().enumerate() {
+                let cell_value = parse_csv_field(value, col_idx, options);
+                let col = u16::try_from(col_idx)
+                    .map_err(|_| format!("Column index {} exceeds u16 limit", col_idx))?;
+                write_cell(worksheet, row_count, col, cell_value, cell_options)?;
+            }
         }
 
         row_count = row_count
@@ -90,14 +172,189 @@ pub fn convert_csv_to_xlsx(
             .ok_or("Row count exceeds u32 limit")?;
     }
 
-    // Save workbook
-    workbook
-        .save(output_path)
-        .map_err(|e| format!("Failed to save workbook to '{}': {}", output_path, e))?;
-
     Ok((row_count, col_count))
 }
 
+/// Parse a CSV dialect character (`delimiter`, `quote`, or `escape`) given as
+/// a one-character string into its byte. It must be ASCII and not a line
+/// break; the two-character spelling `\t` is accepted for a tab, for shells
+/// where a literal tab is awkward to type. `param` names the option in errors.
+pub fn parse_csv_char(param: &str, value: &str) -> Result<u8, String> {
+    let byte = match value {
+        "\\t" => Some(b'\t'),
+        _ if value.len() == 1 => Some(value.as_bytes()[0]),
+        _ => None,
+    };
+    match byte {
+        Some(b) if b.is_ascii() && b != b'\n' && b != b'\r' => Ok(b),
+        _ => Err(format!(
+            "Invalid {} '{}': must be a single ASCII character other than a line break",
+            param,
+            value.escape_debug()
+        )),
+    }
+}
+
+/// Parse a `decimal_separator` or `thousands_separator` given as a
+/// one-character string. Digits, signs, and line breaks are rejected; any
+/// other character is accepted, including a space or non-breaking space as a
+/// thousands separator. `param` names the option in errors.
+pub fn parse_number_separator(param: &str, value: &str) -> Result<char, String> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if !c.is_ascii_digit() && !matches!(c, '-' | '+' | '\n' | '\r') => Ok(c),
+        _ => Err(format!(
+            "Invalid {} '{}': must be a single character other than a digit, sign, or line break",
+            param,
+            value.escape_debug()
+        )),
+    }
+}
+
+/// Look up a text encoding by name for the CSV `encoding` option. Accepts the
+/// WHATWG labels browsers use ("utf-8", "windows-1252", "latin1",
+/// "iso-8859-15", "shift_jis", "utf-16le", ...), case-insensitively, plus
+/// Python-style spellings such as "latin-1" and "utf_8".
+pub fn parse_encoding(name: &str) -> Result<&'static Encoding, String> {
+    let spellings = [
+        name.to_string(),
+        name.replace('_', "-"),
+        name.replace(['-', '_'], ""),
+    ];
+    spellings
+        .iter()
+        .find_map(|label| Encoding::for_label(label.trim().as_bytes()))
+        .ok_or_else(|| {
+            format!(
+                "Unknown encoding '{}'. Use an encoding name such as utf-8, windows-1252, \
+                 latin-1, iso-8859-15, shift_jis, or utf-16",
+                name
+            )
+        })
+}
+
+/// Open `input_path` for reading with the CSV dialect in `options`. Rows may
+/// have differing lengths, and the first row is returned as a record like the
+/// rest (callers handle `has_header` and `skip_empty_rows`). Lines starting
+/// with `options.comment` are dropped here. The delimiter, quote, escape, and
+/// comment characters must all differ, as must the decimal and thousands
+/// separators. Input in an encoding other than UTF-8 is
+/// decoded to UTF-8 as it streams in; a byte-order mark, if present, takes
+/// precedence over `options.encoding`.
+fn open_csv_reader(
+    input_path: &str,
+    options: &CsvOptions,
+) -> Result<csv::Reader<DecodeReaderBytes<File, Vec<u8>>>, String> {
+    let delimiter = options.delimiter.unwrap_or(b',');
+    let quote = options.quote.unwrap_or(b'"');
+    if delimiter == quote {
+        return Err("CSV delimiter and quote must be different characters".to_string());
+    }
+    if options.escape.is_some_and(|e| e == delimiter || e == quote) {
+        return Err("CSV escape must differ from the delimiter and quote characters".to_string());
+    }
+    if options
+        .comment
+        .is_some_and(|c| c == delimiter || c == quote || Some(c) == options.escape)
+    {
+        return Err(
+            "CSV comment must differ from the delimiter, quote, and escape characters".to_string(),
+        );
+    }
+    if options.thousands_separator == Some(options.decimal_separator.unwrap_or('.')) {
+        return Err(
+            "CSV decimal_separator and thousands_separator must be different \
+                    characters"
+                .to_string(),
+        );
+    }
+    let file = File::open(input_path).map_err(|e| format!("Failed to open input file: {}", e))?;
+    // UTF-8 passes through to the csv reader untouched (minus any BOM), so
+    // invalid bytes are still reported as errors rather than replaced
+    let encoding = options.encoding.filter(|&encoding| encoding != UTF_8);
+    let input = DecodeReaderBytesBuilder::new()
+        .encoding(encoding)
+        .bom_override(true)
+        .utf8_passthru(encoding.is_none())
+        .strip_bom(true)
+        .build(file);
+    Ok(ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .quote(quote)
+        .escape(options.escape)
+        .comment(options.comment)
+        .buffer_capacity(1024 * 1024)
+        .from_reader(input))
+}
+
+/// Whether every field of `record` is empty or whitespace (`skip_empty_rows`).
+fn is_blank_record(record: &csv::StringRecord) -> bool {
+    record.iter().all(|field| field.trim().is_empty())
+}
+
+/// Write `record` as the header row: every field as text (a `2024` column name
+/// stays a label rather than becoming a number), cleaned like the data when
+/// `sanitize_strings` is on, with `options.header_format` if set, and freeze
+/// the panes below it.
+fn write_csv_header(
+    worksheet: &mut Worksheet,
+    record: &csv::StringRecord,
+    options: &CsvOptions,
+) -> Result<(), String> {
+    for (col_idx, value) in record.iter().enumerate() {
+        let col = col_idx as u16; // safe: column count already validated via u16::try_from
+        let value = header_label(value, options.sanitize_strings);
+        let value = value.as_ref();
+        let result = match &options.header_format {
+            Some(fmt) => worksheet.write_string_with_format(0, col, value, fmt),
+            None => worksheet.write_string(0, col, value),
+        };
+        result.map_err(|e| format!("Failed to write header '{}': {}", value, e))?;
+    }
+    worksheet
+        .set_freeze_panes(1, 0)
+        .map_err(|e| format!("Failed to freeze panes: {}", e))?;
+    Ok(())
+}
+
+/// A column name as written to a header cell: cleaned by `sanitize_string`
+/// when `sanitize` is on, else unchanged.
+fn header_label(name: &str, sanitize: bool) -> Cow<'_, str> {
+    if sanitize {
+        sanitize_string(name)
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Build the validated cell write options for a CSV conversion, falling back
+/// to the default number formats when not overridden.
+fn csv_cell_options(options: &CsvOptions) -> Result<CellWriteOptions, String> {
+    let cell_options = CellWriteOptions::new(
+        options.date_format.as_deref().unwrap_or(DATE_NUM_FORMAT),
+        options
+            .datetime_format
+            .as_deref()
+            .unwrap_or(DATETIME_NUM_FORMAT),
+        options.time_format.as_deref().unwrap_or(TIME_NUM_FORMAT),
+        options.nan_policy,
+        options.large_int_mode,
+        None,
+    )?;
+    let scientific_format = options
+        .scientific_format
+        .as_deref()
+        .map(|num_format| parse_num_format("scientific_format", num_format))
+        .transpose()?;
+    Ok(CellWriteOptions {
+        scientific_format,
+        long_string_mode: options.long_string_mode,
+        ..cell_options
+    })
+}
+
 /// Rows per chunk for parallel CSV processing. Picked so a parsed chunk's
 /// peak memory stays bounded regardless of total file size.
 const PARALLEL_CHUNK_ROWS: usize = 10_000;
@@ -108,28 +365,31 @@ const PARALLEL_CHUNK_ROWS: usize = 10_000;
 /// parallel across rayon's thread pool, then writes the parsed chunk before
 /// reading the next one. Peak memory is O(chunk) rather than O(file), so this
 /// scales to CSVs larger than available RAM.
+///
+/// `options.constant_memory` is not supported here (use `convert_csv_to_xlsx`)
+/// and returns an error.
 pub fn convert_csv_to_xlsx_parallel(
     input_path: &str,
     output_path: &str,
     sheet_name: &str,
-    date_order: DateOrder,
+    options: &CsvOptions,
 ) -> Result<(u32, u16), String> {
-    let file = File::open(input_path).map_err(|e| format!("Failed to open input file: {}", e))?;
-    let mut csv_reader = ReaderBuilder::new()
-        .has_headers(false)
-        .flexible(true)
-        .buffer_capacity(1024 * 1024)
-        .from_reader(file);
-
-    let mut workbook = rust_xlsxwriter::Workbook::new();
-    let worksheet = workbook.add_worksheet();
-    worksheet
-        .set_name(sheet_name)
-        .map_err(|e| format!("Failed to set sheet name: {}", e))?;
-
-    let date_format = Format::new().set_num_format(DATE_NUM_FORMAT);
-    let datetime_format = Format::new().set_num_format(DATETIME_NUM_FORMAT);
+    let mut workbook = Workbook::new();
+    let dims = write_csv_sheet(&mut workbook, input_path, sheet_name, options, true)?;
+    workbook
+        .save(output_path)
+        .map_err(|e| format!("Failed to save workbook to '{}': {}", output_path, e))?;
+    Ok(dims)
+}
 
+/// Read records in chunks of `PARALLEL_CHUNK_ROWS`, parsing each chunk in
+/// parallel before writing it.
+fn write_csv_records_parallel(
+    worksheet: &mut Worksheet,
+    csv_reader: &mut csv::Reader<DecodeReaderBytes<File, Vec<u8>>>,
+    options: &CsvOptions,
+    cell_options: &CellWriteOptions,
+) -> Result<(u32, u16), String> {
     let mut row_count: u32 = 0;
     let mut col_count: u16 = 0;
     let mut chunk: Vec<Vec<String>> = Vec::with_capacity(PARALLEL_CHUNK_ROWS);
@@ -138,40 +398,30 @@ pub fn convert_csv_to_xlsx_parallel(
         let absolute_row = row_count as usize + chunk.len();
         let record =
             result.map_err(|e| format!("CSV parse error at row {}: {}", absolute_row, e))?;
+        if options.skip_empty_rows && is_blank_record(&record) {
+            continue;
+        }
         let num_cols = u16::try_from(record.len())
             .map_err(|_| format!("Column count {} exceeds u16 limit", record.len()))?;
         if num_cols > col_count {
             col_count = num_cols;
         }
+        if absolute_row == 0 && options.has_header {
+            write_csv_header(worksheet, &record, options)?;
+            row_count = 1;
+            continue;
+        }
         chunk.push(record.iter().map(|s| s.to_string()).collect());
 
         if chunk.len() >= PARALLEL_CHUNK_ROWS {
-            flush_parallel_chunk(
-                worksheet,
-                &mut chunk,
-                &mut row_count,
-                date_order,
-                &date_format,
-                &datetime_format,
-            )?;
+            flush_parallel_chunk(worksheet, &mut chunk, &mut row_count, options, cell_options)?;
         }
     }
 
     if !chunk.is_empty() {
-        flush_parallel_chunk(
-            worksheet,
-            &mut chunk,
-            &mut row_count,
-            date_order,
-            &date_format,
-            &datetime_format,
-        )?;
+        flush_parallel_chunk(worksheet, &mut chunk, &mut row_count, options, cell_options)?;
     }
 
-    workbook
-        .save(output_path)
-        .map_err(|e| format!("Failed to save workbook to '{}': {}", output_path, e))?;
-
     Ok((row_count, col_count))
 }
 
@@ -180,15 +430,15 @@ fn flush_parallel_chunk(
     worksheet: &mut Worksheet,
     chunk: &mut Vec<Vec<String>>,
     row_count: &mut u32,
-    date_order: DateOrder,
-    date_format: &Format,
-    datetime_format: &Format,
+    options: &CsvOptions,
+    cell_options: &CellWriteOptions,
 ) -> Result<(), String> {
     let parsed_rows: Vec<Vec<CellValue>> = chunk
         .par_iter()
         .map(|row| {
             row.iter()
-                .map(|value| parse_value(value, date_order))
+                .enumerate()
+                .map(|(col_idx, value)| parse_csv_field(value, col_idx, options))
                 .collect()
         })
         .collect();
@@ -199,15 +449,7 @@ fn flush_parallel_chunk(
             .ok_or("Row count exceeds u32 limit")?;
         for (col_idx, cell_value) in parsed_row.into_iter().enumerate() {
             let col_u16 = col_idx as u16; // safe: column count already validated via u16::try_from
-            write_cell(
-                worksheet,
-                row_u32,
-                col_u16,
-                cell_value,
-                date_format,
-                datetime_format,
-            )
-            .map_err(|e| format!("Write error at ({}, {}): {}", row_u32, col_idx, e))?;
+            write_cell(worksheet, row_u32, col_u16, cell_value, cell_options)?;
         }
     }
 
@@ -226,39 +468,804 @@ fn flush_parallel_chunk(
 ///
 /// Shared per-cell body used by both the polars and pandas row-writing loops in
 /// `write_sheet_data`: the only difference between those two engines is how a row's
-/// values are obtained, not how each cell is written.
+/// values are obtained, not how each cell is written. `integer_like` marks the
+/// float columns whose whole values get the integer format (empty when
+/// `integer_like_floats` is off), `markup` the `auto_rich_text_columns`, and
+/// `text` the `text_columns`.
 #[allow(clippy::too_many_arguments)]
 fn write_row_cell(
     worksheet: &mut Worksheet,
     row_idx: u32,
     col_idx: usize,
     value: &Bound<'_, PyAny>,
-    date_format: &Format,
-    datetime_format: &Format,
+    data_cols: &[u16],
+    cell_options: &CellWriteOptions,
     col_formats: &[Option<Format>],
+    integer_like: &[bool],
+    markup: &[bool],
+    text: &[bool],
     track_widths: bool,
     max_lens: &mut [usize],
 ) -> Result<(), String> {
-    if track_widths {
-        // Char count, not byte count: width is a visual estimate.
+    write_value_cell(
+        worksheet,
+        row_idx,
+        data_cols[col_idx],
+        value,
+        cell_options,
+        col_formats.get(col_idx).and_then(|f| f.as_ref()),
+        integer_like.get(col_idx).copied().unwrap_or(false),
+        markup.get(col_idx).copied().unwrap_or(false),
+        text.get(col_idx).copied().unwrap_or(false),
+        track_widths.then(|| &mut max_lens[col_idx]),
+    )
+}
+
+/// Write one DataFrame value at (`row`, `col`), raising `max_len` to its
+/// content width when given. Used directly by the transposed writer, where a
+/// DataFrame column's cells run along a sheet row.
+#[allow(clippy::too_many_arguments)]
+fn write_value_cell(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    value: &Bound<'_, PyAny>,
+    cell_options: &CellWriteOptions,
+    column_format: Option<&Format>,
+    integer_like: bool,
+    markup: bool,
+    text: bool,
+    max_len: Option<&mut usize>,
+) -> Result<(), String> {
+    if text {
+        if let Some(len) =
+            write_text_value(worksheet, row, col, value, cell_options, column_format)?
+        {
+            if let Some(max_len) = max_len {
+                *max_len = (*max_len).max(len);
+            }
+            return Ok(());
+        }
+    }
+    if markup {
+        if let Some(len) =
+            write_markup_string(worksheet, row, col, value, cell_options, column_format)?
+        {
+            if let Some(max_len) = max_len {
+                *max_len = (*max_len).max(len);
+            }
+            return Ok(());
+        }
+    }
+    let written = integer_like
+        && write_integer_like_float(worksheet, row, col, value, cell_options, column_format)?;
+    if let Some(max_len) = max_len {
+        // Char count, not byte count: width is a visual estimate. A float
+        // written as an integer displays without its ".0".
         let len = value
             .str()
-            .map(|s| s.to_string_lossy().chars().count())
+            .map(|s| {
+                let s = s.to_string_lossy();
+                let shown = if written {
+                    s.strip_suffix(".0").unwrap_or(&s)
+                } else {
+                    &s
+                };
+                shown.chars().count()
+            })
             .unwrap_or(0);
+        if len > *max_len {
+            *max_len = len;
+        }
+    }
+    if written {
+        return Ok(());
+    }
+    write_py_value_with_format(worksheet, row, col, value, cell_options, column_format)
+}
+
+/// A column decoded in bulk from its dtype, so its cells are written without
+/// dispatching on a Python object per value.
+enum TypedColumn {
+    /// polars `Date`: days since 1970-01-01
+    Date(Vec<Option<i64>>),
+    /// Naive polars `Datetime`: microseconds since 1970-01-01
+    Datetime(Vec<Option<i64>>),
+    /// polars `Categorical`/`Enum` or pandas `category`: each cell indexes
+    /// `labels`, the category set in its declared order
+    Categorical {
+        codes: Vec<Option<usize>>,
+        labels: Vec<String>,
+    },
+}
+
+impl TypedColumn {
+    /// The category labels of a categorical column, for `categorical_validation`
+    fn into_categories(self) -> Option<Vec<String>> {
+        match self {
+            TypedColumn::Categorical { labels, .. } => Some(labels),
+            _ => None,
+        }
+    }
+}
+
+/// Read the `Date`, naive `Datetime`, `Categorical`, and `Enum` columns of a
+/// polars DataFrame from `df.schema`, indexed like the DataFrame's columns.
+/// Every other dtype (and a time-zone-aware `Datetime`, whose Python values are
+/// local wall-clock times) is `None`, meaning it goes through the generic
+/// `iter_rows` path.
+fn polars_typed_columns(df: &Bound<'_, PyAny>) -> Result<Vec<Option<TypedColumn>>, String> {
+    let dtypes = df
+        .getattr("schema")
+        .and_then(|schema| schema.call_method0("values"))
+        .map_err(|e| format!("Failed to read polars schema: {}", e))?;
+    let iter = dtypes
+        .try_iter()
+        .map_err(|e| format!("Failed to iterate polars schema: {}", e))?;
+    let mut typed = Vec::new();
+    for (index, dtype) in iter.enumerate() {
+        let dtype = dtype.map_err(|e| format!("Failed to read polars dtype: {}", e))?;
+        let column = match pytype_name(&dtype).as_str() {
+            "Date" => Some(TypedColumn::Date(polars_physical_values(df, index)?)),
+            "Datetime" if dtype.getattr("time_zone").is_ok_and(|tz| tz.is_none()) => {
+                let unit: String = dtype
+                    .getattr("time_unit")
+                    .and_then(|u| u.extract())
+                    .map_err(|e| format!("Failed to read polars time unit: {}", e))?;
+                let values = polars_physical_values(df, index)?;
+                let to_micros = |v: i64| -> Option<i64> {
+                    match unit.as_str() {
+                        "ms" => v.checked_mul(1_000),
+                        // Python datetimes stop at microseconds, so ns values
+                        // are floored exactly as iter_rows() would
+                        "ns" => Some(v.div_euclid(1_000)),
+                        _ => Some(v),
+                    }
+                };
+                let micros = values
+                    .into_iter()
+                    .map(|v| {
+                        v.map(|v| {
+                            to_micros(v).ok_or_else(|| {
+                                format!("Polars datetime value {}{} is out of range", v, unit)
+                            })
+                        })
+                        .transpose()
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                Some(TypedColumn::Datetime(micros))
+            }
+            "Categorical" | "Enum" => Some(polars_categorical_column(df, index, &dtype)?),
+            _ => None,
+        };
+        typed.push(column);
+    }
+    Ok(typed)
+}
+
+/// Decode a polars `Categorical`/`Enum` column: its labels come from the
+/// dtype's declared categories (`Enum`) or the column's own (`Categorical`),
+/// and its values are read once as strings.
+fn polars_categorical_column(
+    df: &Bound<'_, PyAny>,
+    index: usize,
+    dtype: &Bound<'_, PyAny>,
+) -> Result<TypedColumn, String> {
+    let err = |e: PyErr| format!("Failed to read polars categorical column {}: {}", index, e);
+    let series = df.call_method1("to_series", (index,)).map_err(err)?;
+    let categories = if pytype_name(dtype) == "Enum" {
+        dtype.getattr("categories")
+    } else {
+        series
+            .getattr("cat")
+            .and_then(|cat| cat.call_method0("get_categories"))
+    }
+    .and_then(|categories| categories.call_method0("to_list"))
+    .and_then(|categories| categories.extract::<Vec<String>>())
+    .map_err(err)?;
+    let string_dtype = df
+        .py()
+        .import("polars")
+        .and_then(|polars| polars.getattr("String"))
+        .map_err(err)?;
+    let values: Vec<Option<String>> = series
+        .call_method1("cast", (string_dtype,))
+        .and_then(|strings| strings.call_method0("to_list"))
+        .and_then(|strings| strings.extract())
+        .map_err(err)?;
+    Ok(intern_categories(categories, values))
+}
+
+/// Map each value to its index in `labels`, appending any value the category
+/// set doesn't list so every cell still has a label.
+fn intern_categories(mut labels: Vec<String>, values: Vec<Option<String>>) -> TypedColumn {
+    let mut positions: HashMap<String, usize> = labels
+        .iter()
+        .enumerate()
+        .map(|(i, label)| (label.clone(), i))
+        .collect();
+    let codes = values
+        .into_iter()
+        .map(|value| {
+            value.map(|value| {
+                *positions.entry(value).or_insert_with_key(|value| {
+                    labels.push(value.clone());
+                    labels.len() - 1
+                })
+            })
+        })
+        .collect();
+    TypedColumn::Categorical { codes, labels }
+}
+
+/// Read the `category` columns of a pandas DataFrame from `df.dtypes`,
+/// indexed like the DataFrame's columns; every other dtype is `None`. Each
+/// category is labeled by its `str()`, and a missing value (code -1) is `None`.
+fn pandas_typed_columns(df: &Bound<'_, PyAny>) -> Result<Vec<Option<TypedColumn>>, String> {
+    let dtypes = df
+        .getattr("dtypes")
+        .and_then(|dtypes| dtypes.call_method0("tolist"))
+        .and_then(|dtypes| dtypes.extract::<Vec<Bound<'_, PyAny>>>())
+        .map_err(|e| format!("Failed to read DataFrame dtypes: {}", e))?;
+    let mut typed = Vec::with_capacity(dtypes.len());
+    for (index, dtype) in dtypes.iter().enumerate() {
+        if pytype_name(dtype) != "CategoricalDtype" {
+            typed.push(None);
+            continue;
+        }
+        let err = |e: PyErr| format!("Failed to read categorical column {}: {}", index, e);
+        let slice = pyo3::types::PySlice::full(df.py());
+        let cat = df
+            .getattr("iloc")
+            .and_then(|iloc| iloc.get_item((slice, index)))
+            .and_then(|series| series.getattr("cat"))
+            .map_err(err)?;
+        let labels = cat
+            .getattr("categories")
+            .and_then(|categories| categories.call_method0("tolist"))
+            .and_then(|categories| categories.extract::<Vec<Bound<'_, PyAny>>>())
+            .and_then(|categories| {
+                categories
+                    .iter()
+                    .map(|c| c.str().map(|s| s.to_string()))
+                    .collect::<PyResult<Vec<String>>>()
+            })
+            .map_err(err)?;
+        let codes: Vec<i64> = cat
+            .getattr("codes")
+            .and_then(|codes| codes.call_method0("tolist"))
+            .and_then(|codes| codes.extract())
+            .map_err(err)?;
+        typed.push(Some(TypedColumn::Categorical {
+            codes: codes.into_iter().map(|c| usize::try_from(c).ok()).collect(),
+            labels,
+        }));
+    }
+    Ok(typed)
+}
+
+/// Which columns of a DataFrame have a float dtype (polars `Float32`/`Float64`,
+/// or a pandas dtype of kind `f`), indexed like the DataFrame's columns.
+fn float_columns(df: &Bound<'_, PyAny>, is_polars: bool) -> Result<Vec<bool>, String> {
+    let dtypes: Vec<Bound<'_, PyAny>> = if is_polars {
+        df.getattr("schema")
+            .and_then(|schema| schema.call_method0("values"))
+            .and_then(|dtypes| dtypes.try_iter()?.collect())
+    } else {
+        df.getattr("dtypes")
+            .and_then(|dtypes| dtypes.call_method0("tolist"))
+            .and_then(|dtypes| dtypes.extract())
+    }
+    .map_err(|e| format!("Failed to read DataFrame dtypes: {}", e))?;
+    Ok(dtypes
+        .iter()
+        .map(|dtype| {
+            if is_polars {
+                matches!(pytype_name(dtype).as_str(), "Float32" | "Float64")
+            } else {
+                dtype
+                    .getattr("kind")
+                    .and_then(|kind| kind.extract::<String>())
+                    .is_ok_and(|kind| kind == "f")
+            }
+        })
+        .collect())
+}
+
+/// The labels of a pandas column `MultiIndex`, one list per level from the top,
+/// or `None` for a single-level index (polars columns are always flat).
+fn column_header_levels(
+    df: &Bound<'_, PyAny>,
+    is_polars: bool,
+) -> Result<Option<Vec<Vec<String>>>, String> {
+    if is_polars {
+        return Ok(None);
+    }
+    let columns = df
+        .getattr("columns")
+        .map_err(|e| format!("Failed to access DataFrame columns: {}", e))?;
+    let nlevels: usize = columns
+        .getattr("nlevels")
+        .and_then(|n| n.extract())
+        .unwrap_or(1);
+    if nlevels < 2 {
+        return Ok(None);
+    }
+    (0..nlevels)
+        .map(|level| {
+            let labels = columns
+                .call_method1("get_level_values", (level,))
+                .and_then(|values| values.call_method0("tolist"))
+                .map_err(|e| format!("Failed to read column level {}: {}", level, e))?;
+            labels
+                .try_iter()
+                .and_then(|labels| {
+                    labels
+                        .map(|label| label?.str().map(|s| s.to_string()))
+                        .collect()
+                })
+                .map_err(|e| format!("Failed to read column level {}: {}", level, e))
+        })
+        .collect::<Result<Vec<_>, String>>()
+        .map(Some)
+}
+
+/// Write a multi-row header for a column `MultiIndex`, one row per level from
+/// `start_row`. A label repeated across adjacent columns under the same parent
+/// labels is merged into one cell on every level but the last, which holds one
+/// label per column like a flat header. Runs stop at a gap in `data_cols`,
+/// where a formula column was inserted. Labels are cleaned when `sanitize` is
+/// on.
+fn write_multi_level_header(
+    worksheet: &mut Worksheet,
+    levels: &[Vec<String>],
+    start_row: u32,
+    data_cols: &[u16],
+    header_fmt: Option<&Format>,
+    sanitize: bool,
+) -> Result<(), String> {
+    let default_fmt = Format::new();
+    let last_level = levels.len() - 1;
+    for (level, labels) in levels.iter().enumerate() {
+        let row = start_row + level as u32; // safe: nlevels is tiny next to the row limit
+        let mut first = 0;
+        while first < labels.len() {
+            // Extend the run while this label and every label above it repeat
+            let mut last = first;
+            if level < last_level {
+                while last + 1 < labels.len()
+                    && data_cols[last + 1] == data_cols[last] + 1
+                    && levels[..=level]
+                        .iter()
+                        .all(|above| above[last + 1] == above[first])
+                {
+                    last += 1;
+                }
+            }
+            let label = header_label(&labels[first], sanitize);
+            let label = label.as_ref();
+            let (first_col, last_col) = (data_cols[first], data_cols[last]);
+            let result = if last > first {
+                worksheet
+                    .merge_range(
+                        row,
+                        first_col,
+                        row,
+                        last_col,
+                        label,
+                        header_fmt.unwrap_or(&default_fmt),
+                    )
+                    .map(|_| ())
+            } else if let Some(fmt) = header_fmt {
+                worksheet
+                    .write_string_with_format(row, first_col, label, fmt)
+                    .map(|_| ())
+            } else {
+                worksheet.write_string(row, first_col, label).map(|_| ())
+            };
+            result.map_err(|e| format!("Failed to write header '{}': {}", label, e))?;
+            first = last + 1;
+        }
+    }
+    Ok(())
+}
+
+/// A polars column's physical integer values (`None` for nulls). They are
+/// read through `to_list()`, so each value still costs one Python `int`; the
+/// abi3 build can't borrow the column's buffer directly.
+fn polars_physical_values(df: &Bound<'_, PyAny>, index: usize) -> Result<Vec<Option<i64>>, String> {
+    df.call_method1("to_series", (index,))
+        .and_then(|series| series.call_method0("to_physical"))
+        .and_then(|series| series.call_method0("to_list"))
+        .and_then(|values| values.extract())
+        .map_err(|e| format!("Failed to read polars column {} values: {}", index, e))
+}
+
+/// Write one cell of a [`TypedColumn`], tracking its content width as the
+/// length of the value's Python `str()` when requested, like `write_row_cell`.
+#[allow(clippy::too_many_arguments)]
+fn write_typed_cell(
+    worksheet: &mut Worksheet,
+    row_idx: u32,
+    col_idx: usize,
+    column: &TypedColumn,
+    index: usize,
+    data_cols: &[u16],
+    cell_options: &CellWriteOptions,
+    col_formats: &[Option<Format>],
+    track_widths: bool,
+    max_lens: &mut [usize],
+) -> Result<(), String> {
+    let col = data_cols[col_idx];
+    let column_format = col_formats.get(col_idx).and_then(|f| f.as_ref());
+    // str(None) is "None"; a missing category is written as an empty cell
+    let len = match column {
+        TypedColumn::Date(days) => {
+            let date = days[index]
+                .map(|d| {
+                    epoch_days_to_date(d).ok_or_else(|| format!("Invalid polars date: {} days", d))
+                })
+                .transpose()?;
+            write_naive_date(worksheet, row_idx, col, date, cell_options, column_format)?;
+            track_widths.then(|| date.map_or(4, |d| d.to_string().chars().count()))
+        }
+        TypedColumn::Datetime(micros) => {
+            let dt = micros[index]
+                .map(|us| {
+                    epoch_micros_to_datetime(us)
+                        .ok_or_else(|| format!("Invalid polars datetime: {} us", us))
+                })
+                .transpose()?;
+            write_naive_datetime(worksheet, row_idx, col, dt, cell_options, column_format)?;
+            track_widths.then(|| dt.map_or(4, |dt| python_datetime_str(dt).len()))
+        }
+        TypedColumn::Categorical { codes, labels } => {
+            let label = codes[index].map(|code| labels[code].as_str());
+            write_category(worksheet, row_idx, col, label, cell_options, column_format)?;
+            track_widths.then(|| label.map_or(0, |l| l.chars().count()))
+        }
+    };
+    if let Some(len) = len {
         if len > max_lens[col_idx] {
             max_lens[col_idx] = len;
         }
     }
-    let col = col_idx as u16; // safe: col_count already validated via u16::try_from
-    write_py_value_with_format(
-        worksheet,
-        row_idx,
-        col,
-        value,
-        date_format,
-        datetime_format,
-        col_formats.get(col_idx).and_then(|f| f.as_ref()),
-    )
+    Ok(())
+}
+
+/// Call `progress_callback` with the number of data rows written once it
+/// reaches a multiple of `progress_every`. A `False` return cancels the write:
+/// it sets `progress.cancelled` and fails with "write cancelled", which
+/// `df_to_xlsx` raises as a `RuntimeError` instead of a `ValueError`. An
+/// exception from the callback is reported as a `RuntimeWarning` rather than
+/// aborting the write.
+fn report_progress(
+    py: Python<'_>,
+    progress: Option<Progress<'_>>,
+    rows_written: usize,
+) -> Result<(), String> {
+    let Some(progress) = progress else {
+        return Ok(());
+    };
+    if !rows_written.is_multiple_of(progress.every) {
+        return Ok(());
+    }
+    match progress.callback.call1(py, (rows_written,)) {
+        Ok(ret) => {
+            if ret.bind(py).cast::<PyBool>().is_ok_and(|b| !b.is_true()) {
+                progress.cancelled.set(true);
+                return Err("write cancelled".to_string());
+            }
+        }
+        Err(e) => warn_runtime(
+            py,
+            &format!(
+                "progress_callback raised at row {} (write continues): {}",
+                rows_written, e
+            ),
+        )?,
+    }
+    Ok(())
+}
+
+/// Project a DataFrame onto a `columns` selection, in the selection's order.
+/// Names and indices are resolved against the DataFrame's columns first, so
+/// an unknown name, an out-of-range index, or a repeated column is a clear
+/// error. The projection itself is positional (`df.select` for polars,
+/// `df.iloc` for pandas), so pandas dtypes and duplicate names are kept.
+fn select_columns<'py>(
+    df: &Bound<'py, PyAny>,
+    is_polars: bool,
+    selection: &[ColumnSelector],
+) -> Result<Bound<'py, PyAny>, String> {
+    let names = extract_columns(df, is_polars)?;
+    let mut positions: Vec<usize> = Vec::with_capacity(selection.len());
+    for selector in selection {
+        let position = match selector {
+            ColumnSelector::Index(idx) if *idx < names.len() => *idx,
+            ColumnSelector::Index(_) => {
+                return Err(format!(
+                    "columns: {} is out of range (DataFrame has {} columns)",
+                    selector,
+                    names.len()
+                ))
+            }
+            ColumnSelector::Name(name) => {
+                let mut matches = names.iter().enumerate().filter(|(_, n)| *n == name);
+                match (matches.next(), matches.next()) {
+                    (Some((idx, _)), None) => idx,
+                    (Some(_), Some(_)) => {
+                        return Err(format!(
+                            "columns: {} names more than one column; select it by index",
+                            selector
+                        ))
+                    }
+                    (None, _) => {
+                        return Err(format!(
+                            "columns: {} is not a DataFrame column. Available columns: {}",
+                            selector,
+                            names.join(", ")
+                        ))
+                    }
+                }
+            }
+        };
+        if positions.contains(&position) {
+            return Err(format!(
+                "columns: column '{}' is selected more than once",
+                names[position]
+            ));
+        }
+        positions.push(position);
+    }
+    if is_polars {
+        let selected: Vec<&str> = positions.iter().map(|&i| names[i].as_str()).collect();
+        df.call_method1("select", (selected,))
+            .map_err(|e| format!("Failed to select polars columns: {}", e))
+    } else {
+        df.getattr("iloc")
+            .and_then(|iloc| iloc.get_item((pyo3::types::PySlice::full(df.py()), positions)))
+            .map_err(|e| format!("Failed to select DataFrame columns: {}", e))
+    }
+}
+
+/// Data cell formats for one `banded_rows` band: the write options carrying
+/// the base cell format, plus the per-column formats. Built once per sheet so
+/// the write loop only picks a band per row.
+struct RowBand {
+    cell_options: CellWriteOptions,
+    col_formats: Vec<Option<Format>>,
+    /// Write options with an unlocked base format, for the columns `protect`
+    /// leaves editable (`unlocked[col_idx]`); None when no column is.
+    unlocked_options: Option<CellWriteOptions>,
+    unlocked: Vec<bool>,
+}
+
+impl RowBand {
+    /// The write options for a data cell in column `col_idx`.
+    fn options(&self, col_idx: usize) -> &CellWriteOptions {
+        match &self.unlocked_options {
+            Some(options) if self.unlocked[col_idx] => options,
+            _ => &self.cell_options,
+        }
+    }
+}
+
+/// Flag the written columns matched by any of `patterns` (names or wildcard
+/// patterns). A pattern matching no column is an error, as for
+/// column_formats; `option` names the list in it.
+fn flag_columns(
+    patterns: &[String],
+    columns: &[String],
+    option: &str,
+) -> Result<Vec<bool>, String> {
+    let mut flags = vec![false; columns.len()];
+    for pattern in patterns {
+        let mut matched = false;
+        for (flag, name) in flags.iter_mut().zip(columns) {
+            if matches_pattern(name, pattern) {
+                *flag = true;
+                matched = true;
+            }
+        }
+        if !matched {
+            return Err(format!(
+                "{}['{}']: pattern matched no columns",
+                option, pattern
+            ));
+        }
+    }
+    Ok(flags)
+}
+
+/// Flag the written columns matched by `protect`'s `unlocked_columns`.
+fn unlocked_columns(config: &WriteConfig<'_>, columns: &[String]) -> Result<Vec<bool>, String> {
+    match config.protect {
+        Some(protection) => flag_columns(
+            &protection.unlocked_columns,
+            columns,
+            "protect: unlocked_columns",
+        ),
+        None => Ok(vec![false; columns.len()]),
+    }
+}
+
+/// Flag the written columns matched by `auto_rich_text_columns`, whose string
+/// values are parsed for markup; empty when the option is off.
+fn markup_columns(config: &WriteConfig<'_>, columns: &[String]) -> Result<Vec<bool>, String> {
+    match config.auto_rich_text_columns {
+        Some(patterns) => flag_columns(patterns, columns, "auto_rich_text_columns"),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Flag the written columns named or indexed (0-based among the written
+/// columns) by `text_columns`; empty when the option is off.
+fn text_columns(config: &WriteConfig<'_>, columns: &[String]) -> Result<Vec<bool>, String> {
+    match config.text_columns {
+        Some(selection) => select_columns_by_position(selection, columns, "text_columns"),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Flag the written columns named or indexed (0-based among the written
+/// columns) by `selection`, one flag per column. A name flags every column
+/// with that name. An unknown name or out-of-range index is an error naming
+/// `option`.
+fn select_columns_by_position(
+    selection: &[ColumnSelector],
+    columns: &[String],
+    option: &str,
+) -> Result<Vec<bool>, String> {
+    let mut flags = vec![false; columns.len()];
+    for selector in selection {
+        match selector {
+            ColumnSelector::Index(idx) if *idx < columns.len() => flags[*idx] = true,
+            ColumnSelector::Index(_) => {
+                return Err(format!(
+                    "{}: {} is out of range ({} columns are written)",
+                    option,
+                    selector,
+                    columns.len()
+                ))
+            }
+            ColumnSelector::Name(name) => {
+                let mut matched = false;
+                for (flag, column) in flags.iter_mut().zip(columns) {
+                    if column == name {
+                        *flag = true;
+                        matched = true;
+                    }
+                }
+                if !matched {
+                    return Err(format!(
+                        "{}: {} is not a written column. Available columns: {}",
+                        option,
+                        selector,
+                        columns.join(", ")
+                    ));
+                }
+            }
+        }
+    }
+    Ok(flags)
+}
+
+/// Build the data cell formats for a sheet: a single band normally, or one per
+/// `banded_rows` color, where each fill sits under cell_format and the
+/// column_formats (a column's own `bg_color` overrides it). Every format in a
+/// `protect` unlocked column is additionally unlocked.
+fn build_row_bands(
+    py: Python<'_>,
+    config: &WriteConfig<'_>,
+    opts: &EffectiveOpts<'_>,
+    columns: &[String],
+) -> Result<Vec<RowBand>, String> {
+    let unlocked = unlocked_columns(config, columns)?;
+    let any_unlocked = unlocked.contains(&true);
+    let fills = match config.banded_rows {
+        Some((first, second)) => [first, second]
+            .into_iter()
+            .map(|color| {
+                parse_color(color)
+                    .map(|c| Some(Format::new().set_background_color(c)))
+                    .map_err(|e| format!("Invalid banded_rows color: {}", e))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        None => vec![None],
+    };
+
+    fills
+        .into_iter()
+        .map(|fill| {
+            // cell_format is the base style of every data cell
+            let cell_format = match opts.cell_format {
+                Some(fmt_dict) => Some(parse_column_format_over(
+                    py,
+                    fill.clone().unwrap_or_default(),
+                    fmt_dict,
+                    "cell_format",
+                )?),
+                None => fill.clone(),
+            };
+            let mut col_formats = match opts.column_formats {
+                Some(cf) => build_column_formats(py, columns, cf, fill.as_ref())?,
+                None => vec![None; columns.len()],
+            };
+            for (format, _) in col_formats.iter_mut().zip(&unlocked).filter(|(_, u)| **u) {
+                *format = format.take().map(Format::set_unlocked);
+            }
+            // link_format styles auto_hyperlinks cells, over the band fill
+            let link_format = match opts.link_format {
+                Some(fmt_dict) => Some(parse_column_format_over(
+                    py,
+                    fill.clone().unwrap_or_default(),
+                    fmt_dict,
+                    "link_format",
+                )?),
+                None => None,
+            };
+            let with_links = |options: CellWriteOptions, link_format: Option<Format>| {
+                if config.auto_hyperlinks {
+                    options.with_auto_hyperlinks(link_format)
+                } else {
+                    options
+                }
+            };
+            let unlocked_options = any_unlocked
+                .then(|| {
+                    let base = cell_format.clone().unwrap_or_default().set_unlocked();
+                    CellWriteOptions::from_config(config, Some(base)).map(|options| {
+                        with_links(options, link_format.clone().map(Format::set_unlocked))
+                    })
+                })
+                .transpose()?;
+            Ok(RowBand {
+                cell_options: with_links(
+                    CellWriteOptions::from_config(config, cell_format)?,
+                    link_format,
+                ),
+                col_formats,
+                unlocked_options,
+                unlocked: unlocked.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Slice a DataFrame to the `skip_rows`/`max_rows` window: `df.slice` for
+/// polars, `df.iloc[skip:skip + max]` for pandas. A window past the end is
+/// simply empty.
+fn slice_rows<'py>(
+    df: &Bound<'py, PyAny>,
+    is_polars: bool,
+    skip_rows: usize,
+    max_rows: Option<usize>,
+) -> Result<Bound<'py, PyAny>, String> {
+    let start = isize::try_from(skip_rows).map_err(|_| "skip_rows is too large")?;
+    if is_polars {
+        df.call_method1("slice", (start, max_rows))
+            .map_err(|e| format!("Failed to slice polars rows: {}", e))
+    } else {
+        let stop = max_rows.map_or(isize::MAX, |max| {
+            start.saturating_add(isize::try_from(max).unwrap_or(isize::MAX))
+        });
+        df.getattr("iloc")
+            .and_then(|iloc| iloc.get_item(pyo3::types::PySlice::new(df.py(), start, stop, 1)))
+            .map_err(|e| format!("Failed to slice DataFrame rows: {}", e))
+    }
+}
+
+/// The number of data rows written for a DataFrame of `row_count` rows under
+/// `skip_rows`/`max_rows`; mirrors `slice_rows` for the `dfs_to_xlsx`
+/// duplicate-table-name pre-check.
+pub(crate) fn written_row_count(
+    row_count: usize,
+    skip_rows: usize,
+    max_rows: Option<usize>,
+) -> usize {
+    let remaining = row_count.saturating_sub(skip_rows);
+    max_rows.map_or(remaining, |max| remaining.min(max))
 }
 
 /// Get a DataFrame's row count without reading its data.
@@ -297,9 +1304,68 @@ pub(crate) fn write_sheet_data(
     config: &WriteConfig<'_>,
     opts: EffectiveOpts<'_>,
 ) -> Result<(u32, u16), String> {
-    // Create formats
-    let date_format = Format::new().set_num_format(DATE_NUM_FORMAT);
-    let datetime_format = Format::new().set_num_format(DATETIME_NUM_FORMAT);
+    // Sheet view and print settings touch no cells, so they also apply under
+    // constant_memory
+    // Sheet-wide defaults come first, so column_widths, autofit, and
+    // row_heights override them for the columns and rows they set
+    if let Some(width) = config.default_col_width {
+        worksheet
+            .set_column_range_width(0, MAX_COL_INDEX, width)
+            .map_err(|e| format!("Failed to set default_col_width: {}", e))?;
+    }
+    if let Some(height) = config.default_row_height {
+        worksheet.set_default_row_height(height);
+    }
+    if !config.show_gridlines {
+        worksheet.set_screen_gridlines(false);
+    }
+    if let Some(zoom) = config.zoom {
+        if !(10..=400).contains(&zoom) {
+            return Err(format!(
+                "zoom must be between 10 and 400 percent, got {}",
+                zoom
+            ));
+        }
+        worksheet.set_zoom(zoom);
+    }
+    match config.view {
+        SheetView::Normal => {}
+        SheetView::PageLayout => {
+            worksheet.set_view_page_layout();
+        }
+        SheetView::PageBreak => {
+            worksheet.set_view_page_break_preview();
+        }
+    }
+    if config.right_to_left {
+        worksheet.set_right_to_left(true);
+    }
+    // Where the outline group buttons sit; Excel's default is below and right
+    if !config.outline_below {
+        worksheet.group_symbols_above(true);
+    }
+    if !config.outline_right {
+        worksheet.group_symbols_to_left(true);
+    }
+    if let Some(protection) = config.protect {
+        match &protection.password {
+            Some(password) => worksheet.protect_with_password(password),
+            None => worksheet.protect(),
+        };
+    }
+    if let Some(color) = config.tab_color {
+        let color = parse_color(color).map_err(|e| format!("Invalid tab_color: {}", e))?;
+        worksheet.set_tab_color(color);
+    }
+    if let Some(setup) = config.page_setup {
+        apply_page_setup(worksheet, setup)?;
+    }
+    if let Some(range) = config.print_area {
+        apply_print_area(worksheet, range)?;
+    }
+    if let Some(source) = config.background_image {
+        apply_background_image(worksheet, source)?;
+    }
 
     // Parse header format if provided
     let header_fmt = if let Some(fmt_dict) = opts.header_format {
@@ -308,30 +1374,112 @@ pub(crate) fn write_sheet_data(
         None
     };
 
-    let mut row_idx: u32 = 0;
+    let mut row_idx: u32 = config.start_row;
 
-    // Get column names
+    // Get column names, narrowing the DataFrame to the `columns` selection
+    // first so every later step sees only the written columns
     let is_polars = is_polars_dataframe(df)?;
+    let selected;
+    let df = match config.columns {
+        Some(selection) if !selection.is_empty() => {
+            selected = select_columns(df, is_polars, selection)?;
+            &selected
+        }
+        _ => df,
+    };
+    // Likewise narrow it to the skip_rows/max_rows window, so the row count
+    // (and every range derived from it) covers only the written rows
+    let sliced;
+    let df = if config.skip_rows > 0 || config.max_rows.is_some() {
+        sliced = slice_rows(df, is_polars, config.skip_rows, config.max_rows)?;
+        &sliced
+    } else {
+        df
+    };
+    // Then prepend the pandas index as leading columns, so features see it as
+    // ordinary written columns; polars frames have no index
+    let indexed;
+    let df = if config.include_index && !is_polars {
+        indexed = df
+            .call_method0("reset_index")
+            .map_err(|e| format!("Failed to write the DataFrame index: {}", e))?;
+        &indexed
+    } else {
+        df
+    };
     let columns: Vec<String> = extract_columns(df, is_polars)?;
 
+    // Create data cell formats, one set per banded_rows color
+    let bands = build_row_bands(py, config, &opts, &columns)?;
+
+    if config.transpose {
+        return write_transposed_data(
+            py,
+            worksheet,
+            sheet_name,
+            df,
+            is_polars,
+            &columns,
+            config,
+            opts,
+            &bands,
+            header_fmt.as_ref(),
+        );
+    }
+
     let col_count = u16::try_from(columns.len())
         .map_err(|_| format!("Column count {} exceeds u16 limit", columns.len()))?;
 
-    // Build column formats if provided
-    let col_formats: Vec<Option<Format>> = if let Some(cf) = opts.column_formats {
-        build_column_formats(py, &columns, cf)?
-    } else {
-        vec![None; columns.len()]
-    };
+    // Get row count
+    let row_count: usize = dataframe_row_count(df)?;
 
-    // Track max content lengths for autofit+cap (only when both are active)
-    let track_widths = config.autofit && opts.column_widths.is_some_and(|w| w.contains_key("_all"));
+    // Place the data columns, leaving gaps for formula columns inserted among
+    // them; formula columns are only written under data rows, and never in
+    // constant_memory mode
+    let layout = match opts.formula_columns {
+        Some(formulas) if !config.constant_memory && row_count > 0 => {
+            formula_column_layout(formulas, &columns, config.start_col)?
+        }
+        _ => ColumnLayout::contiguous(config.start_col, col_count),
+    };
+    validate_start_position(config.start_row, config.start_col, layout.block_width)?;
+
+    // Track max content lengths for autofit with column_widths caps and for a
+    // list of columns to autofit, which are sized from these lengths
+    let track_widths = match config.autofit {
+        Autofit::Off => false,
+        Autofit::All => opts.column_widths.is_some_and(|w| !w.is_empty()),
+        Autofit::Columns(_) => true,
+    };
     let mut max_lens = vec![0usize; columns.len()];
 
-    // Write header if requested
-    if config.include_header {
+    // Write header if requested: one row per level of a pandas column
+    // MultiIndex, otherwise a single row of column names
+    let header_levels = if config.include_header {
+        column_header_levels(df, is_polars)?
+    } else {
+        None
+    };
+    if let Some(levels) = &header_levels {
+        write_multi_level_header(
+            worksheet,
+            levels,
+            row_idx,
+            &layout.data,
+            header_fmt.as_ref(),
+            config.sanitize_strings,
+        )?;
+        if track_widths {
+            for (max_len, label) in max_lens.iter_mut().zip(&levels[levels.len() - 1]) {
+                *max_len = label.chars().count();
+            }
+        }
+        row_idx += levels.len() as u32;
+    } else if config.include_header {
         for (col_idx, col_name) in columns.iter().enumerate() {
-            let col = col_idx as u16; // safe: col_count already validated via u16::try_from
+            let col = layout.data[col_idx];
+            let col_name = header_label(col_name, config.sanitize_strings);
+            let col_name = col_name.as_ref();
             if track_widths {
                 // Char count, not byte count: width is a visual estimate.
                 max_lens[col_idx] = col_name.chars().count();
@@ -346,45 +1494,120 @@ pub(crate) fn write_sheet_data(
                     .map_err(|e| format!("Failed to write header '{}': {}", col_name, e))?;
             }
         }
-        row_idx = 1;
+        row_idx += 1;
     }
 
-    // Get row count
-    let row_count: usize = dataframe_row_count(df)?;
+    // Float columns whose whole values are written with an integer format
+    let integer_like = if config.integer_like_floats {
+        float_columns(df, is_polars)?
+    } else {
+        Vec::new()
+    };
+
+    // String columns whose inline markup is written as rich text
+    let markup = markup_columns(config, &columns)?;
+
+    // Columns written as text, which always take the per-cell path
+    let text = text_columns(config, &columns)?;
+
+    // Columns whose dtype lets them skip per-cell Python dispatch
+    let mut typed = if is_polars {
+        polars_typed_columns(df)?
+    } else {
+        pandas_typed_columns(df)?
+    };
+    for (column, &is_text) in typed.iter_mut().zip(&text) {
+        if is_text {
+            *column = None;
+        }
+    }
 
     if is_polars {
-        // Polars: iterate using rows()
-        let rows = df
-            .call_method0("iter_rows")
-            .map_err(|e| format!("Failed to iterate polars rows: {}", e))?;
-        let iter = rows
-            .try_iter()
-            .map_err(|e| format!("Failed to create polars row iterator: {}", e))?;
-        for row_result in iter {
-            let row = row_result.map_err(|e| format!("Failed to read polars row: {}", e))?;
-            let row_iter = row
-                .try_iter()
-                .map_err(|e| format!("Failed to iterate polars row values: {}", e))?;
-            let row_tuple: Vec<Bound<'_, PyAny>> = row_iter
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| format!("Failed to collect polars row values: {}", e))?;
-
-            for (col_idx, value) in row_tuple.iter().enumerate() {
-                write_row_cell(
-                    worksheet,
-                    row_idx,
-                    col_idx,
-                    value,
-                    &date_format,
-                    &datetime_format,
-                    &col_formats,
-                    track_widths,
-                    &mut max_lens,
-                )?;
+        // Polars: Date/Datetime columns are decoded from their physical
+        // integers and categoricals from their labels; every other column
+        // comes through iter_rows() as Python objects, from a narrower frame
+        // when some columns were decoded.
+        let generic_names: Vec<&str> = columns
+            .iter()
+            .zip(&typed)
+            .filter(|(_, t)| t.is_none())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        let mut rows = if generic_names.is_empty() {
+            None
+        } else {
+            let source = if generic_names.len() == columns.len() {
+                df.clone()
+            } else {
+                df.call_method1("select", (generic_names,))
+                    .map_err(|e| format!("Failed to select polars columns: {}", e))?
+            };
+            let rows = source
+                .call_method0("iter_rows")
+                .map_err(|e| format!("Failed to iterate polars rows: {}", e))?;
+            Some(
+                rows.try_iter()
+                    .map_err(|e| format!("Failed to create polars row iterator: {}", e))?,
+            )
+        };
+        for i in 0..row_count {
+            let row_tuple: Vec<Bound<'_, PyAny>> = match rows.as_mut() {
+                Some(iter) => {
+                    let row = iter
+                        .next()
+                        .ok_or("Polars returned fewer rows than its shape")?
+                        .map_err(|e| format!("Failed to read polars row: {}", e))?;
+                    let row_iter = row
+                        .try_iter()
+                        .map_err(|e| format!("Failed to iterate polars row values: {}", e))?;
+                    row_iter
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| format!("Failed to collect polars row values: {}", e))?
+                }
+                None => Vec::new(),
+            };
+            let mut generic_values = row_tuple.iter();
+            let band = &bands[i % bands.len()];
+
+            for (col_idx, column) in typed.iter().enumerate() {
+                match column {
+                    Some(column) => write_typed_cell(
+                        worksheet,
+                        row_idx,
+                        col_idx,
+                        column,
+                        i,
+                        &layout.data,
+                        band.options(col_idx),
+                        &band.col_formats,
+                        track_widths,
+                        &mut max_lens,
+                    )?,
+                    None => {
+                        let value = generic_values
+                            .next()
+                            .ok_or("Polars row has fewer values than columns")?;
+                        write_row_cell(
+                            worksheet,
+                            row_idx,
+                            col_idx,
+                            value,
+                            &layout.data,
+                            band.options(col_idx),
+                            &band.col_formats,
+                            &integer_like,
+                            &markup,
+                            &text,
+                            track_widths,
+                            &mut max_lens,
+                        )?;
+                    }
+                }
             }
             row_idx = row_idx
                 .checked_add(1)
                 .ok_or("Row count exceeds u32 limit")?;
+            report_progress(py, config.progress, i + 1)?;
         }
     } else {
         // Pandas: use .values for faster access
@@ -396,9 +1619,24 @@ pub(crate) fn write_sheet_data(
             let row = values
                 .get_item(i)
                 .map_err(|e| format!("Failed to get row {}: {}", i, e))?;
-
-            #[allow(clippy::needless_range_loop)]
-            for col_idx in 0..columns.len() {
+            let band = &bands[i % bands.len()];
+
+            for (col_idx, column) in typed.iter().enumerate() {
+                if let Some(column) = column {
+                    write_typed_cell(
+                        worksheet,
+                        row_idx,
+                        col_idx,
+                        column,
+                        i,
+                        &layout.data,
+                        band.options(col_idx),
+                        &band.col_formats,
+                        track_widths,
+                        &mut max_lens,
+                    )?;
+                    continue;
+                }
                 let value = row
                     .get_item(col_idx)
                     .map_err(|e| format!("Failed to get value at ({}, {}): {}", i, col_idx, e))?;
@@ -408,9 +1646,12 @@ pub(crate) fn write_sheet_data(
                     row_idx,
                     col_idx,
                     &value,
-                    &date_format,
-                    &datetime_format,
-                    &col_formats,
+                    &layout.data,
+                    band.options(col_idx),
+                    &band.col_formats,
+                    &integer_like,
+                    &markup,
+                    &text,
                     track_widths,
                     &mut max_lens,
                 )?;
@@ -418,6 +1659,7 @@ pub(crate) fn write_sheet_data(
             row_idx = row_idx
                 .checked_add(1)
                 .ok_or("Row count exceeds u32 limit")?;
+            report_progress(py, config.progress, i + 1)?;
         }
     }
 
@@ -430,23 +1672,376 @@ pub(crate) fn write_sheet_data(
     } else {
         Vec::new()
     };
+    // Under a multi-row header, each column's own label is the bottom one
+    let header_widths = match &header_levels {
+        _ if *config.autofit == Autofit::Off => Vec::new(),
+        Some(levels) => header_floor_widths(&levels[levels.len() - 1]),
+        None if config.include_header => header_floor_widths(&columns),
+        None => Vec::new(),
+    };
+
+    let categories: Vec<Option<Vec<String>>> = typed
+        .into_iter()
+        .map(|column| column.and_then(TypedColumn::into_categories))
+        .collect();
 
-    // Apply all worksheet features (table, formulas, formatting, etc.)
-    let total_col_count = apply_worksheet_features(
+    // Apply all worksheet features (table, formulas, formatting, etc.). Under a
+    // multi-row header they anchor on its last row, the one just above the data.
+    let shifted;
+    let features_config = match &header_levels {
+        Some(levels) => {
+            shifted = WriteConfig {
+                start_row: config.start_row + levels.len() as u32 - 1,
+                ..*config
+            };
+            &shifted
+        }
+        None => config,
+    };
+    let (end_row_idx, total_col_count) = apply_worksheet_features(
         py,
         worksheet,
         sheet_name,
         &columns,
-        col_count,
+        &categories,
+        &layout,
         row_idx,
         row_count,
-        config,
+        features_config,
         header_fmt.as_ref(),
         &opts,
         &content_widths,
+        &header_widths,
+    )?;
+    let last_col = config
+        .start_col
+        .checked_add(total_col_count)
+        .ok_or("Total column count exceeds u16 limit")?;
+
+    // The summary row goes below everything written so far, a table's totals
+    // row included, and counts toward the returned rows
+    let end_row_idx = match config.summary_row {
+        Some(summary) if !summary.is_empty() && row_count > 0 => {
+            let cells = summary_row_cells(df, is_polars, &columns, &layout.data, summary)?;
+            // safe: row_idx advanced once per data row
+            let data_start_row = row_idx - row_count as u32;
+            apply_summary_row(worksheet, end_row_idx, data_start_row, row_idx - 1, &cells)?;
+            end_row_idx
+                .checked_add(1)
+                .ok_or("Row count exceeds u32 limit")?
+        }
+        _ => end_row_idx,
+    };
+
+    Ok((end_row_idx, last_col))
+}
+
+/// Resolve each `summary_row` column to its sheet column and compute its
+/// aggregate from the written DataFrame, for caching on the formula. A value
+/// the DataFrame can't aggregate to a number is left for Excel to compute.
+fn summary_row_cells(
+    df: &Bound<'_, PyAny>,
+    is_polars: bool,
+    columns: &[String],
+    data_cols: &[u16],
+    summary: &IndexMap<String, SummaryFunction>,
+) -> Result<Vec<(u16, SummaryFunction, Option<f64>)>, String> {
+    let mut cells = Vec::with_capacity(summary.len());
+    for (col_name, &function) in summary {
+        let col_idx = columns
+            .iter()
+            .position(|c| c == col_name)
+            .ok_or_else(|| format!("summary_row: unknown column '{}'", col_name))?;
+        let series = if is_polars {
+            df.call_method1("to_series", (col_idx,))
+        } else {
+            let slice = pyo3::types::PySlice::full(df.py());
+            df.getattr("iloc")
+                .and_then(|iloc| iloc.get_item((slice, col_idx)))
+        }
+        .map_err(|e| format!("summary_row: failed to read column '{}': {}", col_name, e))?;
+        let method = match function {
+            SummaryFunction::Sum => "sum",
+            SummaryFunction::Mean => "mean",
+            SummaryFunction::Min => "min",
+            SummaryFunction::Max => "max",
+            SummaryFunction::Count => "count",
+        };
+        let result = series
+            .call_method0(method)
+            .and_then(|value| value.extract::<f64>())
+            .ok()
+            .filter(|value| value.is_finite());
+        cells.push((data_cols[col_idx], function, result));
+    }
+    Ok(cells)
+}
+
+/// Characters of room past a header label's length, for bold text and the
+/// filter dropdown button
+const HEADER_WIDTH_PADDING: f64 = 2.0;
+
+/// The narrowest width autofit may give each header label's column: the
+/// label's length plus `HEADER_WIDTH_PADDING`.
+fn header_floor_widths(labels: &[String]) -> Vec<f64> {
+    labels
+        .iter()
+        .map(|label| label.chars().count() as f64 + HEADER_WIDTH_PADDING)
+        .collect()
+}
+
+/// Column-keyed features that a transposed write skips, and whether each is set.
+fn transpose_skipped_features(
+    config: &WriteConfig<'_>,
+    opts: &EffectiveOpts<'_>,
+) -> Vec<&'static str> {
+    [
+        ("table_style", config.table_style.is_some()),
+        ("autofilter", config.autofilter),
+        ("autofit", matches!(config.autofit, Autofit::Columns(_))),
+        ("table_columns", config.table_columns.is_some()),
+        ("table_options", config.table_options.is_some()),
+        ("formula_columns", opts.formula_columns.is_some()),
+        ("conditional_formats", opts.conditional_formats.is_some()),
+        ("validations", opts.validations.is_some()),
+        ("categorical_validation", config.categorical_validation),
+        ("summary_row", config.summary_row.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(name, present)| present.then_some(name))
+    .collect()
+}
+
+/// Write a DataFrame transposed: column names down `start_col` (when
+/// `include_header`), then each DataFrame row as the next sheet column.
+///
+/// Every value goes through the generic per-cell path, so types are still
+/// detected per cell, and `column_formats`/`integer_like_floats` follow each
+/// DataFrame column along its sheet row. Features keyed by column name have no
+/// meaningful target here; they are skipped with a `RuntimeWarning`, and every
+/// other feature applies to the transposed block.
+#[allow(clippy::too_many_arguments)]
+fn write_transposed_data(
+    py: Python<'_>,
+    worksheet: &mut Worksheet,
+    sheet_name: &str,
+    df: &Bound<'_, PyAny>,
+    is_polars: bool,
+    columns: &[String],
+    config: &WriteConfig<'_>,
+    opts: EffectiveOpts<'_>,
+    bands: &[RowBand],
+    header_fmt: Option<&Format>,
+) -> Result<(u32, u16), String> {
+    // constant_memory flushes each row once the next one starts, but a
+    // transposed write fills the sheet column by column
+    if config.constant_memory {
+        return Err("transpose cannot be combined with constant_memory".to_string());
+    }
+
+    let row_count = dataframe_row_count(df)?;
+    let header_cols = usize::from(config.include_header);
+    let col_count = u16::try_from(header_cols + row_count)
+        .ok()
+        .filter(|&n| u32::from(config.start_col) + u32::from(n) <= u32::from(MAX_COL_INDEX) + 1)
+        .ok_or_else(|| {
+            format!(
+                "transpose: {} rows starting at column {} exceed Excel's maximum column index {}",
+                row_count, config.start_col, MAX_COL_INDEX
+            )
+        })?;
+    validate_start_position(config.start_row, config.start_col, col_count)?;
+    let row_span = u32::try_from(columns.len())
+        .ok()
+        .filter(|&n| u64::from(config.start_row) + u64::from(n) <= u64::from(MAX_ROW_INDEX) + 1)
+        .ok_or_else(|| {
+            format!(
+                "transpose: {} columns starting at row {} exceed Excel's maximum row index {}",
+                columns.len(),
+                config.start_row,
+                MAX_ROW_INDEX
+            )
+        })?;
+
+    let integer_like = if config.integer_like_floats {
+        float_columns(df, is_polars)?
+    } else {
+        Vec::new()
+    };
+    let markup = markup_columns(config, columns)?;
+    let text = text_columns(config, columns)?;
+
+    // Widths are tracked per written sheet column: the header column, then
+    // one per DataFrame row
+    let track_widths =
+        *config.autofit == Autofit::All && opts.column_widths.is_some_and(|w| !w.is_empty());
+    let mut max_lens = vec![0usize; usize::from(col_count)];
+
+    // safe: start_row + row_span and start_col + col_count validated above
+    if config.include_header {
+        for (col_idx, col_name) in columns.iter().enumerate() {
+            let row = config.start_row + col_idx as u32;
+            let col_name = header_label(col_name, config.sanitize_strings);
+            let col_name = col_name.as_ref();
+            if track_widths {
+                max_lens[0] = max_lens[0].max(col_name.chars().count());
+            }
+            if let Some(fmt) = header_fmt {
+                worksheet
+                    .write_string_with_format(row, config.start_col, col_name, fmt)
+                    .map_err(|e| format!("Failed to write header '{}': {}", col_name, e))?;
+            } else {
+                worksheet
+                    .write_string(row, config.start_col, col_name)
+                    .map_err(|e| format!("Failed to write header '{}': {}", col_name, e))?;
+            }
+        }
+    }
+
+    // polars rows come from iter_rows(), pandas rows from .values; both are
+    // indexed by column position
+    let source = if is_polars {
+        df.call_method0("iter_rows")
+    } else {
+        df.getattr("values")
+    }
+    .map_err(|e| format!("Failed to read DataFrame rows: {}", e))?;
+    let mut rows = source
+        .try_iter()
+        .map_err(|e| format!("Failed to create row iterator: {}", e))?;
+    for i in 0..row_count {
+        let row = rows
+            .next()
+            .ok_or("DataFrame returned fewer rows than its shape")?
+            .map_err(|e| format!("Failed to get row {}: {}", i, e))?;
+        let sheet_col_idx = header_cols + i;
+        let col = config.start_col + sheet_col_idx as u16;
+        let band = &bands[i % bands.len()];
+        for (col_idx, column_format) in band.col_formats.iter().enumerate() {
+            let value = row
+                .get_item(col_idx)
+                .map_err(|e| format!("Failed to get value at ({}, {}): {}", i, col_idx, e))?;
+            write_value_cell(
+                worksheet,
+                config.start_row + col_idx as u32,
+                col,
+                &value,
+                band.options(col_idx),
+                column_format.as_ref(),
+                integer_like.get(col_idx).copied().unwrap_or(false),
+                markup.get(col_idx).copied().unwrap_or(false),
+                text.get(col_idx).copied().unwrap_or(false),
+                track_widths.then(|| &mut max_lens[sheet_col_idx]),
+            )?;
+        }
+        report_progress(py, config.progress, i + 1)?;
+    }
+
+    let skipped = transpose_skipped_features(config, &opts);
+    if !skipped.is_empty() {
+        warn_runtime(
+            py,
+            &format!(
+                "sheet '{}': transpose=True skips these column-keyed features: {}",
+                sheet_name,
+                skipped.join(", ")
+            ),
+        )?;
+    }
+
+    let content_widths: Vec<f64> = if track_widths {
+        max_lens
+            .iter()
+            .map(|&len| (len as f64 + 1.0).max(8.43))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    // The header column must fit its longest label
+    let header_widths: Vec<f64> = if config.include_header && *config.autofit == Autofit::All {
+        let widest = header_floor_widths(columns).into_iter().reduce(f64::max);
+        widest.into_iter().collect()
+    } else {
+        Vec::new()
+    };
+
+    // The header now runs down the first column, so freezing it freezes that
+    // column instead of the top row
+    let freeze_panes = match config.freeze_panes {
+        FreezePanes::HeaderRow if config.include_header => FreezePanes::At(0, config.start_col + 1),
+        FreezePanes::HeaderRow => FreezePanes::Off,
+        other => other,
+    };
+    let features_config = WriteConfig {
+        include_header: false,
+        table_style: None,
+        table_columns: None,
+        autofilter: false,
+        categorical_validation: false,
+        freeze_panes,
+        // A column list names DataFrame columns, which now run down the rows
+        autofit: match config.autofit {
+            Autofit::Columns(_) => &Autofit::Off,
+            other => other,
+        },
+        ..*config
+    };
+    let features_opts = EffectiveOpts {
+        formula_columns: None,
+        conditional_formats: None,
+        validations: None,
+        ..opts
+    };
+    let last_row_idx = if col_count == 0 {
+        config.start_row
+    } else {
+        config.start_row + row_span
+    };
+    let (end_row_idx, total_col_count) = apply_worksheet_features(
+        py,
+        worksheet,
+        sheet_name,
+        &[],
+        &[],
+        &ColumnLayout::contiguous(config.start_col, col_count),
+        last_row_idx,
+        columns.len(),
+        &features_config,
+        header_fmt,
+        &features_opts,
+        &content_widths,
+        &header_widths,
     )?;
+    let last_col = config
+        .start_col
+        .checked_add(total_col_count)
+        .ok_or("Total column count exceeds u16 limit")?;
 
-    Ok((row_idx, total_col_count))
+    Ok((end_row_idx, last_col))
+}
+
+/// Excel's maximum zero-based row and column indices (row 1048576, column XFD).
+const MAX_ROW_INDEX: u32 = 1_048_575;
+const MAX_COL_INDEX: u16 = 16_383;
+
+/// Check that a `start_row`/`start_col` offset leaves room for the DataFrame's
+/// columns inside Excel's grid. Rows are checked again by rust_xlsxwriter as
+/// they're written; this catches the offset itself up front, before any data.
+fn validate_start_position(start_row: u32, start_col: u16, col_count: u16) -> Result<(), String> {
+    if start_row > MAX_ROW_INDEX {
+        return Err(format!(
+            "start_row {} exceeds Excel's maximum row index {}",
+            start_row, MAX_ROW_INDEX
+        ));
+    }
+    let last_col = u32::from(start_col) + u32::from(col_count.max(1)) - 1;
+    if last_col > u32::from(MAX_COL_INDEX) {
+        return Err(format!(
+            "start_col {} with {} columns exceeds Excel's maximum column index {}",
+            start_col, col_count, MAX_COL_INDEX
+        ));
+    }
+    Ok(())
 }
 
 pub(crate) fn write_configured_sheet(
@@ -457,6 +2052,17 @@ pub(crate) fn write_configured_sheet(
     config: &WriteConfig<'_>,
     opts: EffectiveOpts<'_>,
 ) -> Result<(u32, u16), String> {
+    if config.split_panes.is_some() && config.freeze_panes != FreezePanes::Off {
+        return Err(
+            "freeze_panes and split_panes are mutually exclusive: set one or the other".to_string(),
+        );
+    }
+    if config.split_panes == Some((0, 0)) {
+        return Err(
+            "split_panes: A1 splits nothing; pass a cell below row 1 or right of column A"
+                .to_string(),
+        );
+    }
     let worksheet = if config.constant_memory {
         workbook.add_worksheet_with_constant_memory()
     } else {
@@ -471,7 +2077,13 @@ pub(crate) fn write_configured_sheet(
 /// Complex feature options that still work under `constant_memory` because they
 /// are applied during the data-write phase (in `write_sheet_data`), not in
 /// `apply_worksheet_features`. Every other present complex option is skipped.
-const CONSTANT_MEMORY_SAFE_OPTIONS: &[&str] = &["column_widths", "header_format", "column_formats"];
+const CONSTANT_MEMORY_SAFE_OPTIONS: &[&str] = &[
+    "column_widths",
+    "header_format",
+    "cell_format",
+    "link_format",
+    "column_formats",
+];
 
 /// Emit a `RuntimeWarning` listing the features that `constant_memory` mode
 /// skips. The complex-feature list is derived from
@@ -489,15 +2101,30 @@ fn warn_constant_memory_skips(
     if config.table_style.is_some() {
         disabled.push("table_style");
     }
-    if config.freeze_panes {
+    if config.freeze_panes != FreezePanes::Off {
         disabled.push("freeze_panes");
     }
-    if config.autofit {
+    if config.freeze_top_cell.is_some() {
+        disabled.push("freeze_top_cell");
+    }
+    if config.split_panes.is_some() {
+        disabled.push("split_panes");
+    }
+    if config.autofilter {
+        disabled.push("autofilter");
+    }
+    if config.tables.is_some() {
+        disabled.push("tables");
+    }
+    if *config.autofit != Autofit::Off {
         disabled.push("autofit");
     }
     if config.row_heights.is_some() {
         disabled.push("row_heights");
     }
+    if config.categorical_validation {
+        disabled.push("categorical_validation");
+    }
     // Complex feature options: every present one is skipped except those applied
     // during the write phase. New features default to "skipped + warned", the
     // safe direction.
@@ -509,14 +2136,21 @@ fn warn_constant_memory_skips(
     if disabled.is_empty() {
         return Ok(());
     }
+    warn_runtime(
+        py,
+        &format!(
+            "sheet '{}': constant_memory=True disables these features: {}",
+            sheet_name,
+            disabled.join(", ")
+        ),
+    )
+}
+
+/// Emit a Python `RuntimeWarning` with `msg`.
+fn warn_runtime(py: Python<'_>, msg: &str) -> Result<(), String> {
     let warnings = py
         .import("warnings")
         .map_err(|e| format!("Failed to import warnings: {}", e))?;
-    let msg = format!(
-        "sheet '{}': constant_memory=True disables these features: {}",
-        sheet_name,
-        disabled.join(", ")
-    );
     let runtime_warning = py
         .import("builtins")
         .and_then(|b| b.getattr("RuntimeWarning"))
@@ -530,77 +2164,129 @@ fn warn_constant_memory_skips(
 /// Apply all worksheet features after data has been written.
 ///
 /// Handles: table formatting, formula columns, conditional formats, freeze panes,
-/// column widths/autofit, row heights, merged ranges, hyperlinks, comments,
+/// column widths/autofit, row heights, row/column outline groups, merged
+/// ranges, hyperlinks, comments,
 /// validations, rich text, images, checkboxes, textboxes, native Excel charts,
-/// sparklines, and arbitrary cell writes. All features except column widths,
-/// header format, and column formats are skipped in constant_memory mode.
+/// sparklines, arbitrary cell writes, and extra tables. All features except column widths,
+/// header format, cell format, and column formats are skipped in constant_memory mode.
+///
+/// `layout` places the data and formula columns. Returns one past the last
+/// written row (a table totals row included) and the total column count
+/// (formula columns included).
 #[allow(clippy::too_many_arguments)]
 fn apply_worksheet_features(
     py: Python<'_>,
     worksheet: &mut Worksheet,
     sheet_name: &str,
     columns: &[String],
-    col_count: u16,
+    categories: &[Option<Vec<String>>],
+    layout: &ColumnLayout,
     last_row_idx: u32,
     row_count: usize,
     config: &WriteConfig<'_>,
     header_fmt: Option<&Format>,
     opts: &EffectiveOpts<'_>,
     content_widths: &[f64],
-) -> Result<u16, String> {
+    header_widths: &[f64],
+) -> Result<(u32, u16), String> {
+    // safe: the layout was built from a u16 column count
+    let col_count = layout.data.len() as u16;
+
     // In constant_memory mode, only column widths (without autofit) are supported.
     // Warn about every other requested feature right here, next to the skip.
     if config.constant_memory {
         warn_constant_memory_skips(py, sheet_name, config, opts)?;
         if let Some(widths) = opts.column_widths {
-            apply_column_widths(worksheet, col_count, widths)?;
-        }
-        return Ok(col_count);
-    }
-
-    // Add Excel Table if requested (requires header + at least one data row)
-    if let Some(style_name) = config.table_style {
-        if row_count > 0 && config.include_header {
-            let style = parse_table_style(style_name)?;
-            let mut table = Table::new().set_style(style);
-
-            if let Some(name) = config.table_name {
-                let sanitized = sanitize_table_name(name);
-                table = table.set_name(&sanitized);
-            }
-
-            let last_row = last_row_idx.saturating_sub(1);
-            let last_col = col_count.saturating_sub(1);
-
-            worksheet
-                .add_table(0, 0, last_row, last_col, &table)
-                .map_err(|e| format!("Failed to add table: {}", e))?;
+            apply_column_widths(worksheet, &layout.data, widths)?;
         }
+        return Ok((last_row_idx, col_count));
     }
 
-    let data_row_start = if config.include_header { 1u32 } else { 0u32 };
+    let data_row_start = config.start_row + u32::from(config.include_header);
     let data_row_end = last_row_idx.saturating_sub(1);
     let has_data_rows = row_count > 0 && data_row_end >= data_row_start;
 
-    // Apply formula columns
+    // Apply formula columns, ahead of the table so it picks up the headers of
+    // those inserted among the data columns
     let mut total_col_count = col_count;
     if let Some(formulas) = opts.formula_columns {
         if !formulas.is_empty() && has_data_rows {
-            let formula_cols_added = apply_formula_columns(
+            apply_formula_columns(
+                py,
                 worksheet,
                 formulas,
-                col_count,
+                columns,
+                layout,
                 data_row_start,
                 data_row_end,
-                config.include_header,
+                config.include_header.then_some(config.start_row),
                 header_fmt,
             )?;
-            total_col_count = col_count
-                .checked_add(formula_cols_added)
+            total_col_count = u16::try_from(layout.formulas.len())
+                .ok()
+                .and_then(|n| col_count.checked_add(n))
                 .ok_or("Total column count exceeds u16 limit")?;
         }
     }
 
+    // Add Excel Table if requested (requires at least one data row). Without a
+    // header row the table is created headerless so it starts at the first
+    // data row instead of claiming it as the table header. It spans the data
+    // columns and any formula columns inserted among them.
+    let has_table = config.table_style.is_some() && row_count > 0;
+    let mut end_row_idx = last_row_idx;
+    // The table or autofilter range, which merged ranges must stay clear of
+    let mut filter_region = None;
+    if let Some(style_name) = config.table_style {
+        if has_table {
+            let style = parse_table_style(style_name)?;
+            let look = config.table_options.unwrap_or_default();
+            let mut table = Table::new()
+                .set_style(style)
+                .set_header_row(config.include_header)
+                .set_banded_rows(look.banded_rows)
+                .set_banded_columns(look.banded_columns)
+                .set_first_column(look.first_column)
+                .set_last_column(look.last_column);
+
+            if let Some(name) = config.table_name {
+                let sanitized = sanitize_table_name(name);
+                table = table.set_name(&sanitized);
+            }
+
+            // A totals row sits directly below the data, inside the table range
+            if let Some(overrides) = config.table_columns {
+                let names = layout.sheet_order(columns, opts.formula_columns);
+                let names = &names[..usize::from(layout.block_width)];
+                let (table_columns, total_row) =
+                    build_table_columns(overrides, names, config.sanitize_strings)?;
+                table = table.set_columns(&table_columns);
+                if total_row {
+                    table = table.set_total_row(true);
+                    end_row_idx = last_row_idx
+                        .checked_add(1)
+                        .ok_or("Row count exceeds u32 limit")?;
+                }
+            }
+            let last_row = end_row_idx.saturating_sub(1);
+            let last_col = config.start_col + layout.block_width.saturating_sub(1);
+            filter_region = Some((
+                "table",
+                (config.start_row, config.start_col, last_row, last_col),
+            ));
+
+            worksheet
+                .add_table(
+                    config.start_row,
+                    config.start_col,
+                    last_row,
+                    last_col,
+                    &table,
+                )
+                .map_err(|e| format!("Failed to add table: {}", e))?;
+        }
+    }
+
     // Apply conditional formats
     if let Some(cond_fmts) = opts.conditional_formats {
         if has_data_rows {
@@ -608,35 +2294,115 @@ fn apply_worksheet_features(
                 py,
                 worksheet,
                 columns,
+                &layout.data,
                 data_row_start,
                 data_row_end,
                 cond_fmts,
+                config.conditional_format_mode,
             )?;
         }
     }
 
-    // Freeze panes (freeze header row)
-    if config.freeze_panes && config.include_header {
+    // Freeze panes: the header row (when there is one), or an explicit cell
+    let freeze_at = match config.freeze_panes {
+        FreezePanes::Off => None,
+        FreezePanes::HeaderRow => config.include_header.then_some((config.start_row + 1, 0)),
+        FreezePanes::At(row, col) => Some((row, col)),
+        FreezePanes::Leading { rows, cols } => {
+            let row = u64::from(config.start_row) + u64::from(rows);
+            let col = u32::from(config.start_col) + u32::from(cols);
+            if row > u64::from(MAX_ROW_INDEX) || col > u32::from(MAX_COL_INDEX) {
+                return Err(format!(
+                    "freeze_panes: {} rows and {} cols from start_row {} and start_col {} \
+                     put the frozen cell at ({}, {}), outside Excel's grid \
+                     (rows 0-{}, columns 0-{})",
+                    rows,
+                    cols,
+                    config.start_row,
+                    config.start_col,
+                    row,
+                    col,
+                    MAX_ROW_INDEX,
+                    MAX_COL_INDEX
+                ));
+            }
+            Some((row as u32, col as u16))
+        }
+    };
+    // Split panes are laid out as frozen ones here and unfrozen when the
+    // workbook is saved (see `save_workbook`)
+    if let Some((row, col)) = freeze_at.or(config.split_panes) {
         worksheet
-            .set_freeze_panes(1, 0)
+            .set_freeze_panes(row, col)
             .map_err(|e| format!("Failed to freeze panes: {}", e))?;
+        // Where the scrollable pane starts; meaningless without panes
+        if let Some((top_row, top_col)) = config.freeze_top_cell {
+            worksheet
+                .set_freeze_panes_top_cell(top_row, top_col)
+                .map_err(|e| format!("Failed to set freeze_top_cell: {}", e))?;
+        }
+    }
+
+    // Plain autofilter over the written block (header row plus data, formula
+    // columns included). A table already carries its own filter dropdowns, and
+    // Excel rejects a worksheet autofilter overlapping one, so skip it there.
+    if config.autofilter && !has_table && last_row_idx > config.start_row {
+        let region = (
+            config.start_row,
+            config.start_col,
+            last_row_idx - 1,
+            config.start_col + total_col_count.saturating_sub(1),
+        );
+        filter_region = Some(("autofilter", region));
+        worksheet
+            .autofilter(region.0, region.1, region.2, region.3)
+            .map_err(|e| format!("Failed to add autofilter: {}", e))?;
     }
 
-    // Apply custom column widths and/or autofit
-    if let Some(widths) = opts.column_widths {
-        if config.autofit && widths.contains_key("_all") {
-            apply_column_widths_with_autofit_cap(worksheet, col_count, widths, content_widths)?;
-        } else if config.autofit {
-            // No "_all" cap: autofit every column to its content first, then
-            // apply the explicit widths on top so listed columns win and the
-            // rest still get autofitted instead of being silently skipped.
+    // Apply custom column widths and/or autofit. Autofit measures header labels
+    // like data and clips bold ones, so a column is never fitted narrower than
+    // its padded header.
+    let fitted_widths: Vec<f64> = content_widths
+        .iter()
+        .enumerate()
+        .map(|(idx, &width)| width.max(header_widths.get(idx).copied().unwrap_or(0.0)))
+        .collect();
+    let content_widths = fitted_widths.as_slice();
+    match (config.autofit, opts.column_widths) {
+        (Autofit::Columns(selection), widths) => {
+            // Only the listed columns are sized to their content, from the
+            // tracked lengths rather than a sheet-wide autofit
+            let selected = select_columns_by_position(selection, columns, "autofit")?;
+            apply_column_widths_with_selected_autofit(
+                worksheet,
+                &layout.data,
+                widths,
+                content_widths,
+                &selected,
+            )?;
+        }
+        (Autofit::All, Some(widths)) if !widths.is_empty() => {
+            // Autofit the whole sheet first so columns outside the data
+            // (formula columns) are sized too, then set each data column to
+            // its content width capped at its own width or '_all'
             worksheet.autofit();
-            apply_column_widths(worksheet, col_count, widths)?;
-        } else {
-            apply_column_widths(worksheet, col_count, widths)?;
+            apply_column_widths_with_autofit_cap(worksheet, &layout.data, widths, content_widths)?;
+        }
+        (Autofit::All, _) => {
+            // autofit() only ever widens a column set beforehand, so the
+            // header floors (never below default_col_width) hold
+            let default_width = config.default_col_width.unwrap_or(0.0);
+            for (&col_idx, &width) in layout.data.iter().zip(header_widths) {
+                worksheet
+                    .set_column_width(col_idx, width.max(default_width))
+                    .map_err(|e| format!("Failed to set column width: {}", e))?;
+            }
+            worksheet.autofit();
+        }
+        (Autofit::Off, Some(widths)) => {
+            apply_column_widths(worksheet, &layout.data, widths)?;
         }
-    } else if config.autofit {
-        worksheet.autofit();
+        (Autofit::Off, None) => {}
     }
 
     // Apply custom row heights
@@ -648,34 +2414,63 @@ fn apply_worksheet_features(
         }
     }
 
+    // Apply row/column outline groups
+    if let Some(groups) = opts.row_groups {
+        apply_row_groups(worksheet, groups)?;
+    }
+    if let Some(groups) = opts.col_groups {
+        apply_col_groups(worksheet, groups)?;
+    }
+
     // Apply merged ranges
     if let Some(ranges) = opts.merged_ranges {
         if !ranges.is_empty() {
-            apply_merged_ranges(py, worksheet, ranges)?;
+            apply_merged_ranges(py, worksheet, ranges, config, filter_region)?;
         }
     }
 
     // Apply hyperlinks
     if let Some(links) = opts.hyperlinks {
         if !links.is_empty() {
-            apply_hyperlinks(worksheet, links)?;
+            apply_hyperlinks(worksheet, links, config.sheet_names)?;
         }
     }
 
     // Apply comments/notes
     if let Some(cmts) = opts.comments {
         if !cmts.is_empty() {
-            apply_comments(worksheet, cmts)?;
+            apply_comments(py, worksheet, cmts)?;
         }
     }
 
     // Apply data validations
     if let Some(vals) = opts.validations {
         if has_data_rows {
-            apply_validations(py, worksheet, columns, data_row_start, data_row_end, vals)?;
+            apply_validations(
+                py,
+                worksheet,
+                columns,
+                &layout.data,
+                data_row_start,
+                data_row_end,
+                vals,
+            )?;
         }
     }
 
+    // Dropdowns of each categorical column's categories
+    if config.categorical_validation && has_data_rows {
+        apply_categorical_validations(
+            worksheet,
+            columns,
+            categories,
+            &layout.data,
+            data_row_start,
+            data_row_end,
+            opts.validations,
+        )?;
+    }
+
     // Apply rich text
     if let Some(rt) = opts.rich_text {
         if !rt.is_empty() {
@@ -707,7 +2502,17 @@ fn apply_worksheet_features(
     // Apply native Excel charts
     if let Some(charts) = opts.charts {
         if !charts.is_empty() {
-            apply_charts(py, worksheet, charts)?;
+            // Formula columns count only when they were written
+            let formula_columns = opts.formula_columns.filter(|_| total_col_count > col_count);
+            let layout = ChartDataLayout {
+                sheet_name,
+                columns: layout.sheet_order(columns, formula_columns),
+                start_col: config.start_col,
+                header_row: config.include_header.then_some(config.start_row),
+                first_data_row: data_row_start,
+                last_data_row: data_row_end.max(data_row_start),
+            };
+            apply_charts(py, worksheet, charts, &layout)?;
         }
     }
 
@@ -721,11 +2526,80 @@ fn apply_worksheet_features(
     // Apply cells (arbitrary cell writes, after all DataFrame data)
     if let Some(cells) = opts.cells {
         if !cells.is_empty() {
-            apply_cells(py, worksheet, cells)?;
+            apply_cells(
+                py,
+                worksheet,
+                cells,
+                &CellWriteOptions::from_config(config, None)?,
+            )?;
         }
     }
 
-    Ok(total_col_count)
+    // Apply cell overrides (after cells, so they win on a shared cell)
+    if let Some(overrides) = opts.cell_overrides {
+        if !overrides.is_empty() {
+            apply_cell_overrides(py, worksheet, overrides, config)?;
+        }
+    }
+
+    // Add extra tables over explicit ranges last, so their header rows pick up
+    // text written by cells, and keep them clear of the table or autofilter
+    if let Some(tables) = config.tables {
+        if !tables.is_empty() {
+            let main_table_name = config.table_name.filter(|_| has_table);
+            apply_tables(worksheet, tables, main_table_name, filter_region)?;
+        }
+    }
+
+    Ok((end_row_idx, total_col_count))
+}
+
+/// Build the table's column list from `table_columns` overrides, matched to the
+/// table's columns (in sheet order, inserted formula columns included) from
+/// the left. Headers default to the column name, since the table rewrites its
+/// header row, cleaned like the header cells when `sanitize` is on. Also
+/// returns whether any override needs the totals row.
+fn build_table_columns(
+    overrides: &[TableColumnConfig],
+    columns: &[&str],
+    sanitize: bool,
+) -> Result<(Vec<TableColumn>, bool), String> {
+    if overrides.len() > columns.len() {
+        return Err(format!(
+            "table_columns has {} entries but the table has only {} columns",
+            overrides.len(),
+            columns.len()
+        ));
+    }
+    let mut total_row = false;
+    let table_columns = overrides
+        .iter()
+        .zip(columns)
+        .map(|(column, name)| {
+            let default_header = header_label(name, sanitize);
+            let mut table_column =
+                TableColumn::new().set_header(column.header.as_deref().unwrap_or(&default_header));
+            if let Some(function) = column.total_function {
+                total_row = true;
+                table_column = table_column.set_total_function(match function {
+                    TableTotal::Average => TableFunction::Average,
+                    TableTotal::Count => TableFunction::Count,
+                    TableTotal::CountNumbers => TableFunction::CountNumbers,
+                    TableTotal::Max => TableFunction::Max,
+                    TableTotal::Min => TableFunction::Min,
+                    TableTotal::Sum => TableFunction::Sum,
+                    TableTotal::StdDev => TableFunction::StdDev,
+                    TableTotal::Var => TableFunction::Var,
+                });
+            }
+            if let Some(label) = &column.total_label {
+                total_row = true;
+                table_column = table_column.set_total_label(label);
+            }
+            table_column
+        })
+        .collect();
+    Ok((table_columns, total_row))
 }
 
 /// Convert a DataFrame (pandas or polars) to XLSX format
@@ -733,44 +2607,47 @@ fn apply_worksheet_features(
 pub(crate) fn convert_dataframe_to_xlsx(
     py: Python<'_>,
     df: &Bound<'_, PyAny>,
-    output_path: &str,
+    output: &OutputTarget,
     sheet_name: &str,
-    include_header: bool,
-    autofit: bool,
-    table_style: Option<&str>,
-    freeze_panes: bool,
-    table_name: Option<&str>,
-    row_heights: Option<&HashMap<u32, f64>>,
-    constant_memory: bool,
+    config: &WriteConfig<'_>,
     opts: &ExtractedOptions,
     defined_names: Option<&HashMap<String, String>>,
+    properties: Option<&DocProperties>,
+    calc: CalcSettings,
 ) -> Result<(u32, u16), String> {
     let mut workbook = rust_xlsxwriter::Workbook::new();
 
-    let config = WriteConfig {
-        include_header,
-        autofit,
-        table_style,
-        freeze_panes,
-        table_name,
-        row_heights,
-        constant_memory,
-    };
-
     let result = write_configured_sheet(
         py,
         &mut workbook,
         df,
         sheet_name,
-        &config,
+        config,
         opts.as_effective(),
     )?;
 
     apply_defined_names(&mut workbook, defined_names)?;
-
-    workbook
-        .save(output_path)
-        .map_err(|e| format!("Failed to save workbook to '{}': {}", output_path, e))?;
+    if let Some(properties) = properties {
+        workbook.set_properties(properties);
+    }
+    let split_sheets: Vec<(usize, (u32, u16))> = config
+        .split_panes
+        .map(|cell| (0, cell))
+        .into_iter()
+        .collect();
+    let hidden_headers: Vec<usize> = if config.show_row_col_headers {
+        Vec::new()
+    } else {
+        vec![0]
+    };
+    save_workbook(
+        py,
+        &mut workbook,
+        output,
+        calc,
+        &split_sheets,
+        &hidden_headers,
+    )?;
 
     Ok(result)
 }
@@ -798,6 +2675,8 @@ mod constant_memory_tests {
             "formula_columns",
             "merged_ranges",
             "hyperlinks",
+            "row_groups",
+            "col_groups",
             "comments",
             "validations",
             "rich_text",
@@ -807,6 +2686,7 @@ mod constant_memory_tests {
             "charts",
             "sparklines",
             "cells",
+            "cell_overrides",
         ];
 
         for &name in EffectiveOpts::COMPLEX_OPTION_NAMES {
@@ -831,3 +2711,61 @@ mod constant_memory_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod start_position_tests {
+    use super::{validate_start_position, MAX_COL_INDEX, MAX_ROW_INDEX};
+
+    #[test]
+    fn accepts_offsets_that_fit_the_grid() {
+        assert!(validate_start_position(0, 0, 3).is_ok());
+        assert!(validate_start_position(MAX_ROW_INDEX, MAX_COL_INDEX - 2, 3).is_ok());
+        // An empty DataFrame still needs its start column to exist.
+        assert!(validate_start_position(0, MAX_COL_INDEX, 0).is_ok());
+    }
+
+    #[test]
+    fn rejects_offsets_past_the_grid() {
+        let err = validate_start_position(MAX_ROW_INDEX + 1, 0, 1).unwrap_err();
+        assert!(err.contains("start_row"), "{}", err);
+        let err = validate_start_position(0, MAX_COL_INDEX - 1, 3).unwrap_err();
+        assert!(err.contains("start_col"), "{}", err);
+        assert!(validate_start_position(0, MAX_COL_INDEX + 1, 0).is_err());
+    }
+}
+
+#[cfg(test)]
+mod csv_input_tests {
+    use super::{parse_csv_char, parse_encoding};
+
+    #[test]
+    fn csv_char_accepts_one_ascii_character() {
+        assert_eq!(parse_csv_char("delimiter", ";"), Ok(b';'));
+        assert_eq!(parse_csv_char("delimiter", "\\t"), Ok(b'\t'));
+        assert!(parse_csv_char("delimiter", ";;").is_err());
+        assert!(parse_csv_char("delimiter", "\n").is_err());
+        assert!(parse_csv_char("quote", "é").is_err());
+    }
+
+    #[test]
+    fn encoding_accepts_whatwg_and_python_spellings() {
+        for name in ["utf-8", "UTF8", "utf_8"] {
+            assert_eq!(parse_encoding(name), Ok(encoding_rs::UTF_8), "{}", name);
+        }
+        for name in ["windows-1252", "cp1252", "latin-1", "latin1", "iso-8859-1"] {
+            assert_eq!(
+                parse_encoding(name),
+                Ok(encoding_rs::WINDOWS_1252),
+                "{}",
+                name
+            );
+        }
+        assert_eq!(parse_encoding("utf-16"), Ok(encoding_rs::UTF_16LE));
+    }
+
+    #[test]
+    fn unknown_encoding_is_rejected() {
+        let err = parse_encoding("klingon").unwrap_err();
+        assert!(err.starts_with("Unknown encoding 'klingon'"), "{}", err);
+    }
+}
@@ -1,13 +1,23 @@
 //! Python extraction functions for converting Python objects to Rust types
 
-use crate::parse::{parse_cell_ref, parse_horizontal_alignment, parse_vertical_alignment};
+use crate::parse::{
+    parse_cell_range, parse_cell_ref, parse_horizontal_alignment, parse_table_style,
+    parse_vertical_alignment,
+};
 use crate::types::{
-    pydict_to_hashmap, pytype_name, reject_unknown_keys as types_reject_unknown_keys, CellWrite,
-    ChartConfig, CheckboxConfig, Comment, ConditionalFormatConfigs, Hyperlink, ImageConfig,
-    MergedRange, RichTextSegment, SheetConfig, SparklineConfig, TextboxConfig, ValidationConfig,
+    extract_field, pydict_to_hashmap, pytype_name,
+    reject_unknown_keys as types_reject_unknown_keys, Autofit, BoolMode, CellOverride, CellWrite,
+    ChartConfig, CheckboxConfig, ColumnFormatConfigs, ColumnFormatKey, ColumnSelector, Comment,
+    ConditionalFormatConfigs, CsvSheetConfig, FormulaColumn, FormulaPosition, FormulaType,
+    FreezePanes, HeaderFooter, Hyperlink, ImageConfig, ImageSource, MergedRange, OptionMap,
+    OutlineGroup, PageMargins, PageOrientation, PageSetup, Protection, RichTextSegment,
+    SheetConfig, SheetSelector, SheetVisibility, SparklineConfig, SummaryFunction,
+    TableColumnConfig, TableOptions, TableSpec, TableTotal, TextboxConfig, ValidationConfig,
 };
+use crate::workbook::{parse_iso_datetime, utc_excel_datetime};
 use indexmap::IndexMap;
 use pyo3::prelude::*;
+use rust_xlsxwriter::DocProperties;
 use std::collections::HashMap;
 
 const SHEET_OPTION_NAMES: &[&str] = &[
@@ -15,15 +25,22 @@ const SHEET_OPTION_NAMES: &[&str] = &[
     "autofit",
     "table_style",
     "freeze_panes",
+    "freeze_top_cell",
+    "split_panes",
     "column_widths",
     "row_heights",
     "table_name",
+    "table_columns",
+    "table_options",
+    "tables",
     "header_format",
     "column_formats",
     "conditional_formats",
     "formula_columns",
     "merged_ranges",
     "hyperlinks",
+    "row_groups",
+    "col_groups",
     "comments",
     "validations",
     "rich_text",
@@ -33,6 +50,50 @@ const SHEET_OPTION_NAMES: &[&str] = &[
     "charts",
     "sparklines",
     "cells",
+    "cell_overrides",
+    "date_format",
+    "datetime_format",
+    "time_format",
+    "nan_policy",
+    "large_int_mode",
+    "long_string_mode",
+    "start_row",
+    "start_col",
+    "autofilter",
+    "conditional_format_mode",
+    "show_gridlines",
+    "show_row_col_headers",
+    "zoom",
+    "view",
+    "right_to_left",
+    "outline_below",
+    "outline_right",
+    "tab_color",
+    "page_setup",
+    "print_area",
+    "background_image",
+    "categorical_validation",
+    "integer_like_floats",
+    "columns",
+    "skip_rows",
+    "max_rows",
+    "transpose",
+    "include_index",
+    "cell_format",
+    "banded_rows",
+    "summary_row",
+    "default_col_width",
+    "default_row_height",
+    "protect",
+    "auto_hyperlinks",
+    "auto_rich_text_columns",
+    "infer_string_types",
+    "bool_mode",
+    "text_columns",
+    "sanitize_strings",
+    "link_format",
+    "visible",
+    "active_cell",
 ];
 
 /// Helper: extract an optional scalar field from a Python dict into a SheetConfig field.
@@ -110,7 +171,38 @@ macro_rules! extract_list_field {
     };
 }
 
-fn validate_sheet_option_keys(opts: &Bound<'_, pyo3::types::PyDict>) -> PyResult<()> {
+/// Per-sheet option keys accepted by `csvs_to_xlsx`: every `csv_to_xlsx`
+/// option except the workbook-level `parallel`
+const CSV_SHEET_OPTION_NAMES: &[&str] = &[
+    "date_order",
+    "date_format",
+    "datetime_format",
+    "time_format",
+    "nan_policy",
+    "large_int_mode",
+    "long_string_mode",
+    "constant_memory",
+    "smart_numeric",
+    "delimiter",
+    "quote",
+    "escape",
+    "has_header",
+    "header_format",
+    "encoding",
+    "comment",
+    "skip_empty_rows",
+    "null_values",
+    "decimal_separator",
+    "thousands_separator",
+    "scientific_format",
+    "text_columns",
+    "sanitize_strings",
+];
+
+fn validate_sheet_option_keys(
+    opts: &Bound<'_, pyo3::types::PyDict>,
+    valid_names: &[&str],
+) -> PyResult<()> {
     for key in opts.keys().iter() {
         let key_str: String = key.extract().map_err(|_| {
             pyo3::exceptions::PyTypeError::new_err(format!(
@@ -118,11 +210,11 @@ fn validate_sheet_option_keys(opts: &Bound<'_, pyo3::types::PyDict>) -> PyResult
                 pytype_name(&key)
             ))
         })?;
-        if !SHEET_OPTION_NAMES.contains(&key_str.as_str()) {
+        if !valid_names.contains(&key_str.as_str()) {
             return Err(pyo3::exceptions::PyValueError::new_err(format!(
                 "Unknown sheet option '{}'. Valid keys: {}",
                 key_str,
-                SHEET_OPTION_NAMES.join(", ")
+                valid_names.join(", ")
             )));
         }
     }
@@ -155,6 +247,155 @@ fn reject_unknown_dict_keys(
         .map_err(pyo3::exceptions::PyValueError::new_err)
 }
 
+/// A `df_sections_to_xlsx` section: the DataFrame, its target sheet, the row
+/// it starts at, and an optional title written on that row.
+pub(crate) type SectionInfo<'py> = (Bound<'py, PyAny>, String, u32, Option<String>);
+
+/// Extract section info from a Python tuple
+/// 3-tuple: (df, sheet_name, start_row)
+/// 4-tuple: (df, sheet_name, start_row, title)
+pub(crate) fn extract_section_info<'py>(
+    section_tuple: &Bound<'py, PyAny>,
+) -> PyResult<SectionInfo<'py>> {
+    let len: usize = section_tuple.len()?;
+
+    if !(3..=4).contains(&len) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Section tuple must have exactly 3 or 4 elements, got {}: (df, sheet_name, start_row[, title])",
+            len
+        )));
+    }
+
+    let df = section_tuple.get_item(0)?;
+    let sheet_name: String = section_tuple.get_item(1)?.extract()?;
+    let start_row_obj = section_tuple.get_item(2)?;
+    let start_row: u32 = start_row_obj.extract().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "section start_row must be a non-negative int, got {}",
+            pytype_name(&start_row_obj)
+        ))
+    })?;
+    let title = if len == 4 {
+        let title_obj = section_tuple.get_item(3)?;
+        if title_obj.is_none() {
+            None
+        } else {
+            Some(title_obj.extract::<String>().map_err(|_| {
+                pyo3::exceptions::PyTypeError::new_err(format!(
+                    "section title must be a str or None, got {}",
+                    pytype_name(&title_obj)
+                ))
+            })?)
+        }
+    } else {
+        None
+    };
+
+    Ok((df, sheet_name, start_row, title))
+}
+
+/// Extract CSV sheet info from a `csvs_to_xlsx` input tuple
+/// 2-tuple: (csv_path, sheet_name)
+/// 3-tuple: (csv_path, sheet_name, options_dict)
+pub(crate) fn extract_csv_sheet_info<'py>(
+    sheet_tuple: &Bound<'py, PyAny>,
+) -> PyResult<(Bound<'py, PyAny>, String, CsvSheetConfig)> {
+    let len: usize = sheet_tuple.len()?;
+
+    if !(2..=3).contains(&len) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Sheet tuple must have exactly 2 or 3 elements, got {}: (csv_path, sheet_name[, options_dict])",
+            len
+        )));
+    }
+
+    let csv_path = sheet_tuple.get_item(0)?;
+    let sheet_name: String = sheet_tuple.get_item(1)?.extract()?;
+    let mut config = CsvSheetConfig::default();
+    if len < 3 {
+        return Ok((csv_path, sheet_name, config));
+    }
+    let opts = sheet_tuple.get_item(2)?;
+    if opts.is_none() {
+        return Ok((csv_path, sheet_name, config));
+    }
+    let opts_dict = opts.cast::<pyo3::types::PyDict>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "Sheet options must be a dict, got {}",
+            pytype_name(&opts)
+        ))
+    })?;
+    validate_sheet_option_keys(opts_dict, CSV_SHEET_OPTION_NAMES)?;
+
+    extract_scalar!(opts, config, "date_order", date_order, "a string");
+    extract_scalar!(opts, config, "date_format", date_format, "a string");
+    extract_scalar!(opts, config, "datetime_format", datetime_format, "a string");
+    extract_scalar!(opts, config, "time_format", time_format, "a string");
+    extract_scalar!(opts, config, "nan_policy", nan_policy, "a string");
+    extract_scalar!(opts, config, "large_int_mode", large_int_mode, "a string");
+    extract_scalar!(
+        opts,
+        config,
+        "long_string_mode",
+        long_string_mode,
+        "a string"
+    );
+    extract_scalar!(opts, config, "constant_memory", constant_memory, "a bool");
+    extract_scalar!(opts, config, "smart_numeric", smart_numeric, "a bool");
+    extract_scalar!(opts, config, "delimiter", delimiter, "a string");
+    extract_scalar!(opts, config, "quote", quote, "a string");
+    extract_scalar!(opts, config, "escape", escape, "a string");
+    extract_scalar!(opts, config, "has_header", has_header, "a bool");
+    extract_dict_field!(
+        opts,
+        config,
+        "header_format",
+        header_format,
+        extract_header_format
+    );
+    extract_scalar!(opts, config, "encoding", encoding, "a string");
+    extract_scalar!(opts, config, "comment", comment, "a string");
+    extract_scalar!(opts, config, "skip_empty_rows", skip_empty_rows, "a bool");
+    extract_scalar!(
+        opts,
+        config,
+        "null_values",
+        null_values,
+        "a list of strings"
+    );
+    extract_scalar!(
+        opts,
+        config,
+        "decimal_separator",
+        decimal_separator,
+        "a string"
+    );
+    extract_scalar!(
+        opts,
+        config,
+        "thousands_separator",
+        thousands_separator,
+        "a string"
+    );
+    extract_scalar!(
+        opts,
+        config,
+        "scientific_format",
+        scientific_format,
+        "a string"
+    );
+    extract_scalar!(
+        opts,
+        config,
+        "text_columns",
+        text_columns,
+        "a list of column positions"
+    );
+    extract_scalar!(opts, config, "sanitize_strings", sanitize_strings, "a bool");
+
+    Ok((csv_path, sheet_name, config))
+}
+
 /// Extract sheet info from a Python tuple (supports both 2-tuple and 3-tuple formats)
 /// 2-tuple: (df, sheet_name)
 /// 3-tuple: (df, sheet_name, options_dict)
@@ -186,13 +427,19 @@ pub(crate) fn extract_sheet_info<'py>(
                 pytype_name(&opts)
             ))
         })?;
-        validate_sheet_option_keys(opts_dict)?;
+        validate_sheet_option_keys(opts_dict, SHEET_OPTION_NAMES)?;
         let mut config = SheetConfig::default();
 
         // Extract scalar fields
         extract_scalar!(opts, config, "header", header, "a bool");
-        extract_scalar!(opts, config, "autofit", autofit, "a bool");
-        extract_scalar!(opts, config, "freeze_panes", freeze_panes, "a bool");
+        match opts.get_item("autofit") {
+            Ok(val) if !val.is_none() => {
+                config.autofit = Some(extract_autofit(&val, "sheet option 'autofit'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
         extract_scalar!(
             opts,
             config,
@@ -201,6 +448,245 @@ pub(crate) fn extract_sheet_info<'py>(
             "a dict mapping row index (int) to height (number)"
         );
         extract_scalar!(opts, config, "table_name", table_name, "a string");
+        extract_scalar!(opts, config, "date_format", date_format, "a string");
+        extract_scalar!(opts, config, "datetime_format", datetime_format, "a string");
+        extract_scalar!(opts, config, "time_format", time_format, "a string");
+        extract_scalar!(opts, config, "nan_policy", nan_policy, "a string");
+        extract_scalar!(opts, config, "large_int_mode", large_int_mode, "a string");
+        extract_scalar!(
+            opts,
+            config,
+            "long_string_mode",
+            long_string_mode,
+            "a string"
+        );
+        extract_scalar!(opts, config, "start_row", start_row, "a non-negative int");
+        extract_scalar!(opts, config, "skip_rows", skip_rows, "a non-negative int");
+        extract_scalar!(opts, config, "max_rows", max_rows, "a non-negative int");
+        extract_scalar!(opts, config, "transpose", transpose, "a bool");
+        extract_scalar!(opts, config, "include_index", include_index, "a bool");
+        extract_scalar!(opts, config, "start_col", start_col, "a non-negative int");
+        extract_scalar!(opts, config, "autofilter", autofilter, "a bool");
+        extract_scalar!(
+            opts,
+            config,
+            "conditional_format_mode",
+            conditional_format_mode,
+            "a string"
+        );
+        extract_scalar!(opts, config, "show_gridlines", show_gridlines, "a bool");
+        extract_scalar!(opts, config, "zoom", zoom, "an int");
+        extract_scalar!(opts, config, "view", view, "a string");
+        extract_scalar!(opts, config, "right_to_left", right_to_left, "a bool");
+        extract_scalar!(opts, config, "outline_below", outline_below, "a bool");
+        extract_scalar!(opts, config, "outline_right", outline_right, "a bool");
+        extract_scalar!(
+            opts,
+            config,
+            "show_row_col_headers",
+            show_row_col_headers,
+            "a bool"
+        );
+        extract_scalar!(
+            opts,
+            config,
+            "categorical_validation",
+            categorical_validation,
+            "a bool"
+        );
+        extract_scalar!(
+            opts,
+            config,
+            "integer_like_floats",
+            integer_like_floats,
+            "a bool"
+        );
+        extract_scalar!(opts, config, "auto_hyperlinks", auto_hyperlinks, "a bool");
+        extract_scalar!(
+            opts,
+            config,
+            "auto_rich_text_columns",
+            auto_rich_text_columns,
+            "a list of column names or patterns"
+        );
+        extract_scalar!(
+            opts,
+            config,
+            "infer_string_types",
+            infer_string_types,
+            "a bool"
+        );
+        extract_scalar!(opts, config, "sanitize_strings", sanitize_strings, "a bool");
+        extract_scalar!(opts, config, "tab_color", tab_color, "a string");
+        extract_scalar!(opts, config, "print_area", print_area, "a string");
+        extract_scalar!(
+            opts,
+            config,
+            "default_col_width",
+            default_col_width,
+            "a number"
+        );
+        extract_scalar!(
+            opts,
+            config,
+            "default_row_height",
+            default_row_height,
+            "a number"
+        );
+
+        // freeze_panes accepts several types, so it gets its own extractor
+        match opts.get_item("freeze_panes") {
+            Ok(val) if !val.is_none() => {
+                config.freeze_panes =
+                    Some(extract_freeze_panes(&val, "sheet option 'freeze_panes'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("visible") {
+            Ok(val) if !val.is_none() => {
+                config.visible = Some(extract_sheet_visibility(&val)?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("active_cell") {
+            Ok(val) if !val.is_none() => {
+                config.active_cell = Some(extract_cell_ref(&val, "sheet option 'active_cell'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("table_options") {
+            Ok(val) if !val.is_none() => {
+                config.table_options =
+                    Some(extract_table_options(&val, "sheet option 'table_options'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+        match opts.get_item("tables") {
+            Ok(val) if !val.is_none() => {
+                config.tables = Some(extract_tables(&val, "sheet option 'tables'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+        match opts.get_item("table_columns") {
+            Ok(val) if !val.is_none() => {
+                config.table_columns =
+                    Some(extract_table_columns(&val, "sheet option 'table_columns'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("columns") {
+            Ok(val) if !val.is_none() => {
+                config.columns = Some(extract_column_selection(&val, "sheet option 'columns'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("page_setup") {
+            Ok(val) if !val.is_none() => {
+                config.page_setup = Some(extract_page_setup(&val, "sheet option 'page_setup'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("background_image") {
+            Ok(val) if !val.is_none() => {
+                config.background_image = Some(extract_background_image(
+                    &val,
+                    "sheet option 'background_image'",
+                )?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("bool_mode") {
+            Ok(val) if !val.is_none() => {
+                config.bool_mode = Some(extract_bool_mode(&val, "sheet option 'bool_mode'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("text_columns") {
+            Ok(val) if !val.is_none() => {
+                config.text_columns = Some(extract_column_selection(
+                    &val,
+                    "sheet option 'text_columns'",
+                )?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("banded_rows") {
+            Ok(val) if !val.is_none() => {
+                config.banded_rows = Some(extract_banded_rows(&val, "sheet option 'banded_rows'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("summary_row") {
+            Ok(val) if !val.is_none() => {
+                config.summary_row = Some(extract_summary_row(&val, "sheet option 'summary_row'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        // protect=False is recorded as Some(None) so it overrides a global True
+        match opts.get_item("protect") {
+            Ok(val) if !val.is_none() => {
+                config.protect = Some(extract_protect(&val, "sheet option 'protect'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("freeze_top_cell") {
+            Ok(val) if !val.is_none() => {
+                config.freeze_top_cell =
+                    Some(extract_cell_ref(&val, "sheet option 'freeze_top_cell'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
+
+        match opts.get_item("split_panes") {
+            Ok(val) if !val.is_none() => {
+                config.split_panes = Some(extract_cell_ref(&val, "sheet option 'split_panes'")?);
+            }
+            Ok(_) => {}
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyKeyError>(opts.py()) => {}
+            Err(e) => return Err(e),
+        }
 
         // table_style needs special handling: None means "explicitly no style"
         if let Ok(val) = opts.get_item("table_style") {
@@ -226,6 +712,20 @@ pub(crate) fn extract_sheet_info<'py>(
             header_format,
             extract_header_format
         );
+        extract_dict_field!(
+            opts,
+            config,
+            "cell_format",
+            cell_format,
+            extract_header_format
+        );
+        extract_dict_field!(
+            opts,
+            config,
+            "link_format",
+            link_format,
+            extract_header_format
+        );
         extract_dict_field!(
             opts,
             config,
@@ -263,6 +763,13 @@ pub(crate) fn extract_sheet_info<'py>(
         extract_dict_field!(opts, config, "sparklines", sparklines, extract_sparklines);
 
         extract_dict_field!(opts, config, "cells", cells, extract_cells);
+        extract_dict_field!(
+            opts,
+            config,
+            "cell_overrides",
+            cell_overrides,
+            extract_cell_overrides
+        );
 
         // Extract complex list fields
         extract_list_field!(
@@ -273,6 +780,8 @@ pub(crate) fn extract_sheet_info<'py>(
             extract_merged_ranges
         );
         extract_list_field!(opts, config, "hyperlinks", hyperlinks, extract_hyperlinks);
+        extract_list_field!(opts, config, "row_groups", row_groups, extract_row_groups);
+        extract_list_field!(opts, config, "col_groups", col_groups, extract_col_groups);
 
         config
     } else {
@@ -285,6 +794,752 @@ pub(crate) fn extract_sheet_info<'py>(
 /// Excel's maximum column index (zero-based; column XFD is the 16384th column).
 const MAX_COLUMN_INDEX: i64 = 16_383;
 
+/// Excel's maximum row index (zero-based; row 1048576).
+const MAX_ROW_INDEX: i64 = 1_048_575;
+
+const FREEZE_PANES_KEYS: &[&str] = &["rows", "cols"];
+
+/// Extract a `freeze_panes` value: a bool (`True` freezes the header row), a
+/// cell reference like `"C2"`, a zero-based `(row, col)` tuple, or a
+/// `{"rows": N, "cols": M}` dict. The cell is the top-left scrollable cell, so
+/// `"C2"` freezes row 1 and columns A-B; the dict counts its rows and columns
+/// from the written block's top-left corner instead, missing keys as 0.
+/// `context` names the option in error messages.
+pub(crate) fn extract_freeze_panes(val: &Bound<'_, PyAny>, context: &str) -> PyResult<FreezePanes> {
+    let (row, col) = if let Ok(b) = val.cast::<pyo3::types::PyBool>() {
+        return Ok(if b.is_true() {
+            FreezePanes::HeaderRow
+        } else {
+            FreezePanes::Off
+        });
+    } else if let Ok(dict) = val.cast::<pyo3::types::PyDict>() {
+        let map = pydict_to_hashmap(dict)?;
+        let view = OptionMap::new(val.py(), &map, context.to_string());
+        let parse = || -> Result<FreezePanes, String> {
+            view.reject_unknown(FREEZE_PANES_KEYS)?;
+            let rows = view.u32("rows")?.unwrap_or(0);
+            let cols = view.u32("cols")?.unwrap_or(0);
+            if i64::from(rows) > MAX_ROW_INDEX || i64::from(cols) > MAX_COLUMN_INDEX {
+                return Err(format!(
+                    "{}: rows {} and cols {} must fit Excel's grid (rows 0-{}, columns 0-{})",
+                    context, rows, cols, MAX_ROW_INDEX, MAX_COLUMN_INDEX
+                ));
+            }
+            Ok(FreezePanes::Leading {
+                rows,
+                cols: cols as u16,
+            })
+        };
+        return parse().map_err(pyo3::exceptions::PyValueError::new_err);
+    } else if let Ok(cell_ref) = val.extract::<String>() {
+        let (row, col) = parse_cell_ref(&cell_ref)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}: {}", context, e)))?;
+        (i64::from(row), i64::from(col))
+    } else if let Ok(pair) = val.extract::<(i64, i64)>() {
+        pair
+    } else {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a bool, a cell reference like 'C2', a (row, col) tuple, or a {{'rows', 'cols'}} dict, got {}",
+            context,
+            pytype_name(val)
+        )));
+    };
+    if !(0..=MAX_ROW_INDEX).contains(&row) || !(0..=MAX_COLUMN_INDEX).contains(&col) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "{}: ({}, {}) is outside Excel's grid (rows 0-{}, columns 0-{})",
+            context, row, col, MAX_ROW_INDEX, MAX_COLUMN_INDEX
+        )));
+    }
+    Ok(FreezePanes::At(row as u32, col as u16))
+}
+
+/// Extract a single cell reference like `"B2"` as a zero-based (row, col).
+/// `context` names the option in error messages.
+pub(crate) fn extract_cell_ref(val: &Bound<'_, PyAny>, context: &str) -> PyResult<(u32, u16)> {
+    let cell_ref: String = val.extract().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a cell reference like 'B2', got {}",
+            context,
+            pytype_name(val)
+        ))
+    })?;
+    parse_cell_ref(&cell_ref)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}: {}", context, e)))
+}
+
+/// Extract a `banded_rows` value: a pair of colors (list or tuple of two
+/// strings) for alternating data rows. Colors are validated at write time.
+/// `context` names the option in error messages.
+pub(crate) fn extract_banded_rows(
+    val: &Bound<'_, PyAny>,
+    context: &str,
+) -> PyResult<(String, String)> {
+    let colors = if val.is_instance_of::<pyo3::types::PyString>() {
+        None
+    } else {
+        val.extract::<Vec<String>>().ok()
+    };
+    match colors.as_deref() {
+        Some([first, second]) => Ok((first.clone(), second.clone())),
+        _ => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a pair of colors, e.g. ('#FFFFFF', '#F2F2F2'), got {}",
+            context,
+            pytype_name(val)
+        ))),
+    }
+}
+
+/// Extract a `bool_mode` value: `"boolean"`, `"yes_no"`, `"one_zero"`, or a
+/// `(true_text, false_text)` pair of strings for custom labels. `context`
+/// names the option in error messages.
+pub(crate) fn extract_bool_mode(val: &Bound<'_, PyAny>, context: &str) -> PyResult<BoolMode> {
+    if let Ok(mode) = val.cast::<pyo3::types::PyString>() {
+        return match mode.to_string().as_str() {
+            "boolean" => Ok(BoolMode::Boolean),
+            "yes_no" => Ok(BoolMode::Text("Yes".to_string(), "No".to_string())),
+            "one_zero" => Ok(BoolMode::OneZero),
+            "custom" => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{}: for custom labels pass a (true_text, false_text) pair, e.g. ('Y', 'N')",
+                context
+            ))),
+            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{}: invalid mode '{}'. Valid values: boolean, yes_no, one_zero, or a (true_text, false_text) pair",
+                context, other
+            ))),
+        };
+    }
+    match val.extract::<Vec<String>>().ok().as_deref() {
+        Some([true_text, false_text]) => Ok(BoolMode::Text(true_text.clone(), false_text.clone())),
+        _ => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be 'boolean', 'yes_no', 'one_zero', or a (true_text, false_text) pair of strings, got {}",
+            context,
+            pytype_name(val)
+        ))),
+    }
+}
+
+const PROTECT_KEYS: &[&str] = &["password", "unlocked_columns"];
+
+/// Extract a `protect` value: a bool, or a dict with an optional `password`
+/// and `unlocked_columns` (a list of column names or wildcard patterns).
+/// `False` yields `None`. Patterns are matched against the written columns at
+/// write time. `context` names the option in error messages.
+pub(crate) fn extract_protect(
+    val: &Bound<'_, PyAny>,
+    context: &str,
+) -> PyResult<Option<Protection>> {
+    if let Ok(b) = val.cast::<pyo3::types::PyBool>() {
+        return Ok(b.is_true().then(Protection::default));
+    }
+    let dict = val.cast::<pyo3::types::PyDict>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a bool or a dict, got {}",
+            context,
+            pytype_name(val)
+        ))
+    })?;
+    let map = pydict_to_hashmap(dict)?;
+    let view = OptionMap::new(val.py(), &map, context.to_string());
+    let parse = || -> Result<Protection, String> {
+        view.reject_unknown(PROTECT_KEYS)?;
+        let password = view.string("password")?;
+        if password.as_deref() == Some("") {
+            return Err(format!("{}: 'password' must not be empty", context));
+        }
+        let unlocked_columns = extract_field(
+            view.py(),
+            view.get("unlocked_columns"),
+            context,
+            "unlocked_columns",
+            "a list of column names or patterns",
+        )?
+        .unwrap_or_default();
+        Ok(Protection {
+            password,
+            unlocked_columns,
+        })
+    };
+    parse()
+        .map(Some)
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+/// Add the `custom_properties` dict (property name to a str, int, float,
+/// bool, date, or datetime value, in dict order) to `doc`. A datetime with a
+/// tzinfo is converted to UTC; a naive one is taken as UTC.
+pub(crate) fn extract_custom_properties(
+    val: &Bound<'_, PyAny>,
+    mut doc: DocProperties,
+) -> PyResult<DocProperties> {
+    let dict = val.cast::<pyo3::types::PyDict>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "custom_properties must be a dict, got {}",
+            pytype_name(val)
+        ))
+    })?;
+    for (key, value) in dict.iter() {
+        let name: String = key.extract().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "custom_properties keys must be str, got {}",
+                pytype_name(&key)
+            ))
+        })?;
+        if name.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "custom_properties: property name must not be empty",
+            ));
+        }
+        let invalid = |message: String| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "custom_properties: '{}': {}",
+                name, message
+            ))
+        };
+        doc = if let Ok(b) = value.cast::<pyo3::types::PyBool>() {
+            doc.set_custom_property(&name, b.is_true())
+        } else if value.cast::<pyo3::types::PyInt>().is_ok() {
+            // Excel stores whole numbers as 32-bit ints; larger ones go in
+            // as doubles, which are only exact up to 2**53.
+            let n: i64 = value
+                .extract()
+                .map_err(|_| invalid(format!("integer {} is out of range", value)))?;
+            match i32::try_from(n) {
+                Ok(n) => doc.set_custom_property(&name, n),
+                Err(_) if n.unsigned_abs() <= 1 << 53 => doc.set_custom_property(&name, n as f64),
+                Err(_) => {
+                    return Err(invalid(format!(
+                        "integer {} cannot be stored exactly; pass it as a string",
+                        n
+                    )))
+                }
+            }
+        } else if let Ok(f) = value.cast::<pyo3::types::PyFloat>() {
+            let f = f.value();
+            if !f.is_finite() {
+                return Err(invalid(format!("value must be finite, got {}", f)));
+            }
+            doc.set_custom_property(&name, f)
+        } else if value.cast::<pyo3::types::PyString>().is_ok() {
+            doc.set_custom_property(&name, value.extract::<String>()?)
+        } else if value.cast::<pyo3::types::PyDate>().is_ok() {
+            // isoformat() carries any UTC offset, so both dates and aware or
+            // naive datetimes go through the same ISO parsing as `created`.
+            let iso: String = value.call_method0("isoformat")?.extract()?;
+            let utc = parse_iso_datetime(&iso)
+                .ok_or_else(|| invalid(format!("unsupported datetime '{}'", iso)))
+                .and_then(|utc| utc_excel_datetime(utc).map_err(invalid))?;
+            doc.set_custom_property(&name, &utc)
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "custom_properties: '{}' must be a str, int, float, bool, date, or datetime, got {}",
+                name,
+                pytype_name(&value)
+            )));
+        };
+    }
+    Ok(doc)
+}
+
+/// Validate `df_to_xlsx`'s `progress_callback` (any callable) and
+/// `progress_every` (at least 1 row), returning the callback to hold for the write.
+pub(crate) fn extract_progress_callback(
+    callback: Option<&Bound<'_, PyAny>>,
+    every: usize,
+) -> PyResult<Option<Py<PyAny>>> {
+    if every == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "progress_every must be at least 1",
+        ));
+    }
+    callback
+        .map(|callback| {
+            if callback.is_callable() {
+                Ok(callback.clone().unbind())
+            } else {
+                Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "progress_callback must be callable, got {}",
+                    pytype_name(callback)
+                )))
+            }
+        })
+        .transpose()
+}
+
+/// Extract `dfs_to_xlsx`'s `active_sheet`: a 0-based sheet index or a sheet name.
+pub(crate) fn extract_sheet_selector(val: &Bound<'_, PyAny>) -> PyResult<SheetSelector> {
+    if val.cast::<pyo3::types::PyBool>().is_err() {
+        if let Ok(index) = val.extract::<usize>() {
+            return Ok(SheetSelector::Index(index));
+        }
+        if let Ok(name) = val.extract::<String>() {
+            return Ok(SheetSelector::Name(name));
+        }
+    }
+    Err(pyo3::exceptions::PyTypeError::new_err(format!(
+        "active_sheet must be a non-negative sheet index or a sheet name, got {}",
+        pytype_name(val)
+    )))
+}
+
+/// Extract the per-sheet `visible` option: `True`, `False`, or `"very_hidden"`.
+fn extract_sheet_visibility(val: &Bound<'_, PyAny>) -> PyResult<SheetVisibility> {
+    if let Ok(b) = val.cast::<pyo3::types::PyBool>() {
+        return Ok(if b.is_true() {
+            SheetVisibility::Visible
+        } else {
+            SheetVisibility::Hidden
+        });
+    }
+    match val.extract::<String>() {
+        Ok(s) if s == "very_hidden" => Ok(SheetVisibility::VeryHidden),
+        Ok(s) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "sheet option 'visible': invalid value '{}'. Valid values: True, False, \"very_hidden\"",
+            s
+        ))),
+        Err(_) => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "sheet option 'visible' must be a bool or \"very_hidden\", got {}",
+            pytype_name(val)
+        ))),
+    }
+}
+
+const PAGE_SETUP_KEYS: &[&str] = &[
+    "orientation",
+    "fit_to_pages",
+    "paper_size",
+    "margins",
+    "header",
+    "footer",
+    "header_images",
+    "footer_images",
+    "header_footer_scale_with_doc",
+];
+const PAGE_MARGIN_KEYS: &[&str] = &["left", "right", "top", "bottom", "header", "footer"];
+const HEADER_IMAGE_KEYS: &[&str] = &["left", "center", "right"];
+
+/// Extract a `background_image`: an image file path or the image's bytes.
+/// `context` names the option in error messages.
+pub(crate) fn extract_background_image(
+    val: &Bound<'_, PyAny>,
+    context: &str,
+) -> PyResult<ImageSource> {
+    if let Ok(data) = val.cast::<pyo3::types::PyBytes>() {
+        Ok(ImageSource::Data(data.as_bytes().to_vec()))
+    } else if let Ok(path) = val.extract::<String>() {
+        Ok(ImageSource::Path(path))
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be an image path (str) or bytes, got {}",
+            context,
+            pytype_name(val)
+        )))
+    }
+}
+
+/// Extract a `page_setup` dict (orientation, fit_to_pages, paper_size,
+/// margins, header/footer) into a typed [`PageSetup`]. `context` names the
+/// option in error messages.
+pub(crate) fn extract_page_setup(val: &Bound<'_, PyAny>, context: &str) -> PyResult<PageSetup> {
+    let dict = val.cast::<pyo3::types::PyDict>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a dict, got {}",
+            context,
+            pytype_name(val)
+        ))
+    })?;
+    let map = pydict_to_hashmap(dict)?;
+    let view = OptionMap::new(val.py(), &map, context.to_string());
+    parse_page_setup(&view).map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+fn parse_page_setup(view: &OptionMap<'_, '_>) -> Result<PageSetup, String> {
+    view.reject_unknown(PAGE_SETUP_KEYS)?;
+    let orientation = match view.string("orientation")?.as_deref() {
+        None => None,
+        Some("portrait") => Some(PageOrientation::Portrait),
+        Some("landscape") => Some(PageOrientation::Landscape),
+        Some(other) => {
+            return Err(format!(
+                "{}: invalid orientation '{}'. Valid values: portrait, landscape",
+                view.context(),
+                other
+            ))
+        }
+    };
+    let fit_to_pages = extract_field(
+        view.py(),
+        view.get("fit_to_pages"),
+        view.context(),
+        "fit_to_pages",
+        "a (width, height) tuple of non-negative integers",
+    )?;
+    let margins = match view.dict("margins")? {
+        Some(map) => {
+            let margins = OptionMap::new(view.py(), &map, format!("{}: margins", view.context()));
+            margins.reject_unknown(PAGE_MARGIN_KEYS)?;
+            let edge = |key: &str| -> Result<Option<f64>, String> {
+                match margins.f64(key)? {
+                    Some(inches) if !(inches >= 0.0 && inches.is_finite()) => Err(format!(
+                        "{}: '{}' must be a non-negative number of inches, got {}",
+                        margins.context(),
+                        key,
+                        inches
+                    )),
+                    inches => Ok(inches),
+                }
+            };
+            Some(PageMargins {
+                left: edge("left")?,
+                right: edge("right")?,
+                top: edge("top")?,
+                bottom: edge("bottom")?,
+                header: edge("header")?,
+                footer: edge("footer")?,
+            })
+        }
+        None => None,
+    };
+    Ok(PageSetup {
+        orientation,
+        fit_to_pages,
+        paper_size: view.u8("paper_size")?,
+        margins,
+        header: parse_header_footer(view, "header")?,
+        footer: parse_header_footer(view, "footer")?,
+        header_footer_scale_with_doc: view.bool("header_footer_scale_with_doc")?,
+    })
+}
+
+/// Parse `page_setup[key]` (`"header"` or `"footer"`) and its
+/// `<key>_images` dict of section (left, center, right) to image path.
+fn parse_header_footer(
+    view: &OptionMap<'_, '_>,
+    key: &str,
+) -> Result<Option<HeaderFooter>, String> {
+    let images_key = format!("{}_images", key);
+    let images = match view.dict(&images_key)? {
+        Some(map) => {
+            let images = OptionMap::new(
+                view.py(),
+                &map,
+                format!("{}: {}", view.context(), images_key),
+            );
+            images.reject_unknown(HEADER_IMAGE_KEYS)?;
+            [
+                images.string("left")?,
+                images.string("center")?,
+                images.string("right")?,
+            ]
+        }
+        None => Default::default(),
+    };
+    let Some(text) = view.string(key)? else {
+        if images.iter().any(Option::is_some) {
+            return Err(format!(
+                "{}: '{}' needs a '{}' with a &[Picture] placeholder for each image",
+                view.context(),
+                images_key,
+                key
+            ));
+        }
+        return Ok(None);
+    };
+    // Excel's limit applies after the &[...] placeholders are shortened to
+    // their two-character codes (&[Page] -> &P).
+    let expanded = [
+        "Tab", "Date", "File", "Page", "Path", "Time", "Pages", "Picture",
+    ]
+    .iter()
+    .fold(text.clone(), |text, name| {
+        text.replace(&format!("&[{}]", name), "&X")
+    });
+    if expanded.chars().count() > 255 {
+        return Err(format!(
+            "{}: '{}' exceeds Excel's limit of 255 characters",
+            view.context(),
+            key
+        ));
+    }
+    Ok(Some(HeaderFooter { text, images }))
+}
+
+/// Extract an `autofit` value: a bool, or a list of column names (`str`)
+/// and/or 0-based positions (`int`) to autofit alone. An empty list autofits
+/// nothing. `context` names the option in error messages.
+pub(crate) fn extract_autofit(val: &Bound<'_, PyAny>, context: &str) -> PyResult<Autofit> {
+    if let Ok(b) = val.cast::<pyo3::types::PyBool>() {
+        return Ok(if b.is_true() {
+            Autofit::All
+        } else {
+            Autofit::Off
+        });
+    }
+    if !val.is_instance_of::<pyo3::types::PyList>() && !val.is_instance_of::<pyo3::types::PyTuple>()
+    {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a bool or a list of column names or indices, got {}",
+            context,
+            pytype_name(val)
+        )));
+    }
+    let selection = extract_column_selection(val, context)?;
+    Ok(if selection.is_empty() {
+        Autofit::Off
+    } else {
+        Autofit::Columns(selection)
+    })
+}
+
+/// Extract a `columns` selection: a list of column names (`str`) and/or
+/// 0-based positions (`int`), in output order. `context` names the option in
+/// error messages.
+pub(crate) fn extract_column_selection(
+    val: &Bound<'_, PyAny>,
+    context: &str,
+) -> PyResult<Vec<ColumnSelector>> {
+    let items: Vec<Bound<'_, PyAny>> = if val.is_instance_of::<pyo3::types::PyList>()
+        || val.is_instance_of::<pyo3::types::PyTuple>()
+    {
+        val.extract()?
+    } else {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a list of column names or indices, got {}",
+            context,
+            pytype_name(val)
+        )));
+    };
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let selector = if item.is_instance_of::<pyo3::types::PyBool>() {
+                None // bool is an int subclass, but True never means column 1
+            } else if let Ok(idx) = item.extract::<i64>() {
+                let idx = usize::try_from(idx).map_err(|_| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "{}[{}]: must be a non-negative column index, got {}",
+                        context, i, idx
+                    ))
+                })?;
+                Some(ColumnSelector::Index(idx))
+            } else {
+                item.extract::<String>().ok().map(ColumnSelector::Name)
+            };
+            selector.ok_or_else(|| {
+                pyo3::exceptions::PyTypeError::new_err(format!(
+                    "{}[{}]: expected a column name or index, got {}",
+                    context,
+                    i,
+                    pytype_name(item)
+                ))
+            })
+        })
+        .collect()
+}
+
+const TABLE_COLUMN_KEYS: &[&str] = &["header", "total_function", "total_label"];
+
+/// Extract a `table_columns` list of per-column dicts (header, total_function,
+/// total_label), one per table column from the left. `context` names the
+/// option in error messages.
+pub(crate) fn extract_table_columns(
+    val: &Bound<'_, PyAny>,
+    context: &str,
+) -> PyResult<Vec<TableColumnConfig>> {
+    let list = val.cast::<pyo3::types::PyList>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a list of dicts, got {}",
+            context,
+            pytype_name(val)
+        ))
+    })?;
+    let mut columns = Vec::with_capacity(list.len());
+    for (i, item) in list.iter().enumerate() {
+        let item_context = format!("{}[{}]", context, i);
+        let dict = item.cast::<pyo3::types::PyDict>().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "{}: expected a dict, got {}",
+                item_context,
+                pytype_name(&item)
+            ))
+        })?;
+        let map = pydict_to_hashmap(dict)?;
+        let view = OptionMap::new(val.py(), &map, item_context);
+        columns.push(parse_table_column(&view).map_err(pyo3::exceptions::PyValueError::new_err)?);
+    }
+    Ok(columns)
+}
+
+const TABLE_OPTION_KEYS: &[&str] = &[
+    "banded_rows",
+    "banded_columns",
+    "first_column",
+    "last_column",
+];
+
+/// Extract a `table_options` dict of table look toggles (banded_rows,
+/// banded_columns, first_column, last_column); omitted keys keep the table
+/// defaults. `context` names the option in error messages.
+pub(crate) fn extract_table_options(
+    val: &Bound<'_, PyAny>,
+    context: &str,
+) -> PyResult<TableOptions> {
+    let dict = val.cast::<pyo3::types::PyDict>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a dict, got {}",
+            context,
+            pytype_name(val)
+        ))
+    })?;
+    let map = pydict_to_hashmap(dict)?;
+    let view = OptionMap::new(val.py(), &map, context.to_string());
+    let parse = || -> Result<TableOptions, String> {
+        view.reject_unknown(TABLE_OPTION_KEYS)?;
+        let defaults = TableOptions::default();
+        Ok(TableOptions {
+            banded_rows: view.bool("banded_rows")?.unwrap_or(defaults.banded_rows),
+            banded_columns: view
+                .bool("banded_columns")?
+                .unwrap_or(defaults.banded_columns),
+            first_column: view.bool("first_column")?.unwrap_or(defaults.first_column),
+            last_column: view.bool("last_column")?.unwrap_or(defaults.last_column),
+        })
+    };
+    parse().map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+const TABLE_SPEC_KEYS: &[&str] = &["range", "style", "name", "total_row"];
+
+/// Extract a `tables` list of extra tables, each a dict with a required
+/// `range` ("A1:C10") and optional style, name, and total_row. Ranges and
+/// styles are validated here; overlaps are checked when the tables are added.
+/// `context` names the option in error messages.
+pub(crate) fn extract_tables(val: &Bound<'_, PyAny>, context: &str) -> PyResult<Vec<TableSpec>> {
+    let list = val.cast::<pyo3::types::PyList>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a list of dicts, got {}",
+            context,
+            pytype_name(val)
+        ))
+    })?;
+    let mut tables = Vec::with_capacity(list.len());
+    for (i, item) in list.iter().enumerate() {
+        let item_context = format!("{}[{}]", context, i);
+        let dict = item.cast::<pyo3::types::PyDict>().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "{}: expected a dict, got {}",
+                item_context,
+                pytype_name(&item)
+            ))
+        })?;
+        let map = pydict_to_hashmap(dict)?;
+        let view = OptionMap::new(val.py(), &map, item_context);
+        tables.push(parse_table_spec(&view).map_err(pyo3::exceptions::PyValueError::new_err)?);
+    }
+    Ok(tables)
+}
+
+fn parse_table_spec(view: &OptionMap<'_, '_>) -> Result<TableSpec, String> {
+    view.reject_unknown(TABLE_SPEC_KEYS)?;
+    let range = view.required_string("range")?;
+    parse_cell_range(&range).map_err(|e| format!("{}: {}", view.context(), e))?;
+    let style = view.string("style")?;
+    if let Some(style) = &style {
+        parse_table_style(style).map_err(|e| format!("{}: {}", view.context(), e))?;
+    }
+    Ok(TableSpec {
+        range,
+        style,
+        name: view.string("name")?,
+        total_row: view.bool("total_row")?.unwrap_or(false),
+    })
+}
+
+fn parse_table_column(view: &OptionMap<'_, '_>) -> Result<TableColumnConfig, String> {
+    view.reject_unknown(TABLE_COLUMN_KEYS)?;
+    let total_function = match view.string("total_function")?.as_deref() {
+        None => None,
+        Some("average") => Some(TableTotal::Average),
+        Some("count") => Some(TableTotal::Count),
+        Some("count_numbers") => Some(TableTotal::CountNumbers),
+        Some("max") => Some(TableTotal::Max),
+        Some("min") => Some(TableTotal::Min),
+        Some("sum") => Some(TableTotal::Sum),
+        Some("std_dev") => Some(TableTotal::StdDev),
+        Some("var") => Some(TableTotal::Var),
+        Some(other) => {
+            return Err(format!(
+                "{}: invalid total_function '{}'. Valid values: average, count, count_numbers, \
+                 max, min, sum, std_dev, var",
+                view.context(),
+                other
+            ))
+        }
+    };
+    let total_label = view.string("total_label")?;
+    if total_function.is_some() && total_label.is_some() {
+        return Err(format!(
+            "{}: 'total_function' and 'total_label' cannot both be set",
+            view.context()
+        ));
+    }
+    Ok(TableColumnConfig {
+        header: view.string("header")?,
+        total_function,
+        total_label,
+    })
+}
+
+/// Extract a `summary_row` dict mapping column names to an aggregate ("sum",
+/// "mean", "min", "max", or "count"), in the order given. Column names are
+/// checked against the written columns at write time. `context` names the
+/// option in error messages.
+pub(crate) fn extract_summary_row(
+    val: &Bound<'_, PyAny>,
+    context: &str,
+) -> PyResult<IndexMap<String, SummaryFunction>> {
+    let dict = val.cast::<pyo3::types::PyDict>().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a dict of column name to aggregate, got {}",
+            context,
+            pytype_name(val)
+        ))
+    })?;
+    let mut functions = IndexMap::with_capacity(dict.len());
+    for (col_name, function) in dict.iter() {
+        let col_name: String = col_name.extract().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "{}: column names must be strings, got {}",
+                context,
+                pytype_name(&col_name)
+            ))
+        })?;
+        let function: String = function.extract().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "{}['{}'] must be a string, got {}",
+                context,
+                col_name,
+                pytype_name(&function)
+            ))
+        })?;
+        let function = match function.as_str() {
+            "sum" => SummaryFunction::Sum,
+            "mean" => SummaryFunction::Mean,
+            "min" => SummaryFunction::Min,
+            "max" => SummaryFunction::Max,
+            "count" => SummaryFunction::Count,
+            other => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "{}['{}']: invalid aggregate '{}'. Valid values: sum, mean, min, max, count",
+                    context, col_name, other
+                )))
+            }
+        };
+        functions.insert(col_name, function);
+    }
+    Ok(functions)
+}
+
 /// Validate a resolved column_widths integer key against Excel's column range
 /// (0..=16383). `label` is the key's original representation — the int
 /// restringified, or the source string key — used to build the
@@ -359,22 +1614,46 @@ pub(crate) fn extract_header_format(
     pydict_to_hashmap(py_dict)
 }
 
-/// Extract column_formats from Python dict (pattern -> format dict)
+/// Extract column_formats from Python dict (index or pattern -> format dict)
+/// Integer keys are DataFrame column positions; string keys are name patterns.
 /// Uses IndexMap to preserve insertion order from Python dict
 pub(crate) fn extract_column_formats(
     py_dict: &Bound<'_, pyo3::types::PyDict>,
-) -> PyResult<IndexMap<String, HashMap<String, Py<PyAny>>>> {
-    let mut col_fmts: IndexMap<String, HashMap<String, Py<PyAny>>> = IndexMap::new();
-    for (pattern, fmt_dict) in py_dict.iter() {
-        let pattern_str: String = pattern.extract()?;
+) -> PyResult<ColumnFormatConfigs> {
+    let mut col_fmts: ColumnFormatConfigs = IndexMap::new();
+    for (key, fmt_dict) in py_dict.iter() {
+        let parsed = if key.is_instance_of::<pyo3::types::PyBool>() {
+            None // bool is an int subclass, but True never means column 1
+        } else if let Ok(i) = key.extract::<i64>() {
+            let idx = usize::try_from(i).map_err(|_| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "column_formats[{}]: must be a non-negative column index",
+                    i
+                ))
+            })?;
+            Some(ColumnFormatKey::Index(idx))
+        } else {
+            key.extract::<String>().ok().map(ColumnFormatKey::Pattern)
+        };
+        let Some(parsed) = parsed else {
+            let key_repr = key
+                .str()
+                .map(|s| s.to_string())
+                .unwrap_or_else(|_| "?".to_string());
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "column_formats[{}]: must be a column name pattern or an integer column index, got {}",
+                key_repr,
+                pytype_name(&key)
+            )));
+        };
         let inner_dict = fmt_dict.cast::<pyo3::types::PyDict>().map_err(|_| {
             pyo3::exceptions::PyTypeError::new_err(format!(
-                "column_formats['{}']: expected dict, got {}",
-                pattern_str,
+                "column_formats[{}]: expected dict, got {}",
+                key,
                 pytype_name(&fmt_dict)
             ))
         })?;
-        col_fmts.insert(pattern_str, pydict_to_hashmap(inner_dict)?);
+        col_fmts.insert(parsed, pydict_to_hashmap(inner_dict)?);
     }
     Ok(col_fmts)
 }
@@ -412,20 +1691,121 @@ pub(crate) fn extract_conditional_formats(
     Ok(cond_fmts)
 }
 
-/// Extract formula_columns from Python dict (column name -> formula template)
+const FORMULA_COLUMN_KEYS: &[&str] = &[
+    "formula",
+    "formula_type",
+    "range",
+    "position",
+    "formula_result",
+];
+
+/// Extract formula_columns from Python dict (column name -> formula template,
+/// or a dict with `formula`, `formula_type`, `range`, `position`, and
+/// `formula_result`)
 /// Uses IndexMap to preserve column order
 pub(crate) fn extract_formula_columns(
     py_dict: &Bound<'_, pyo3::types::PyDict>,
-) -> PyResult<IndexMap<String, String>> {
-    let mut formulas: IndexMap<String, String> = IndexMap::new();
+) -> PyResult<IndexMap<String, FormulaColumn>> {
+    let mut formulas: IndexMap<String, FormulaColumn> = IndexMap::new();
     for (col_name, formula) in py_dict.iter() {
         let col_str: String = col_name.extract()?;
-        let formula_str: String = formula.extract()?;
-        formulas.insert(col_str, formula_str);
+        let column = if let Ok(inner_dict) = formula.cast::<pyo3::types::PyDict>() {
+            let map = pydict_to_hashmap(inner_dict)?;
+            let view = OptionMap::new(
+                py_dict.py(),
+                &map,
+                format!("formula_columns['{}']", col_str),
+            );
+            parse_formula_column(&view).map_err(pyo3::exceptions::PyValueError::new_err)?
+        } else {
+            FormulaColumn {
+                template: formula.extract()?,
+                formula_type: FormulaType::Regular,
+                range: None,
+                position: None,
+                result: None,
+            }
+        };
+        formulas.insert(col_str, column);
     }
     Ok(formulas)
 }
 
+fn parse_formula_column(view: &OptionMap<'_, '_>) -> Result<FormulaColumn, String> {
+    view.reject_unknown(FORMULA_COLUMN_KEYS)?;
+    let formula_type = match view.string("formula_type")?.as_deref() {
+        None | Some("regular") => FormulaType::Regular,
+        Some("dynamic") => FormulaType::Dynamic,
+        Some("array") => FormulaType::Array,
+        Some(other) => {
+            return Err(format!(
+                "{}: invalid formula_type '{}'. Valid values: regular, dynamic, array",
+                view.context(),
+                other
+            ))
+        }
+    };
+    let range = match view.string("range")? {
+        None => None,
+        Some(_) if formula_type != FormulaType::Array => {
+            return Err(format!(
+                "{}: 'range' needs formula_type 'array'",
+                view.context()
+            ))
+        }
+        // A single cell such as "G2" is a one-cell range
+        Some(range) => Some(
+            if range.contains(':') {
+                parse_cell_range(&range)
+            } else {
+                parse_cell_ref(&range).map(|(row, col)| (row, col, row, col))
+            }
+            .map_err(|e| format!("{}: invalid range: {}", view.context(), e))?,
+        ),
+    };
+    let position = match view.get("position").map(|obj| obj.bind(view.py())) {
+        Some(obj) if !obj.is_none() => Some(parse_formula_position(obj, view.context())?),
+        _ => None,
+    };
+    // Checked per value at write time, where a callable is called
+    let result = view
+        .get("formula_result")
+        .filter(|obj| !obj.bind(view.py()).is_none())
+        .map(|obj| obj.clone_ref(view.py()));
+    Ok(FormulaColumn {
+        template: view.required_string("formula")?,
+        formula_type,
+        range,
+        position,
+        result,
+    })
+}
+
+/// Parse a formula column `position`: a 0-based column index or
+/// `"after:col_name"`. Column names are checked at write time.
+fn parse_formula_position(
+    obj: &Bound<'_, PyAny>,
+    context: &str,
+) -> Result<FormulaPosition, String> {
+    if let Ok(index) = obj.extract::<usize>() {
+        return Ok(FormulaPosition::Index(index));
+    }
+    match obj.extract::<String>() {
+        Ok(text) => match text.strip_prefix("after:") {
+            Some(name) if !name.is_empty() => Ok(FormulaPosition::After(name.to_string())),
+            _ => Err(format!(
+                "{}: invalid position '{}'. Use a column index or 'after:<column>'",
+                context, text
+            )),
+        },
+        Err(_) => Err(format!(
+            "{}: position must be a column index or 'after:<column>', got {}",
+            context,
+            pytype_name(obj)
+        )),
+    }
+}
+
 /// Extract merged_ranges from Python list of tuples
 /// Each tuple: (range_str, text) or (range_str, text, format_dict)
 pub(crate) fn extract_merged_ranges(
@@ -443,7 +1823,7 @@ pub(crate) fn extract_merged_ranges(
         }
 
         let range_str: String = item.get_item(0)?.extract()?;
-        let text: String = item.get_item(1)?.extract()?;
+        let value = item.get_item(1)?.unbind();
 
         let format_dict = if tuple_len >= 3 {
             let fmt_item = item.get_item(2)?;
@@ -463,12 +1843,120 @@ pub(crate) fn extract_merged_ranges(
             None
         };
 
-        ranges.push((range_str, text, format_dict));
+        ranges.push((range_str, value, format_dict));
     }
 
     Ok(ranges)
 }
 
+/// Extract row_groups from a Python list of (first_row, last_row, level) or
+/// (first_row, last_row, level, collapsed) tuples. Rows are absolute sheet rows.
+pub(crate) fn extract_row_groups(
+    py_list: &Bound<'_, pyo3::types::PyList>,
+) -> PyResult<Vec<OutlineGroup>> {
+    extract_outline_groups(py_list, "row_groups", MAX_ROW_INDEX)
+}
+
+/// Extract col_groups from a Python list of (first_col, last_col, level) or
+/// (first_col, last_col, level, collapsed) tuples. Columns are absolute sheet
+/// column indices.
+pub(crate) fn extract_col_groups(
+    py_list: &Bound<'_, pyo3::types::PyList>,
+) -> PyResult<Vec<OutlineGroup>> {
+    extract_outline_groups(py_list, "col_groups", MAX_COLUMN_INDEX)
+}
+
+/// Shared tuple parsing for `row_groups`/`col_groups`. `max_index` is Excel's
+/// last row or column index.
+fn extract_outline_groups(
+    py_list: &Bound<'_, pyo3::types::PyList>,
+    option: &str,
+    max_index: i64,
+) -> PyResult<Vec<OutlineGroup>> {
+    let mut groups = Vec::new();
+
+    for (i, item) in py_list.iter().enumerate() {
+        let context = format!("{}[{}]", option, i);
+        let tuple = item.cast::<pyo3::types::PyTuple>().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "{}: expected a (first, last, level) or (first, last, level, collapsed) tuple, got {}",
+                context,
+                pytype_name(&item)
+            ))
+        })?;
+        if !(3..=4).contains(&tuple.len()) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{}: tuple must have 3 or 4 elements, got {}",
+                context,
+                tuple.len()
+            )));
+        }
+
+        let index = |pos: usize, name: &str| -> PyResult<u32> {
+            let value = tuple.get_item(pos)?;
+            let n: i64 = value.extract().map_err(|_| {
+                pyo3::exceptions::PyTypeError::new_err(format!(
+                    "{}: {} must be an integer, got {}",
+                    context,
+                    name,
+                    pytype_name(&value)
+                ))
+            })?;
+            if !(0..=max_index).contains(&n) {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "{}: {} {} is outside Excel's range 0-{}",
+                    context, name, n, max_index
+                )));
+            }
+            Ok(n as u32)
+        };
+        let first = index(0, "first")?;
+        let last = index(1, "last")?;
+        if first > last {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{}: first ({}) must not be greater than last ({})",
+                context, first, last
+            )));
+        }
+
+        let level_item = tuple.get_item(2)?;
+        let level = match level_item.extract::<u8>() {
+            Ok(level) if (1..=7).contains(&level) => level,
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "{}: level must be an integer from 1 to 7, got {}",
+                    context,
+                    level_item.repr()?
+                )))
+            }
+        };
+
+        let collapsed = if tuple.len() == 4 {
+            let flag = tuple.get_item(3)?;
+            flag.cast::<pyo3::types::PyBool>()
+                .map_err(|_| {
+                    pyo3::exceptions::PyTypeError::new_err(format!(
+                        "{}: collapsed must be a bool, got {}",
+                        context,
+                        pytype_name(&flag)
+                    ))
+                })?
+                .is_true()
+        } else {
+            false
+        };
+
+        groups.push(OutlineGroup {
+            first,
+            last,
+            level,
+            collapsed,
+        });
+    }
+
+    Ok(groups)
+}
+
 /// Extract hyperlinks from Python list of tuples
 /// Each tuple: (cell_ref, url) or (cell_ref, url, display_text)
 pub(crate) fn extract_hyperlinks(
@@ -478,9 +1966,9 @@ pub(crate) fn extract_hyperlinks(
 
     for item in py_list.iter() {
         let tuple_len = item.len()?;
-        if !(2..=3).contains(&tuple_len) {
+        if !(2..=4).contains(&tuple_len) {
             return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                "hyperlinks tuple must have exactly 2 or 3 elements, got {}",
+                "hyperlinks tuple must have 2 to 4 elements, got {}",
                 tuple_len
             )));
         }
@@ -488,23 +1976,48 @@ pub(crate) fn extract_hyperlinks(
         let cell_ref: String = item.get_item(0)?.extract()?;
         let url: String = item.get_item(1)?.extract()?;
 
-        let display_text = if tuple_len >= 3 {
-            let text_item = item.get_item(2)?;
-            if !text_item.is_none() {
-                Some(text_item.extract()?)
+        // Display text and tooltip are optional, and None skips either one
+        let optional_str = |index: usize| -> PyResult<Option<String>> {
+            if index >= tuple_len {
+                return Ok(None);
+            }
+            let value = item.get_item(index)?;
+            if value.is_none() {
+                Ok(None)
             } else {
-                None
+                value.extract().map(Some)
             }
-        } else {
-            None
         };
+        let display_text = optional_str(2)?;
+        let tooltip = optional_str(3)?;
+        if let Some(tip) = &tooltip {
+            if tip.chars().count() > 255 {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "hyperlinks['{}']: tooltip exceeds Excel's 255-character limit ({} characters)",
+                    cell_ref,
+                    tip.chars().count()
+                )));
+            }
+        }
 
-        links.push((cell_ref, url, display_text));
+        links.push((cell_ref, url, display_text, tooltip));
     }
 
     Ok(links)
 }
 
+/// Keys accepted in the dict form of a comment; the styling ones are read by
+/// `apply_comments`.
+const COMMENT_KEYS: &[&str] = &[
+    "text",
+    "author",
+    "width",
+    "height",
+    "bg_color",
+    "font_size",
+    "visible",
+];
+
 /// Extract comments from Python dict
 /// Supports: {'A1': 'text'} or {'A1': {'text': 'note', 'author': 'John'}}
 /// Uses IndexMap to preserve insertion order so output is reproducible.
@@ -521,9 +2034,9 @@ pub(crate) fn extract_comments(
             reject_unknown_dict_keys(
                 inner_dict,
                 &format!("comments['{}']", cell_str),
-                &["text", "author"],
+                COMMENT_KEYS,
             )?;
-            // Dict format: {'text': '...', 'author': '...'}
+            // Dict format: {'text': '...', 'author': '...', ...style options}
             let text: String = inner_dict
                 .get_item("text")?
                 .ok_or_else(|| {
@@ -542,11 +2055,28 @@ pub(crate) fn extract_comments(
             } else {
                 None
             };
-            comments.insert(cell_str, (text, author));
+            let mut options = pydict_to_hashmap(inner_dict)?;
+            options.remove("text");
+            options.remove("author");
+            comments.insert(
+                cell_str,
+                Comment {
+                    text,
+                    author,
+                    options: Some(options),
+                },
+            );
         } else {
             // Simple string format
             let text: String = value.extract()?;
-            comments.insert(cell_str, (text, None));
+            comments.insert(
+                cell_str,
+                Comment {
+                    text,
+                    author: None,
+                    options: None,
+                },
+            );
         }
     }
 
@@ -640,7 +2170,7 @@ pub(crate) fn extract_rich_text(
     Ok(rich_text)
 }
 
-/// Extract images from Python dict (cell_ref -> path or config dict)
+/// Extract images from Python dict (cell_ref -> path, bytes, or config dict)
 /// Uses IndexMap to preserve insertion order so output is reproducible.
 pub(crate) fn extract_images(
     py_dict: &Bound<'_, pyo3::types::PyDict>,
@@ -650,34 +2180,60 @@ pub(crate) fn extract_images(
     for (cell_ref, value) in py_dict.iter() {
         let cell_str: String = cell_ref.extract()?;
 
-        // Check if value is a dict or simple string (path)
+        // Check if value is a dict, raw bytes, or simple string (path)
         if let Ok(inner_dict) = value.cast::<pyo3::types::PyDict>() {
-            // Dict format: {'path': '...', 'scale_width': 0.5, ...}
-            let path: String = inner_dict
-                .get_item("path")?
-                .ok_or_else(|| {
-                    pyo3::exceptions::PyValueError::new_err(format!(
-                        "Image at '{}' missing 'path' key",
+            // Dict format: {'path': '...', 'scale_width': 0.5, ...} or
+            // {'data': b'...', ...}; exactly one of the two
+            let source = match (inner_dict.get_item("path")?, inner_dict.get_item("data")?) {
+                (Some(path), None) => ImageSource::Path(path.extract()?),
+                (None, Some(data)) => {
+                    let data = data.cast::<pyo3::types::PyBytes>().map_err(|_| {
+                        pyo3::exceptions::PyTypeError::new_err(format!(
+                            "Image at '{}': 'data' must be bytes",
+                            cell_str
+                        ))
+                    })?;
+                    ImageSource::Data(data.as_bytes().to_vec())
+                }
+                (Some(_), Some(_)) => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Image at '{}' has both 'path' and 'data' keys; use one",
                         cell_str
-                    ))
-                })?
-                .extract()?;
+                    )))
+                }
+                (None, None) => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Image at '{}' missing 'path' or 'data' key",
+                        cell_str
+                    )))
+                }
+            };
             let mut options = pydict_to_hashmap(inner_dict)?;
             options.remove("path");
+            options.remove("data");
             images.insert(
                 cell_str,
                 ImageConfig {
-                    path,
+                    source,
                     options: Some(options),
                 },
             );
+        } else if let Ok(data) = value.cast::<pyo3::types::PyBytes>() {
+            // Raw image bytes
+            images.insert(
+                cell_str,
+                ImageConfig {
+                    source: ImageSource::Data(data.as_bytes().to_vec()),
+                    options: None,
+                },
+            );
         } else {
             // Simple string format (just path)
             let path: String = value.extract()?;
             images.insert(
                 cell_str,
                 ImageConfig {
-                    path,
+                    source: ImageSource::Path(path),
                     options: None,
                 },
             );
@@ -934,6 +2490,55 @@ pub(crate) fn extract_cells(py_dict: &Bound<'_, pyo3::types::PyDict>) -> PyResul
     Ok(cells)
 }
 
+/// Extract `cell_overrides` (cell_ref -> {value, format}). Each entry needs a
+/// `value`, a `format` (a column_formats-style dict), or both.
+pub(crate) fn extract_cell_overrides(
+    py_dict: &Bound<'_, pyo3::types::PyDict>,
+) -> PyResult<Vec<CellOverride>> {
+    let mut overrides = Vec::with_capacity(py_dict.len());
+    for (key, value) in py_dict.iter() {
+        let cell_ref: String = key.extract()?;
+        let context = format!("cell_overrides['{}']", cell_ref);
+        let (row, col) = parse_cell_ref(&cell_ref)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}: {}", context, e)))?;
+        let d = value.cast::<pyo3::types::PyDict>().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "{} must be a dict with 'value' and/or 'format', got {}",
+                context,
+                pytype_name(&value)
+            ))
+        })?;
+        reject_unknown_dict_keys(d, &context, &["value", "format"])?;
+        let format = match d.get_item("format")? {
+            Some(f) if !f.is_none() => {
+                let f = f.cast::<pyo3::types::PyDict>().map_err(|_| {
+                    pyo3::exceptions::PyTypeError::new_err(format!(
+                        "{}: 'format' must be a dict, got {}",
+                        context,
+                        pytype_name(&f)
+                    ))
+                })?;
+                Some(pydict_to_hashmap(f)?)
+            }
+            _ => None,
+        };
+        let value = d.get_item("value")?.map(Bound::unbind);
+        if value.is_none() && format.is_none() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{} needs a 'value', a 'format', or both",
+                context
+            )));
+        }
+        overrides.push(CellOverride {
+            row,
+            col,
+            value,
+            format,
+        });
+    }
+    Ok(overrides)
+}
+
 #[cfg(test)]
 mod sheet_option_name_tests {
     use super::SHEET_OPTION_NAMES;
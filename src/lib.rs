@@ -5,7 +5,8 @@
 //! - Booleans (true/false) → Excel booleans
 //! - Dates → Excel dates
 //! - Datetimes → Excel datetimes
-//! - NaN/Inf/None → Empty cells
+//! - Times → Excel times
+//! - NaN/Inf/None → Empty cells (NaN/Inf configurable via `nan_policy`)
 //! - Everything else → Strings
 //!
 //! Supports pandas DataFrames, polars DataFrames, and CSV files.
@@ -14,30 +15,55 @@ mod apply;
 mod convert;
 mod extract;
 mod parse;
+mod read;
 mod types;
 mod workbook;
 mod write;
 
 // Re-export public API for the CLI binary (main.rs)
-pub use convert::{convert_csv_to_xlsx, convert_csv_to_xlsx_parallel};
-pub use types::DateOrder;
+pub use convert::{
+    convert_csv_to_xlsx, convert_csv_to_xlsx_parallel, convert_csvs_to_xlsx, parse_csv_char,
+    parse_encoding, parse_number_separator,
+};
+pub use types::{CsvOptions, DateOrder, LargeIntMode, LongStringMode, NanPolicy};
 
-use convert::{convert_dataframe_to_xlsx, dataframe_row_count, write_configured_sheet};
+use convert::{
+    convert_dataframe_to_xlsx, dataframe_row_count, write_configured_sheet, write_sheet_data,
+    written_row_count,
+};
 use extract::{
-    extract_cells, extract_charts, extract_checkboxes, extract_column_formats,
-    extract_column_widths, extract_comments, extract_conditional_formats, extract_formula_columns,
+    extract_autofit, extract_background_image, extract_banded_rows, extract_bool_mode,
+    extract_cell_overrides, extract_cell_ref, extract_cells, extract_charts, extract_checkboxes,
+    extract_col_groups, extract_column_formats, extract_column_selection, extract_column_widths,
+    extract_comments, extract_conditional_formats, extract_csv_sheet_info,
+    extract_custom_properties, extract_formula_columns, extract_freeze_panes,
     extract_header_format, extract_hyperlinks, extract_images, extract_merged_ranges,
-    extract_rich_text, extract_sheet_info, extract_sparklines, extract_textboxes,
-    extract_validations,
+    extract_page_setup, extract_progress_callback, extract_protect, extract_rich_text,
+    extract_row_groups, extract_section_info, extract_sheet_info, extract_sheet_selector,
+    extract_sparklines, extract_summary_row, extract_table_columns, extract_table_options,
+    extract_tables, extract_textboxes, extract_validations,
 };
-use parse::sanitize_table_name;
+use parse::{parse_header_format, sanitize_table_name};
+use types::collect_if_lazy;
 use types::pytype_name;
+use types::ConditionalFormatMode;
+use types::CsvSheetConfig;
 use types::ExtractedOptions;
+use types::Progress;
+use types::SheetView;
 use types::WriteConfig;
-use workbook::apply_defined_names;
+use types::{Autofit, BoolMode, FreezePanes};
+use types::{CalcMode, CalcSettings};
+use workbook::{
+    apply_defined_names, apply_sheet_states, deterministic_created, parse_doc_properties,
+    resolve_active_sheet, save_workbook, BufferTarget, OutputTarget,
+};
+use write::{DATETIME_NUM_FORMAT, DATE_NUM_FORMAT, TIME_NUM_FORMAT};
 
 use pyo3::prelude::*;
-use rust_xlsxwriter::Workbook;
+use pyo3::types::{PyBytes, PyDict};
+use rust_xlsxwriter::{DocProperties, Workbook};
+use std::cell::Cell;
 use std::collections::HashMap;
 
 fn path_arg_to_string(value: &Bound<'_, PyAny>, param_name: &str) -> PyResult<String> {
@@ -56,6 +82,106 @@ fn path_arg_to_string(value: &Bound<'_, PyAny>, param_name: &str) -> PyResult<St
     )))
 }
 
+/// Resolve `output_path` for the DataFrame writers: a filesystem path, a
+/// binary file-like object with a `write()` method, or the internal buffer
+/// handed over by the `*_to_xlsx_bytes` functions.
+fn output_target(value: &Bound<'_, PyAny>, param_name: &str) -> PyResult<OutputTarget> {
+    if let Ok(buffer) = value.cast::<BufferTarget>() {
+        return Ok(OutputTarget::Buffer(buffer.clone().unbind()));
+    }
+    if let Ok(path) = path_arg_to_string(value, param_name) {
+        return Ok(OutputTarget::Path(path));
+    }
+    if value.hasattr("write")? {
+        return Ok(OutputTarget::Writer(value.clone().unbind()));
+    }
+    Err(pyo3::exceptions::PyTypeError::new_err(format!(
+        "'{}' must be str, a path-like object returning str (bytes paths are not supported), or a binary file-like object with a write() method, got {}",
+        param_name,
+        pytype_name(value)
+    )))
+}
+
+fn parse_nan_policy(value: &str) -> Result<NanPolicy, String> {
+    NanPolicy::parse(value).ok_or_else(|| {
+        format!(
+            "Invalid nan_policy '{}'. Valid values: empty, string, error",
+            value
+        )
+    })
+}
+
+fn parse_large_int_mode(value: &str) -> Result<LargeIntMode, String> {
+    LargeIntMode::parse(value).ok_or_else(|| {
+        format!(
+            "Invalid large_int_mode '{}'. Valid values: string, number, text",
+            value
+        )
+    })
+}
+
+fn parse_long_string_mode(value: &str) -> Result<LongStringMode, String> {
+    LongStringMode::parse(value).ok_or_else(|| {
+        format!(
+            "Invalid long_string_mode '{}'. Valid values: error, truncate, split",
+            value
+        )
+    })
+}
+
+fn parse_conditional_format_mode(value: &str) -> Result<ConditionalFormatMode, String> {
+    ConditionalFormatMode::parse(value).ok_or_else(|| {
+        format!(
+            "Invalid conditional_format_mode '{}'. Valid values: all_matches, first_match_wins",
+            value
+        )
+    })
+}
+
+fn parse_sheet_view(value: &str) -> Result<SheetView, String> {
+    SheetView::parse(value).ok_or_else(|| {
+        format!(
+            "Invalid view '{}'. Valid values: normal, page_layout, page_break",
+            value
+        )
+    })
+}
+
+/// Build the document properties from the `properties`, `custom_properties`,
+/// and `deterministic` arguments; `None` when none of them is given.
+fn extract_doc_properties(
+    properties: Option<&HashMap<String, String>>,
+    custom_properties: Option<&Bound<'_, PyAny>>,
+    deterministic: bool,
+) -> PyResult<Option<DocProperties>> {
+    if properties.is_none() && custom_properties.is_none() && !deterministic {
+        return Ok(None);
+    }
+    let mut doc = properties
+        .map(parse_doc_properties)
+        .transpose()
+        .map_err(pyo3::exceptions::PyValueError::new_err)?
+        .unwrap_or_default();
+    if deterministic && !properties.is_some_and(|p| p.contains_key("created")) {
+        let created = deterministic_created().map_err(pyo3::exceptions::PyValueError::new_err)?;
+        doc = doc.set_creation_datetime(&created);
+    }
+    match custom_properties {
+        Some(custom) => extract_custom_properties(custom, doc).map(Some),
+        None => Ok(Some(doc)),
+    }
+}
+
+fn parse_calc_settings(calc_mode: &str, force_recalc: bool) -> Result<CalcSettings, String> {
+    let mode = CalcMode::parse(calc_mode).ok_or_else(|| {
+        format!(
+            "Invalid calc_mode '{}'. Valid values: auto, manual, auto_except_tables",
+            calc_mode
+        )
+    })?;
+    Ok(CalcSettings { mode, force_recalc })
+}
+
 /// Helper: cast a PyAny to PyDict or raise TypeError with a clear message.
 fn require_dict<'py>(
     value: &Bound<'py, PyAny>,
@@ -87,11 +213,14 @@ fn require_list<'py>(
 struct RawOptions<'a, 'py> {
     column_widths: Option<&'a Bound<'py, PyAny>>,
     header_format: Option<&'a Bound<'py, PyAny>>,
+    cell_format: Option<&'a Bound<'py, PyAny>>,
     column_formats: Option<&'a Bound<'py, PyAny>>,
     conditional_formats: Option<&'a Bound<'py, PyAny>>,
     formula_columns: Option<&'a Bound<'py, PyAny>>,
     merged_ranges: Option<&'a Bound<'py, PyAny>>,
     hyperlinks: Option<&'a Bound<'py, PyAny>>,
+    row_groups: Option<&'a Bound<'py, PyAny>>,
+    col_groups: Option<&'a Bound<'py, PyAny>>,
     comments: Option<&'a Bound<'py, PyAny>>,
     validations: Option<&'a Bound<'py, PyAny>>,
     rich_text: Option<&'a Bound<'py, PyAny>>,
@@ -101,6 +230,8 @@ struct RawOptions<'a, 'py> {
     charts: Option<&'a Bound<'py, PyAny>>,
     sparklines: Option<&'a Bound<'py, PyAny>>,
     cells: Option<&'a Bound<'py, PyAny>>,
+    cell_overrides: Option<&'a Bound<'py, PyAny>>,
+    link_format: Option<&'a Bound<'py, PyAny>>,
 }
 
 /// Extract and validate all optional write parameters from Python into typed Rust structs.
@@ -114,6 +245,14 @@ fn extract_options(raw: &RawOptions<'_, '_>) -> PyResult<ExtractedOptions> {
             .header_format
             .map(|v| require_dict(v, "header_format").and_then(|d| extract_header_format(&d)))
             .transpose()?,
+        cell_format: raw
+            .cell_format
+            .map(|v| require_dict(v, "cell_format").and_then(|d| extract_header_format(&d)))
+            .transpose()?,
+        link_format: raw
+            .link_format
+            .map(|v| require_dict(v, "link_format").and_then(|d| extract_header_format(&d)))
+            .transpose()?,
         column_formats: raw
             .column_formats
             .map(|v| require_dict(v, "column_formats").and_then(|d| extract_column_formats(&d)))
@@ -136,6 +275,14 @@ fn extract_options(raw: &RawOptions<'_, '_>) -> PyResult<ExtractedOptions> {
             .hyperlinks
             .map(|v| require_list(v, "hyperlinks").and_then(|l| extract_hyperlinks(&l)))
             .transpose()?,
+        row_groups: raw
+            .row_groups
+            .map(|v| require_list(v, "row_groups").and_then(|l| extract_row_groups(&l)))
+            .transpose()?,
+        col_groups: raw
+            .col_groups
+            .map(|v| require_list(v, "col_groups").and_then(|l| extract_col_groups(&l)))
+            .transpose()?,
         comments: raw
             .comments
             .map(|v| require_dict(v, "comments").and_then(|d| extract_comments(&d)))
@@ -172,6 +319,10 @@ fn extract_options(raw: &RawOptions<'_, '_>) -> PyResult<ExtractedOptions> {
             .cells
             .map(|v| require_dict(v, "cells").and_then(|d| extract_cells(&d)))
             .transpose()?,
+        cell_overrides: raw
+            .cell_overrides
+            .map(|v| require_dict(v, "cell_overrides").and_then(|d| extract_cell_overrides(&d)))
+            .transpose()?,
     })
 }
 
@@ -183,7 +334,8 @@ fn extract_options(raw: &RawOptions<'_, '_>) -> PyResult<ExtractedOptions> {
 /// - "true"/"false" (case-insensitive) become Excel booleans
 /// - Dates (YYYY-MM-DD, DD-MM-YYYY, MM-DD-YYYY) become Excel dates
 /// - Datetimes (ISO 8601) become Excel datetimes
-/// - NaN/Inf values become empty cells
+/// - Times (HH:MM:SS) become Excel times
+/// - NaN/Inf values become empty cells (see nan_policy)
 /// - Everything else becomes text
 ///
 /// Args:
@@ -192,16 +344,85 @@ fn extract_options(raw: &RawOptions<'_, '_>) -> PyResult<ExtractedOptions> {
 ///     sheet_name: Name of the worksheet (default: "Sheet1")
 ///     parallel: Use multi-core parallel processing (default: False).
 ///               Faster for large files (100K+ rows) but uses more memory.
+///               Cannot be combined with constant_memory.
 ///     date_order: Date parsing order for ambiguous dates like "01-02-2024" (default: "auto").
 ///                 "auto" - ISO first, then European (DMY), then US (MDY)
 ///                 "mdy" or "us" - US format: 01-02-2024 = January 2nd
 ///                 "dmy" or "eu" - European format: 01-02-2024 = February 1st
+///     date_format: Excel number format for date cells (default: "yyyy-mm-dd").
+///                  Example: "dd/mm/yyyy" or "mmm d, yyyy"
+///     datetime_format: Excel number format for datetime cells (default: "yyyy-mm-dd hh:mm:ss").
+///                      Example: "dd/mm/yyyy hh:mm"
+///     time_format: Excel number format for time-of-day cells such as "14:30:00" (default: "hh:mm:ss").
+///                  Example: "h:mm AM/PM"
+///     nan_policy: How NaN/Inf values are written (default: "empty").
+///                 "empty" - an empty cell
+///                 "string" - the text "NaN", "Inf", or "-Inf"
+///                 "error" - the Excel error #N/A, so dependent formulas see an error
+///     large_int_mode: How integers beyond 2^53 (which an Excel number can't hold exactly)
+///                     are written (default: "string").
+///                     "string" - a text cell holding every digit
+///                     "number" - an Excel number, accepting float rounding of the low digits
+///                     "text" - a text cell with the "@" (Text) number format
+///     long_string_mode: How strings longer than Excel's 32,767-character cell limit are
+///                       written (default: "error").
+///                       "error" - raise ValueError naming the cell
+///                       "truncate" - keep the first 32,767 characters
+///                       "split" - keep the first 32,767 characters in the cell and the
+///                                 rest in a note on it (up to 32,713 more characters)
+///     constant_memory: Stream rows to disk as they are written instead of holding the
+///                      worksheet in memory (default: False). Use for very large files.
+///                      Cannot be combined with parallel.
+///     smart_numeric: Detect formatted numbers that would otherwise stay text (default: False).
+///                    "45%" is written as 0.45 with a percent format, "$1,234.50" (also
+///                    €, £, ¥) as 1234.5 with a "#,##0.00" format, and "1,234,567" as a number.
+///     delimiter: Field separator, a single ASCII character such as ";" or "\t"
+///                (default: ",").
+///     quote: Character that quotes a field containing the delimiter or line breaks
+///            (default: '"'). A doubled quote inside a quoted field is a literal quote.
+///     escape: Character that escapes a quote inside a quoted field, e.g. "\\" for
+///             files written as \" (default: None).
+///     has_header: Treat the first row as a header (default: False). Its fields are
+///                 written as text even when they look like numbers or dates, and the
+///                 panes are frozen below it, matching df_to_xlsx output.
+///     header_format: Format dict for the header row, same keys as in df_to_xlsx
+///                    (e.g. {"bold": True, "bg_color": "#DDEBF7"}). Requires has_header.
+///     encoding: Text encoding of the input file (default: "utf-8"), e.g. "windows-1252",
+///               "latin-1", "iso-8859-15", "shift_jis", or "utf-16". A byte-order mark
+///               in the file takes precedence.
+///     comment: Character that marks a comment line, e.g. "#" (default: None). Lines
+///              starting with it are skipped.
+///     skip_empty_rows: Skip rows whose fields are all empty or whitespace, such as ",,,"
+///                      separator lines (default: False). Lines with no content at all
+///                      are always skipped. Skipped rows are not counted or written.
+///     null_values: List of field values written as empty cells, such as ["NA", "N/A",
+///                  "NULL", "-"] (default: None). Matching ignores case and surrounding
+///                  whitespace; the header row is not affected.
+///     decimal_separator: Decimal separator of numbers in the file, e.g. "," for European
+///                        files (default: None, meaning "."). Values like "1234,56" are
+///                        written as numbers.
+///     thousands_separator: Thousands separator of numbers in the file, e.g. "." or " "
+///                          (default: None). With decimal_separator=",", "1.234,56" is
+///                          written as 1234.56. Groups must be well-formed ("12.34,5"
+///                          stays text), and the two separators must differ.
+///     scientific_format: Excel number format for numbers written in scientific notation,
+///                        such as "1.5e10" (default: None, General format). For example,
+///                        "0.00E+00" shows 1.50E+10; the full value is still stored.
+///     text_columns: List of 0-based column positions whose fields are written as text
+///                   as-is, with Excel's "@" format, skipping type detection (default:
+///                   None). Use it for zip codes and other identifiers: "01234" keeps its
+///                   leading zero. Empty fields and null_values still become empty cells.
+///     sanitize_strings: Strip a leading byte order mark (U+FEFF) and replace control
+///                       characters XML forbids (below U+0020 except tab, line feed, and
+///                       carriage return) with spaces in every field before it is parsed
+///                       (default: False). Use it for exports with stray NUL or other
+///                       control bytes.
 ///
 /// Returns:
 ///     Tuple of (rows, columns) written to the Excel file
 ///
 /// Raises:
-///     ValueError: If the conversion fails
+///     ValueError: If the conversion fails or a number format is invalid
 ///
 /// Example:
 ///     >>> import xlsxturbo
@@ -210,8 +431,49 @@ fn extract_options(raw: &RawOptions<'_, '_>) -> PyResult<ExtractedOptions> {
 ///     >>> rows, cols = xlsxturbo.csv_to_xlsx("data.csv", "out.xlsx", date_order="us")
 ///     >>> # For large files, use parallel processing:
 ///     >>> rows, cols = xlsxturbo.csv_to_xlsx("big.csv", "out.xlsx", parallel=True)
+///     >>> # For files too large to hold in memory, stream rows to disk:
+///     >>> rows, cols = xlsxturbo.csv_to_xlsx("huge.csv", "out.xlsx", constant_memory=True)
+///     >>> # Style and freeze the first row as a header:
+///     >>> rows, cols = xlsxturbo.csv_to_xlsx("data.csv", "out.xlsx", has_header=True,
+///     ...                                    header_format={"bold": True})
+///     >>> # Read a file exported from Excel on Windows:
+///     >>> rows, cols = xlsxturbo.csv_to_xlsx("export.csv", "out.xlsx", encoding="windows-1252")
+///     >>> # Leave "NA" and "NULL" cells empty:
+///     >>> rows, cols = xlsxturbo.csv_to_xlsx("data.csv", "out.xlsx", null_values=["NA", "NULL"])
+///     >>> # European numbers such as 1.234,56 in a semicolon-separated file:
+///     >>> rows, cols = xlsxturbo.csv_to_xlsx("eu.csv", "out.xlsx", delimiter=";",
+///     ...                                    decimal_separator=",", thousands_separator=".")
 #[pyfunction]
-#[pyo3(signature = (input_path, output_path, sheet_name = "Sheet1", parallel = false, date_order = "auto"))]
+#[pyo3(signature = (
+    input_path,
+    output_path,
+    sheet_name = "Sheet1",
+    parallel = false,
+    date_order = "auto",
+    date_format = None,
+    datetime_format = None,
+    time_format = None,
+    nan_policy = "empty",
+    large_int_mode = "string",
+    long_string_mode = "error",
+    constant_memory = false,
+    smart_numeric = false,
+    delimiter = None,
+    quote = None,
+    escape = None,
+    has_header = false,
+    header_format = None,
+    encoding = "utf-8",
+    comment = None,
+    skip_empty_rows = false,
+    null_values = None,
+    decimal_separator = None,
+    thousands_separator = None,
+    scientific_format = None,
+    text_columns = None,
+    sanitize_strings = false,
+))]
+#[allow(clippy::too_many_arguments)]
 fn csv_to_xlsx(
     py: Python<'_>,
     input_path: &Bound<'_, PyAny>,
@@ -219,66 +481,427 @@ fn csv_to_xlsx(
     sheet_name: &str,
     parallel: bool,
     date_order: &str,
+    date_format: Option<String>,
+    datetime_format: Option<String>,
+    time_format: Option<String>,
+    nan_policy: &str,
+    large_int_mode: &str,
+    long_string_mode: &str,
+    constant_memory: bool,
+    smart_numeric: bool,
+    delimiter: Option<&str>,
+    quote: Option<&str>,
+    escape: Option<&str>,
+    has_header: bool,
+    header_format: Option<&Bound<'_, PyAny>>,
+    encoding: &str,
+    comment: Option<&str>,
+    skip_empty_rows: bool,
+    null_values: Option<Vec<String>>,
+    decimal_separator: Option<&str>,
+    thousands_separator: Option<&str>,
+    scientific_format: Option<String>,
+    text_columns: Option<Vec<usize>>,
+    sanitize_strings: bool,
 ) -> PyResult<(u32, u16)> {
     let input_path = path_arg_to_string(input_path, "input_path")?;
     let output_path = path_arg_to_string(output_path, "output_path")?;
     let sheet_name = sheet_name.to_string();
-    let order = DateOrder::parse(date_order).ok_or_else(|| {
-        pyo3::exceptions::PyValueError::new_err(format!(
-            "Invalid date_order '{}'. Valid values: auto, mdy, us, dmy, eu, european",
-            date_order
-        ))
-    })?;
+    let config = CsvSheetConfig {
+        date_order: Some(date_order.to_string()),
+        date_format,
+        datetime_format,
+        time_format,
+        nan_policy: Some(nan_policy.to_string()),
+        large_int_mode: Some(large_int_mode.to_string()),
+        long_string_mode: Some(long_string_mode.to_string()),
+        constant_memory: Some(constant_memory),
+        smart_numeric: Some(smart_numeric),
+        delimiter: delimiter.map(str::to_string),
+        quote: quote.map(str::to_string),
+        escape: escape.map(str::to_string),
+        has_header: Some(has_header),
+        header_format: header_format
+            .map(|v| require_dict(v, "header_format").and_then(|d| extract_header_format(&d)))
+            .transpose()?,
+        encoding: Some(encoding.to_string()),
+        comment: comment.map(str::to_string),
+        skip_empty_rows: Some(skip_empty_rows),
+        null_values,
+        decimal_separator: decimal_separator.map(str::to_string),
+        thousands_separator: thousands_separator.map(str::to_string),
+        scientific_format,
+        text_columns,
+        sanitize_strings: Some(sanitize_strings),
+    };
+    let options = resolve_csv_options(py, &config, &CsvSheetConfig::default())?;
 
     // No Python objects are touched below this point, so release the GIL for
     // the (potentially rayon-parallel) pure-Rust conversion work.
     let result = py.detach(|| {
         if parallel {
-            convert_csv_to_xlsx_parallel(&input_path, &output_path, &sheet_name, order)
+            convert_csv_to_xlsx_parallel(&input_path, &output_path, &sheet_name, &options)
         } else {
-            convert_csv_to_xlsx(&input_path, &output_path, &sheet_name, order)
+            convert_csv_to_xlsx(&input_path, &output_path, &sheet_name, &options)
         }
     });
     result.map_err(pyo3::exceptions::PyValueError::new_err)
 }
 
+/// Convert several CSV files to one XLSX workbook, one sheet per file.
+///
+/// Sheets are written in list order. Every csv_to_xlsx option can be given as
+/// a keyword argument (the default for every sheet) or in a sheet's options
+/// dict (that sheet only).
+///
+/// Args:
+///     inputs: List of tuples. Each tuple can be:
+///             - (csv_path, sheet_name) - uses the keyword defaults
+///             - (csv_path, sheet_name, options_dict) - per-sheet overrides
+///             Options dict keys: date_order, date_format, datetime_format, time_format,
+///             nan_policy, large_int_mode, long_string_mode, constant_memory, smart_numeric,
+///             delimiter, quote, escape, has_header, header_format, encoding, comment,
+///             skip_empty_rows, null_values, decimal_separator, thousands_separator,
+///             scientific_format, text_columns, sanitize_strings
+///     output_path: Path for the output XLSX file
+///     parallel: Parse each file's rows in parallel, as in csv_to_xlsx (default: False).
+///               Cannot be combined with constant_memory.
+///     date_order, date_format, datetime_format, time_format, nan_policy, large_int_mode,
+///     long_string_mode, constant_memory, smart_numeric, delimiter, quote, escape, has_header,
+///     encoding, comment, skip_empty_rows, null_values, decimal_separator,
+///     thousands_separator, scientific_format, text_columns, sanitize_strings: Defaults for
+///             every sheet, with the same meaning and
+///             defaults as in csv_to_xlsx
+///     header_format: Default format dict for the header row. Applies to the sheets
+///                    with has_header; a header_format in a sheet's options dict
+///                    requires has_header for that sheet.
+///
+/// Returns:
+///     List of (rows, columns) tuples, one per sheet in input order
+///
+/// Raises:
+///     ValueError: If inputs is empty, an option is unknown or invalid, or a
+///                 conversion fails (the message names the sheet)
+///
+/// Example:
+///     >>> import xlsxturbo
+///     >>> xlsxturbo.csvs_to_xlsx(
+///     ...     [("jan.csv", "January"), ("feb.csv", "February", {"delimiter": ";"})],
+///     ...     "q1.xlsx",
+///     ...     has_header=True,
+///     ... )
+///     [(101, 4), (96, 4)]
+#[pyfunction]
+#[pyo3(signature = (
+    inputs,
+    output_path,
+    parallel = false,
+    date_order = "auto",
+    date_format = None,
+    datetime_format = None,
+    time_format = None,
+    nan_policy = "empty",
+    large_int_mode = "string",
+    long_string_mode = "error",
+    constant_memory = false,
+    smart_numeric = false,
+    delimiter = None,
+    quote = None,
+    escape = None,
+    has_header = false,
+    header_format = None,
+    encoding = "utf-8",
+    comment = None,
+    skip_empty_rows = false,
+    null_values = None,
+    decimal_separator = None,
+    thousands_separator = None,
+    scientific_format = None,
+    text_columns = None,
+    sanitize_strings = false,
+))]
+#[allow(clippy::too_many_arguments)]
+fn csvs_to_xlsx<'py>(
+    py: Python<'py>,
+    inputs: Vec<Bound<'py, PyAny>>,
+    output_path: &Bound<'py, PyAny>,
+    parallel: bool,
+    date_order: &str,
+    date_format: Option<String>,
+    datetime_format: Option<String>,
+    time_format: Option<String>,
+    nan_policy: &str,
+    large_int_mode: &str,
+    long_string_mode: &str,
+    constant_memory: bool,
+    smart_numeric: bool,
+    delimiter: Option<&str>,
+    quote: Option<&str>,
+    escape: Option<&str>,
+    has_header: bool,
+    header_format: Option<&Bound<'py, PyAny>>,
+    encoding: &str,
+    comment: Option<&str>,
+    skip_empty_rows: bool,
+    null_values: Option<Vec<String>>,
+    decimal_separator: Option<&str>,
+    thousands_separator: Option<&str>,
+    scientific_format: Option<String>,
+    text_columns: Option<Vec<usize>>,
+    sanitize_strings: bool,
+) -> PyResult<Vec<(u32, u16)>> {
+    if inputs.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "csvs_to_xlsx requires at least one input, got an empty list",
+        ));
+    }
+    let output_path = path_arg_to_string(output_path, "output_path")?;
+    let defaults = CsvSheetConfig {
+        date_order: Some(date_order.to_string()),
+        date_format,
+        datetime_format,
+        time_format,
+        nan_policy: Some(nan_policy.to_string()),
+        large_int_mode: Some(large_int_mode.to_string()),
+        long_string_mode: Some(long_string_mode.to_string()),
+        constant_memory: Some(constant_memory),
+        smart_numeric: Some(smart_numeric),
+        delimiter: delimiter.map(str::to_string),
+        quote: quote.map(str::to_string),
+        escape: escape.map(str::to_string),
+        has_header: Some(has_header),
+        header_format: header_format
+            .map(|v| require_dict(v, "header_format").and_then(|d| extract_header_format(&d)))
+            .transpose()?,
+        encoding: Some(encoding.to_string()),
+        comment: comment.map(str::to_string),
+        skip_empty_rows: Some(skip_empty_rows),
+        null_values,
+        decimal_separator: decimal_separator.map(str::to_string),
+        thousands_separator: thousands_separator.map(str::to_string),
+        scientific_format,
+        text_columns,
+        sanitize_strings: Some(sanitize_strings),
+    };
+
+    let mut sheets = Vec::with_capacity(inputs.len());
+    for input in &inputs {
+        let (csv_path, sheet_name, config) = extract_csv_sheet_info(input)?;
+        let csv_path = path_arg_to_string(&csv_path, "csv_path")?;
+        let options = resolve_csv_options(py, &config, &defaults).map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "sheet '{}': {}",
+                sheet_name,
+                e.value(py)
+            ))
+        })?;
+        sheets.push((csv_path, sheet_name, options));
+    }
+
+    // As in csv_to_xlsx, the conversion itself needs no Python objects
+    let result = py.detach(|| convert_csvs_to_xlsx(&sheets, &output_path, parallel));
+    result.map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+/// Resolve CSV conversion options: each `sheet` value falls back to the
+/// workbook-wide `defaults`, then to the `csv_to_xlsx` defaults. A
+/// `header_format` set on `sheet` itself requires `has_header`; one inherited
+/// from `defaults` only styles sheets that have a header row.
+fn resolve_csv_options(
+    py: Python<'_>,
+    sheet: &CsvSheetConfig,
+    defaults: &CsvSheetConfig,
+) -> PyResult<CsvOptions> {
+    let date_order = sheet
+        .date_order
+        .as_deref()
+        .or(defaults.date_order.as_deref())
+        .unwrap_or("auto");
+    let order = DateOrder::parse(date_order).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid date_order '{}'. Valid values: auto, mdy, us, dmy, eu, european",
+            date_order
+        ))
+    })?;
+    let has_header = sheet.has_header.or(defaults.has_header).unwrap_or(false);
+    if sheet.header_format.is_some() && !has_header {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "header_format requires has_header=True: without a header row there is \
+             nothing to style",
+        ));
+    }
+    let header_format = sheet
+        .header_format
+        .as_ref()
+        .or(defaults.header_format.as_ref())
+        .filter(|_| has_header)
+        .map(|map| parse_header_format(py, map, "header_format"))
+        .transpose()
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let csv_char = |param: &str, value: Option<&String>| {
+        value
+            .map(|v| parse_csv_char(param, v))
+            .transpose()
+            .map_err(pyo3::exceptions::PyValueError::new_err)
+    };
+    let number_separator = |param: &str, value: Option<&String>| {
+        value
+            .map(|v| parse_number_separator(param, v))
+            .transpose()
+            .map_err(pyo3::exceptions::PyValueError::new_err)
+    };
+    Ok(CsvOptions {
+        date_order: order,
+        date_format: sheet.date_format.clone().or(defaults.date_format.clone()),
+        datetime_format: sheet
+            .datetime_format
+            .clone()
+            .or(defaults.datetime_format.clone()),
+        time_format: sheet.time_format.clone().or(defaults.time_format.clone()),
+        nan_policy: parse_nan_policy(
+            sheet
+                .nan_policy
+                .as_deref()
+                .or(defaults.nan_policy.as_deref())
+                .unwrap_or("empty"),
+        )
+        .map_err(pyo3::exceptions::PyValueError::new_err)?,
+        large_int_mode: parse_large_int_mode(
+            sheet
+                .large_int_mode
+                .as_deref()
+                .or(defaults.large_int_mode.as_deref())
+                .unwrap_or("string"),
+        )
+        .map_err(pyo3::exceptions::PyValueError::new_err)?,
+        long_string_mode: parse_long_string_mode(
+            sheet
+                .long_string_mode
+                .as_deref()
+                .or(defaults.long_string_mode.as_deref())
+                .unwrap_or("error"),
+        )
+        .map_err(pyo3::exceptions::PyValueError::new_err)?,
+        constant_memory: sheet
+            .constant_memory
+            .or(defaults.constant_memory)
+            .unwrap_or(false),
+        smart_numeric: sheet
+            .smart_numeric
+            .or(defaults.smart_numeric)
+            .unwrap_or(false),
+        delimiter: csv_char(
+            "delimiter",
+            sheet.delimiter.as_ref().or(defaults.delimiter.as_ref()),
+        )?,
+        quote: csv_char("quote", sheet.quote.as_ref().or(defaults.quote.as_ref()))?,
+        escape: csv_char("escape", sheet.escape.as_ref().or(defaults.escape.as_ref()))?,
+        has_header,
+        header_format,
+        encoding: Some(
+            parse_encoding(
+                sheet
+                    .encoding
+                    .as_deref()
+                    .or(defaults.encoding.as_deref())
+                    .unwrap_or("utf-8"),
+            )
+            .map_err(pyo3::exceptions::PyValueError::new_err)?,
+        ),
+        comment: csv_char(
+            "comment",
+            sheet.comment.as_ref().or(defaults.comment.as_ref()),
+        )?,
+        skip_empty_rows: sheet
+            .skip_empty_rows
+            .or(defaults.skip_empty_rows)
+            .unwrap_or(false),
+        null_values: sheet
+            .null_values
+            .clone()
+            .or(defaults.null_values.clone())
+            .unwrap_or_default(),
+        decimal_separator: number_separator(
+            "decimal_separator",
+            sheet
+                .decimal_separator
+                .as_ref()
+                .or(defaults.decimal_separator.as_ref()),
+        )?,
+        thousands_separator: number_separator(
+            "thousands_separator",
+            sheet
+                .thousands_separator
+                .as_ref()
+                .or(defaults.thousands_separator.as_ref()),
+        )?,
+        scientific_format: sheet
+            .scientific_format
+            .clone()
+            .or(defaults.scientific_format.clone()),
+        text_columns: sheet
+            .text_columns
+            .clone()
+            .or(defaults.text_columns.clone())
+            .unwrap_or_default(),
+        sanitize_strings: sheet
+            .sanitize_strings
+            .or(defaults.sanitize_strings)
+            .unwrap_or(false),
+    })
+}
+
 /// Convert a pandas or polars DataFrame to XLSX format.
 ///
 /// This function writes a DataFrame directly to an Excel XLSX file,
 /// preserving data types without intermediate CSV conversion.
 ///
 /// Args:
-///     df: pandas DataFrame or polars DataFrame to export
-///     output_path: Path for the output XLSX file
+///     df: pandas DataFrame, polars DataFrame, or polars LazyFrame (collected first) to export
+///     output_path: Path for the output XLSX file, or a binary file-like object with
+///                  a write() method (e.g. io.BytesIO or an open 'wb' file)
 ///     sheet_name: Name of the worksheet (default: "Sheet1")
-///     header: Include column names as header row (default: True)
+///     header: Include column names as header row (default: True). A pandas column
+///             MultiIndex gets one header row per level, with repeated upper-level
+///             labels merged across their columns.
 ///     autofit: Automatically adjust column widths to fit content (default: False)
-///              Combined with column_widths: explicit widths win for the columns
-///              they name; every other column is still autofitted (rather than
-///              left at Excel's default width). Add an "_all" entry in
-///              column_widths to cap the autofit width instead of overriding it.
+///              Combined with column_widths: each autofitted column is capped at
+///              its own column_widths entry, else at "_all", rather than set to it.
+///              A list of column names and/or 0-based positions autofits only
+///              those columns; the rest keep column_widths or Excel's default.
+///              An autofitted column is at least as wide as its header label plus
+///              two characters, so bold headers are not clipped.
 ///     table_style: Apply Excel table formatting with this style name (default: None).
 ///                  Styles: "Light1"-"Light21", "Medium1"-"Medium28", "Dark1"-"Dark11", "None"
 ///                  Tables include autofilter dropdowns and banded rows.
-///     freeze_panes: Freeze the header row for easier scrolling (default: False)
+///                  With header=False the table is created without a header row.
+///     freeze_panes: Freeze panes for easier scrolling (default: False). True freezes the
+///                   header row; a cell reference like "C2" or a 0-based (row, col) tuple
+///                   freezes everything above and left of that cell ("C2" == (1, 2) keeps
+///                   row 1 and columns A-B in view). Positions are absolute, not offset
+///                   by start_row/start_col. A {"rows": N, "cols": M} dict freezes the
+///                   first N rows and M columns of the written block, counted from
+///                   start_row/start_col (missing keys count as 0).
 ///     column_widths: Dict mapping column index (0-based) or "_all" to width in characters
 ///                    (default: None). Example: {0: 20, 1: 15, 3: 30} sets widths for columns
 ///                    A, B, and D. An integer key must be a non-negative index within Excel's
 ///                    column range (0..=16383); a negative key, a key beyond 16383, or a
-///                    non-integer/non-"_all" key raises. With autofit=True and no "_all" key:
-///                    listed columns get the explicit width, unlisted columns are autofitted.
-///                    With autofit=True and an "_all" key: "_all" caps the autofit width for
-///                    unlisted columns instead of overriding it.
+///                    non-integer/non-"_all" key raises. With autofit, the widths of
+///                    autofitted columns are maximums: each is fitted to its content, then
+///                    capped at its own entry, else at "_all".
 ///     row_heights: Dict mapping row index (0-based) to height in points (default: None)
 ///                  Example: {0: 20, 5: 30} sets heights for specific rows
 ///     constant_memory: Use constant memory mode for large files (default: False).
 ///                      Emits a RuntimeWarning and disables: table_style, freeze_panes,
-///                      row_heights, autofit, column_widths with autofit cap, conditional_formats,
-///                      formula_columns, merged_ranges, hyperlinks, comments, validations,
-///                      rich_text, images, checkboxes, textboxes, charts, sparklines, and cells.
+///                      autofilter, row_heights, autofit, column_widths with autofit cap, conditional_formats,
+///                      formula_columns, merged_ranges, hyperlinks, row_groups, col_groups,
+///                      comments, validations, categorical_validation, rich_text, images,
+///                      checkboxes, textboxes, charts, sparklines, cells, and cell_overrides.
 ///                      Plain column_widths, header_format, and column_formats remain supported.
-///     column_formats: Dict mapping column name patterns to format dicts (default: None)
+///     column_formats: Dict mapping column name patterns or column indices to format dicts (default: None)
 ///                     Supports wildcards: "prefix*", "*suffix", "*contains*", or exact match.
+///                     An int key (e.g. 2) targets that DataFrame column by position and
+///                     takes precedence over name patterns.
 ///                     Format options: bg_color, font_color, num_format, bold, italic, underline, border.
 ///                     Example: {"price_*": {"bg_color": "#D6EAF8", "num_format": "$#,##0.00"}}
 ///     conditional_formats: Dict mapping column names/patterns to conditional format configs (default: None)
@@ -287,25 +910,50 @@ fn csv_to_xlsx(
 ///     table_name: Custom name for the Excel table (requires table_style; default: auto-generated).
 ///                 Must be alphanumeric/underscore, max 255 chars.
 ///     formula_columns: Dict mapping column names to Excel formula templates (default: None).
-///                      Use {row} as placeholder for the current row number.
-///                      Example: {"Total": "=SUM(A{row}:C{row})"}
-///     merged_ranges: List of merge specs: (range, text) or (range, text, format_dict) (default: None).
+///                      Use {row} as placeholder for the current row number, and
+///                      {col_name} for that column's cell in the current row (data or
+///                      formula columns; an unknown name raises ValueError).
+///                      Example: {"Total": "=SUM(A{row}:C{row})", "Net": "={Total}-{tax}"}
+///                      A dict value {"formula": ..., "formula_type": ..., "range": ...}
+///                      picks "regular" (default), "dynamic" (one spilling formula in
+///                      the first data row; needs Excel 365 or 2021+), or "array"
+///                      (one CSE formula over "range", default the column's data rows).
+///                      A "position" (a 0-based column index, or "after:col_name")
+///                      inserts the column among the data columns, shifting the later
+///                      ones right; a table then includes it. A "formula_result" (a
+///                      constant, or a callable taking the 0-based DataFrame row index)
+///                      is cached as the formula's value, for readers that don't
+///                      recalculate.
+///     merged_ranges: List of merge specs: (range, value) or (range, value, format_dict) (default: None).
+///                    A string is the merged text; a number, bool, date, or datetime is
+///                    written with its type into the top-left cell, dates with
+///                    date_format/datetime_format unless the format sets num_format.
+///                    format_dict takes the column_formats keys.
 ///                    Example: [("A1:D1", "Title", {"bold": True, "bg_color": "#4F81BD"})]
-///     hyperlinks: List of link specs: (cell_ref, url) or (cell_ref, url, display_text) (default: None).
-///                 Example: [("A1", "https://example.com", "Click here")]
+///     hyperlinks: List of link specs: (cell_ref, url), (cell_ref, url, display_text), or
+///                 (cell_ref, url, display_text, tooltip) (default: None). display_text may be
+///                 None; the tooltip (at most 255 characters) is shown on hover.
+///                 Example: [("A1", "https://example.com", "Click here", "Opens example.com")]
+///                 A url like "internal:Summary!A1" jumps to a cell in the workbook; the
+///                 sheet must exist, or ValueError is raised.
 ///     comments: Dict mapping cell refs to note text or config dict (default: None).
 ///               Example: {"A1": "Note text"} or {"A1": {"text": "Note", "author": "John"}}
 ///     validations: Dict mapping column names/patterns to validation configs (default: None).
-///                  Types: list, whole_number, decimal, text_length
+///                  Types: list, whole_number, decimal, text_length, date, time
 ///                  (aliases accepted, e.g. integer/number/length — see README).
 ///                  Example: {"status": {"type": "list", "values": ["Open", "Closed"]}}
+///                  A list can instead take source_range, a cell range such as
+///                  "=Lists!$A$1:$A$100", which avoids the 255 character limit.
 ///                  For "whole_number", min/max are bounded to the i32 range
 ///                  (-2147483648..=2147483647); a value outside that range raises
 ///                  ValueError naming the field and range.
 ///     rich_text: Dict mapping cell refs to lists of formatted text segments (default: None).
 ///                Example: {"A1": [("Bold text", {"bold": True}), (" normal text",)]}
-///     images: Dict mapping cell refs to image paths or config dicts (default: None).
+///     images: Dict mapping cell refs to image paths, image bytes, or config dicts (default: None).
 ///             Example: {"A1": "logo.png"} or {"A1": {"path": "logo.png", "scale_width": 0.5}}
+///             Config dicts take "data" (bytes) in place of "path", plus
+///             x_offset/y_offset (pixels), dpi, and anchor ("move_and_size",
+///             "move", "absolute", or "move_and_size_after").
 ///     checkboxes: Dict mapping cell refs to checkbox state (default: None).
 ///                 Simple form: {"A1": True, "A2": False}
 ///                 Dict form with optional cell format: {"A3": {"checked": True, "format": {"bg_color": "#C6EFCE"}}}
@@ -323,6 +971,9 @@ fn csv_to_xlsx(
 ///             raises ValueError.
 ///             Example: {"D2": {"type": "bar", "data_range": "Sheet1!$B$2:$B$10",
 ///                       "categories_range": "Sheet1!$A$2:$A$10", "title": "Monthly Activity"}}
+///             Or name written columns instead of ranges: "value_cols" (a column name
+///             or list, one series each) with an optional "categories_col", e.g.
+///             {"D2": {"type": "column", "categories_col": "Month", "value_cols": ["Sales"]}}
 ///     sparklines: Dict mapping a location ref to a sparkline (mini in-cell chart) config (default: None).
 ///                 A single-cell key (e.g. "D2") places one sparkline; a range key (e.g. "D2:D10")
 ///                 places a grouped sparkline, one per row of the data range.
@@ -339,12 +990,230 @@ fn csv_to_xlsx(
 ///            "num_format", "align_horizontal", "align_vertical", and "wrap_text".
 ///            Cells are written after all DataFrame data, so they can overwrite data cells.
 ///            Example: {"B9": "Label", "D6": {"value": "934728173849", "num_format": "@"}}
+///     cell_overrides: Dict mapping cell refs to a dict with "value" and/or "format" (a full
+///                     format dict, as for column_formats) (default: None). Written after the
+///                     DataFrame data and cells, so they can override any cell; a "format"
+///                     without "value" restyles the cell, keeping its value.
+///                     Example: {"D2": {"value": "Check", "format": {"bg_color": "#FFFF00"}}}
+///     date_format: Excel number format for date cells (default: "yyyy-mm-dd").
+///                  A column_formats num_format still takes precedence for its columns.
+///                  Example: "dd/mm/yyyy" or "mmm d, yyyy"
+///     datetime_format: Excel number format for datetime cells (default: "yyyy-mm-dd hh:mm:ss").
+///                      Example: "dd/mm/yyyy hh:mm"
+///     time_format: Excel number format for datetime.time cells (default: "hh:mm:ss").
+///                  Example: "h:mm AM/PM"
+///     nan_policy: How NaN/Inf values are written (default: "empty").
+///                 "empty" - an empty cell
+///                 "string" - the text "NaN", "Inf", or "-Inf"
+///                 "error" - the Excel error #N/A, so dependent formulas see an error
+///     large_int_mode: How integers beyond 2^53 (which an Excel number can't hold exactly)
+///                     are written (default: "string").
+///                     "string" - a text cell holding every digit
+///                     "number" - an Excel number, accepting float rounding of the low digits
+///                     "text" - a text cell with the "@" (Text) number format
+///     long_string_mode: How strings longer than Excel's 32,767-character cell limit are
+///                       written (default: "error").
+///                       "error" - raise ValueError naming the cell
+///                       "truncate" - keep the first 32,767 characters
+///                       "split" - keep the first 32,767 characters in the cell and the
+///                                 rest in a note on it (up to 32,713 more characters)
+///     start_row: Zero-based sheet row for the header (or first data row) (default: 0).
+///     start_col: Zero-based sheet column for the first DataFrame column (default: 0).
+///                The data, table, formula columns, conditional formats, validations,
+///                and freeze panes move with the offset. Cell-ref features (cells,
+///                merged_ranges, hyperlinks, ...), column_widths keys, and row_heights
+///                keys stay absolute sheet positions.
+///     autofilter: Add autofilter dropdowns over the written data (header row through the
+///                 last data row, formula columns included) without creating a table
+///                 (default: False). Ignored when a table_style table is created, since
+///                 tables have their own filter. With header=False the first data row
+///                 holds the dropdowns.
+///     conditional_format_mode: How conditional_formats patterns that match the same column
+///                              combine (default: "all_matches").
+///                              "all_matches" - every matching pattern's rules stack on the column
+///                              "first_match_wins" - only the first matching pattern (in dict
+///                              order) applies, like column_formats
+///     freeze_top_cell: Top-left cell of the scrollable pane when panes are frozen or split,
+///                      e.g. "A100" opens the area below a frozen header scrolled to row 100
+///                      (default: None). Has no effect without freeze_panes or split_panes.
+///     split_panes: Cell reference like "B3" where the window is split into resizable,
+///                  unfrozen panes (default: None). The dividers start at the given cell
+///                  for default row heights and column widths and can be dragged.
+///                  Mutually exclusive with freeze_panes.
+///     show_gridlines: Show the worksheet gridlines on screen (default: True). False gives
+///                     a clean dashboard look; printing is unaffected.
+///     show_row_col_headers: Show the row numbers and column letters on screen (default:
+///                           True). False hides them for a dashboard look; printing is
+///                           unaffected.
+///     zoom: Zoom level as a percentage from 10 to 400 (default: None, Excel's 100).
+///     view: View the sheet opens in (default: "normal"):
+///           "normal" - the ordinary grid
+///           "page_layout" - pages as printed, with headers and footers
+///           "page_break" - the grid with page breaks marked
+///     right_to_left: Show the sheet right to left, with column A at the right edge, for
+///                    Arabic or Hebrew reports (default: False). Only the view is mirrored;
+///                    the data keeps its column order and cell references.
+///     outline_below: Put the row_groups expand/collapse buttons below each group, where
+///                    a summary row follows its details (default: True). False puts them
+///                    above, for summaries that head their group.
+///     outline_right: Put the col_groups expand/collapse buttons right of each group
+///                    (default: True). False puts them on the left.
+///     tab_color: Color of the sheet tab, as '#RRGGBB' or a named color (default: None)
+///     page_setup: Dict of print layout settings (default: None). Keys:
+///                 orientation ("portrait" or "landscape"), fit_to_pages ((width, height)
+///                 in pages, 0 meaning as many as needed), paper_size (Excel paper index,
+///                 e.g. 1 = Letter, 9 = A4), margins (dict of left, right, top, bottom,
+///                 header, footer in inches), header and footer (Excel header/footer
+///                 format strings such as "&CPage &P of &N"), header_images and
+///                 footer_images (dicts of "left"/"center"/"right" to an image path,
+///                 shown at a &[Picture] placeholder in that section), and
+///                 header_footer_scale_with_doc (bool). Only affects printing, not the data.
+///     print_area: Cell range to print, e.g. "A1:F50" (default: None, the whole used
+///                 range). Honored as given, even when it extends past the written data.
+///     background_image: Image path or bytes shown tiled behind the cells, e.g. a "DRAFT"
+///                       watermark (default: None). Excel doesn't print background images.
+///     row_groups: List of (first_row, last_row, level) or (first_row, last_row, level,
+///                 collapsed) tuples outlining absolute 0-based sheet rows as collapsible
+///                 groups (default: None). level is 1-7; overlapping groups keep the
+///                 deepest level per row. collapsed=True starts the group collapsed.
+///     col_groups: List of (first_col, last_col, level[, collapsed]) tuples outlining
+///                 absolute 0-based sheet columns, as for row_groups (default: None).
+///     table_columns: List of per-column dicts for the table_style table, matched to the
+///                    columns from the left (default: None). Keys: header (replacement
+///                    header text), total_function ("sum", "average", "count",
+///                    "count_numbers", "max", "min", "std_dev", "var") or total_label
+///                    (text). Any total adds a totals row below the data, counted in the
+///                    returned rows. Ignored without table_style.
+///     table_options: Dict of table look toggles for the table_style table (default:
+///                    None): banded_rows (default: True), banded_columns, first_column,
+///                    and last_column (default: False), each a bool. Lets a style show
+///                    e.g. emphasized first-column labels. Ignored without table_style.
+///     tables: List of extra tables over explicit ranges, each a dict (default: None):
+///             range ("A1:C10", required; its first row is the header row), style
+///             (as for table_style; default: "Medium9"), name, and total_row (bool:
+///             the range's last row is the totals row; default: False). Ranges must
+///             not overlap each other or the table_style table or autofilter range.
+///     categorical_validation: Add a dropdown list validation over each categorical
+///                             column (polars Categorical/Enum, pandas category) listing
+///                             its categories (default: False). Columns already covered
+///                             by validations keep those. Categorical values are always
+///                             written as their label text.
+///     integer_like_floats: Write whole-number values of float columns with an integer
+///                          number format ("0"), so e.g. 3.0 displays as 3 (default: False).
+///                          Fractional values, other dtypes, and columns with a
+///                          column_formats entry are unaffected.
+///     columns: List of column names and/or 0-based positions to write, in output order
+///              (default: None, all columns). Every other option (column_formats int keys,
+///              formula_columns, conditional_formats, table_columns, charts, ...) sees only
+///              the selected columns, in the selected order. An empty list writes all
+///              columns; an unknown name, an out-of-range index, or a column selected twice
+///              raises ValueError.
+///     skip_rows: Number of leading DataFrame rows to leave out (default: 0).
+///     max_rows: Write at most this many DataFrame rows, after skip_rows (default: None,
+///               no limit). Tables, autofilters, conditional formats, validations, and
+///               the returned row count all cover only the written rows.
+///     transpose: Write the DataFrame transposed (default: False): column names go down
+///                the first column and each DataFrame row becomes a sheet column. Cell
+///                types are still detected per value and column_formats still apply to
+///                each original column's cells. Features keyed by column name
+///                (table_style, autofilter, table_columns, formula_columns,
+///                conditional_formats, validations, categorical_validation,
+///                summary_row) don't map onto transposed data and are skipped with a
+///                RuntimeWarning.
+///                Cannot be combined with constant_memory.
+///     include_index: Write the pandas index as the leading column(s), headed by the index
+///                    name ("index" when unnamed), as DataFrame.reset_index() would
+///                    (default: False). A MultiIndex becomes one column per level. Options
+///                    that address written columns (column_formats index keys,
+///                    column_widths, charts) count the index columns; name-keyed options
+///                    can target them by name. Ignored for polars, which has no index.
+///     cell_format: Format dict applied to every data cell without a column_formats entry
+///                  (default: None), e.g. a font and borders for the whole sheet. Same keys
+///                  as column_formats. Date, time, and other typed cells keep their number
+///                  format on top of it; a matching column_formats entry replaces it for
+///                  that column. Header cells use header_format instead.
+///     banded_rows: Pair of fill colors, e.g. ("#FFFFFF", "#F2F2F2"), alternated across
+///                  data rows starting with the first (default: None). Works without a
+///                  table; cell_format and column_formats keep their other properties on
+///                  top of the fill, and a column's own bg_color wins. Formula columns
+///                  are not banded. With transpose=True the bands run across columns.
+///     summary_row: Dict mapping column names to "sum", "mean", "min", "max", or "count"
+///                  (default: None). Writes a bold row of SUM/AVERAGE/MIN/MAX/COUNTA
+///                  formulas over each column's data directly below the written block
+///                  (after a table's totals row), with the value computed from the
+///                  DataFrame cached for readers that don't recalculate. The returned row
+///                  count includes it. Needs at least one data row.
+///     default_col_width: Width of every column on the sheet, in characters (default: None,
+///                        Excel's 8.43). column_widths and autofit override it for the
+///                        columns they set.
+///     default_row_height: Height of every row on the sheet, in points (default: None,
+///                         Excel's 15). row_heights overrides it for the rows it sets.
+///     protect: Protect the sheet against edits (default: None). True locks every cell;
+///              a dict takes an optional "password" and "unlocked_columns", a list of
+///              column names or wildcard patterns whose data cells stay editable. A
+///              pattern matching no column raises ValueError. Validations still apply
+///              to the unlocked cells.
+///     auto_hyperlinks: Write string values starting with "http://", "https://", or
+///                      "mailto:" as clickable hyperlinks (default: False). Values with
+///                      whitespace or longer than Excel's 2079-character URL limit stay
+///                      plain text.
+///     link_format: Format dict for the cells auto_hyperlinks turns into links (default:
+///                  None, Excel's blue underlined hyperlink style over the cell's usual
+///                  format). Same keys as column_formats.
+///     auto_rich_text_columns: List of column names or wildcard patterns whose string
+///                             values are parsed for inline markup, **bold** and
+///                             *italic*, and written as rich text (default: None).
+///                             An unpaired marker or a backslash-escaped \* stays
+///                             literal. A pattern matching no column raises ValueError.
+///     infer_string_types: Write string values that read as numbers, booleans, dates,
+///                         datetimes, or times as those types, with the same detection
+///                         csv_to_xlsx uses (default: False). Useful for all-string
+///                         DataFrames such as read_csv(dtype=str) output. Leading zeros
+///                         are not kept: "007" becomes 7.
+///     bool_mode: How booleans are written (default: "boolean", Excel TRUE/FALSE):
+///                "yes_no" writes the text "Yes"/"No", "one_zero" the numbers 1/0, and a
+///                (true_text, false_text) pair of strings writes custom labels. Column
+///                formats and cell_format still apply.
+///     text_columns: List of column names and/or 0-based positions among the written
+///                   columns whose values are written as text with Excel's "@" format
+///                   (default: None). Each value is written as its str(), skipping type
+///                   detection and infer_string_types, so "01234" keeps its leading zero.
+///                   Nulls stay empty cells. An unknown name or out-of-range position
+///                   raises ValueError.
+///     sanitize_strings: Strip a leading byte order mark (U+FEFF) and replace control
+///                       characters XML forbids (below U+0020 except tab, line feed, and
+///                       carriage return) with spaces in string values and header labels
+///                       before writing (default: False). This keeps stray NUL or other
+///                       control bytes from leaving Excel with a file it offers to repair.
+///     calc_mode: Workbook calculation mode: "auto" (default), "manual", or
+///                "auto_except_tables" (automatic except for data tables).
+///     force_recalc: Have Excel recalculate every formula when the file is opened
+///                   (default: True). False makes Excel trust the cached formula
+///                   values, such as those set by formula_result.
+///     properties: Dict of document properties: title, subject, author, manager, company,
+///                 keywords, comments, category, and created (an ISO 8601 date or
+///                 datetime string, taken as UTC without an offset) (default: None).
+///     custom_properties: Dict of custom document properties mapping a name to a str,
+///                        int, float, bool, date, or datetime value (default: None).
+///     deterministic: Write byte-identical output for identical inputs (default: False).
+///                    The created timestamp is fixed at 1980-01-01 instead of the time
+///                    of writing, unless properties sets created.
+///     progress_callback: Callable invoked with the number of data rows written so far
+///                        after every progress_every rows (default: None). It runs with
+///                        the GIL held; an exception it raises is reported as a
+///                        RuntimeWarning and the write continues. Returning False
+///                        cancels the write: nothing is saved and RuntimeError is raised.
+///     progress_every: Rows between progress_callback calls (default: 100000). Must be
+///                     at least 1.
 ///
 /// Returns:
-///     Tuple of (rows, columns) written to the Excel file
+///     Tuple of (rows, columns): one past the last written row and column, counted
+///     from A1, so the next block can start at start_row=rows. Without an offset
+///     this is simply the number of rows and columns written.
 ///
 /// Raises:
 ///     ValueError: If the conversion fails
+///     RuntimeError: If progress_callback returned False ("write cancelled")
 ///
 /// Example:
 ///     >>> import xlsxturbo
@@ -364,9 +1233,9 @@ fn csv_to_xlsx(
     output_path,
     sheet_name = "Sheet1",
     header = true,
-    autofit = false,
+    autofit = None,
     table_style = None,
-    freeze_panes = false,
+    freeze_panes = None,
     column_widths = None,
     table_name = None,
     header_format = None,
@@ -386,7 +1255,63 @@ fn csv_to_xlsx(
     charts = None,
     defined_names = None,
     cells = None,
+    cell_overrides = None,
     sparklines = None,
+    date_format = None,
+    datetime_format = None,
+    time_format = None,
+    nan_policy = "empty",
+    large_int_mode = "string",
+    long_string_mode = "error",
+    start_row = 0,
+    start_col = 0,
+    autofilter = false,
+    conditional_format_mode = "all_matches",
+    freeze_top_cell = None,
+    split_panes = None,
+    show_gridlines = true,
+    show_row_col_headers = true,
+    zoom = None,
+    view = "normal",
+    right_to_left = false,
+    outline_below = true,
+    outline_right = true,
+    tab_color = None,
+    page_setup = None,
+    print_area = None,
+    background_image = None,
+    row_groups = None,
+    col_groups = None,
+    table_columns = None,
+    table_options = None,
+    tables = None,
+    categorical_validation = false,
+    integer_like_floats = false,
+    columns = None,
+    skip_rows = 0,
+    max_rows = None,
+    transpose = false,
+    include_index = false,
+    cell_format = None,
+    banded_rows = None,
+    summary_row = None,
+    default_col_width = None,
+    default_row_height = None,
+    protect = None,
+    auto_hyperlinks = false,
+    auto_rich_text_columns = None,
+    infer_string_types = false,
+    bool_mode = None,
+    text_columns = None,
+    sanitize_strings = false,
+    link_format = None,
+    calc_mode = "auto",
+    force_recalc = true,
+    properties = None,
+    custom_properties = None,
+    deterministic = false,
+    progress_callback = None,
+    progress_every = 100_000,
 ))]
 #[allow(clippy::too_many_arguments)]
 fn df_to_xlsx<'py>(
@@ -395,9 +1320,9 @@ fn df_to_xlsx<'py>(
     output_path: &Bound<'py, PyAny>,
     sheet_name: &str,
     header: bool,
-    autofit: bool,
+    autofit: Option<&Bound<'py, PyAny>>,
     table_style: Option<&str>,
-    freeze_panes: bool,
+    freeze_panes: Option<&Bound<'py, PyAny>>,
     column_widths: Option<&Bound<'py, PyAny>>,
     table_name: Option<String>,
     header_format: Option<&Bound<'py, PyAny>>,
@@ -417,17 +1342,76 @@ fn df_to_xlsx<'py>(
     charts: Option<&Bound<'py, PyAny>>,
     defined_names: Option<HashMap<String, String>>,
     cells: Option<&Bound<'py, PyAny>>,
+    cell_overrides: Option<&Bound<'py, PyAny>>,
     sparklines: Option<&Bound<'py, PyAny>>,
+    date_format: Option<String>,
+    datetime_format: Option<String>,
+    time_format: Option<String>,
+    nan_policy: &str,
+    large_int_mode: &str,
+    long_string_mode: &str,
+    start_row: u32,
+    start_col: u16,
+    autofilter: bool,
+    conditional_format_mode: &str,
+    freeze_top_cell: Option<&Bound<'py, PyAny>>,
+    split_panes: Option<&Bound<'py, PyAny>>,
+    show_gridlines: bool,
+    show_row_col_headers: bool,
+    zoom: Option<u16>,
+    view: &str,
+    right_to_left: bool,
+    outline_below: bool,
+    outline_right: bool,
+    tab_color: Option<String>,
+    page_setup: Option<&Bound<'py, PyAny>>,
+    print_area: Option<String>,
+    background_image: Option<&Bound<'py, PyAny>>,
+    row_groups: Option<&Bound<'py, PyAny>>,
+    col_groups: Option<&Bound<'py, PyAny>>,
+    table_columns: Option<&Bound<'py, PyAny>>,
+    table_options: Option<&Bound<'py, PyAny>>,
+    tables: Option<&Bound<'py, PyAny>>,
+    categorical_validation: bool,
+    integer_like_floats: bool,
+    columns: Option<&Bound<'py, PyAny>>,
+    skip_rows: usize,
+    max_rows: Option<usize>,
+    transpose: bool,
+    include_index: bool,
+    cell_format: Option<&Bound<'py, PyAny>>,
+    banded_rows: Option<&Bound<'py, PyAny>>,
+    summary_row: Option<&Bound<'py, PyAny>>,
+    default_col_width: Option<f64>,
+    default_row_height: Option<f64>,
+    protect: Option<&Bound<'py, PyAny>>,
+    auto_hyperlinks: bool,
+    auto_rich_text_columns: Option<Vec<String>>,
+    infer_string_types: bool,
+    bool_mode: Option<&Bound<'py, PyAny>>,
+    text_columns: Option<&Bound<'py, PyAny>>,
+    sanitize_strings: bool,
+    link_format: Option<&Bound<'py, PyAny>>,
+    calc_mode: &str,
+    force_recalc: bool,
+    properties: Option<HashMap<String, String>>,
+    custom_properties: Option<&Bound<'py, PyAny>>,
+    deterministic: bool,
+    progress_callback: Option<&Bound<'py, PyAny>>,
+    progress_every: usize,
 ) -> PyResult<(u32, u16)> {
-    let output_path = path_arg_to_string(output_path, "output_path")?;
+    let output = output_target(output_path, "output_path")?;
     let opts = extract_options(&RawOptions {
         column_widths,
         header_format,
+        cell_format,
         column_formats,
         conditional_formats,
         formula_columns,
         merged_ranges,
         hyperlinks,
+        row_groups,
+        col_groups,
         comments,
         validations,
         rich_text,
@@ -437,24 +1421,195 @@ fn df_to_xlsx<'py>(
         charts,
         sparklines,
         cells,
+        cell_overrides,
+        link_format,
     })?;
 
-    convert_dataframe_to_xlsx(
-        py,
-        df,
-        &output_path,
-        sheet_name,
-        header,
-        autofit,
+    let autofit = autofit
+        .map(|v| extract_autofit(v, "autofit"))
+        .transpose()?
+        .unwrap_or_default();
+    let freeze_panes = freeze_panes
+        .map(|v| extract_freeze_panes(v, "freeze_panes"))
+        .transpose()?
+        .unwrap_or_default();
+    let freeze_top_cell = freeze_top_cell
+        .map(|v| extract_cell_ref(v, "freeze_top_cell"))
+        .transpose()?;
+    let split_panes = split_panes
+        .map(|v| extract_cell_ref(v, "split_panes"))
+        .transpose()?;
+    let banded_rows = banded_rows
+        .map(|v| extract_banded_rows(v, "banded_rows"))
+        .transpose()?;
+    let bool_mode = bool_mode
+        .map(|v| extract_bool_mode(v, "bool_mode"))
+        .transpose()?
+        .unwrap_or_default();
+    let text_columns = text_columns
+        .map(|v| extract_column_selection(v, "text_columns"))
+        .transpose()?;
+    let summary_row = summary_row
+        .map(|v| extract_summary_row(v, "summary_row"))
+        .transpose()?;
+    let protect = protect
+        .map(|v| extract_protect(v, "protect"))
+        .transpose()?
+        .flatten();
+    let page_setup = page_setup
+        .map(|v| extract_page_setup(v, "page_setup"))
+        .transpose()?;
+    let background_image = background_image
+        .map(|v| extract_background_image(v, "background_image"))
+        .transpose()?;
+    let table_columns = table_columns
+        .map(|v| extract_table_columns(v, "table_columns"))
+        .transpose()?;
+    let table_options = table_options
+        .map(|v| extract_table_options(v, "table_options"))
+        .transpose()?;
+    let tables = tables.map(|v| extract_tables(v, "tables")).transpose()?;
+    let columns = columns
+        .map(|v| extract_column_selection(v, "columns"))
+        .transpose()?;
+    let progress_callback = extract_progress_callback(progress_callback, progress_every)?;
+    let calc = parse_calc_settings(calc_mode, force_recalc)
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let properties = extract_doc_properties(properties.as_ref(), custom_properties, deterministic)?;
+    let sheet_names = [sheet_name.to_string()];
+    let cancelled = Cell::new(false);
+
+    let config = WriteConfig {
+        include_header: header,
+        autofit: &autofit,
         table_style,
         freeze_panes,
-        table_name.as_deref(),
-        row_heights.as_ref(),
+        freeze_top_cell,
+        split_panes,
+        table_name: table_name.as_deref(),
+        table_columns: table_columns.as_deref(),
+        table_options,
+        tables: tables.as_deref(),
+        row_heights: row_heights.as_ref(),
         constant_memory,
+        date_format: date_format.as_deref().unwrap_or(DATE_NUM_FORMAT),
+        datetime_format: datetime_format.as_deref().unwrap_or(DATETIME_NUM_FORMAT),
+        time_format: time_format.as_deref().unwrap_or(TIME_NUM_FORMAT),
+        nan_policy: parse_nan_policy(nan_policy)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?,
+        large_int_mode: parse_large_int_mode(large_int_mode)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?,
+        long_string_mode: parse_long_string_mode(long_string_mode)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?,
+        start_row,
+        start_col,
+        autofilter,
+        conditional_format_mode: parse_conditional_format_mode(conditional_format_mode)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?,
+        show_gridlines,
+        show_row_col_headers,
+        zoom,
+        view: parse_sheet_view(view).map_err(pyo3::exceptions::PyValueError::new_err)?,
+        right_to_left,
+        outline_below,
+        outline_right,
+        tab_color: tab_color.as_deref(),
+        page_setup: page_setup.as_ref(),
+        print_area: print_area.as_deref(),
+        background_image: background_image.as_ref(),
+        categorical_validation,
+        integer_like_floats,
+        columns: columns.as_deref(),
+        skip_rows,
+        max_rows,
+        transpose,
+        include_index,
+        banded_rows: banded_rows.as_ref().map(|(a, b)| (a.as_str(), b.as_str())),
+        summary_row: summary_row.as_ref(),
+        default_col_width,
+        default_row_height,
+        protect: protect.as_ref(),
+        auto_hyperlinks,
+        auto_rich_text_columns: auto_rich_text_columns.as_deref(),
+        infer_string_types,
+        bool_mode: &bool_mode,
+        text_columns: text_columns.as_deref(),
+        sanitize_strings,
+        sheet_names: &sheet_names,
+        progress: progress_callback.as_ref().map(|callback| Progress {
+            callback,
+            every: progress_every,
+            cancelled: &cancelled,
+        }),
+    };
+
+    let df = collect_if_lazy(df.clone()).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    convert_dataframe_to_xlsx(
+        py,
+        &df,
+        &output,
+        sheet_name,
+        &config,
         &opts,
         defined_names.as_ref(),
+        properties.as_ref(),
+        calc,
     )
-    .map_err(pyo3::exceptions::PyValueError::new_err)
+    .map_err(|e| {
+        if cancelled.get() {
+            pyo3::exceptions::PyRuntimeError::new_err(e)
+        } else {
+            pyo3::exceptions::PyValueError::new_err(e)
+        }
+    })
+}
+
+/// Read a worksheet of an XLSX file back into rows of typed values.
+///
+/// Useful for checking what a write produced. Row 0 is Excel row 1 and
+/// column 0 is column A, so rows[r][c] is the cell at that position; empty
+/// cells, including leading empty rows and columns, are None. Every row has
+/// the same length, up to the last column that holds a value.
+///
+/// Args:
+///     path: Path to the XLSX file
+///     sheet_name: Name of the worksheet to read (default: the first sheet)
+///
+/// Returns:
+///     List of rows, each a list of cell values: float for numbers (Excel
+///     stores every number as a float, so 1 reads back as 1.0), bool, str,
+///     datetime.datetime for dates and datetimes, datetime.time for a time of
+///     day, datetime.timedelta for a duration, None for an empty cell, and the
+///     error text (e.g. "#N/A") for an error cell. Formula cells read as their
+///     cached result.
+///
+/// Raises:
+///     ValueError: If the file can't be read as XLSX or sheet_name matches no sheet
+///
+/// Example:
+///     >>> import xlsxturbo
+///     >>> xlsxturbo.df_to_xlsx(df, "out.xlsx")
+///     >>> rows = xlsxturbo.read_xlsx("out.xlsx")
+///     >>> rows[0]  # the header row
+///     ['name', 'value']
+#[pyfunction]
+#[pyo3(signature = (path, sheet_name = None))]
+fn read_xlsx<'py>(
+    py: Python<'py>,
+    path: &Bound<'py, PyAny>,
+    sheet_name: Option<&str>,
+) -> PyResult<Vec<Vec<Bound<'py, PyAny>>>> {
+    let path = path_arg_to_string(path, "path")?;
+    let rows = py
+        .detach(|| read::read_sheet(&path, sheet_name))
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|value| read::data_to_py(py, value))
+                .collect()
+        })
+        .collect()
 }
 
 /// Get the version of the xlsxturbo library
@@ -473,30 +1628,53 @@ fn version() -> &'static str {
 ///     sheets: List of tuples. Each tuple can be:
 ///             - (DataFrame, sheet_name) - uses global defaults
 ///             - (DataFrame, sheet_name, options_dict) - per-sheet overrides
+///             A polars LazyFrame in place of a DataFrame is collected first.
 ///             Options dict keys: header, autofit, table_style, freeze_panes,
 ///             column_widths, row_heights, table_name, header_format, column_formats,
 ///             conditional_formats, formula_columns, merged_ranges, hyperlinks,
 ///             comments, validations, rich_text, images, checkboxes, textboxes, charts,
-///             sparklines, cells
-///     output_path: Path for the output XLSX file
+///             sparklines, cells, cell_overrides, date_format, datetime_format,
+///             time_format, nan_policy, large_int_mode, start_row, start_col,
+///             autofilter, conditional_format_mode, freeze_top_cell, split_panes,
+///             show_gridlines, show_row_col_headers, zoom, view, right_to_left,
+///             outline_below, outline_right, tab_color, page_setup, print_area,
+///             background_image, row_groups, col_groups, table_columns, table_options,
+///             tables, categorical_validation, integer_like_floats, columns, skip_rows,
+///             max_rows, transpose, include_index, cell_format, banded_rows,
+///             summary_row, default_col_width, default_row_height, protect,
+///             auto_hyperlinks, auto_rich_text_columns, infer_string_types, bool_mode,
+///             text_columns, sanitize_strings, link_format, visible (per-sheet only:
+///             True, False to hide the tab, or "very_hidden"; at least one sheet must
+///             stay visible), active_cell (per-sheet only: cell reference like "B2"
+///             selected when the sheet is opened)
+///     output_path: Path for the output XLSX file, or a binary file-like object with
+///                  a write() method (e.g. io.BytesIO or an open 'wb' file)
 ///     header: Include column names as header row (default: True)
 ///     autofit: Automatically adjust column widths to fit content (default: False)
-///              Combined with column_widths: explicit widths win for the columns
-///              they name; every other column is still autofitted (rather than
-///              left at Excel's default width). Add an "_all" entry in
-///              column_widths to cap the autofit width instead of overriding it.
+///              Combined with column_widths: each autofitted column is capped at
+///              its own column_widths entry, else at "_all", rather than set to it.
+///              A list of column names and/or 0-based positions autofits only
+///              those columns; the rest keep column_widths or Excel's default.
+///              An autofitted column is at least as wide as its header label plus
+///              two characters, so bold headers are not clipped.
 ///     table_style: Apply Excel table formatting with this style name (default: None).
 ///                  Styles: "Light1"-"Light21", "Medium1"-"Medium28", "Dark1"-"Dark11", "None"
 ///                  Tables include autofilter dropdowns and banded rows.
-///     freeze_panes: Freeze the header row for easier scrolling (default: False)
+///                  With header=False the table is created without a header row.
+///     freeze_panes: Freeze panes for easier scrolling (default: False). True freezes the
+///                   header row; a cell reference like "C2" or a 0-based (row, col) tuple
+///                   freezes everything above and left of that cell ("C2" == (1, 2) keeps
+///                   row 1 and columns A-B in view). Positions are absolute, not offset
+///                   by start_row/start_col. A {"rows": N, "cols": M} dict freezes the
+///                   first N rows and M columns of the written block, counted from
+///                   start_row/start_col (missing keys count as 0).
 ///     column_widths: Dict mapping column index or "_all" to width in characters (default: None)
 ///                    Example: {0: 20, "_all": 50} sets col A to 20, caps others at 50. An
 ///                    integer key must be a non-negative index within Excel's column range
 ///                    (0..=16383); a negative key, a key beyond 16383, or a
-///                    non-integer/non-"_all" key raises. With autofit=True and no "_all"
-///                    key: listed columns get the explicit width, unlisted columns are
-///                    autofitted. With autofit=True and an "_all" key: "_all" caps the
-///                    autofit width for unlisted columns instead of overriding it.
+///                    non-integer/non-"_all" key raises. With autofit, the widths of
+///                    autofitted columns are maximums: each is fitted to its content,
+///                    then capped at its own entry, else at "_all".
 ///     table_name: Name for Excel table (requires table_style; default: auto-generated).
 ///         Effective names must be unique across the workbook after sanitization.
 ///     header_format: Dict with header cell formatting options (default: None)
@@ -504,30 +1682,42 @@ fn version() -> &'static str {
 ///     row_heights: Dict mapping row index (0-based) to height in points (default: None)
 ///     constant_memory: Use constant memory mode for large files (default: False).
 ///                      Emits a RuntimeWarning and disables: table_style, freeze_panes,
-///                      row_heights, autofit, column_widths with autofit cap, conditional_formats,
-///                      formula_columns, merged_ranges, hyperlinks, comments, validations,
-///                      rich_text, images, checkboxes, textboxes, charts, sparklines, and cells.
+///                      autofilter, row_heights, autofit, column_widths with autofit cap, conditional_formats,
+///                      formula_columns, merged_ranges, hyperlinks, row_groups, col_groups,
+///                      comments, validations, categorical_validation, rich_text, images,
+///                      checkboxes, textboxes, charts, sparklines, cells, and cell_overrides.
 ///                      Plain column_widths, header_format, and column_formats remain supported.
-///     column_formats: Dict mapping column name patterns to format dicts (default: None)
+///     column_formats: Dict mapping column name patterns or column indices to format dicts (default: None)
 ///                     Supports wildcards: "prefix*", "*suffix", "*contains*", or exact match.
+///                     An int key (e.g. 2) targets that DataFrame column by position and
+///                     takes precedence over name patterns.
 ///                     Format options: bg_color, font_color, num_format, bold, italic, underline, border.
 ///                     Example: {"price_*": {"bg_color": "#D6EAF8", "num_format": "$#,##0.00"}}
 ///     conditional_formats: Dict mapping column names to conditional format configs (default: None)
 ///                          Supported types: 2_color_scale, 3_color_scale, data_bar, icon_set, cell
 ///                          Example: {"score": {"type": "2_color_scale", "min_color": "#FF0000", "max_color": "#00FF00"}}
 ///     formula_columns: Dict mapping column names to Excel formula templates (default: None).
-///                      Use {row} as placeholder for the current row number.
-///     merged_ranges: List of merge specs: (range, text) or (range, text, format_dict) (default: None).
-///     hyperlinks: List of link specs: (cell_ref, url) or (cell_ref, url, display_text) (default: None).
+///                      Use {row} as placeholder for the current row number, and
+///                      {col_name} for that column's cell in the current row.
+///                      A dict value adds "formula_type" ("regular", "dynamic", or
+///                      "array") and, for arrays, a target "range". A "position"
+///                      (index or "after:col_name") inserts it among the data columns,
+///                      and a "formula_result" (constant or callable(row_index))
+///                      caches its value.
+///     merged_ranges: List of merge specs: (range, value) or (range, value, format_dict) (default: None).
+///                    Non-string values keep their type, as for df_to_xlsx.
+///     hyperlinks: List of link specs: (cell_ref, url), (cell_ref, url, display_text), or
+///                 (cell_ref, url, display_text, tooltip) (default: None).
+///                 An "internal:Sheet!A1" url may target any sheet in the list.
 ///     comments: Dict mapping cell refs to note text or config dict (default: None).
 ///     validations: Dict mapping column names/patterns to validation configs (default: None).
-///                  Types: list, whole_number, decimal, text_length
+///                  Types: list, whole_number, decimal, text_length, date, time
 ///                  (aliases accepted, e.g. integer/number/length — see README).
 ///                  For "whole_number", min/max are bounded to the i32 range
 ///                  (-2147483648..=2147483647); a value outside that range raises
 ///                  ValueError naming the field and range.
 ///     rich_text: Dict mapping cell refs to lists of formatted text segments (default: None).
-///     images: Dict mapping cell refs to image paths or config dicts (default: None).
+///     images: Dict mapping cell refs to image paths, image bytes, or config dicts (default: None).
 ///     checkboxes: Dict mapping cell refs to checkbox state (bool) or config dict (default: None).
 ///                 Example: {"A1": True} or {"A1": {"checked": True, "format": {"bg_color": "#C6EFCE"}}}
 ///     textboxes: Dict mapping cell refs to floating text shapes (default: None).
@@ -536,7 +1726,8 @@ fn version() -> &'static str {
 ///             "data_range"/"values_range"/"values" and "categories_range"/"categories"
 ///             (including a chart-level fallback used by series without their own)
 ///             must include a sheet name (e.g. "Sheet1!$B$2:$B$10"); a bare range
-///             raises ValueError.
+///             raises ValueError. "categories_col"/"value_cols" name columns of the
+///             sheet's own DataFrame instead.
 ///     sparklines: Dict mapping a location ref to a sparkline (mini in-cell chart) config (default: None).
 ///                 Range key (e.g. "D2:D10") makes a grouped sparkline; single cell makes one.
 ///                 "range" must be sheet-qualified, e.g. "Sheet1!A2:C10".
@@ -547,6 +1738,111 @@ fn version() -> &'static str {
 ///            Values can be simple (str, int, float, bool) or dicts with "value" and optional
 ///            "num_format", "align_horizontal", "align_vertical", and "wrap_text".
 ///            Example: {"B9": "Label", "D6": {"value": "934728173849", "num_format": "@"}}
+///     cell_overrides: Dict mapping cell refs to a value and/or format written after the
+///                     data, as for df_to_xlsx (default: None).
+///     date_format: Excel number format for date cells (default: "yyyy-mm-dd").
+///     datetime_format: Excel number format for datetime cells (default: "yyyy-mm-dd hh:mm:ss").
+///     time_format: Excel number format for datetime.time cells (default: "hh:mm:ss").
+///     nan_policy: How NaN/Inf values are written: "empty", "string", or "error" (default: "empty").
+///     large_int_mode: How integers beyond 2^53 are written: "string", "number", or "text"
+///                     (default: "string").
+///     long_string_mode: How strings beyond Excel's 32,767-character cell limit are
+///                       written: "error", "truncate", or "split" (default: "error").
+///     start_row: Zero-based sheet row where each sheet's data begins (default: 0).
+///     start_col: Zero-based sheet column where each sheet's data begins (default: 0).
+///     autofilter: Add autofilter dropdowns over each sheet's data without a table (default: False).
+///     conditional_format_mode: How conditional_formats patterns that match the same column
+///                              combine: "all_matches" (rules stack) or "first_match_wins"
+///                              (first pattern in dict order only) (default: "all_matches").
+///     active_sheet: Sheet that is active (shown) when the workbook opens, as a 0-based
+///                   index into sheets or a sheet name (default: None, the first visible sheet).
+///                   Must not be a hidden sheet.
+///     freeze_top_cell: Top-left cell of the scrollable pane when panes are frozen or split,
+///                      e.g. "A100" (default: None). Has no effect without freeze_panes or
+///                      split_panes.
+///     split_panes: Cell reference like "B3" where every sheet's window is split into
+///                  resizable, unfrozen panes (default: None). Mutually exclusive with
+///                  freeze_panes; a sheet's own freeze_panes replaces the global split.
+///     show_gridlines: Show the worksheet gridlines on screen (default: True).
+///     show_row_col_headers: Show the row numbers and column letters on screen
+///                           (default: True).
+///     zoom: Zoom level of every sheet as a percentage from 10 to 400 (default: None).
+///     view: View every sheet opens in: "normal", "page_layout", or "page_break"
+///           (default: "normal"). Usually set per sheet instead.
+///     right_to_left: Show every sheet right to left (default: False). Only the view is
+///                    mirrored; the data keeps its column order.
+///     outline_below: Put row group buttons below each group (default: True); False puts
+///                    them above.
+///     outline_right: Put column group buttons right of each group (default: True); False
+///                    puts them on the left.
+///     tab_color: Color of every sheet tab, as '#RRGGBB' or a named color (default: None).
+///                Usually set per sheet instead.
+///     page_setup: Dict of print layout settings for every sheet: orientation, fit_to_pages,
+///                 paper_size, margins, header/footer, as for df_to_xlsx (default: None).
+///     print_area: Cell range to print on every sheet, e.g. "A1:F50" (default: None).
+///                 Usually set per sheet instead.
+///     background_image: Image path or bytes tiled behind the cells of every sheet, as for
+///                       df_to_xlsx (default: None).
+///     row_groups: List of (first_row, last_row, level[, collapsed]) row outline groups
+///                 for every sheet, as for df_to_xlsx (default: None).
+///     col_groups: List of (first_col, last_col, level[, collapsed]) column outline groups
+///                 for every sheet, as for df_to_xlsx (default: None).
+///     table_columns: List of per-column dicts (header, total_function, total_label) for
+///                    every sheet's table, as for df_to_xlsx (default: None).
+///     table_options: Dict of table look toggles (banded_rows, banded_columns,
+///                    first_column, last_column) for every sheet's table, as for
+///                    df_to_xlsx (default: None).
+///     tables: List of extra table dicts (range, style, name, total_row) for every
+///             sheet, as for df_to_xlsx (default: None). Table names must be unique
+///             across the workbook.
+///     categorical_validation: Add a dropdown of its categories over each categorical
+///                             column on every sheet, as for df_to_xlsx (default: False).
+///     integer_like_floats: Write whole-number values of float columns with an integer
+///                          number format on every sheet, as for df_to_xlsx (default: False).
+///     columns: List of column names and/or 0-based positions to write on every sheet,
+///              as for df_to_xlsx (default: None, all columns).
+///     skip_rows: Number of leading DataFrame rows to leave out on every sheet (default: 0).
+///     max_rows: Write at most this many DataFrame rows per sheet, after skip_rows
+///               (default: None, no limit).
+///     transpose: Write every sheet transposed, as for df_to_xlsx (default: False).
+///     include_index: Write each pandas DataFrame's index as its leading column(s), as for
+///                    df_to_xlsx (default: False).
+///     cell_format: Format dict applied to every data cell without a column_formats entry,
+///                  as for df_to_xlsx (default: None).
+///     banded_rows: Pair of fill colors alternated across data rows, as for df_to_xlsx
+///                  (default: None).
+///     summary_row: Dict mapping column names to an aggregate written in a bold row
+///                  below the data, as for df_to_xlsx (default: None).
+///     default_col_width: Width of every column on each sheet, as for df_to_xlsx
+///                        (default: None).
+///     default_row_height: Height of every row on each sheet, as for df_to_xlsx
+///                         (default: None).
+///     protect: Protect each sheet against edits, as for df_to_xlsx (default: None).
+///              A per-sheet False leaves that sheet unprotected.
+///     auto_hyperlinks: Write URL and mailto: string values as hyperlinks on every sheet,
+///                      as for df_to_xlsx (default: False).
+///     link_format: Format dict for auto_hyperlinks links, as for df_to_xlsx
+///                  (default: None).
+///     auto_rich_text_columns: Columns whose **bold**/*italic* markup is written as
+///                             rich text on every sheet, as for df_to_xlsx (default: None).
+///     infer_string_types: Write string values that read as numbers, booleans, or dates
+///                         as those types on every sheet, as for df_to_xlsx
+///                         (default: False).
+///     bool_mode: How booleans are written on every sheet: "boolean", "yes_no",
+///                "one_zero", or a (true_text, false_text) pair, as for df_to_xlsx
+///                (default: "boolean").
+///     text_columns: Columns written as text on every sheet, as for df_to_xlsx
+///                   (default: None).
+///     sanitize_strings: Strip byte order marks and illegal control characters from
+///                       string values on every sheet, as for df_to_xlsx (default: False).
+///     calc_mode: Workbook calculation mode, as for df_to_xlsx (default: "auto").
+///     force_recalc: Recalculate every formula when the file is opened, as for
+///                   df_to_xlsx (default: True).
+///     properties: Dict of document properties, as for df_to_xlsx (default: None).
+///     custom_properties: Dict of custom document properties, as for df_to_xlsx
+///                        (default: None).
+///     deterministic: Write byte-identical output for identical inputs, as for
+///                    df_to_xlsx (default: False).
 ///
 /// Returns:
 ///     List of (rows, columns) tuples for each sheet
@@ -573,9 +1869,9 @@ fn version() -> &'static str {
     sheets,
     output_path,
     header = true,
-    autofit = false,
+    autofit = None,
     table_style = None,
-    freeze_panes = false,
+    freeze_panes = None,
     column_widths = None,
     table_name = None,
     header_format = None,
@@ -595,7 +1891,62 @@ fn version() -> &'static str {
     charts = None,
     defined_names = None,
     cells = None,
+    cell_overrides = None,
     sparklines = None,
+    date_format = None,
+    datetime_format = None,
+    time_format = None,
+    nan_policy = "empty",
+    large_int_mode = "string",
+    long_string_mode = "error",
+    start_row = 0,
+    start_col = 0,
+    autofilter = false,
+    conditional_format_mode = "all_matches",
+    active_sheet = None,
+    freeze_top_cell = None,
+    split_panes = None,
+    show_gridlines = true,
+    show_row_col_headers = true,
+    zoom = None,
+    view = "normal",
+    right_to_left = false,
+    outline_below = true,
+    outline_right = true,
+    tab_color = None,
+    page_setup = None,
+    print_area = None,
+    background_image = None,
+    row_groups = None,
+    col_groups = None,
+    table_columns = None,
+    table_options = None,
+    tables = None,
+    categorical_validation = false,
+    integer_like_floats = false,
+    columns = None,
+    skip_rows = 0,
+    max_rows = None,
+    transpose = false,
+    include_index = false,
+    cell_format = None,
+    banded_rows = None,
+    summary_row = None,
+    default_col_width = None,
+    default_row_height = None,
+    protect = None,
+    auto_hyperlinks = false,
+    auto_rich_text_columns = None,
+    infer_string_types = false,
+    bool_mode = None,
+    text_columns = None,
+    sanitize_strings = false,
+    link_format = None,
+    calc_mode = "auto",
+    force_recalc = true,
+    properties = None,
+    custom_properties = None,
+    deterministic = false,
 ))]
 #[allow(clippy::too_many_arguments)]
 fn dfs_to_xlsx<'py>(
@@ -603,9 +1954,9 @@ fn dfs_to_xlsx<'py>(
     sheets: Vec<Bound<'py, PyAny>>,
     output_path: &Bound<'py, PyAny>,
     header: bool,
-    autofit: bool,
+    autofit: Option<&Bound<'py, PyAny>>,
     table_style: Option<&str>,
-    freeze_panes: bool,
+    freeze_panes: Option<&Bound<'py, PyAny>>,
     column_widths: Option<&Bound<'py, PyAny>>,
     table_name: Option<String>,
     header_format: Option<&Bound<'py, PyAny>>,
@@ -625,9 +1976,68 @@ fn dfs_to_xlsx<'py>(
     charts: Option<&Bound<'py, PyAny>>,
     defined_names: Option<HashMap<String, String>>,
     cells: Option<&Bound<'py, PyAny>>,
+    cell_overrides: Option<&Bound<'py, PyAny>>,
     sparklines: Option<&Bound<'py, PyAny>>,
+    date_format: Option<String>,
+    datetime_format: Option<String>,
+    time_format: Option<String>,
+    nan_policy: &str,
+    large_int_mode: &str,
+    long_string_mode: &str,
+    start_row: u32,
+    start_col: u16,
+    autofilter: bool,
+    conditional_format_mode: &str,
+    active_sheet: Option<&Bound<'py, PyAny>>,
+    freeze_top_cell: Option<&Bound<'py, PyAny>>,
+    split_panes: Option<&Bound<'py, PyAny>>,
+    show_gridlines: bool,
+    show_row_col_headers: bool,
+    zoom: Option<u16>,
+    view: &str,
+    right_to_left: bool,
+    outline_below: bool,
+    outline_right: bool,
+    tab_color: Option<String>,
+    page_setup: Option<&Bound<'py, PyAny>>,
+    print_area: Option<String>,
+    background_image: Option<&Bound<'py, PyAny>>,
+    row_groups: Option<&Bound<'py, PyAny>>,
+    col_groups: Option<&Bound<'py, PyAny>>,
+    table_columns: Option<&Bound<'py, PyAny>>,
+    table_options: Option<&Bound<'py, PyAny>>,
+    tables: Option<&Bound<'py, PyAny>>,
+    categorical_validation: bool,
+    integer_like_floats: bool,
+    columns: Option<&Bound<'py, PyAny>>,
+    skip_rows: usize,
+    max_rows: Option<usize>,
+    transpose: bool,
+    include_index: bool,
+    cell_format: Option<&Bound<'py, PyAny>>,
+    banded_rows: Option<&Bound<'py, PyAny>>,
+    summary_row: Option<&Bound<'py, PyAny>>,
+    default_col_width: Option<f64>,
+    default_row_height: Option<f64>,
+    protect: Option<&Bound<'py, PyAny>>,
+    auto_hyperlinks: bool,
+    auto_rich_text_columns: Option<Vec<String>>,
+    infer_string_types: bool,
+    bool_mode: Option<&Bound<'py, PyAny>>,
+    text_columns: Option<&Bound<'py, PyAny>>,
+    sanitize_strings: bool,
+    link_format: Option<&Bound<'py, PyAny>>,
+    calc_mode: &str,
+    force_recalc: bool,
+    properties: Option<HashMap<String, String>>,
+    custom_properties: Option<&Bound<'py, PyAny>>,
+    deterministic: bool,
 ) -> PyResult<Vec<(u32, u16)>> {
-    let output_path = path_arg_to_string(output_path, "output_path")?;
+    let output = output_target(output_path, "output_path")?;
+    let calc = parse_calc_settings(calc_mode, force_recalc)
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let properties = extract_doc_properties(properties.as_ref(), custom_properties, deterministic)?;
+    let active_sheet = active_sheet.map(extract_sheet_selector).transpose()?;
     if sheets.is_empty() {
         return Err(pyo3::exceptions::PyValueError::new_err(
             "dfs_to_xlsx requires at least one sheet, got an empty list",
@@ -636,15 +2046,21 @@ fn dfs_to_xlsx<'py>(
     let mut workbook = Workbook::new();
     let mut stats = Vec::new();
     let mut table_names: HashMap<String, String> = HashMap::new();
+    let mut visibility = Vec::new();
+    let mut hidden_headers = Vec::new();
+    let mut split_sheets = Vec::new();
 
     let opts = extract_options(&RawOptions {
         column_widths,
         header_format,
+        cell_format,
         column_formats,
         conditional_formats,
         formula_columns,
         merged_ranges,
         hyperlinks,
+        row_groups,
+        col_groups,
         comments,
         validations,
         rich_text,
@@ -654,19 +2070,92 @@ fn dfs_to_xlsx<'py>(
         charts,
         sparklines,
         cells,
+        cell_overrides,
+        link_format,
     })?;
 
-    for sheet_tuple in sheets {
-        let (df, sheet_name, sheet_config) = extract_sheet_info(&sheet_tuple)?;
+    let autofit = autofit
+        .map(|v| extract_autofit(v, "autofit"))
+        .transpose()?
+        .unwrap_or_default();
+    let freeze_panes = freeze_panes
+        .map(|v| extract_freeze_panes(v, "freeze_panes"))
+        .transpose()?
+        .unwrap_or_default();
+    let freeze_top_cell = freeze_top_cell
+        .map(|v| extract_cell_ref(v, "freeze_top_cell"))
+        .transpose()?;
+    let split_panes = split_panes
+        .map(|v| extract_cell_ref(v, "split_panes"))
+        .transpose()?;
+    let banded_rows = banded_rows
+        .map(|v| extract_banded_rows(v, "banded_rows"))
+        .transpose()?;
+    let bool_mode = bool_mode
+        .map(|v| extract_bool_mode(v, "bool_mode"))
+        .transpose()?
+        .unwrap_or_default();
+    let text_columns = text_columns
+        .map(|v| extract_column_selection(v, "text_columns"))
+        .transpose()?;
+    let summary_row = summary_row
+        .map(|v| extract_summary_row(v, "summary_row"))
+        .transpose()?;
+    let protect = protect
+        .map(|v| extract_protect(v, "protect"))
+        .transpose()?
+        .flatten();
+    let page_setup = page_setup
+        .map(|v| extract_page_setup(v, "page_setup"))
+        .transpose()?;
+    let background_image = background_image
+        .map(|v| extract_background_image(v, "background_image"))
+        .transpose()?;
+    let table_columns = table_columns
+        .map(|v| extract_table_columns(v, "table_columns"))
+        .transpose()?;
+    let table_options = table_options
+        .map(|v| extract_table_options(v, "table_options"))
+        .transpose()?;
+    let tables = tables.map(|v| extract_tables(v, "tables")).transpose()?;
+    let columns = columns
+        .map(|v| extract_column_selection(v, "columns"))
+        .transpose()?;
+
+    // Read every sheet's options up front, so internal hyperlinks can target
+    // sheets written after theirs
+    let sheet_infos = sheets
+        .iter()
+        .map(extract_sheet_info)
+        .collect::<PyResult<Vec<_>>>()?;
+    let sheet_names: Vec<String> = sheet_infos
+        .iter()
+        .map(|(_, name, _)| name.clone())
+        .collect();
+
+    for (index, (df, sheet_name, sheet_config)) in sheet_infos.into_iter().enumerate() {
+        let df = collect_if_lazy(df).map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("sheet '{}': {}", sheet_name, e))
+        })?;
 
         // Merge per-sheet scalar options with global defaults
         let effective_header = sheet_config.header.unwrap_or(header);
-        let effective_autofit = sheet_config.autofit.unwrap_or(autofit);
+        let effective_autofit = sheet_config.autofit.as_ref().unwrap_or(&autofit);
         let effective_table_style: Option<String> = match &sheet_config.table_style {
             Some(style_opt) => style_opt.clone(),
             None => table_style.map(|s| s.to_string()),
         };
-        let effective_freeze_panes = sheet_config.freeze_panes.unwrap_or(freeze_panes);
+        // A sheet's own freeze_panes or split_panes replaces the other's
+        // workbook-wide default, since the two can't be combined
+        let effective_freeze_panes = match (sheet_config.freeze_panes, sheet_config.split_panes) {
+            (Some(sheet_freeze), _) => sheet_freeze,
+            (None, Some(_)) => FreezePanes::Off,
+            (None, None) => freeze_panes,
+        };
+        let effective_split_panes = match sheet_config.freeze_panes {
+            Some(_) => sheet_config.split_panes,
+            None => sheet_config.split_panes.or(split_panes),
+        };
         let effective_table_name: Option<String> = sheet_config
             .table_name
             .as_ref()
@@ -674,29 +2163,89 @@ fn dfs_to_xlsx<'py>(
             .or_else(|| table_name.clone());
         let effective_row_heights: Option<&HashMap<u32, f64>> =
             sheet_config.row_heights.as_ref().or(row_heights.as_ref());
+        let effective_date_format = sheet_config
+            .date_format
+            .as_deref()
+            .or(date_format.as_deref())
+            .unwrap_or(DATE_NUM_FORMAT);
+        let effective_datetime_format = sheet_config
+            .datetime_format
+            .as_deref()
+            .or(datetime_format.as_deref())
+            .unwrap_or(DATETIME_NUM_FORMAT);
+        let effective_time_format = sheet_config
+            .time_format
+            .as_deref()
+            .or(time_format.as_deref())
+            .unwrap_or(TIME_NUM_FORMAT);
+        let effective_nan_policy = parse_nan_policy(
+            sheet_config.nan_policy.as_deref().unwrap_or(nan_policy),
+        )
+        .map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("sheet '{}': {}", sheet_name, e))
+        })?;
+        let effective_large_int_mode = parse_large_int_mode(
+            sheet_config
+                .large_int_mode
+                .as_deref()
+                .unwrap_or(large_int_mode),
+        )
+        .map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("sheet '{}': {}", sheet_name, e))
+        })?;
+        let effective_long_string_mode = parse_long_string_mode(
+            sheet_config
+                .long_string_mode
+                .as_deref()
+                .unwrap_or(long_string_mode),
+        )
+        .map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("sheet '{}': {}", sheet_name, e))
+        })?;
+        let effective_view = parse_sheet_view(sheet_config.view.as_deref().unwrap_or(view))
+            .map_err(|e| {
+                pyo3::exceptions::PyValueError::new_err(format!("sheet '{}': {}", sheet_name, e))
+            })?;
+        let effective_conditional_format_mode = parse_conditional_format_mode(
+            sheet_config
+                .conditional_format_mode
+                .as_deref()
+                .unwrap_or(conditional_format_mode),
+        )
+        .map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("sheet '{}': {}", sheet_name, e))
+        })?;
 
         // A table is only actually created when there's at least one data row
         // (see the `row_count > 0` gate in `apply_worksheet_features`), so an
-        // empty DataFrame never claims a table name here either — otherwise
-        // two empty sheets sharing a table name would false-positive as a
-        // conflict.
-        if !constant_memory && effective_header && effective_table_style.is_some() {
+        // empty DataFrame (or one whose skip_rows/max_rows leave no rows)
+        // never claims a table name here either — otherwise two empty sheets
+        // sharing a table name would false-positive as a conflict. A
+        // transposed sheet never gets a table either.
+        let effective_skip_rows = sheet_config.skip_rows.unwrap_or(skip_rows);
+        let effective_max_rows = sheet_config.max_rows.or(max_rows);
+        let effective_transpose = sheet_config.transpose.unwrap_or(transpose);
+        if !constant_memory && !effective_transpose && effective_table_style.is_some() {
             let row_count = dataframe_row_count(&df).map_err(|e| {
                 pyo3::exceptions::PyValueError::new_err(format!("sheet '{}': {}", sheet_name, e))
             })?;
-            if row_count > 0 {
+            if written_row_count(row_count, effective_skip_rows, effective_max_rows) > 0 {
                 if let Some(name) = effective_table_name.as_deref() {
-                    let sanitized = sanitize_table_name(name);
-                    let key = sanitized.to_ascii_lowercase();
-                    if let Some(previous_sheet) = table_names.insert(key, sheet_name.clone()) {
-                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                            "Duplicate table name '{}' for sheets '{}' and '{}'. Excel table names must be unique within a workbook",
-                            sanitized, previous_sheet, sheet_name
-                        )));
-                    }
+                    register_table_name(&mut table_names, name, &sheet_name)?;
                 }
             }
         }
+        // Extra tables are added whatever the row count, transposed or not
+        let effective_tables = sheet_config.tables.as_deref().or(tables.as_deref());
+        if !constant_memory {
+            for name in effective_tables
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|spec| spec.name.as_deref())
+            {
+                register_table_name(&mut table_names, name, &sheet_name)?;
+            }
+        }
 
         // Merge per-sheet complex options with global defaults (references, no cloning needed)
         let effective_opts = sheet_config.merge_with(&opts);
@@ -706,9 +2255,82 @@ fn dfs_to_xlsx<'py>(
             autofit: effective_autofit,
             table_style: effective_table_style.as_deref(),
             freeze_panes: effective_freeze_panes,
+            freeze_top_cell: sheet_config.freeze_top_cell.or(freeze_top_cell),
+            split_panes: effective_split_panes,
             table_name: effective_table_name.as_deref(),
+            table_columns: sheet_config
+                .table_columns
+                .as_deref()
+                .or(table_columns.as_deref()),
+            table_options: sheet_config.table_options.or(table_options),
+            tables: effective_tables,
             row_heights: effective_row_heights,
             constant_memory,
+            date_format: effective_date_format,
+            datetime_format: effective_datetime_format,
+            time_format: effective_time_format,
+            nan_policy: effective_nan_policy,
+            large_int_mode: effective_large_int_mode,
+            long_string_mode: effective_long_string_mode,
+            start_row: sheet_config.start_row.unwrap_or(start_row),
+            start_col: sheet_config.start_col.unwrap_or(start_col),
+            autofilter: sheet_config.autofilter.unwrap_or(autofilter),
+            conditional_format_mode: effective_conditional_format_mode,
+            show_gridlines: sheet_config.show_gridlines.unwrap_or(show_gridlines),
+            show_row_col_headers: sheet_config
+                .show_row_col_headers
+                .unwrap_or(show_row_col_headers),
+            zoom: sheet_config.zoom.or(zoom),
+            view: effective_view,
+            right_to_left: sheet_config.right_to_left.unwrap_or(right_to_left),
+            outline_below: sheet_config.outline_below.unwrap_or(outline_below),
+            outline_right: sheet_config.outline_right.unwrap_or(outline_right),
+            tab_color: sheet_config.tab_color.as_deref().or(tab_color.as_deref()),
+            page_setup: sheet_config.page_setup.as_ref().or(page_setup.as_ref()),
+            print_area: sheet_config.print_area.as_deref().or(print_area.as_deref()),
+            background_image: sheet_config
+                .background_image
+                .as_ref()
+                .or(background_image.as_ref()),
+            categorical_validation: sheet_config
+                .categorical_validation
+                .unwrap_or(categorical_validation),
+            integer_like_floats: sheet_config
+                .integer_like_floats
+                .unwrap_or(integer_like_floats),
+            columns: sheet_config.columns.as_deref().or(columns.as_deref()),
+            skip_rows: effective_skip_rows,
+            max_rows: effective_max_rows,
+            transpose: effective_transpose,
+            include_index: sheet_config.include_index.unwrap_or(include_index),
+            banded_rows: sheet_config
+                .banded_rows
+                .as_ref()
+                .or(banded_rows.as_ref())
+                .map(|(a, b)| (a.as_str(), b.as_str())),
+            summary_row: sheet_config.summary_row.as_ref().or(summary_row.as_ref()),
+            default_col_width: sheet_config.default_col_width.or(default_col_width),
+            default_row_height: sheet_config.default_row_height.or(default_row_height),
+            protect: match &sheet_config.protect {
+                Some(sheet_protect) => sheet_protect.as_ref(),
+                None => protect.as_ref(),
+            },
+            auto_hyperlinks: sheet_config.auto_hyperlinks.unwrap_or(auto_hyperlinks),
+            auto_rich_text_columns: sheet_config
+                .auto_rich_text_columns
+                .as_deref()
+                .or(auto_rich_text_columns.as_deref()),
+            infer_string_types: sheet_config
+                .infer_string_types
+                .unwrap_or(infer_string_types),
+            bool_mode: sheet_config.bool_mode.as_ref().unwrap_or(&bool_mode),
+            text_columns: sheet_config
+                .text_columns
+                .as_deref()
+                .or(text_columns.as_deref()),
+            sanitize_strings: sheet_config.sanitize_strings.unwrap_or(sanitize_strings),
+            sheet_names: &sheet_names,
+            progress: None,
         };
 
         let result = write_configured_sheet(
@@ -723,23 +2345,358 @@ fn dfs_to_xlsx<'py>(
             pyo3::exceptions::PyValueError::new_err(format!("sheet '{}': {}", sheet_name, e))
         })?;
 
+        if let Some((row, col)) = sheet_config.active_cell {
+            workbook
+                .worksheet_from_index(index)
+                .and_then(|ws| ws.set_selection(row, col, row, col))
+                .map_err(|e| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "sheet '{}': Failed to set active_cell: {}",
+                        sheet_name, e
+                    ))
+                })?;
+        }
+
+        if !sheet_config_write.show_row_col_headers {
+            hidden_headers.push(index);
+        }
+        if let Some(cell) = effective_split_panes {
+            split_sheets.push((index, cell));
+        }
         stats.push(result);
+        visibility.push(sheet_config.visible.unwrap_or_default());
     }
 
+    let active_index = active_sheet
+        .map(|selector| resolve_active_sheet(&selector, &sheet_names))
+        .transpose()
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    apply_sheet_states(&mut workbook, &visibility, active_index)
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
     apply_defined_names(&mut workbook, defined_names.as_ref())
         .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    if let Some(properties) = &properties {
+        workbook.set_properties(properties);
+    }
 
-    // Save workbook
-    workbook.save(&output_path).map_err(|e| {
-        pyo3::exceptions::PyValueError::new_err(format!(
-            "Failed to save workbook to '{}': {}",
-            output_path, e
-        ))
-    })?;
+    save_workbook(
+        py,
+        &mut workbook,
+        &output,
+        calc,
+        &split_sheets,
+        &hidden_headers,
+    )
+    .map_err(pyo3::exceptions::PyValueError::new_err)?;
 
     Ok(stats)
 }
 
+/// Record `sheet_name`'s table `name` in `table_names` (keyed by the lowercased
+/// sanitized name), rejecting a name another sheet already claimed: Excel table
+/// names are case-insensitive and unique within a workbook. A repeat on the same
+/// sheet is left to that sheet's own `tables` check, which names the ranges.
+fn register_table_name(
+    table_names: &mut HashMap<String, String>,
+    name: &str,
+    sheet_name: &str,
+) -> PyResult<()> {
+    let sanitized = sanitize_table_name(name);
+    let key = sanitized.to_ascii_lowercase();
+    match table_names.insert(key, sheet_name.to_string()) {
+        Some(previous_sheet) if previous_sheet != sheet_name => {
+            Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Duplicate table name '{}' for sheets '{}' and '{}'. Excel table names must be unique within a workbook",
+                sanitized, previous_sheet, sheet_name
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Write several DataFrames as stacked sections, sharing worksheets by name.
+///
+/// Each section lands on its sheet at its own start_row, so small related
+/// tables (with an optional title row above each) can live on one sheet.
+/// Sections naming the same sheet are written into the same worksheet; sheets
+/// are created in order of first appearance.
+///
+/// Args:
+///     sections: List of tuples. Each tuple can be:
+///               - (DataFrame, sheet_name, start_row)
+///               - (DataFrame, sheet_name, start_row, title) - title is written at
+///                 start_row (column A) and the DataFrame starts on the next row
+///               start_row is zero-based. Sections on the same sheet must be listed
+///               top to bottom and must not overlap. A polars LazyFrame in place of
+///               a DataFrame is collected first.
+///     output_path: Path for the output XLSX file (str or os.PathLike), or a binary
+///                  file-like object with a write() method
+///     header: Include column names as each section's header row (default: True)
+///     autofit: Automatically adjust column widths to fit content (default: False)
+///     table_style: Format each section as an Excel table with this style (default: None)
+///     header_format: Dict of header cell styles, as for df_to_xlsx (default: None)
+///     title_format: Dict of title cell styles, same keys as header_format (default: None)
+///
+/// Returns:
+///     List of (rows, columns) tuples for each section: one past its last written
+///     row and column, so the next section can start at start_row=rows + gap.
+///
+/// Raises:
+///     ValueError: If sections overlap or the conversion fails
+///
+/// Example:
+///     >>> import xlsxturbo
+///     >>> import pandas as pd
+///     >>> summary = pd.DataFrame({'region': ['N', 'S'], 'total': [10, 20]})
+///     >>> detail = pd.DataFrame({'item': ['a', 'b', 'c'], 'qty': [1, 2, 3]})
+///     >>> xlsxturbo.df_sections_to_xlsx([
+///     ...     (summary, "Report", 0, "Summary"),
+///     ...     (detail, "Report", 5, "Detail"),
+///     ... ], "report.xlsx", title_format={"bold": True})
+///     [(4, 2), (10, 2)]
+#[pyfunction]
+#[pyo3(signature = (
+    sections,
+    output_path,
+    header = true,
+    autofit = false,
+    table_style = None,
+    header_format = None,
+    title_format = None,
+))]
+#[allow(clippy::too_many_arguments)]
+fn df_sections_to_xlsx<'py>(
+    py: Python<'py>,
+    sections: Vec<Bound<'py, PyAny>>,
+    output_path: &Bound<'py, PyAny>,
+    header: bool,
+    autofit: bool,
+    table_style: Option<&str>,
+    header_format: Option<&Bound<'py, PyAny>>,
+    title_format: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Vec<(u32, u16)>> {
+    let output = output_target(output_path, "output_path")?;
+    if sections.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "df_sections_to_xlsx requires at least one section, got an empty list",
+        ));
+    }
+    let opts = ExtractedOptions {
+        header_format: header_format
+            .map(|v| require_dict(v, "header_format").and_then(|d| extract_header_format(&d)))
+            .transpose()?,
+        ..Default::default()
+    };
+    let title_fmt = title_format
+        .map(|v| require_dict(v, "title_format").and_then(|d| extract_header_format(&d)))
+        .transpose()?
+        .map(|map| parse_header_format(py, &map, "title_format"))
+        .transpose()
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+    let mut workbook = Workbook::new();
+    // Row one past the last section written on each sheet, for the overlap check
+    let mut sheet_ends: HashMap<String, u32> = HashMap::new();
+    let mut stats = Vec::with_capacity(sections.len());
+
+    for (i, section_tuple) in sections.iter().enumerate() {
+        let (df, sheet_name, start_row, title) = extract_section_info(section_tuple)?;
+        let section_err = |e: String| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "section {} (sheet '{}'): {}",
+                i, sheet_name, e
+            ))
+        };
+        let df = collect_if_lazy(df).map_err(section_err)?;
+
+        let worksheet = match sheet_ends.get(&sheet_name) {
+            Some(&end) if start_row < end => {
+                return Err(section_err(format!(
+                    "start_row {} overlaps the previous section on this sheet; start at row {} or later",
+                    start_row, end
+                )));
+            }
+            Some(_) => workbook
+                .worksheet_from_name(&sheet_name)
+                .map_err(|e| section_err(e.to_string()))?,
+            None => workbook
+                .add_worksheet()
+                .set_name(&sheet_name)
+                .map_err(|e| {
+                    section_err(format!("Failed to set sheet name '{}': {}", sheet_name, e))
+                })?,
+        };
+
+        let data_row = match &title {
+            Some(text) => {
+                match &title_fmt {
+                    Some(fmt) => worksheet.write_string_with_format(start_row, 0, text, fmt),
+                    None => worksheet.write_string(start_row, 0, text),
+                }
+                .map_err(|e| section_err(format!("Failed to write title: {}", e)))?;
+                start_row
+                    .checked_add(1)
+                    .ok_or_else(|| section_err("title row leaves no room for data".to_string()))?
+            }
+            None => start_row,
+        };
+
+        let config = WriteConfig {
+            include_header: header,
+            autofit: if autofit {
+                &Autofit::All
+            } else {
+                &Autofit::Off
+            },
+            table_style,
+            freeze_panes: FreezePanes::Off,
+            freeze_top_cell: None,
+            split_panes: None,
+            table_name: None,
+            table_columns: None,
+            table_options: None,
+            tables: None,
+            row_heights: None,
+            constant_memory: false,
+            date_format: DATE_NUM_FORMAT,
+            datetime_format: DATETIME_NUM_FORMAT,
+            time_format: TIME_NUM_FORMAT,
+            nan_policy: NanPolicy::default(),
+            large_int_mode: LargeIntMode::default(),
+            long_string_mode: LongStringMode::default(),
+            start_row: data_row,
+            start_col: 0,
+            autofilter: false,
+            conditional_format_mode: ConditionalFormatMode::default(),
+            show_gridlines: true,
+            show_row_col_headers: true,
+            zoom: None,
+            view: SheetView::Normal,
+            right_to_left: false,
+            outline_below: true,
+            outline_right: true,
+            tab_color: None,
+            page_setup: None,
+            print_area: None,
+            background_image: None,
+            categorical_validation: false,
+            integer_like_floats: false,
+            columns: None,
+            skip_rows: 0,
+            max_rows: None,
+            transpose: false,
+            include_index: false,
+            banded_rows: None,
+            summary_row: None,
+            default_col_width: None,
+            default_row_height: None,
+            protect: None,
+            auto_hyperlinks: false,
+            auto_rich_text_columns: None,
+            infer_string_types: false,
+            bool_mode: &BoolMode::Boolean,
+            text_columns: None,
+            sanitize_strings: false,
+            sheet_names: &[],
+            progress: None,
+        };
+        let result = write_sheet_data(
+            py,
+            worksheet,
+            &sheet_name,
+            &df,
+            &config,
+            opts.as_effective(),
+        )
+        .map_err(section_err)?;
+
+        sheet_ends.insert(sheet_name, result.0);
+        stats.push(result);
+    }
+
+    save_workbook(
+        py,
+        &mut workbook,
+        &output,
+        CalcSettings::default(),
+        &[],
+        &[],
+    )
+    .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+    Ok(stats)
+}
+
+/// Convert a DataFrame to XLSX and return the workbook as bytes.
+///
+/// Takes the same keyword arguments as df_to_xlsx (everything except
+/// output_path), so all formatting options are available. Useful for web
+/// responses, object storage uploads, or email attachments where writing a
+/// temporary file is unwanted.
+///
+/// Args:
+///     df: pandas DataFrame, polars DataFrame, or polars LazyFrame to export
+///     **kwargs: Any df_to_xlsx keyword argument (sheet_name, autofit, ...)
+///
+/// Returns:
+///     The complete .xlsx file contents as bytes
+///
+/// Raises:
+///     TypeError: If an unknown keyword argument (or output_path) is passed
+///     ValueError: If the conversion fails
+///
+/// Example:
+///     >>> import xlsxturbo
+///     >>> import pandas as pd
+///     >>> df = pd.DataFrame({'a': [1, 2], 'b': [3.14, 2.71]})
+///     >>> data = xlsxturbo.df_to_xlsx_bytes(df, autofit=True)
+///     >>> data[:2]
+///     b'PK'
+#[pyfunction]
+#[pyo3(signature = (df, **kwargs))]
+fn df_to_xlsx_bytes<'py>(
+    py: Python<'py>,
+    df: &Bound<'py, PyAny>,
+    kwargs: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let buffer = Bound::new(py, BufferTarget::default())?;
+    wrap_pyfunction!(df_to_xlsx, py)?.call((df, &buffer), kwargs)?;
+    let bytes = buffer.borrow_mut().take_bytes();
+    Ok(PyBytes::new(py, &bytes))
+}
+
+/// Write multiple DataFrames to one workbook and return it as bytes.
+///
+/// Takes the same arguments as dfs_to_xlsx (everything except output_path),
+/// including per-sheet option dicts.
+///
+/// Args:
+///     sheets: List of (DataFrame, sheet_name[, options_dict]) tuples, as for dfs_to_xlsx
+///     **kwargs: Any dfs_to_xlsx keyword argument (header, autofit, ...)
+///
+/// Returns:
+///     The complete .xlsx file contents as bytes
+///
+/// Raises:
+///     TypeError: If an unknown keyword argument (or output_path) is passed
+///     ValueError: If the conversion fails
+///
+/// Example:
+///     >>> import xlsxturbo
+///     >>> data = xlsxturbo.dfs_to_xlsx_bytes([(df1, "Sales"), (df2, "Costs")], autofit=True)
+#[pyfunction]
+#[pyo3(signature = (sheets, **kwargs))]
+fn dfs_to_xlsx_bytes<'py>(
+    py: Python<'py>,
+    sheets: &Bound<'py, PyAny>,
+    kwargs: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let buffer = Bound::new(py, BufferTarget::default())?;
+    wrap_pyfunction!(dfs_to_xlsx, py)?.call((sheets, &buffer), kwargs)?;
+    let bytes = buffer.borrow_mut().take_bytes();
+    Ok(PyBytes::new(py, &bytes))
+}
+
 /// xlsxturbo - High-performance Excel writer
 ///
 /// A Rust-powered library for converting DataFrames and CSV files to Excel XLSX format.
@@ -762,8 +2719,13 @@ fn dfs_to_xlsx<'py>(
 #[pymodule]
 fn xlsxturbo(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(csv_to_xlsx, m)?)?;
+    m.add_function(wrap_pyfunction!(csvs_to_xlsx, m)?)?;
     m.add_function(wrap_pyfunction!(df_to_xlsx, m)?)?;
     m.add_function(wrap_pyfunction!(dfs_to_xlsx, m)?)?;
+    m.add_function(wrap_pyfunction!(df_sections_to_xlsx, m)?)?;
+    m.add_function(wrap_pyfunction!(df_to_xlsx_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(dfs_to_xlsx_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(read_xlsx, m)?)?;
     m.add_function(wrap_pyfunction!(version, m)?)?;
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     Ok(())
@@ -4,7 +4,10 @@
 
 use clap::Parser;
 use std::time::Instant;
-use xlsxturbo_core::DateOrder;
+use xlsxturbo_core::{
+    parse_csv_char, parse_encoding, parse_number_separator, CsvOptions, DateOrder, LargeIntMode,
+    LongStringMode, NanPolicy,
+};
 
 #[derive(Parser, Debug)]
 #[command(name = "xlsxturbo")]
@@ -16,7 +19,8 @@ use xlsxturbo_core::DateOrder;
     - Booleans (true/false) -> Excel booleans\n\
     - Dates (YYYY-MM-DD) -> Excel dates\n\
     - Datetimes (ISO 8601) -> Excel datetimes\n\
-    - NaN/Inf -> Empty cells"
+    - Times (HH:MM:SS) -> Excel times\n\
+    - NaN/Inf -> Empty cells (see --nan-policy)"
 )]
 struct Args {
     /// Input CSV file path
@@ -36,6 +40,39 @@ struct Args {
     #[arg(short, long, default_value = "auto")]
     date_order: String,
 
+    /// Excel number format for date cells (default: "yyyy-mm-dd")
+    #[arg(long)]
+    date_format: Option<String>,
+
+    /// Excel number format for datetime cells (default: "yyyy-mm-dd hh:mm:ss")
+    #[arg(long)]
+    datetime_format: Option<String>,
+
+    /// Excel number format for time-of-day cells (default: "hh:mm:ss")
+    #[arg(long)]
+    time_format: Option<String>,
+
+    /// How NaN/Inf values are written
+    /// empty: empty cells
+    /// string: the text "NaN", "Inf", or "-Inf"
+    /// error: the Excel error #N/A
+    #[arg(long, default_value = "empty")]
+    nan_policy: String,
+
+    /// How integers beyond 2^53 are written
+    /// string: text cells holding every digit
+    /// number: Excel numbers (low digits rounded)
+    /// text: text cells with the "@" number format
+    #[arg(long, default_value = "string")]
+    large_int_mode: String,
+
+    /// How strings longer than Excel's 32,767-character cell limit are written
+    /// error: stop with an error naming the cell
+    /// truncate: keep the first 32,767 characters
+    /// split: keep the first 32,767 characters and put the rest in a note
+    #[arg(long, default_value = "error")]
+    long_string_mode: String,
+
     /// Show progress information
     #[arg(short, long)]
     verbose: bool,
@@ -43,6 +80,90 @@ struct Args {
     /// Use multi-core parallel processing (faster for large files, uses more memory)
     #[arg(short, long)]
     parallel: bool,
+
+    /// Stream rows to disk instead of holding the worksheet in memory
+    /// (for very large files; cannot be combined with --parallel)
+    #[arg(long)]
+    constant_memory: bool,
+
+    /// Detect "45%", "$1,234.50", and "1,234" as numbers instead of text
+    #[arg(long)]
+    smart_numeric: bool,
+
+    /// Field separator, a single ASCII character (default: ","); "\t" for tab
+    #[arg(long)]
+    delimiter: Option<String>,
+
+    /// Quote character around fields (default: '"')
+    #[arg(long)]
+    quote: Option<String>,
+
+    /// Escape character for a quote inside a quoted field (default: none)
+    #[arg(long)]
+    escape: Option<String>,
+
+    /// Treat the first row as a header: keep it as text and freeze it
+    #[arg(long)]
+    has_header: bool,
+
+    /// Text encoding of the input file, e.g. windows-1252, latin-1, or utf-16
+    #[arg(long, default_value = "utf-8")]
+    encoding: String,
+
+    /// Skip lines starting with this character, e.g. "#"
+    #[arg(long)]
+    comment: Option<String>,
+
+    /// Skip rows whose fields are all empty or whitespace
+    #[arg(long)]
+    skip_empty_rows: bool,
+
+    /// Values written as empty cells, comma-separated, e.g. "NA,N/A,NULL"
+    #[arg(long, value_delimiter = ',')]
+    null_values: Vec<String>,
+
+    /// Decimal separator of numbers in the file, e.g. "," for 1234,56
+    #[arg(long)]
+    decimal_separator: Option<String>,
+
+    /// Thousands separator of numbers in the file, e.g. "." for 1.234,56
+    #[arg(long)]
+    thousands_separator: Option<String>,
+
+    /// Excel number format for numbers in scientific notation, e.g. "0.00E+00"
+    #[arg(long)]
+    scientific_format: Option<String>,
+
+    /// 0-based positions of columns written as text as-is, comma-separated, e.g. "0,3"
+    #[arg(long, value_delimiter = ',')]
+    text_columns: Vec<usize>,
+
+    /// Strip a leading byte order mark and replace illegal control characters
+    /// with spaces in every field
+    #[arg(long)]
+    sanitize_strings: bool,
+}
+
+/// Parse an optional CSV dialect character, exiting with the error message
+/// when it is invalid.
+fn csv_char_arg(param: &str, value: Option<&str>) -> Option<u8> {
+    value.map(|v| {
+        parse_csv_char(param, v).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    })
+}
+
+/// Parse an optional number separator, exiting with the error message if it
+/// is invalid.
+fn number_separator_arg(param: &str, value: Option<&str>) -> Option<char> {
+    value.map(|v| {
+        parse_number_separator(param, v).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    })
 }
 
 fn main() {
@@ -56,6 +177,35 @@ fn main() {
         std::process::exit(1);
     });
 
+    let nan_policy = NanPolicy::parse(&args.nan_policy).unwrap_or_else(|| {
+        eprintln!(
+            "Invalid nan_policy '{}'. Valid values: empty, string, error",
+            args.nan_policy
+        );
+        std::process::exit(1);
+    });
+
+    let large_int_mode = LargeIntMode::parse(&args.large_int_mode).unwrap_or_else(|| {
+        eprintln!(
+            "Invalid large_int_mode '{}'. Valid values: string, number, text",
+            args.large_int_mode
+        );
+        std::process::exit(1);
+    });
+
+    let long_string_mode = LongStringMode::parse(&args.long_string_mode).unwrap_or_else(|| {
+        eprintln!(
+            "Invalid long_string_mode '{}'. Valid values: error, truncate, split",
+            args.long_string_mode
+        );
+        std::process::exit(1);
+    });
+
+    let encoding = parse_encoding(&args.encoding).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+
     if args.verbose {
         eprintln!("xlsxturbo - CSV to XLSX converter");
         eprintln!("Input:  {}", args.input);
@@ -63,8 +213,43 @@ fn main() {
         eprintln!("Sheet:  {}", args.sheet_name);
         eprintln!("Dates:  {:?}", date_order);
         eprintln!("Parallel: {}", args.parallel);
+        eprintln!("Constant memory: {}", args.constant_memory);
+        eprintln!("Header row: {}", args.has_header);
+        eprintln!("Encoding: {}", encoding.name());
     }
 
+    let options = CsvOptions {
+        date_order,
+        date_format: args.date_format,
+        datetime_format: args.datetime_format,
+        time_format: args.time_format,
+        nan_policy,
+        large_int_mode,
+        long_string_mode,
+        constant_memory: args.constant_memory,
+        smart_numeric: args.smart_numeric,
+        delimiter: csv_char_arg("delimiter", args.delimiter.as_deref()),
+        quote: csv_char_arg("quote", args.quote.as_deref()),
+        escape: csv_char_arg("escape", args.escape.as_deref()),
+        has_header: args.has_header,
+        header_format: None,
+        encoding: Some(encoding),
+        comment: csv_char_arg("comment", args.comment.as_deref()),
+        skip_empty_rows: args.skip_empty_rows,
+        null_values: args.null_values,
+        decimal_separator: number_separator_arg(
+            "decimal_separator",
+            args.decimal_separator.as_deref(),
+        ),
+        thousands_separator: number_separator_arg(
+            "thousands_separator",
+            args.thousands_separator.as_deref(),
+        ),
+        scientific_format: args.scientific_format,
+        text_columns: args.text_columns,
+        sanitize_strings: args.sanitize_strings,
+    };
+
     let start = Instant::now();
 
     let result = if args.parallel {
@@ -72,10 +257,10 @@ fn main() {
             &args.input,
             &args.output,
             &args.sheet_name,
-            date_order,
+            &options,
         )
     } else {
-        xlsxturbo_core::convert_csv_to_xlsx(&args.input, &args.output, &args.sheet_name, date_order)
+        xlsxturbo_core::convert_csv_to_xlsx(&args.input, &args.output, &args.sheet_name, &options)
     };
 
     match result {
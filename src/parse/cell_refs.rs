@@ -62,8 +62,16 @@ pub(crate) fn parse_cell_ref(cell_ref: &str) -> Result<(u32, u16), String> {
     Ok((row, col))
 }
 
+/// A cell range as (first_row, first_col, last_row, last_col)
+pub(crate) type CellRange = (u32, u16, u32, u16);
+
+/// Whether two cell ranges share at least one cell
+pub(crate) fn ranges_intersect(a: CellRange, b: CellRange) -> bool {
+    a.0 <= b.2 && b.0 <= a.2 && a.1 <= b.3 && b.1 <= a.3
+}
+
 /// Parse a cell range like "A1:D1" into (first_row, first_col, last_row, last_col) - 0-based
-pub(crate) fn parse_cell_range(range_str: &str) -> Result<(u32, u16, u32, u16), String> {
+pub(crate) fn parse_cell_range(range_str: &str) -> Result<CellRange, String> {
     let parts: Vec<&str> = range_str.split(':').collect();
     if parts.len() != 2 {
         return Err(format!(
@@ -1,7 +1,9 @@
-use crate::types::{pytype_name, OptionMap};
-use indexmap::IndexMap;
+use crate::types::{pytype_name, ColumnFormatConfigs, ColumnFormatKey, OptionMap};
 use pyo3::{prelude::*, Py};
-use rust_xlsxwriter::{ConditionalFormatIconType, Format, FormatAlign, FormatBorder};
+use rust_xlsxwriter::{
+    Color, ConditionalFormatIconType, FontScheme, Format, FormatAlign, FormatBorder,
+    FormatDiagonalBorder, FormatScript, FormatUnderline,
+};
 use std::collections::HashMap;
 
 use super::colors::parse_color;
@@ -66,6 +68,66 @@ pub(crate) fn parse_border_style(style: &str) -> Result<FormatBorder, String> {
     }
 }
 
+/// Parse diagonal border direction string into `FormatDiagonalBorder`.
+pub(crate) fn parse_diagonal_type(direction: &str) -> Result<FormatDiagonalBorder, String> {
+    match direction.to_lowercase().as_str() {
+        "up" => Ok(FormatDiagonalBorder::BorderUp),
+        "down" => Ok(FormatDiagonalBorder::BorderDown),
+        "both" | "up_down" => Ok(FormatDiagonalBorder::BorderUpDown),
+        _ => Err(format!(
+            "Unknown diagonal_type '{}'. Valid values: up, down, both",
+            direction
+        )),
+    }
+}
+
+/// Parse underline style string into `FormatUnderline`.
+pub(crate) fn parse_underline_style(style: &str) -> Result<FormatUnderline, String> {
+    match style.to_lowercase().as_str() {
+        "single" => Ok(FormatUnderline::Single),
+        "double" => Ok(FormatUnderline::Double),
+        "single_accounting" => Ok(FormatUnderline::SingleAccounting),
+        "double_accounting" => Ok(FormatUnderline::DoubleAccounting),
+        _ => Err(format!(
+            "Unknown underline style '{}'. Valid values: single, double, \
+             single_accounting, double_accounting",
+            style
+        )),
+    }
+}
+
+/// Parse font scheme string into `FontScheme`. "minor" and "major" are the
+/// theme body and heading fonts; "none" opts out of the theme.
+pub(crate) fn parse_font_scheme(scheme: &str) -> Result<FontScheme, String> {
+    match scheme.to_lowercase().as_str() {
+        "minor" | "body" => Ok(FontScheme::Body),
+        "major" | "headings" => Ok(FontScheme::Headings),
+        "none" => Ok(FontScheme::None),
+        _ => Err(format!(
+            "Unknown font_scheme '{}'. Valid values: minor, major, none",
+            scheme
+        )),
+    }
+}
+
+/// Parse font script string into `FormatScript`, raising or lowering the
+/// text as for footnote markers and chemical formulas.
+pub(crate) fn parse_font_script(script: &str) -> Result<FormatScript, String> {
+    match script.to_lowercase().as_str() {
+        "superscript" => Ok(FormatScript::Superscript),
+        "subscript" => Ok(FormatScript::Subscript),
+        "none" => Ok(FormatScript::None),
+        _ => Err(format!(
+            "Unknown font_script '{}'. Valid values: superscript, subscript, none",
+            script
+        )),
+    }
+}
+
+/// Highest OOXML font family class (1 Roman, 2 Swiss, 3 Modern, 4 Script,
+/// 5 Decorative; 0 means not applicable).
+const MAX_FONT_FAMILY: u8 = 5;
+
 /// Parse horizontal alignment string into `FormatAlign` enum value.
 pub(crate) fn parse_horizontal_alignment(align: &str) -> Result<FormatAlign, String> {
     match align.to_lowercase().as_str() {
@@ -88,18 +150,79 @@ pub(crate) fn parse_horizontal_alignment(align: &str) -> Result<FormatAlign, Str
 pub(crate) fn parse_vertical_alignment(align: &str) -> Result<FormatAlign, String> {
     match align.to_lowercase().as_str() {
         "top" => Ok(FormatAlign::Top),
-        "center" => Ok(FormatAlign::VerticalCenter),
+        "center" | "middle" => Ok(FormatAlign::VerticalCenter),
         "bottom" => Ok(FormatAlign::Bottom),
         "justify" => Ok(FormatAlign::VerticalJustify),
         "distributed" => Ok(FormatAlign::VerticalDistributed),
         _ => Err(format!(
-            "Unknown vertical alignment '{}'. Valid values: top, center, bottom, \
-             justify, distributed",
+            "Unknown vertical alignment '{}'. Valid values: top, center (or middle), \
+             bottom, justify, distributed",
             align
         )),
     }
 }
 
+/// Maximum length of an Excel number format string.
+const MAX_NUM_FORMAT_LEN: usize = 255;
+
+/// Validate an Excel number format string and build a `Format` carrying it.
+///
+/// Excel accepts almost any text as a number format, so this only rejects
+/// strings that Excel would refuse to open or silently mangle: empty or
+/// whitespace-only, longer than 255 characters, an unterminated `"..."`
+/// literal or `[...]` section, or more than four `;`-separated sections.
+/// `param` (e.g. `"date_format"`) is prepended to any error.
+pub(crate) fn parse_num_format(param: &str, num_format: &str) -> Result<Format, String> {
+    if num_format.trim().is_empty() {
+        return Err(format!("{}: number format must not be empty", param));
+    }
+    if num_format.chars().count() > MAX_NUM_FORMAT_LEN {
+        return Err(format!(
+            "{}: number format exceeds {} characters",
+            param, MAX_NUM_FORMAT_LEN
+        ));
+    }
+
+    let mut in_quotes = false;
+    let mut in_brackets = false;
+    let mut sections = 1;
+    let mut chars = num_format.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            _ if in_quotes => {}
+            '\\' => {
+                // Backslash escapes the next character literally.
+                chars.next();
+            }
+            '[' if !in_brackets => in_brackets = true,
+            ']' if in_brackets => in_brackets = false,
+            ';' if !in_brackets => sections += 1,
+            _ => {}
+        }
+    }
+    if in_quotes {
+        return Err(format!(
+            "{}: invalid number format '{}': unterminated quoted literal",
+            param, num_format
+        ));
+    }
+    if in_brackets {
+        return Err(format!(
+            "{}: invalid number format '{}': unterminated '[' section",
+            param, num_format
+        ));
+    }
+    if sections > 4 {
+        return Err(format!(
+            "{}: invalid number format '{}': at most 4 ';'-separated sections are allowed",
+            param, num_format
+        ));
+    }
+
+    Ok(Format::new().set_num_format(num_format))
+}
+
 /// Parse header format dictionary into rust_xlsxwriter Format
 /// Delegates to parse_format_dict without column-specific options.
 /// `context` (e.g. `"header_format"` or `"merged_ranges['A1:B1']"`) is
@@ -109,12 +232,12 @@ pub(crate) fn parse_header_format(
     fmt_dict: &HashMap<String, Py<PyAny>>,
     context: &str,
 ) -> Result<Format, String> {
-    parse_format_dict(py, fmt_dict, false, context)
+    parse_format_dict(py, fmt_dict, false, context, Format::new())
 }
 
 /// Parse a rich text segment format dictionary into rust_xlsxwriter Format.
 /// Rich text segments only carry font-level formatting (bold, italic, color,
-/// size, underline, bg_color). Borders/alignment/wrap/num_format are meaningless
+/// size, underline, font_script, bg_color). Borders/alignment/wrap/num_format are meaningless
 /// for an inline text run, so we reuse the no-column-options parser.
 /// `context` (e.g. `"rich_text['A1']"`) is prepended to any error.
 pub(crate) fn parse_rich_text_format(
@@ -122,7 +245,7 @@ pub(crate) fn parse_rich_text_format(
     fmt_dict: &HashMap<String, Py<PyAny>>,
     context: &str,
 ) -> Result<Format, String> {
-    parse_format_dict(py, fmt_dict, false, context)
+    parse_format_dict(py, fmt_dict, false, context, Format::new())
 }
 
 /// Keys accepted by `parse_format_dict` regardless of context.
@@ -130,35 +253,76 @@ const FORMAT_KEYS_BASE: &[&str] = &[
     "bold",
     "italic",
     "underline",
+    "font_strikethrough",
+    "font_script",
     "bg_color",
     "font_color",
     "font_size",
+    "font_name",
+    "font_family",
+    "font_scheme",
     "border",
     "border_left",
     "border_right",
     "border_top",
     "border_bottom",
     "border_color",
+    "border_style",
+    "border_left_color",
+    "border_right_color",
+    "border_top_color",
+    "border_bottom_color",
+    "diagonal_border",
+    "diagonal_type",
+    "diagonal_color",
     "align_horizontal",
     "align_vertical",
     "wrap_text",
+    "align",
+    "valign",
+    "text_wrap",
 ];
 
 /// Keys accepted only when `include_column_options` is true.
 const FORMAT_KEYS_COLUMN: &[&str] = &["num_format"];
 
-/// Extract a border field accepting bool (True=thin) or a style name string.
-/// None, missing, or `false` return Ok(None). Unknown types error.
+/// Read an option that has a short alias (e.g. `align` for
+/// `align_horizontal`), rejecting a dict that sets both spellings.
+fn aliased<T>(
+    view: &OptionMap<'_, '_>,
+    key: &str,
+    alias: &str,
+    get: impl Fn(&str) -> Result<Option<T>, String>,
+) -> Result<Option<T>, String> {
+    match (get(key)?, get(alias)?) {
+        (Some(_), Some(_)) => Err(format!(
+            "{}: '{}' and '{}' are the same option; set only one",
+            view.context(),
+            key,
+            alias
+        )),
+        (value, alias_value) => Ok(value.or(alias_value)),
+    }
+}
+
+/// A format value that is either an on/off flag or a named style.
+enum FlagOrName {
+    Flag(bool),
+    Name(String),
+}
+
+/// Extract a field accepting a bool or a style name string. None or missing
+/// return Ok(None); any other type errors.
 ///
-/// `OptionMap` has no "bool-or-string" accessor (this is the only field that
-/// needs one), so this binds and inspects the raw entry directly rather than
+/// `OptionMap` has no "bool-or-string" accessor (only borders and underline
+/// need one), so this binds and inspects the raw entry directly rather than
 /// forcing a single type through `.string`/`.bool`.
-fn get_border_field(
+fn get_flag_or_name(
     py: Python<'_>,
     fmt_dict: &HashMap<String, Py<PyAny>>,
     context: &str,
     key: &str,
-) -> Result<Option<FormatBorder>, String> {
+) -> Result<Option<FlagOrName>, String> {
     let Some(obj) = fmt_dict.get(key) else {
         return Ok(None);
     };
@@ -166,11 +330,11 @@ fn get_border_field(
     if bound.is_none() {
         return Ok(None);
     }
-    if let Ok(style_str) = bound.extract::<String>() {
-        return Ok(Some(parse_border_style(&style_str)?));
+    if let Ok(name) = bound.extract::<String>() {
+        return Ok(Some(FlagOrName::Name(name)));
     }
     if let Ok(flag) = bound.extract::<bool>() {
-        return Ok(if flag { Some(FormatBorder::Thin) } else { None });
+        return Ok(Some(FlagOrName::Flag(flag)));
     }
     Err(format!(
         "{}: '{}' must be a bool or a style name string, got {}",
@@ -180,7 +344,24 @@ fn get_border_field(
     ))
 }
 
-/// Shared format parser for header, column, and rich-text formats.
+/// Extract a border field accepting bool (True=`true_style`) or a style name
+/// string. None, missing, or `false` return Ok(None).
+fn get_border_field(
+    py: Python<'_>,
+    fmt_dict: &HashMap<String, Py<PyAny>>,
+    context: &str,
+    key: &str,
+    true_style: FormatBorder,
+) -> Result<Option<FormatBorder>, String> {
+    match get_flag_or_name(py, fmt_dict, context, key)? {
+        Some(FlagOrName::Name(style)) => Ok(Some(parse_border_style(&style)?)),
+        Some(FlagOrName::Flag(flag)) => Ok(flag.then_some(true_style)),
+        None => Ok(None),
+    }
+}
+
+/// Shared format parser for header, column, and rich-text formats, applying
+/// the dict's keys on top of `base`.
 /// When `include_column_options` is true, also handles `num_format`.
 /// Unknown keys produce a clear error listing the valid options. `context`
 /// (e.g. `"header_format"` or `"column_formats['price_*']"`) is prepended to
@@ -190,6 +371,7 @@ fn parse_format_dict(
     fmt_dict: &HashMap<String, Py<PyAny>>,
     include_column_options: bool,
     context: &str,
+    base: Format,
 ) -> Result<Format, String> {
     let view = OptionMap::new(py, fmt_dict, context.to_string());
 
@@ -201,7 +383,7 @@ fn parse_format_dict(
     }
     view.reject_unknown(&valid)?;
 
-    let mut format = Format::new();
+    let mut format = base;
 
     if view.bool("bold")?.unwrap_or(false) {
         format = format.set_bold();
@@ -211,8 +393,22 @@ fn parse_format_dict(
         format = format.set_italic();
     }
 
-    if view.bool("underline")?.unwrap_or(false) {
-        format = format.set_underline(rust_xlsxwriter::FormatUnderline::Single);
+    // `underline: True` is a single underline; a string picks the style
+    let underline = match get_flag_or_name(py, fmt_dict, context, "underline")? {
+        Some(FlagOrName::Name(style)) => Some(parse_underline_style(&style)?),
+        Some(FlagOrName::Flag(true)) => Some(FormatUnderline::Single),
+        Some(FlagOrName::Flag(false)) | None => None,
+    };
+    if let Some(style) = underline {
+        format = format.set_underline(style);
+    }
+
+    if view.bool("font_strikethrough")?.unwrap_or(false) {
+        format = format.set_font_strikethrough();
+    }
+
+    if let Some(script) = view.string("font_script")? {
+        format = format.set_font_script(parse_font_script(&script)?);
     }
 
     if let Some(color_str) = view.string("bg_color")? {
@@ -227,6 +423,29 @@ fn parse_format_dict(
         format = format.set_font_size(size);
     }
 
+    // Naming a font drops the theme scheme; an explicit `font_scheme` is
+    // applied afterwards so it still wins.
+    if let Some(name) = view.string("font_name")? {
+        if name.trim().is_empty() {
+            return Err(format!("{}: 'font_name' must not be empty", context));
+        }
+        format = format.set_font_name(name);
+    }
+
+    if let Some(family) = view.u8("font_family")? {
+        if family > MAX_FONT_FAMILY {
+            return Err(format!(
+                "{}: 'font_family' must be between 0 and {}, got {}",
+                context, MAX_FONT_FAMILY, family
+            ));
+        }
+        format = format.set_font_family(family);
+    }
+
+    if let Some(scheme) = view.string("font_scheme")? {
+        format = format.set_font_scheme(parse_font_scheme(&scheme)?);
+    }
+
     if include_column_options {
         if let Some(num_fmt_str) = view.string("num_format")? {
             format = format.set_num_format(&num_fmt_str);
@@ -234,8 +453,16 @@ fn parse_format_dict(
     }
 
     // Borders: `border` applies to all four sides; per-side keys override.
-    if let Some(style) = get_border_field(py, fmt_dict, context, "border")? {
+    // A `True` flag draws `border_style`, thin by default.
+    let border_style = view
+        .string("border_style")?
+        .map(|style| parse_border_style(&style))
+        .transpose()?;
+    let true_style = border_style.unwrap_or(FormatBorder::Thin);
+    let mut has_border = false;
+    if let Some(style) = get_border_field(py, fmt_dict, context, "border", true_style)? {
         format = format.set_border(style);
+        has_border = true;
     }
 
     for (key, setter) in [
@@ -256,24 +483,76 @@ fn parse_format_dict(
             Format::set_border_bottom as fn(Format, FormatBorder) -> Format,
         ),
     ] {
-        if let Some(style) = get_border_field(py, fmt_dict, context, key)? {
+        if let Some(style) = get_border_field(py, fmt_dict, context, key, true_style)? {
             format = setter(format, style);
+            has_border = true;
         }
     }
+    // Diagonal lines go corner to corner: up (bottom-left to top-right) by
+    // default, or `diagonal_type` 'down' / 'both'.
+    let diagonal_type = view
+        .string("diagonal_type")?
+        .map(|direction| parse_diagonal_type(&direction))
+        .transpose()?;
+    if let Some(style) = get_border_field(py, fmt_dict, context, "diagonal_border", true_style)? {
+        format = format
+            .set_border_diagonal(style)
+            .set_border_diagonal_type(diagonal_type.unwrap_or(FormatDiagonalBorder::BorderUp));
+        has_border = true;
+    } else if diagonal_type.is_some() {
+        return Err(format!(
+            "{}: 'diagonal_type' needs 'diagonal_border' to draw",
+            context
+        ));
+    }
+    if let Some(color_str) = view.string("diagonal_color")? {
+        format = format.set_border_diagonal_color(parse_color(&color_str)?);
+    }
+
+    if border_style.is_some() && !has_border {
+        return Err(format!(
+            "{}: 'border_style' needs 'border' or a per-side border key to draw",
+            context
+        ));
+    }
 
+    // `border_color` colors every side; per-side colors override
     if let Some(color_str) = view.string("border_color")? {
         format = format.set_border_color(parse_color(&color_str)?);
     }
+    for (key, setter) in [
+        (
+            "border_left_color",
+            Format::set_border_left_color as fn(Format, Color) -> Format,
+        ),
+        (
+            "border_right_color",
+            Format::set_border_right_color as fn(Format, Color) -> Format,
+        ),
+        (
+            "border_top_color",
+            Format::set_border_top_color as fn(Format, Color) -> Format,
+        ),
+        (
+            "border_bottom_color",
+            Format::set_border_bottom_color as fn(Format, Color) -> Format,
+        ),
+    ] {
+        if let Some(color_str) = view.string(key)? {
+            format = setter(format, Color::from(parse_color(&color_str)?));
+        }
+    }
 
-    if let Some(align_str) = view.string("align_horizontal")? {
+    // `align`, `valign`, and `text_wrap` are short spellings of the next three
+    if let Some(align_str) = aliased(&view, "align_horizontal", "align", |k| view.string(k))? {
         format = format.set_align(parse_horizontal_alignment(&align_str)?);
     }
 
-    if let Some(align_str) = view.string("align_vertical")? {
+    if let Some(align_str) = aliased(&view, "align_vertical", "valign", |k| view.string(k))? {
         format = format.set_align(parse_vertical_alignment(&align_str)?);
     }
 
-    if view.bool("wrap_text")?.unwrap_or(false) {
+    if aliased(&view, "wrap_text", "text_wrap", |k| view.bool(k))?.unwrap_or(false) {
         format = format.set_text_wrap();
     }
 
@@ -288,43 +567,70 @@ pub(crate) fn parse_column_format(
     fmt_dict: &HashMap<String, Py<PyAny>>,
     context: &str,
 ) -> Result<Format, String> {
-    parse_format_dict(py, fmt_dict, true, context)
+    parse_column_format_over(py, Format::new(), fmt_dict, context)
 }
 
-/// Build a vector of column formats, one for each column.
-/// Returns None for columns with no matching pattern.
-/// Uses IndexMap to preserve pattern order - first matching pattern wins.
+/// Parse a column format dictionary on top of `base`, so the dict's own keys
+/// win over the base style (e.g. a column's `bg_color` over a `banded_rows`
+/// fill).
+pub(crate) fn parse_column_format_over(
+    py: Python<'_>,
+    base: Format,
+    fmt_dict: &HashMap<String, Py<PyAny>>,
+    context: &str,
+) -> Result<Format, String> {
+    parse_format_dict(py, fmt_dict, true, context, base)
+}
+
+/// Build a vector of column formats, one for each column, each parsed on top
+/// of `base` when given. Returns None for columns with no matching key.
+/// Index keys are checked first, then name patterns; uses IndexMap to preserve
+/// pattern order - first matching pattern wins.
 pub(crate) fn build_column_formats(
     py: Python<'_>,
     columns: &[String],
-    column_formats: &IndexMap<String, HashMap<String, Py<PyAny>>>,
+    column_formats: &ColumnFormatConfigs,
+    base: Option<&Format>,
 ) -> Result<Vec<Option<Format>>, String> {
-    let mut parsed_formats = Vec::with_capacity(column_formats.len());
-    for (pattern, fmt_dict) in column_formats {
-        let format = parse_column_format(py, fmt_dict, &format!("column_formats['{}']", pattern))?;
-        if !columns
-            .iter()
-            .any(|column| matches_pattern(column, pattern))
-        {
-            return Err(format!(
-                "column_formats['{}']: pattern matched no columns",
-                pattern
-            ));
+    let mut indexed_formats = HashMap::new();
+    let mut pattern_formats = Vec::with_capacity(column_formats.len());
+    for (key, fmt_dict) in column_formats {
+        let context = format!("column_formats[{}]", key);
+        let format =
+            parse_column_format_over(py, base.cloned().unwrap_or_default(), fmt_dict, &context)?;
+        match key {
+            ColumnFormatKey::Index(idx) => {
+                if *idx >= columns.len() {
+                    return Err(format!(
+                        "{}: column index out of range (DataFrame has {} columns)",
+                        context,
+                        columns.len()
+                    ));
+                }
+                indexed_formats.insert(*idx, format);
+            }
+            ColumnFormatKey::Pattern(pattern) => {
+                if !columns
+                    .iter()
+                    .any(|column| matches_pattern(column, pattern))
+                {
+                    return Err(format!("{}: pattern matched no columns", context));
+                }
+                pattern_formats.push((pattern, format));
+            }
         }
-        parsed_formats.push((pattern, format));
     }
 
     let mut formats = Vec::with_capacity(columns.len());
 
-    for col_name in columns {
-        // Find the first matching pattern (order preserved by IndexMap)
-        let mut matched_format = None;
-        for (pattern, format) in &parsed_formats {
-            if matches_pattern(col_name, pattern) {
-                matched_format = Some(format.clone());
-                break;
-            }
-        }
+    for (idx, col_name) in columns.iter().enumerate() {
+        let matched_format = indexed_formats.get(&idx).cloned().or_else(|| {
+            // Find the first matching pattern (order preserved by IndexMap)
+            pattern_formats
+                .iter()
+                .find(|(pattern, _)| matches_pattern(col_name, pattern))
+                .map(|(_, format)| format.clone())
+        });
         formats.push(matched_format);
     }
 
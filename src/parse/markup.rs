@@ -0,0 +1,83 @@
+//! Inline markup parsing for `auto_rich_text_columns`.
+
+/// One run of text parsed from inline markup, with its emphasis.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MarkupRun {
+    pub(crate) text: String,
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+}
+
+/// A piece of markup: literal text, or a `**` (bold) / `*` (italic) marker.
+enum Token {
+    Text(String),
+    Bold,
+    Italic,
+}
+
+/// Split a string with `**bold**` and `*italic*` markup into runs.
+///
+/// Markers pair up in order of appearance; an unpaired marker is kept as
+/// literal text, as is an asterisk escaped with a backslash (`\*`). Empty runs
+/// are dropped and adjacent runs with the same emphasis are joined, so a
+/// string without markup comes back as a single plain run.
+pub(crate) fn parse_markup(s: &str) -> Vec<MarkupRun> {
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if matches!(chars.peek(), Some('*' | '\\')) => {
+                text.extend(chars.next());
+            }
+            '*' => {
+                tokens.push(Token::Text(std::mem::take(&mut text)));
+                if chars.next_if_eq(&'*').is_some() {
+                    tokens.push(Token::Bold);
+                } else {
+                    tokens.push(Token::Italic);
+                }
+            }
+            _ => text.push(c),
+        }
+    }
+    tokens.push(Token::Text(text));
+
+    // The last marker of an odd count has no partner and stays literal
+    let unpaired = |is_kind: fn(&Token) -> bool| {
+        let positions: Vec<usize> = (0..tokens.len()).filter(|&i| is_kind(&tokens[i])).collect();
+        (positions.len() % 2 == 1).then(|| positions[positions.len() - 1])
+    };
+    let literal = [
+        unpaired(|t| matches!(t, Token::Bold)),
+        unpaired(|t| matches!(t, Token::Italic)),
+    ];
+
+    let mut runs: Vec<MarkupRun> = Vec::new();
+    let (mut bold, mut italic) = (false, false);
+    for (i, token) in tokens.into_iter().enumerate() {
+        let text = match token {
+            _ if literal.contains(&Some(i)) => match token {
+                Token::Bold => "**".to_string(),
+                _ => "*".to_string(),
+            },
+            Token::Bold => {
+                bold = !bold;
+                continue;
+            }
+            Token::Italic => {
+                italic = !italic;
+                continue;
+            }
+            Token::Text(text) => text,
+        };
+        if text.is_empty() {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(last) if last.bold == bold && last.italic == italic => last.text.push_str(&text),
+            _ => runs.push(MarkupRun { text, bold, italic }),
+        }
+    }
+    runs
+}
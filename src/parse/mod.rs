@@ -3,29 +3,39 @@
 mod cell_refs;
 mod colors;
 mod formats;
+mod markup;
 mod patterns;
 mod tables;
 mod values;
 
-pub(crate) use cell_refs::{parse_cell_range, parse_cell_ref};
+pub(crate) use cell_refs::{parse_cell_range, parse_cell_ref, ranges_intersect, CellRange};
 pub(crate) use colors::{parse_color, parse_color_enum};
 pub(crate) use formats::{
-    build_column_formats, parse_column_format, parse_header_format, parse_horizontal_alignment,
-    parse_icon_type, parse_rich_text_format, parse_vertical_alignment,
+    build_column_formats, parse_column_format, parse_column_format_over, parse_header_format,
+    parse_horizontal_alignment, parse_icon_type, parse_num_format, parse_rich_text_format,
+    parse_vertical_alignment,
 };
+pub(crate) use markup::parse_markup;
 pub(crate) use patterns::matches_pattern;
 pub(crate) use tables::{parse_table_style, sanitize_table_name};
-pub(crate) use values::{naive_date_to_excel, naive_datetime_to_excel, parse_value};
+pub(crate) use values::{
+    epoch_days_to_date, epoch_micros_to_datetime, naive_date_to_excel, naive_datetime_to_excel,
+    naive_time_to_excel, parse_csv_field, parse_value, python_datetime_str, sanitize_string,
+};
 
 #[cfg(test)]
 mod tests {
-    use super::formats::parse_border_style;
+    use super::formats::{
+        parse_border_style, parse_diagonal_type, parse_font_scheme, parse_underline_style,
+    };
+    use super::markup::MarkupRun;
+    use super::values::{parse_csv_value, parse_smart_numeric, parse_value};
     use super::{
         matches_pattern, naive_date_to_excel, parse_cell_range, parse_cell_ref, parse_color,
-        parse_horizontal_alignment, parse_table_style, parse_value, parse_vertical_alignment,
-        sanitize_table_name,
+        parse_csv_field, parse_horizontal_alignment, parse_markup, parse_num_format,
+        parse_table_style, parse_vertical_alignment, sanitize_string, sanitize_table_name,
     };
-    use crate::types::{CellValue, DateOrder};
+    use crate::types::{CellValue, CsvOptions, DateOrder};
 
     #[test]
     fn test_parse_integer() {
@@ -79,9 +89,21 @@ mod tests {
             parse_value("   ", DateOrder::Auto),
             CellValue::Empty
         ));
+    }
+
+    #[test]
+    fn test_parse_non_finite() {
         assert!(matches!(
             parse_value("NaN", DateOrder::Auto),
-            CellValue::Empty
+            CellValue::NonFinite(v) if v.is_nan()
+        ));
+        assert!(matches!(
+            parse_value("inf", DateOrder::Auto),
+            CellValue::NonFinite(v) if v == f64::INFINITY
+        ));
+        assert!(matches!(
+            parse_value("-Infinity", DateOrder::Auto),
+            CellValue::NonFinite(v) if v == f64::NEG_INFINITY
         ));
     }
 
@@ -132,6 +154,253 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_smart_numeric_percent() {
+        assert!(matches!(
+            parse_smart_numeric("45%"),
+            Some(CellValue::Percent(v)) if (v - 0.45).abs() < 1e-12
+        ));
+        assert!(matches!(
+            parse_smart_numeric("-12.5 %"),
+            Some(CellValue::Percent(v)) if (v + 0.125).abs() < 1e-12
+        ));
+        assert!(matches!(
+            parse_smart_numeric("1,250%"),
+            Some(CellValue::Percent(v)) if (v - 12.5).abs() < 1e-12
+        ));
+    }
+
+    #[test]
+    fn test_parse_smart_numeric_currency() {
+        assert!(matches!(
+            parse_smart_numeric("$1,234.50"),
+            Some(CellValue::Currency(v)) if v == 1234.5
+        ));
+        assert!(matches!(
+            parse_smart_numeric("-€99"),
+            Some(CellValue::Currency(v)) if v == -99.0
+        ));
+        assert!(matches!(
+            parse_smart_numeric("£-0.75"),
+            Some(CellValue::Currency(v)) if v == -0.75
+        ));
+        assert!(parse_smart_numeric("-$-5").is_none());
+    }
+
+    #[test]
+    fn test_parse_smart_numeric_thousands_separators() {
+        assert!(matches!(
+            parse_smart_numeric("1,234,567"),
+            Some(CellValue::Float(v)) if v == 1_234_567.0
+        ));
+        assert!(matches!(
+            parse_smart_numeric("-1,000.25"),
+            Some(CellValue::Float(v)) if v == -1000.25
+        ));
+    }
+
+    #[test]
+    fn test_parse_smart_numeric_rejects_non_numbers() {
+        for text in [
+            "12,34", "1,2345", ",123", "1,,000", "1.000,50", "%", "$", "$abc", "abc%", "50%%",
+            "1.", "US$5", "hello", "5 USD",
+        ] {
+            assert!(
+                parse_smart_numeric(text).is_none(),
+                "{:?} should stay a string",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_csv_value_smart_numeric_is_opt_in() {
+        let off = CsvOptions::default();
+        assert!(matches!(
+            parse_csv_value("$1,234.50", &off),
+            CellValue::String(_)
+        ));
+        assert!(matches!(parse_csv_value("45%", &off), CellValue::String(_)));
+
+        let on = CsvOptions {
+            smart_numeric: true,
+            ..CsvOptions::default()
+        };
+        assert!(matches!(
+            parse_csv_value(" $1,234.50 ", &on),
+            CellValue::Currency(_)
+        ));
+        assert!(matches!(parse_csv_value("45%", &on), CellValue::Percent(_)));
+        // Values parse_value already types are unaffected
+        assert!(matches!(parse_csv_value("42", &on), CellValue::Integer(42)));
+        assert!(matches!(
+            parse_csv_value("hello, world", &on),
+            CellValue::String(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_csv_value_null_values() {
+        let options = CsvOptions {
+            null_values: vec!["NA".to_string(), "null".to_string(), "-".to_string()],
+            ..CsvOptions::default()
+        };
+        for value in ["NA", "na", " NULL ", "-"] {
+            assert!(
+                matches!(parse_csv_value(value, &options), CellValue::Empty),
+                "{:?}",
+                value
+            );
+        }
+        // Only whole fields match
+        assert!(matches!(
+            parse_csv_value("NAN-free", &options),
+            CellValue::String(_)
+        ));
+        assert!(matches!(
+            parse_csv_value("-5", &options),
+            CellValue::Integer(-5)
+        ));
+        // Off by default
+        assert!(matches!(
+            parse_csv_value("NA", &CsvOptions::default()),
+            CellValue::String(_)
+        ));
+    }
+
+    #[test]
+    fn test_normalize_localized_number() {
+        use super::values::normalize_localized_number;
+        let eu = |s| normalize_localized_number(s, ',', Some('.'));
+        assert_eq!(eu("1.234,56").as_deref(), Some("1234.56"));
+        assert_eq!(eu("-1.234.567").as_deref(), Some("-1234567"));
+        assert_eq!(eu("0,5").as_deref(), Some("0.5"));
+        assert_eq!(eu("42").as_deref(), Some("42"));
+        // Misplaced groups, dates, and text are left alone
+        assert_eq!(eu("12.34,5"), None);
+        assert_eq!(eu("1234.567"), None);
+        assert_eq!(eu("15.01.2024"), None);
+        assert_eq!(eu("1,2,3"), None);
+        assert_eq!(eu(",5"), None);
+        assert_eq!(eu("abc"), None);
+
+        let spaced = normalize_localized_number("1 234 567,8", ',', Some(' '));
+        assert_eq!(spaced.as_deref(), Some("1234567.8"));
+    }
+
+    #[test]
+    fn test_parse_csv_value_number_separators() {
+        let options = CsvOptions {
+            decimal_separator: Some(','),
+            thousands_separator: Some('.'),
+            ..CsvOptions::default()
+        };
+        assert!(matches!(
+            parse_csv_value("1.234,5", &options),
+            CellValue::Float(v) if v == 1234.5
+        ));
+        assert!(matches!(
+            parse_csv_value("1.234", &options),
+            CellValue::Integer(1234)
+        ));
+        // Dates still go through the usual detection
+        assert!(matches!(
+            parse_csv_value("2024-01-15", &options),
+            CellValue::Date(_)
+        ));
+        // Off by default
+        assert!(matches!(
+            parse_csv_value("1.234,5", &CsvOptions::default()),
+            CellValue::String(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_csv_value_scientific() {
+        let options = CsvOptions {
+            scientific_format: Some("0.00E+00".to_string()),
+            ..CsvOptions::default()
+        };
+        assert!(matches!(
+            parse_csv_value("1.5e10", &options),
+            CellValue::Scientific(v) if v == 1.5e10
+        ));
+        assert!(matches!(
+            parse_csv_value(" -2E-3 ", &options),
+            CellValue::Scientific(v) if v == -2e-3
+        ));
+        // Plain numbers and text with an "e" are unaffected
+        assert!(matches!(
+            parse_csv_value("1.5", &options),
+            CellValue::Float(_)
+        ));
+        assert!(matches!(
+            parse_csv_value("1e", &options),
+            CellValue::String(_)
+        ));
+        // Off by default
+        assert!(matches!(
+            parse_csv_value("1.5e10", &CsvOptions::default()),
+            CellValue::Float(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_csv_field_text_columns() {
+        let options = CsvOptions {
+            text_columns: vec![0],
+            null_values: vec!["NA".to_string()],
+            ..CsvOptions::default()
+        };
+        assert!(matches!(
+            parse_csv_field("01234", 0, &options),
+            CellValue::Text(ref s) if s == "01234"
+        ));
+        assert!(matches!(
+            parse_csv_field(" ", 0, &options),
+            CellValue::Empty
+        ));
+        assert!(matches!(
+            parse_csv_field("na", 0, &options),
+            CellValue::Empty
+        ));
+        // Other columns are type-detected as usual
+        assert!(matches!(
+            parse_csv_field("01234", 1, &options),
+            CellValue::Integer(1234)
+        ));
+    }
+
+    #[test]
+    fn test_sanitize_string() {
+        assert_eq!(sanitize_string("\u{feff}id"), "id");
+        assert_eq!(sanitize_string("a\u{0}b\u{b}c"), "a b c");
+        // Tab, line feed, and carriage return are legal XML and kept
+        assert_eq!(sanitize_string("a\tb\r\nc"), "a\tb\r\nc");
+        // Only a leading BOM is a byte order mark
+        assert_eq!(sanitize_string("a\u{feff}b"), "a\u{feff}b");
+    }
+
+    #[test]
+    fn test_parse_csv_field_sanitize_strings() {
+        let options = CsvOptions {
+            sanitize_strings: true,
+            ..CsvOptions::default()
+        };
+        assert!(matches!(
+            parse_csv_field("\u{feff}42", 0, &options),
+            CellValue::Integer(42)
+        ));
+        assert!(matches!(
+            parse_csv_field("ab\u{1}c", 0, &options),
+            CellValue::String(ref s) if s == "ab c"
+        ));
+        assert!(matches!(
+            parse_csv_field("ab\u{1}c", 0, &CsvOptions::default()),
+            CellValue::String(ref s) if s == "ab\u{1}c"
+        ));
+    }
+
     #[test]
     fn test_matches_pattern_exact() {
         assert!(matches_pattern("column_name", "column_name"));
@@ -530,6 +799,10 @@ mod tests {
             parse_vertical_alignment("center").unwrap(),
             FormatAlign::VerticalCenter
         );
+        assert_eq!(
+            parse_vertical_alignment("middle").unwrap(),
+            FormatAlign::VerticalCenter
+        );
         assert_eq!(
             parse_vertical_alignment("bottom").unwrap(),
             FormatAlign::Bottom
@@ -549,6 +822,49 @@ mod tests {
         assert!(parse_vertical_alignment("general").is_err());
     }
 
+    // --- parse_diagonal_type tests ---
+
+    #[test]
+    fn test_parse_diagonal_type() {
+        use rust_xlsxwriter::FormatDiagonalBorder;
+        assert_eq!(
+            parse_diagonal_type("up").unwrap(),
+            FormatDiagonalBorder::BorderUp
+        );
+        assert_eq!(
+            parse_diagonal_type("Down").unwrap(),
+            FormatDiagonalBorder::BorderDown
+        );
+        assert_eq!(
+            parse_diagonal_type("both").unwrap(),
+            FormatDiagonalBorder::BorderUpDown
+        );
+        assert!(parse_diagonal_type("left").is_err());
+    }
+
+    #[test]
+    fn test_parse_font_scheme() {
+        use rust_xlsxwriter::FontScheme;
+        assert_eq!(parse_font_scheme("minor").unwrap(), FontScheme::Body);
+        assert_eq!(parse_font_scheme("Major").unwrap(), FontScheme::Headings);
+        assert_eq!(parse_font_scheme("none").unwrap(), FontScheme::None);
+        assert!(parse_font_scheme("serif").is_err());
+    }
+
+    #[test]
+    fn test_parse_underline_style() {
+        use rust_xlsxwriter::FormatUnderline;
+        assert_eq!(
+            parse_underline_style("double").unwrap(),
+            FormatUnderline::Double
+        );
+        assert_eq!(
+            parse_underline_style("Single_Accounting").unwrap(),
+            FormatUnderline::SingleAccounting
+        );
+        assert!(parse_underline_style("wavy").is_err());
+    }
+
     // --- naive_datetime_to_excel tests ---
 
     #[test]
@@ -656,4 +972,169 @@ mod tests {
         let result = parse_value("   ", DateOrder::Auto);
         assert!(matches!(result, CellValue::Empty));
     }
+
+    // --- parse_num_format tests ---
+
+    #[test]
+    fn test_parse_num_format_accepts_common_formats() {
+        for fmt in [
+            "yyyy-mm-dd",
+            "dd/mm/yyyy hh:mm",
+            "[$-409]mmmm d, yyyy",
+            "\"Date: \"yyyy-mm-dd",
+            "#,##0.00;[Red]-#,##0.00;0;@",
+            "yyyy\\;mm",
+        ] {
+            assert!(parse_num_format("date_format", fmt).is_ok(), "{}", fmt);
+        }
+    }
+
+    #[test]
+    fn test_parse_num_format_rejects_empty() {
+        let err = parse_num_format("date_format", "  ").unwrap_err();
+        assert!(err.starts_with("date_format:"), "{}", err);
+        assert!(err.contains("must not be empty"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_num_format_rejects_too_long() {
+        let fmt = "y".repeat(256);
+        let err = parse_num_format("datetime_format", &fmt).unwrap_err();
+        assert!(err.contains("exceeds 255 characters"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_num_format_rejects_unbalanced_literals() {
+        let err = parse_num_format("date_format", "\"yyyy-mm-dd").unwrap_err();
+        assert!(err.contains("unterminated quoted literal"), "{}", err);
+        let err = parse_num_format("date_format", "[$-409yyyy").unwrap_err();
+        assert!(err.contains("unterminated '[' section"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_num_format_rejects_too_many_sections() {
+        let err = parse_num_format("date_format", "0;0;0;@;0").unwrap_err();
+        assert!(err.contains("at most 4"), "{}", err);
+    }
+
+    // --- time-of-day parsing tests ---
+
+    #[test]
+    fn test_parse_time() {
+        match parse_value("14:30:00", DateOrder::Auto) {
+            CellValue::Time(v) => assert!((v - 14.5 / 24.0).abs() < 1e-12, "{}", v),
+            other => panic!("expected Time, got {:?}", other),
+        }
+        match parse_value("00:00:00", DateOrder::Auto) {
+            CellValue::Time(v) => assert_eq!(v, 0.0),
+            other => panic!("expected Time, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_time_fractional_seconds() {
+        match parse_value("12:00:00.5", DateOrder::Auto) {
+            CellValue::Time(v) => assert!((v - (43200.5 / 86400.0)).abs() < 1e-12, "{}", v),
+            other => panic!("expected Time, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_time_invalid_is_string() {
+        // Out-of-range components and bare HH:MM are not times.
+        assert!(matches!(
+            parse_value("25:00:00", DateOrder::Auto),
+            CellValue::String(_)
+        ));
+        assert!(matches!(
+            parse_value("14:30", DateOrder::Auto),
+            CellValue::String(_)
+        ));
+    }
+
+    #[test]
+    fn test_naive_time_to_excel_end_of_day() {
+        let t = chrono::NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+        let v = super::naive_time_to_excel(t);
+        assert!((0.0..1.0).contains(&v), "{}", v);
+        assert!((v - 86399.0 / 86400.0).abs() < 1e-12, "{}", v);
+    }
+
+    #[test]
+    fn test_naive_time_to_excel_leap_second_stays_below_one() {
+        // chrono stores the leap second 23:59:60 as 23:59:59 plus 1e9 nanos
+        let t = chrono::NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+        let v = super::naive_time_to_excel(t);
+        assert!((0.0..1.0).contains(&v), "{}", v);
+        assert!(v > 86399.0 / 86400.0, "{}", v);
+    }
+
+    // --- polars physical temporal value tests ---
+
+    #[test]
+    fn test_epoch_days_to_date() {
+        let d = |y, m, day| chrono::NaiveDate::from_ymd_opt(y, m, day).unwrap();
+        assert_eq!(super::epoch_days_to_date(0), Some(d(1970, 1, 1)));
+        assert_eq!(super::epoch_days_to_date(19_737), Some(d(2024, 1, 15)));
+        assert_eq!(super::epoch_days_to_date(-25_567), Some(d(1900, 1, 1)));
+        assert_eq!(super::epoch_days_to_date(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_epoch_micros_to_datetime_before_epoch() {
+        // Negative values floor toward the earlier second, like Python does.
+        let dt = super::epoch_micros_to_datetime(-1).unwrap();
+        assert_eq!(super::python_datetime_str(dt), "1969-12-31 23:59:59.999999");
+    }
+
+    #[test]
+    fn test_python_datetime_str_matches_python() {
+        let base = chrono::NaiveDate::from_ymd_opt(1899, 12, 31).unwrap();
+        let whole = base.and_hms_opt(10, 30, 0).unwrap();
+        assert_eq!(super::python_datetime_str(whole), "1899-12-31 10:30:00");
+        let fractional = base.and_hms_micro_opt(10, 30, 0, 789_000).unwrap();
+        assert_eq!(
+            super::python_datetime_str(fractional),
+            "1899-12-31 10:30:00.789000"
+        );
+    }
+
+    // --- inline markup tests ---
+
+    fn run(text: &str, bold: bool, italic: bool) -> MarkupRun {
+        MarkupRun {
+            text: text.to_string(),
+            bold,
+            italic,
+        }
+    }
+
+    #[test]
+    fn test_parse_markup_bold_and_italic() {
+        assert_eq!(
+            parse_markup("a **b** *c* ***d***"),
+            vec![
+                run("a ", false, false),
+                run("b", true, false),
+                run(" ", false, false),
+                run("c", false, true),
+                run(" ", false, false),
+                run("d", true, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_markup_literal_asterisks() {
+        // No markup, an unpaired marker, and escapes all come back as one plain run
+        assert_eq!(parse_markup("plain"), vec![run("plain", false, false)]);
+        assert_eq!(parse_markup("2 * 3"), vec![run("2 * 3", false, false)]);
+        assert_eq!(
+            parse_markup("**a** and **b"),
+            vec![run("a", true, false), run(" and **b", false, false)]
+        );
+        assert_eq!(parse_markup(r"\*x\* \\"), vec![run(r"*x* \", false, false)]);
+        assert!(parse_markup("").is_empty());
+        assert!(parse_markup("****").is_empty());
+    }
 }
@@ -1,5 +1,6 @@
-use crate::types::{CellValue, DateOrder, DATETIME_PATTERNS};
+use crate::types::{CellValue, CsvOptions, DateOrder, DATETIME_PATTERNS, TIME_PATTERNS};
 use chrono::Timelike;
+use std::borrow::Cow;
 
 /// Parse a string value and detect its type
 pub(crate) fn parse_value(value: &str, date_order: DateOrder) -> CellValue {
@@ -17,7 +18,7 @@ pub(crate) fn parse_value(value: &str, date_order: DateOrder) -> CellValue {
     // Try float
     if let Ok(float_val) = trimmed.parse::<f64>() {
         if float_val.is_nan() || float_val.is_infinite() {
-            return CellValue::Empty;
+            return CellValue::NonFinite(float_val);
         }
         return CellValue::Float(float_val);
     }
@@ -61,12 +62,245 @@ pub(crate) fn parse_value(value: &str, date_order: DateOrder) -> CellValue {
         }
     }
 
+    // Try time of day (after dates, so "2024-01-15" is never a time)
+    for pattern in TIME_PATTERNS {
+        if let Ok(time) = chrono::NaiveTime::parse_from_str(trimmed, pattern) {
+            return CellValue::Time(naive_time_to_excel(time));
+        }
+    }
+
     // Default to string. Use the original (untrimmed) value so leading/
     // trailing whitespace on genuine string cells is preserved; `trimmed`
     // is only used above for type detection.
     CellValue::String(value.to_string())
 }
 
+/// Parse a CSV field per the conversion options: `null_values` become empty
+/// cells, numbers written with `decimal_separator`/`thousands_separator` are
+/// normalized, the rest go through `parse_value`, then (with `smart_numeric`)
+/// a second look at anything it left as a string. With `scientific_format`,
+/// floats written with an exponent are marked `Scientific`.
+pub(crate) fn parse_csv_value(value: &str, options: &CsvOptions) -> CellValue {
+    if is_null_value(value, options) {
+        return CellValue::Empty;
+    }
+    match parse_csv_number(value, options) {
+        CellValue::Float(v)
+            if options.scientific_format.is_some() && value.contains(['e', 'E']) =>
+        {
+            CellValue::Scientific(v)
+        }
+        other => other,
+    }
+}
+
+/// Parse the CSV field in column `col_idx`: a `text_columns` field is kept as
+/// text (blank and `null_values` fields still become empty cells), any other
+/// goes through `parse_csv_value`.
+pub(crate) fn parse_csv_field(value: &str, col_idx: usize, options: &CsvOptions) -> CellValue {
+    let value = if options.sanitize_strings {
+        sanitize_string(value)
+    } else {
+        Cow::Borrowed(value)
+    };
+    let value = value.as_ref();
+    if !options.text_columns.contains(&col_idx) {
+        return parse_csv_value(value, options);
+    }
+    if value.trim().is_empty() || is_null_value(value, options) {
+        CellValue::Empty
+    } else {
+        CellValue::Text(value.to_string())
+    }
+}
+
+/// Strip a leading byte order mark (U+FEFF) and replace each control
+/// character XML forbids (below U+0020 other than tab, line feed, and carriage
+/// return) with a space (`sanitize_strings`). Borrows when nothing changes
+/// but the BOM.
+pub(crate) fn sanitize_string(value: &str) -> Cow<'_, str> {
+    let value = value.strip_prefix('\u{feff}').unwrap_or(value);
+    if !value.contains(is_illegal_xml_control) {
+        return Cow::Borrowed(value);
+    }
+    value
+        .chars()
+        .map(|c| if is_illegal_xml_control(c) { ' ' } else { c })
+        .collect()
+}
+
+fn is_illegal_xml_control(c: char) -> bool {
+    c < ' ' && !matches!(c, '\t' | '\n' | '\r')
+}
+
+/// Whether `value` is one of the `null_values` markers.
+fn is_null_value(value: &str, options: &CsvOptions) -> bool {
+    let trimmed = value.trim();
+    options
+        .null_values
+        .iter()
+        .any(|null| null.trim().eq_ignore_ascii_case(trimmed))
+}
+
+fn parse_csv_number(value: &str, options: &CsvOptions) -> CellValue {
+    if options.decimal_separator.is_some() || options.thousands_separator.is_some() {
+        let decimal = options.decimal_separator.unwrap_or('.');
+        if let Some(number) =
+            normalize_localized_number(value.trim(), decimal, options.thousands_separator)
+        {
+            return parse_value(&number, options.date_order);
+        }
+    }
+    match parse_value(value, options.date_order) {
+        CellValue::String(s) if options.smart_numeric => {
+            parse_smart_numeric(s.trim()).unwrap_or(CellValue::String(s))
+        }
+        other => other,
+    }
+}
+
+/// Rewrite a number written with locale separators, such as `"-1.234,56"`
+/// with `decimal = ','` and `thousands = Some('.')`, as `"-1234.56"`. The
+/// thousands groups must be well-formed as in `parse_grouped_number`. Returns
+/// `None` for anything that isn't such a number, including dates like
+/// `"15.01.2024"`, so the caller falls back to the usual detection.
+pub(crate) fn normalize_localized_number(
+    trimmed: &str,
+    decimal: char,
+    thousands: Option<char>,
+) -> Option<String> {
+    let (sign, unsigned) = match trimmed.strip_prefix(['-', '+']) {
+        Some(rest) => (&trimmed[..1], rest),
+        None => ("", trimmed),
+    };
+    let (int_part, frac_part) = match unsigned.split_once(decimal) {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (unsigned, None),
+    };
+    let all_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+
+    let mut number = sign.to_string();
+    match thousands.filter(|&sep| int_part.contains(sep)) {
+        Some(sep) => {
+            let mut groups = int_part.split(sep);
+            let first = groups.next().unwrap_or_default();
+            if !all_digits(first) || first.len() > 3 {
+                return None;
+            }
+            number.push_str(first);
+            for group in groups {
+                if group.len() != 3 || !all_digits(group) {
+                    return None;
+                }
+                number.push_str(group);
+            }
+        }
+        None if all_digits(int_part) => number.push_str(int_part),
+        None => return None,
+    }
+    if let Some(frac) = frac_part {
+        if !all_digits(frac) {
+            return None;
+        }
+        number.push('.');
+        number.push_str(frac);
+    }
+    Some(number)
+}
+
+/// Currency symbols `parse_smart_numeric` strips from the front of an amount.
+const CURRENCY_SYMBOLS: &[char] = &['$', '€', '£', '¥'];
+
+/// Detect formatted numbers that `parse_value` leaves as strings:
+/// - `"45%"` / `"-12.5%"` become `Percent` (already divided by 100)
+/// - `"$1,234.50"` / `"-€99"` / `"$-99"` become `Currency`
+/// - `"1,234,567.8"` (thousands separators, no symbol) becomes `Float`
+///
+/// Returns `None` for anything else, including misplaced separators like
+/// `"12,34"`, so ordinary text is never misread as a number.
+pub(crate) fn parse_smart_numeric(trimmed: &str) -> Option<CellValue> {
+    if let Some(number) = trimmed.strip_suffix('%') {
+        let (negative, digits) = split_sign(number.trim_end());
+        let v = parse_grouped_number(digits)?;
+        return Some(CellValue::Percent(apply_sign(negative, v) / 100.0));
+    }
+
+    let (negative, rest) = split_sign(trimmed);
+    if let Some(amount) = rest.strip_prefix(CURRENCY_SYMBOLS) {
+        // The sign may sit on either side of the symbol, but not both.
+        let (inner_negative, digits) = split_sign(amount);
+        if negative && inner_negative {
+            return None;
+        }
+        let v = parse_grouped_number(digits)?;
+        return Some(CellValue::Currency(apply_sign(
+            negative || inner_negative,
+            v,
+        )));
+    }
+
+    // Plain numbers already parsed in `parse_value`; only separator-grouped
+    // ones are left to detect here.
+    if !rest.contains(',') {
+        return None;
+    }
+    let v = parse_grouped_number(rest)?;
+    Some(CellValue::Float(apply_sign(negative, v)))
+}
+
+/// Strip a single leading `-`, reporting whether it was present.
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    }
+}
+
+fn apply_sign(negative: bool, v: f64) -> f64 {
+    if negative {
+        -v
+    } else {
+        v
+    }
+}
+
+/// Parse an unsigned decimal number whose integer part may use `,` thousands
+/// separators. Separators must be well-formed: a leading group of 1-3 digits,
+/// then groups of exactly 3.
+fn parse_grouped_number(s: &str) -> Option<f64> {
+    let (int_part, frac_part) = match s.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (s, None),
+    };
+    let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+
+    if let Some(frac) = frac_part {
+        if frac.is_empty() || !all_digits(frac) {
+            return None;
+        }
+    } else if int_part.is_empty() {
+        return None;
+    }
+
+    let mut groups = int_part.split(',');
+    let first = groups.next().unwrap_or_default();
+    if !all_digits(first) {
+        return None;
+    }
+    let mut digits = first.to_string();
+    for group in groups {
+        if first.is_empty() || first.len() > 3 || group.len() != 3 || !all_digits(group) {
+            return None;
+        }
+        digits.push_str(group);
+    }
+    if let Some(frac) = frac_part {
+        digits.push('.');
+        digits.push_str(frac);
+    }
+    digits.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
 /// Convert NaiveDate to Excel serial date number.
 ///
 /// The epoch used here (1899-12-30) only produces the correct Excel serial
@@ -86,10 +320,36 @@ pub(crate) fn naive_date_to_excel(date: chrono::NaiveDate) -> f64 {
 
 /// Convert NaiveDateTime to Excel serial datetime number
 pub(crate) fn naive_datetime_to_excel(dt: chrono::NaiveDateTime) -> f64 {
-    let date_part = naive_date_to_excel(dt.date());
-    let time = dt.time();
+    naive_date_to_excel(dt.date()) + naive_time_to_excel(dt.time())
+}
+
+/// Convert days since 1970-01-01 (a polars `Date` physical value) to a date.
+pub(crate) fn epoch_days_to_date(days: i64) -> Option<chrono::NaiveDate> {
+    chrono::DateTime::from_timestamp(days.checked_mul(86_400)?, 0).map(|dt| dt.date_naive())
+}
+
+/// Convert microseconds since 1970-01-01 (a naive polars `Datetime` physical
+/// value) to a datetime.
+pub(crate) fn epoch_micros_to_datetime(micros: i64) -> Option<chrono::NaiveDateTime> {
+    chrono::DateTime::from_timestamp_micros(micros).map(|dt| dt.naive_utc())
+}
+
+/// Render a datetime the way Python's `str()` does: microseconds only when
+/// non-zero, always six digits.
+pub(crate) fn python_datetime_str(dt: chrono::NaiveDateTime) -> String {
+    let micros = dt.and_utc().timestamp_subsec_micros();
+    if micros == 0 {
+        dt.format("%Y-%m-%d %H:%M:%S").to_string()
+    } else {
+        format!("{}.{:06}", dt.format("%Y-%m-%d %H:%M:%S"), micros)
+    }
+}
+
+/// Convert NaiveTime to an Excel time: the fraction of a day elapsed since
+/// midnight, in [0, 1). A leap second (chrono's nanosecond of 1e9 or more) is
+/// clamped into the second before it, which Excel can represent.
+pub(crate) fn naive_time_to_excel(time: chrono::NaiveTime) -> f64 {
     let seconds = time.num_seconds_from_midnight() as f64;
-    let fractional_seconds = time.nanosecond() as f64 / 1_000_000_000.0;
-    let time_fraction = (seconds + fractional_seconds) / 86400.0;
-    date_part + time_fraction
+    let fractional_seconds = time.nanosecond().min(999_999_999) as f64 / 1_000_000_000.0;
+    (seconds + fractional_seconds) / 86400.0
 }
@@ -0,0 +1,121 @@
+//! Reading XLSX files back into rows of typed values, via calamine.
+
+use calamine::{open_workbook, Data, Reader, Xlsx};
+use chrono::{Datelike, Timelike};
+use pyo3::prelude::*;
+use pyo3::types::{PyDateTime, PyDelta, PyTime};
+use pyo3::IntoPyObjectExt;
+
+/// Read one worksheet of the XLSX file at `path`: the sheet named
+/// `sheet_name`, or the first sheet when `None`.
+///
+/// Rows and columns are indexed from A1 (`rows[0][0]` is cell A1), so leading
+/// empty rows and columns are kept as `Data::Empty`. Every row has the same
+/// length, up to the last column that holds a value.
+pub(crate) fn read_sheet(path: &str, sheet_name: Option<&str>) -> Result<Vec<Vec<Data>>, String> {
+    let mut workbook: Xlsx<_> =
+        open_workbook(path).map_err(|e| format!("Failed to open '{}': {}", path, e))?;
+    let sheet_names = workbook.sheet_names();
+    let name = match sheet_name {
+        Some(name) => sheet_names
+            .iter()
+            .find(|s| s.as_str() == name)
+            .ok_or_else(|| {
+                format!(
+                    "sheet_name '{}' does not match any sheet. Sheets: {}",
+                    name,
+                    sheet_names.join(", ")
+                )
+            })?,
+        None => sheet_names
+            .first()
+            .ok_or_else(|| format!("'{}' has no worksheets", path))?,
+    }
+    .clone();
+    let range = workbook
+        .worksheet_range(&name)
+        .map_err(|e| format!("Failed to read sheet '{}': {}", name, e))?;
+
+    let Some((end_row, end_col)) = range.end() else {
+        return Ok(Vec::new());
+    };
+    let width = end_col as usize + 1;
+    Ok((0..=end_row)
+        .map(|row| {
+            (0..width)
+                .map(|col| {
+                    range
+                        .get_value((row, col as u32))
+                        .cloned()
+                        .unwrap_or(Data::Empty)
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Convert a cell value read by `read_sheet` to the matching Python object:
+/// `None` for an empty cell, `float`, `int` (only from the rare files that
+/// store integers), `bool`, `str`, `datetime.datetime` for dates and
+/// datetimes, `datetime.time` for a time of day, and `datetime.timedelta` for
+/// a duration. An error cell becomes its text, such as `"#N/A"`.
+pub(crate) fn data_to_py<'py>(py: Python<'py>, value: &Data) -> PyResult<Bound<'py, PyAny>> {
+    match value {
+        Data::Empty => Ok(py.None().into_bound(py)),
+        Data::Int(i) => i.into_bound_py_any(py),
+        Data::Float(f) => f.into_bound_py_any(py),
+        Data::Bool(b) => b.into_bound_py_any(py),
+        Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => s.into_bound_py_any(py),
+        Data::Error(e) => e.to_string().into_bound_py_any(py),
+        Data::DateTime(dt) if dt.is_duration() => {
+            let duration = dt.as_duration().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "Invalid duration serial {}",
+                    dt.as_f64()
+                ))
+            })?;
+            let micros = duration.num_microseconds().unwrap_or(i64::MAX);
+            PyDelta::new(
+                py,
+                (micros / 86_400_000_000) as i32,
+                ((micros % 86_400_000_000) / 1_000_000) as i32,
+                (micros % 1_000_000) as i32,
+                true,
+            )?
+            .into_bound_py_any(py)
+        }
+        Data::DateTime(dt) => {
+            let datetime = dt.as_datetime().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "Invalid date serial {}",
+                    dt.as_f64()
+                ))
+            })?;
+            let microsecond = datetime.nanosecond() / 1_000;
+            // Serials below 1 have no date part: the writer's time-of-day cells
+            if (0.0..1.0).contains(&dt.as_f64()) {
+                return PyTime::new(
+                    py,
+                    datetime.hour() as u8,
+                    datetime.minute() as u8,
+                    datetime.second() as u8,
+                    microsecond,
+                    None,
+                )?
+                .into_bound_py_any(py);
+            }
+            PyDateTime::new(
+                py,
+                datetime.year(),
+                datetime.month() as u8,
+                datetime.day() as u8,
+                datetime.hour() as u8,
+                datetime.minute() as u8,
+                datetime.second() as u8,
+                microsecond,
+                None,
+            )?
+            .into_bound_py_any(py)
+        }
+    }
+}
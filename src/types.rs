@@ -2,6 +2,8 @@
 
 use indexmap::IndexMap;
 use pyo3::prelude::*;
+use rust_xlsxwriter::Format;
+use std::cell::Cell;
 use std::collections::HashMap;
 
 /// Date formats by locale/order preference
@@ -65,6 +67,277 @@ impl DateOrder {
     }
 }
 
+/// How NaN and infinite float values are written
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NanPolicy {
+    /// Empty cell (default)
+    #[default]
+    Empty,
+    /// The literal text "NaN", "Inf", or "-Inf"
+    AsString,
+    /// The Excel error `#N/A`, so dependent formulas see an error rather than a blank
+    AsError,
+}
+
+impl NanPolicy {
+    /// Parse from string, returns None for invalid input
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "empty" => Some(NanPolicy::Empty),
+            "string" => Some(NanPolicy::AsString),
+            "error" => Some(NanPolicy::AsError),
+            _ => None,
+        }
+    }
+}
+
+/// How integers beyond 2^53 (the largest magnitude an f64 holds exactly) are written
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LargeIntMode {
+    /// Plain text cell holding every digit (default)
+    #[default]
+    String,
+    /// Excel number, accepting float rounding of the low digits
+    Number,
+    /// Text cell with the `@` (Text) number format, holding every digit
+    Text,
+}
+
+impl LargeIntMode {
+    /// Parse from string, returns None for invalid input
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "string" => Some(LargeIntMode::String),
+            "number" => Some(LargeIntMode::Number),
+            "text" => Some(LargeIntMode::Text),
+            _ => None,
+        }
+    }
+}
+
+/// How strings longer than Excel's 32,767-character cell limit are written
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LongStringMode {
+    /// Fail the write, naming the cell (default)
+    #[default]
+    Error,
+    /// Keep the first 32,767 characters and drop the rest
+    Truncate,
+    /// Keep the first 32,767 characters in the cell and the rest in a note
+    /// on it
+    Split,
+}
+
+impl LongStringMode {
+    /// Parse from string, returns None for invalid input
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "error" => Some(LongStringMode::Error),
+            "truncate" => Some(LongStringMode::Truncate),
+            "split" => Some(LongStringMode::Split),
+            _ => None,
+        }
+    }
+}
+
+/// How DataFrame booleans are written, from the `bool_mode` option
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) enum BoolMode {
+    /// Excel TRUE/FALSE booleans (`"boolean"`, the default)
+    #[default]
+    Boolean,
+    /// The numbers 1 and 0 (`"one_zero"`)
+    OneZero,
+    /// Text for true and for false: "Yes"/"No" (`"yes_no"`) or a custom pair
+    Text(String, String),
+}
+
+/// Excel's workbook calculation mode, from the `calc_mode` parameter
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum CalcMode {
+    /// Recalculate formulas whenever their inputs change (default)
+    #[default]
+    Auto,
+    /// Recalculate only on request (F9)
+    Manual,
+    /// Automatic, except for data tables
+    AutoExceptTables,
+}
+
+impl CalcMode {
+    /// Parse from string, returns None for invalid input
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "auto" => Some(CalcMode::Auto),
+            "manual" => Some(CalcMode::Manual),
+            "auto_except_tables" => Some(CalcMode::AutoExceptTables),
+            _ => None,
+        }
+    }
+}
+
+/// Workbook calculation settings from `calc_mode` and `force_recalc`. The
+/// default matches what rust_xlsxwriter writes on its own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CalcSettings {
+    pub(crate) mode: CalcMode,
+    /// Have Excel recalculate every formula when the file is opened
+    pub(crate) force_recalc: bool,
+}
+
+impl Default for CalcSettings {
+    fn default() -> Self {
+        Self {
+            mode: CalcMode::Auto,
+            force_recalc: true,
+        }
+    }
+}
+
+/// How `conditional_formats` patterns that match the same column combine
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum ConditionalFormatMode {
+    /// Every matching pattern's rules are applied, stacking on the column (default)
+    #[default]
+    AllMatches,
+    /// Only the first pattern (in dict order) that matches a column applies to it
+    FirstMatchWins,
+}
+
+impl ConditionalFormatMode {
+    /// Parse from string, returns None for invalid input
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "all_matches" => Some(ConditionalFormatMode::AllMatches),
+            "first_match_wins" => Some(ConditionalFormatMode::FirstMatchWins),
+            _ => None,
+        }
+    }
+}
+
+/// Which view a worksheet opens in, from the `view` option
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum SheetView {
+    /// The ordinary grid (default)
+    #[default]
+    Normal,
+    /// Pages laid out as printed, with headers and footers
+    PageLayout,
+    /// The grid with page breaks marked
+    PageBreak,
+}
+
+impl SheetView {
+    /// Parse from string, returns None for invalid input
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "normal" => Some(SheetView::Normal),
+            "page_layout" => Some(SheetView::PageLayout),
+            "page_break" => Some(SheetView::PageBreak),
+            _ => None,
+        }
+    }
+}
+
+/// Options for the CSV conversion functions, shared by the Python binding and
+/// the CLI. `Default` gives the historical behavior (auto date order, ISO
+/// date/datetime/time number formats, NaN/Inf as empty cells, large integers as
+/// strings, worksheet held in memory, formatted numbers left as strings,
+/// comma-separated UTF-8 fields quoted with `"`, every row written as data).
+#[derive(Debug, Clone, Default)]
+pub struct CsvOptions {
+    /// Date parsing order for ambiguous dates like 01-02-2024
+    pub date_order: DateOrder,
+    /// Excel number format for date cells (default: `yyyy-mm-dd`)
+    pub date_format: Option<String>,
+    /// Excel number format for datetime cells (default: `yyyy-mm-dd hh:mm:ss`)
+    pub datetime_format: Option<String>,
+    /// Excel number format for time-of-day cells (default: `hh:mm:ss`)
+    pub time_format: Option<String>,
+    /// How NaN/Inf values are written (default: empty cells)
+    pub nan_policy: NanPolicy,
+    /// How integers beyond 2^53 are written (default: strings)
+    pub large_int_mode: LargeIntMode,
+    /// How strings beyond Excel's 32,767-character cell limit are written
+    /// (default: error)
+    pub long_string_mode: LongStringMode,
+    /// Stream rows to disk with a constant-memory worksheet (sequential
+    /// conversion only; `convert_csv_to_xlsx_parallel` rejects it)
+    pub constant_memory: bool,
+    /// Detect `"45%"`, `"$1,234.50"`, and `"1,234"` as numbers rather than
+    /// strings (default: off)
+    pub smart_numeric: bool,
+    /// Field separator byte (default: `,`); see `parse_csv_char`
+    pub delimiter: Option<u8>,
+    /// Quote byte around fields (default: `"`)
+    pub quote: Option<u8>,
+    /// Escape byte for a quote inside a quoted field, as in `\"` (default:
+    /// none; a doubled quote `""` is always understood)
+    pub escape: Option<u8>,
+    /// Text encoding of the input file (default: UTF-8); see `parse_encoding`
+    pub encoding: Option<&'static encoding_rs::Encoding>,
+    /// Lines starting with this byte are comments and skipped (default: none)
+    pub comment: Option<u8>,
+    /// Skip rows whose fields are all empty or whitespace, such as `,,,`
+    /// separator lines (default: off; fully empty lines are always skipped)
+    pub skip_empty_rows: bool,
+    /// Field values written as empty cells, such as `NA` or `NULL`; compared
+    /// with surrounding whitespace trimmed and ASCII case ignored (default:
+    /// none, so only blank fields are empty)
+    pub null_values: Vec<String>,
+    /// Decimal separator of numeric fields, e.g. `,` for `1234,56` (default:
+    /// `.`); see `parse_number_separator`
+    pub decimal_separator: Option<char>,
+    /// Thousands separator of numeric fields, e.g. `.` for `1.234,56`
+    /// (default: none, so grouped numbers stay text unless `smart_numeric`)
+    pub thousands_separator: Option<char>,
+    /// Excel number format for numbers written in scientific notation, such
+    /// as `1.5e10`, e.g. `0.00E+00` (default: none, so they display in the
+    /// General format)
+    pub scientific_format: Option<String>,
+    /// 0-based positions of columns written as text as-is, skipping type
+    /// detection, so identifiers such as `01234` keep their leading zeros
+    /// (default: none)
+    pub text_columns: Vec<usize>,
+    /// Strip a leading byte order mark and replace control characters XML
+    /// forbids with spaces in every field (default: off)
+    pub sanitize_strings: bool,
+    /// Write the first row as a header: its fields are kept as text, styled
+    /// with `header_format`, and frozen in place below the scrolling data
+    pub has_header: bool,
+    /// Format for the header row when `has_header` is set (default: none)
+    pub header_format: Option<Format>,
+}
+
+/// Per-sheet CSV options from a `csvs_to_xlsx` input tuple (all optional,
+/// defaults to the workbook-wide keyword arguments)
+#[derive(Debug, Default)]
+pub(crate) struct CsvSheetConfig {
+    pub(crate) date_order: Option<String>,
+    pub(crate) date_format: Option<String>,
+    pub(crate) datetime_format: Option<String>,
+    pub(crate) time_format: Option<String>,
+    pub(crate) nan_policy: Option<String>,
+    pub(crate) large_int_mode: Option<String>,
+    pub(crate) long_string_mode: Option<String>,
+    pub(crate) constant_memory: Option<bool>,
+    pub(crate) smart_numeric: Option<bool>,
+    pub(crate) delimiter: Option<String>,
+    pub(crate) quote: Option<String>,
+    pub(crate) escape: Option<String>,
+    pub(crate) has_header: Option<bool>,
+    pub(crate) header_format: Option<HashMap<String, Py<PyAny>>>,
+    pub(crate) encoding: Option<String>,
+    pub(crate) comment: Option<String>,
+    pub(crate) skip_empty_rows: Option<bool>,
+    pub(crate) null_values: Option<Vec<String>>,
+    pub(crate) decimal_separator: Option<String>,
+    pub(crate) thousands_separator: Option<String>,
+    pub(crate) scientific_format: Option<String>,
+    pub(crate) text_columns: Option<Vec<usize>>,
+    pub(crate) sanitize_strings: Option<bool>,
+}
+
 /// Datetime formats we recognize
 pub(crate) const DATETIME_PATTERNS: &[&str] = &[
     "%Y-%m-%dT%H:%M:%S",    // ISO 8601
@@ -73,6 +346,12 @@ pub(crate) const DATETIME_PATTERNS: &[&str] = &[
     "%Y-%m-%d %H:%M:%S%.f", // With fractional seconds
 ];
 
+/// Time-of-day formats we recognize
+pub(crate) const TIME_PATTERNS: &[&str] = &[
+    "%H:%M:%S",    // 14:30:00
+    "%H:%M:%S%.f", // With fractional seconds
+];
+
 /// Represents the detected type of a cell value
 #[derive(Debug, Clone)]
 pub(crate) enum CellValue {
@@ -80,19 +359,43 @@ pub(crate) enum CellValue {
     Integer(i64),
     Float(f64),
     Boolean(bool),
-    Date(f64),     // Excel serial date
-    DateTime(f64), // Excel serial datetime
+    Date(f64),       // Excel serial date
+    DateTime(f64),   // Excel serial datetime
+    Time(f64),       // Excel time (fraction of a day)
+    NonFinite(f64),  // NaN or +/-Inf, written per NanPolicy
+    Percent(f64),    // "45%" as 0.45 (smart_numeric CSV only)
+    Currency(f64),   // "$1,234.50" as 1234.5 (smart_numeric CSV only)
+    Scientific(f64), // "1.5e10" as 1.5e10 (scientific_format CSV only)
+    Text(String),    // Field kept as text with the `@` format (text_columns CSV only)
     String(String),
 }
 
-/// Type alias for merged range tuple: (range_str, text, optional format_dict)
-pub(crate) type MergedRange = (String, String, Option<HashMap<String, Py<PyAny>>>);
+/// Type alias for merged range tuple: (range_str, value, optional format_dict).
+/// The value is a string or any value `cells` accepts.
+pub(crate) type MergedRange = (String, Py<PyAny>, Option<HashMap<String, Py<PyAny>>>);
+
+/// One `row_groups`/`col_groups` entry: an inclusive zero-based index range
+/// outlined at `level` (1-7), optionally collapsed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct OutlineGroup {
+    pub(crate) first: u32,
+    pub(crate) last: u32,
+    pub(crate) level: u8,
+    pub(crate) collapsed: bool,
+}
 
-/// Type alias for hyperlink tuple: (cell_ref, url, optional display_text)
-pub(crate) type Hyperlink = (String, String, Option<String>);
+/// Type alias for hyperlink tuple: (cell_ref, url, optional display_text,
+/// optional tooltip)
+pub(crate) type Hyperlink = (String, String, Option<String>, Option<String>);
 
-/// Type alias for comment: either simple text or dict with 'text' and optionally 'author'
-pub(crate) type Comment = (String, Option<String>); // (text, author)
+/// Comment (note) config extracted from the Python API: simple text, or a dict
+/// with 'text', an optional 'author', and note styling options.
+#[derive(Debug)]
+pub(crate) struct Comment {
+    pub(crate) text: String,
+    pub(crate) author: Option<String>,
+    pub(crate) options: Option<HashMap<String, Py<PyAny>>>,
+}
 
 /// Type alias for validation: column name/pattern -> validation config
 pub(crate) type ValidationConfig = HashMap<String, Py<PyAny>>;
@@ -100,10 +403,17 @@ pub(crate) type ValidationConfig = HashMap<String, Py<PyAny>>;
 /// Type alias for rich text segment: (text, optional format_dict) or just text
 pub(crate) type RichTextSegment = (String, Option<HashMap<String, Py<PyAny>>>);
 
+/// Where an image comes from: a file path or in-memory `bytes`.
+#[derive(Debug)]
+pub(crate) enum ImageSource {
+    Path(String),
+    Data(Vec<u8>),
+}
+
 /// Image insertion config extracted from the Python API.
 #[derive(Debug)]
 pub(crate) struct ImageConfig {
-    pub(crate) path: String,
+    pub(crate) source: ImageSource,
     pub(crate) options: Option<HashMap<String, Py<PyAny>>>,
 }
 
@@ -127,6 +437,45 @@ pub(crate) type ChartConfig = HashMap<String, Py<PyAny>>;
 /// Type alias for sparkline config: location ref -> sparkline options dict
 pub(crate) type SparklineConfig = HashMap<String, Py<PyAny>>;
 
+/// A `column_formats` key: a DataFrame column position (Python `int` key) or a
+/// column-name pattern (Python `str` key)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum ColumnFormatKey {
+    Index(usize),
+    Pattern(String),
+}
+
+impl std::fmt::Display for ColumnFormatKey {
+    /// Render the key as written in Python, for error context: `2` or `'price_*'`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnFormatKey::Index(idx) => write!(f, "{}", idx),
+            ColumnFormatKey::Pattern(pattern) => write!(f, "'{}'", pattern),
+        }
+    }
+}
+
+/// A `columns` entry: a DataFrame column name (Python `str`) or position
+/// (Python `int`) to write
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ColumnSelector {
+    Index(usize),
+    Name(String),
+}
+
+impl std::fmt::Display for ColumnSelector {
+    /// Render the entry as written in Python, for error context: `2` or `'price'`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnSelector::Index(idx) => write!(f, "{}", idx),
+            ColumnSelector::Name(name) => write!(f, "'{}'", name),
+        }
+    }
+}
+
+/// Type alias for column format configs: column index or pattern -> format dict (ordered)
+pub(crate) type ColumnFormatConfigs = IndexMap<ColumnFormatKey, HashMap<String, Py<PyAny>>>;
+
 /// Type alias for conditional format configs: column/pattern -> list of format config dicts
 pub(crate) type ConditionalFormatConfigs = IndexMap<String, Vec<HashMap<String, Py<PyAny>>>>;
 
@@ -142,6 +491,16 @@ pub(crate) struct CellWrite {
     pub(crate) wrap_text: bool,
 }
 
+/// One `cell_overrides` entry: a value and/or a full format for one cell,
+/// applied after all DataFrame data and `cells` writes
+#[derive(Debug)]
+pub(crate) struct CellOverride {
+    pub(crate) row: u32,
+    pub(crate) col: u16,
+    pub(crate) value: Option<Py<PyAny>>, // None = restyle the cell, keeping its value
+    pub(crate) format: Option<HashMap<String, Py<PyAny>>>, // column_formats-style dict
+}
+
 /// Infallible variant of `PyAny::get_type().name()` returning "unknown" on failure.
 /// Used for error-message construction where we must produce a String even if the
 /// name lookup itself errors (e.g., during another exception's formatting).
@@ -441,6 +800,19 @@ pub(crate) fn is_polars_dataframe(df: &Bound<'_, PyAny>) -> Result<bool, String>
     }
 }
 
+/// Materialize a polars LazyFrame so the eager DataFrame path can write it.
+///
+/// A LazyFrame has `collect` and `schema` but none of the `iter_rows`/`shape`
+/// the writer relies on; anything else is returned unchanged.
+pub(crate) fn collect_if_lazy<'py>(df: Bound<'py, PyAny>) -> Result<Bound<'py, PyAny>, String> {
+    let has = |attr: &str| df.hasattr(attr).unwrap_or(false);
+    if !(has("collect") && has("schema")) || has("iter_rows") || has("shape") {
+        return Ok(df);
+    }
+    df.call_method0("collect")
+        .map_err(|e| format!("Failed to collect polars LazyFrame: {}", e))
+}
+
 /// Extract column names from a DataFrame (Polars or Pandas).
 pub(crate) fn extract_columns(
     df: &Bound<'_, PyAny>,
@@ -468,28 +840,232 @@ pub(crate) fn extract_columns(
     }
 }
 
+/// Where to freeze panes, from the `freeze_panes` option
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum FreezePanes {
+    /// No frozen panes (`False`, the default)
+    #[default]
+    Off,
+    /// Freeze the header row, wherever `start_row` puts it (`True`)
+    HeaderRow,
+    /// Freeze rows above and columns left of this absolute (row, col) cell
+    At(u32, u16),
+    /// Freeze the first `rows` rows and `cols` columns of the written block,
+    /// counted from `start_row`/`start_col` (`{"rows": N, "cols": M}`)
+    Leading { rows: u32, cols: u16 },
+}
+
+/// Which columns to size to their content, from the `autofit` option
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) enum Autofit {
+    /// Keep Excel's column widths (`False`, the default)
+    #[default]
+    Off,
+    /// Autofit every column (`True`)
+    All,
+    /// Autofit only these written columns, by name or 0-based position
+    Columns(Vec<ColumnSelector>),
+}
+
+/// Tab visibility of a sheet, from the per-sheet `visible` option
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum SheetVisibility {
+    /// Shown in the tab bar (`True`, the default)
+    #[default]
+    Visible,
+    /// Hidden, but can be unhidden from Excel's UI (`False`)
+    Hidden,
+    /// Hidden and only unhideable via VBA (`"very_hidden"`)
+    VeryHidden,
+}
+
+/// A sheet chosen by position or by name, from `dfs_to_xlsx(active_sheet=...)`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SheetSelector {
+    Index(usize),
+    Name(String),
+}
+
+/// Printed page orientation, from `page_setup["orientation"]`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PageOrientation {
+    Portrait,
+    Landscape,
+}
+
+/// Printed page margins in inches; `None` keeps Excel's default for that edge
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct PageMargins {
+    pub(crate) left: Option<f64>,
+    pub(crate) right: Option<f64>,
+    pub(crate) top: Option<f64>,
+    pub(crate) bottom: Option<f64>,
+    pub(crate) header: Option<f64>,
+    pub(crate) footer: Option<f64>,
+}
+
+/// Printed page header or footer from `page_setup["header"]`/`["footer"]`:
+/// an Excel header/footer format string like `"&CPage &P of &N"`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct HeaderFooter {
+    pub(crate) text: String,
+    /// Image paths for the left, center and right sections, each shown at
+    /// the `&[Picture]` placeholder in that section of `text`
+    pub(crate) images: [Option<String>; 3],
+}
+
+/// Print layout from the `page_setup` option. Unset fields keep Excel's
+/// defaults; none of them affect the written data.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct PageSetup {
+    pub(crate) orientation: Option<PageOrientation>,
+    pub(crate) fit_to_pages: Option<(u16, u16)>, // (width, height) in pages; 0 = as many as needed
+    pub(crate) paper_size: Option<u8>,
+    pub(crate) margins: Option<PageMargins>,
+    pub(crate) header: Option<HeaderFooter>,
+    pub(crate) footer: Option<HeaderFooter>,
+    pub(crate) header_footer_scale_with_doc: Option<bool>,
+}
+
+/// Totals-row function for a table column, from `table_columns[i]["total_function"]`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TableTotal {
+    Average,
+    Count,
+    CountNumbers,
+    Max,
+    Min,
+    Sum,
+    StdDev,
+    Var,
+}
+
+/// One `table_columns` entry, overriding a table column's header text and/or
+/// its cell in the totals row. Any totals entry turns the totals row on.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct TableColumnConfig {
+    pub(crate) header: Option<String>,
+    pub(crate) total_function: Option<TableTotal>,
+    pub(crate) total_label: Option<String>,
+}
+
+/// Table look toggles from the `table_options` dict. `Default` matches a table
+/// without it: banded rows on, banded columns and first/last column emphasis off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TableOptions {
+    pub(crate) banded_rows: bool,
+    pub(crate) banded_columns: bool,
+    pub(crate) first_column: bool,
+    pub(crate) last_column: bool,
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        TableOptions {
+            banded_rows: true,
+            banded_columns: false,
+            first_column: false,
+            last_column: false,
+        }
+    }
+}
+
+/// One `tables` entry: an extra Excel table over an explicit cell range, whose
+/// first row is the header row and, with `total_row`, last row the totals row.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TableSpec {
+    pub(crate) range: String,
+    pub(crate) style: Option<String>,
+    pub(crate) name: Option<String>,
+    pub(crate) total_row: bool,
+}
+
+/// How a formula column is written, from `formula_columns[name]["formula_type"]`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum FormulaType {
+    /// One formula per data row (the default)
+    #[default]
+    Regular,
+    /// A single dynamic array formula in the first data row that spills down
+    Dynamic,
+    /// A single legacy CSE array formula over a range
+    Array,
+}
+
+/// Where a formula column is inserted among the DataFrame columns, from
+/// `formula_columns[name]["position"]`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FormulaPosition {
+    /// Before the DataFrame column at this 0-based index (an int); the column
+    /// count means after the last one
+    Index(usize),
+    /// Right after the named DataFrame column (`"after:col_name"`)
+    After(String),
+}
+
+/// One `formula_columns` entry: a template string, or a dict adding a
+/// `formula_type`, for array formulas a target range as
+/// (first_row, first_col, last_row, last_col), an insert `position`, and a
+/// cached `formula_result` (a constant or a callable taking the row index).
+/// Without a position the column is appended after the data.
+#[derive(Debug)]
+pub(crate) struct FormulaColumn {
+    pub(crate) template: String,
+    pub(crate) formula_type: FormulaType,
+    pub(crate) range: Option<(u32, u16, u32, u16)>,
+    pub(crate) position: Option<FormulaPosition>,
+    pub(crate) result: Option<Py<PyAny>>,
+}
+
+/// Aggregate for one column of a `summary_row`, from `summary_row[col]`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SummaryFunction {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Count,
+}
+
+/// Sheet protection from the `protect` option. Data cells in the columns
+/// matched by `unlocked_columns` (names or wildcard patterns) stay editable.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct Protection {
+    pub(crate) password: Option<String>,
+    pub(crate) unlocked_columns: Vec<String>,
+}
+
 /// Per-sheet configuration options (all optional, defaults to global settings)
 #[derive(Debug, Default)]
 pub(crate) struct SheetConfig {
     pub(crate) header: Option<bool>,
-    pub(crate) autofit: Option<bool>,
+    pub(crate) autofit: Option<Autofit>,
     pub(crate) table_style: Option<Option<String>>, // None = use default, Some(None) = explicitly no style
-    pub(crate) freeze_panes: Option<bool>,
+    pub(crate) freeze_panes: Option<FreezePanes>,
+    pub(crate) freeze_top_cell: Option<(u32, u16)>,
+    pub(crate) split_panes: Option<(u32, u16)>,
     pub(crate) column_widths: Option<HashMap<String, f64>>, // Keys: "0", "1", "_all" for global cap
     pub(crate) table_name: Option<String>,
+    pub(crate) table_columns: Option<Vec<TableColumnConfig>>,
+    pub(crate) table_options: Option<TableOptions>,
+    pub(crate) tables: Option<Vec<TableSpec>>,
     pub(crate) header_format: Option<HashMap<String, Py<PyAny>>>,
+    pub(crate) cell_format: Option<HashMap<String, Py<PyAny>>>,
+    pub(crate) link_format: Option<HashMap<String, Py<PyAny>>>,
     pub(crate) row_heights: Option<HashMap<u32, f64>>,
-    pub(crate) column_formats: Option<IndexMap<String, HashMap<String, Py<PyAny>>>>, // Pattern -> format dict (ordered)
+    pub(crate) column_formats: Option<ColumnFormatConfigs>, // Index or pattern -> format dict (ordered)
     pub(crate) conditional_formats: Option<ConditionalFormatConfigs>, // Column/pattern -> list of conditional format configs
-    pub(crate) formula_columns: Option<IndexMap<String, String>>, // Column name -> formula template (ordered)
-    pub(crate) merged_ranges: Option<Vec<MergedRange>>,           // (range, text, format)
-    pub(crate) hyperlinks: Option<Vec<Hyperlink>>, // (cell, url, optional display_text)
+    pub(crate) formula_columns: Option<IndexMap<String, FormulaColumn>>, // Column name -> formula (ordered)
+    pub(crate) merged_ranges: Option<Vec<MergedRange>>,                  // (range, text, format)
+    pub(crate) hyperlinks: Option<Vec<Hyperlink>>, // (cell, url, optional display_text, optional tooltip)
+    pub(crate) row_groups: Option<Vec<OutlineGroup>>,
+    pub(crate) col_groups: Option<Vec<OutlineGroup>>,
     // The following feature maps use `IndexMap` (not `HashMap`) so their
     // iteration order follows Python dict insertion order — a `HashMap`'s
     // random iteration order would make generated workbooks non-reproducible
     // byte-for-byte across runs (the XML parts list objects in insertion
     // order).
-    pub(crate) comments: Option<IndexMap<String, Comment>>, // cell_ref -> (text, author)
+    pub(crate) comments: Option<IndexMap<String, Comment>>, // cell_ref -> text, author, style
     pub(crate) validations: Option<IndexMap<String, ValidationConfig>>, // column name/pattern -> validation config
     pub(crate) rich_text: Option<IndexMap<String, Vec<RichTextSegment>>>, // cell_ref -> segments
     pub(crate) images: Option<IndexMap<String, ImageConfig>>,
@@ -498,6 +1074,59 @@ pub(crate) struct SheetConfig {
     pub(crate) charts: Option<IndexMap<String, ChartConfig>>, // cell_ref -> chart options
     pub(crate) sparklines: Option<IndexMap<String, SparklineConfig>>, // location ref -> sparkline options
     pub(crate) cells: Option<Vec<CellWrite>>,
+    pub(crate) cell_overrides: Option<Vec<CellOverride>>,
+    pub(crate) date_format: Option<String>,
+    pub(crate) datetime_format: Option<String>,
+    pub(crate) time_format: Option<String>,
+    pub(crate) nan_policy: Option<String>,
+    pub(crate) large_int_mode: Option<String>,
+    pub(crate) long_string_mode: Option<String>,
+    pub(crate) start_row: Option<u32>,
+    pub(crate) start_col: Option<u16>,
+    pub(crate) autofilter: Option<bool>,
+    pub(crate) conditional_format_mode: Option<String>,
+    pub(crate) show_gridlines: Option<bool>,
+    pub(crate) show_row_col_headers: Option<bool>,
+    pub(crate) zoom: Option<u16>,
+    pub(crate) view: Option<String>,
+    pub(crate) right_to_left: Option<bool>,
+    pub(crate) outline_below: Option<bool>,
+    pub(crate) outline_right: Option<bool>,
+    pub(crate) tab_color: Option<String>,
+    pub(crate) page_setup: Option<PageSetup>,
+    pub(crate) print_area: Option<String>,
+    pub(crate) background_image: Option<ImageSource>,
+    pub(crate) categorical_validation: Option<bool>,
+    pub(crate) integer_like_floats: Option<bool>,
+    pub(crate) auto_hyperlinks: Option<bool>,
+    pub(crate) auto_rich_text_columns: Option<Vec<String>>,
+    pub(crate) infer_string_types: Option<bool>,
+    pub(crate) bool_mode: Option<BoolMode>,
+    pub(crate) text_columns: Option<Vec<ColumnSelector>>,
+    pub(crate) sanitize_strings: Option<bool>,
+    pub(crate) columns: Option<Vec<ColumnSelector>>,
+    pub(crate) skip_rows: Option<usize>,
+    pub(crate) max_rows: Option<usize>,
+    pub(crate) transpose: Option<bool>,
+    pub(crate) include_index: Option<bool>,
+    pub(crate) banded_rows: Option<(String, String)>,
+    pub(crate) summary_row: Option<IndexMap<String, SummaryFunction>>,
+    pub(crate) default_col_width: Option<f64>,
+    pub(crate) default_row_height: Option<f64>,
+    pub(crate) protect: Option<Option<Protection>>, // Some(None) = explicitly unprotected
+    pub(crate) visible: Option<SheetVisibility>,    // dfs_to_xlsx only; no global kwarg
+    pub(crate) active_cell: Option<(u32, u16)>,     // dfs_to_xlsx only; no global kwarg
+}
+
+/// Row-progress reporting for `df_to_xlsx`: `callback` is called with the
+/// number of data rows written after every `every` rows. `cancelled` is set
+/// when the callback cancels the write, so the caller can tell that error
+/// apart from a failed write.
+#[derive(Clone, Copy)]
+pub(crate) struct Progress<'a> {
+    pub(crate) callback: &'a Py<PyAny>,
+    pub(crate) every: usize,
+    pub(crate) cancelled: &'a Cell<bool>,
 }
 
 /// Scalar configuration for writing a single sheet.
@@ -505,12 +1134,58 @@ pub(crate) struct SheetConfig {
 /// and apply_worksheet_features.
 pub(crate) struct WriteConfig<'a> {
     pub(crate) include_header: bool,
-    pub(crate) autofit: bool,
+    pub(crate) autofit: &'a Autofit,
     pub(crate) table_style: Option<&'a str>,
-    pub(crate) freeze_panes: bool,
+    pub(crate) freeze_panes: FreezePanes,
+    pub(crate) freeze_top_cell: Option<(u32, u16)>,
+    pub(crate) split_panes: Option<(u32, u16)>,
     pub(crate) table_name: Option<&'a str>,
+    pub(crate) table_columns: Option<&'a [TableColumnConfig]>,
+    pub(crate) table_options: Option<TableOptions>,
+    pub(crate) tables: Option<&'a [TableSpec]>,
     pub(crate) row_heights: Option<&'a HashMap<u32, f64>>,
     pub(crate) constant_memory: bool,
+    pub(crate) date_format: &'a str,
+    pub(crate) datetime_format: &'a str,
+    pub(crate) time_format: &'a str,
+    pub(crate) nan_policy: NanPolicy,
+    pub(crate) large_int_mode: LargeIntMode,
+    pub(crate) long_string_mode: LongStringMode,
+    pub(crate) start_row: u32,
+    pub(crate) start_col: u16,
+    pub(crate) autofilter: bool,
+    pub(crate) conditional_format_mode: ConditionalFormatMode,
+    pub(crate) show_gridlines: bool,
+    pub(crate) show_row_col_headers: bool,
+    pub(crate) zoom: Option<u16>,
+    pub(crate) view: SheetView,
+    pub(crate) right_to_left: bool,
+    pub(crate) outline_below: bool,
+    pub(crate) outline_right: bool,
+    pub(crate) tab_color: Option<&'a str>,
+    pub(crate) page_setup: Option<&'a PageSetup>,
+    pub(crate) print_area: Option<&'a str>,
+    pub(crate) background_image: Option<&'a ImageSource>,
+    pub(crate) categorical_validation: bool,
+    pub(crate) integer_like_floats: bool,
+    pub(crate) columns: Option<&'a [ColumnSelector]>,
+    pub(crate) skip_rows: usize,
+    pub(crate) max_rows: Option<usize>,
+    pub(crate) transpose: bool,
+    pub(crate) include_index: bool,
+    pub(crate) banded_rows: Option<(&'a str, &'a str)>,
+    pub(crate) summary_row: Option<&'a IndexMap<String, SummaryFunction>>,
+    pub(crate) default_col_width: Option<f64>,
+    pub(crate) default_row_height: Option<f64>,
+    pub(crate) protect: Option<&'a Protection>,
+    pub(crate) auto_hyperlinks: bool,
+    pub(crate) auto_rich_text_columns: Option<&'a [String]>,
+    pub(crate) infer_string_types: bool,
+    pub(crate) bool_mode: &'a BoolMode,
+    pub(crate) text_columns: Option<&'a [ColumnSelector]>,
+    pub(crate) sanitize_strings: bool,
+    pub(crate) sheet_names: &'a [String], // every sheet in the workbook, for internal links
+    pub(crate) progress: Option<Progress<'a>>, // df_to_xlsx only
 }
 
 /// Minimal "is this collection empty" trait so `present_complex_options` can
@@ -624,11 +1299,15 @@ macro_rules! define_options {
 define_options! {
     column_widths: HashMap<String, f64>,
     header_format: HashMap<String, Py<PyAny>>,
-    column_formats: IndexMap<String, HashMap<String, Py<PyAny>>>,
+    cell_format: HashMap<String, Py<PyAny>>,
+    link_format: HashMap<String, Py<PyAny>>,
+    column_formats: ColumnFormatConfigs,
     conditional_formats: ConditionalFormatConfigs,
-    formula_columns: IndexMap<String, String>,
+    formula_columns: IndexMap<String, FormulaColumn>,
     merged_ranges: Vec<MergedRange>,
     hyperlinks: Vec<Hyperlink>,
+    row_groups: Vec<OutlineGroup>,
+    col_groups: Vec<OutlineGroup>,
     comments: IndexMap<String, Comment>,
     validations: IndexMap<String, ValidationConfig>,
     rich_text: IndexMap<String, Vec<RichTextSegment>>,
@@ -638,6 +1317,7 @@ define_options! {
     charts: IndexMap<String, ChartConfig>,
     sparklines: IndexMap<String, SparklineConfig>,
     cells: Vec<CellWrite>,
+    cell_overrides: Vec<CellOverride>,
 }
 
 #[cfg(test)]
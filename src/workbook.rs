@@ -1,7 +1,306 @@
 //! Shared workbook-level helpers.
 
-use rust_xlsxwriter::Workbook;
+use crate::types::{reject_unknown_keys, CalcMode, CalcSettings, SheetSelector, SheetVisibility};
+use chrono::{Datelike, Timelike};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use rust_xlsxwriter::{DocProperties, ExcelDateTime, Workbook};
 use std::collections::HashMap;
+use std::io::{Cursor, Read, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// In-memory destination used by the `*_to_xlsx_bytes` functions.
+///
+/// They pass an instance as `output_path` when forwarding their keyword
+/// arguments, so every writer option stays available without repeating the
+/// full signatures. Never exposed to Python callers.
+#[pyclass(module = "xlsxturbo")]
+#[derive(Default)]
+pub(crate) struct BufferTarget {
+    bytes: Option<Vec<u8>>,
+}
+
+impl BufferTarget {
+    pub(crate) fn take_bytes(&mut self) -> Vec<u8> {
+        self.bytes.take().unwrap_or_default()
+    }
+}
+
+/// Where a finished workbook is written.
+pub(crate) enum OutputTarget {
+    Path(String),
+    /// A Python object with a `write()` method (`io.BytesIO`, an open file,
+    /// a cloud storage stream, ...). Receives the whole workbook in one call.
+    Writer(Py<PyAny>),
+    Buffer(Py<BufferTarget>),
+}
+
+/// Save `workbook` to `output`. Calculation settings other than the default,
+/// `split_panes`, and `hidden_headers` are patched into the saved package,
+/// since rust_xlsxwriter always writes automatic calculation with a full
+/// recalculation on load, only writes frozen panes, and always shows the row
+/// and column headers. `split_panes` lists the 0-based worksheet index and
+/// split cell of each sheet whose frozen panes become split panes;
+/// `hidden_headers` lists the 0-based index of each sheet whose row and column
+/// headers are hidden.
+pub(crate) fn save_workbook(
+    py: Python<'_>,
+    workbook: &mut Workbook,
+    output: &OutputTarget,
+    calc: CalcSettings,
+    split_panes: &[(usize, (u32, u16))],
+    hidden_headers: &[usize],
+) -> Result<(), String> {
+    if calc != CalcSettings::default() || !split_panes.is_empty() || !hidden_headers.is_empty() {
+        let bytes = workbook
+            .save_to_buffer()
+            .map_err(|e| format!("Failed to write workbook to buffer: {}", e))?;
+        let bytes = patch_package(&bytes, calc, split_panes, hidden_headers)?;
+        return match output {
+            OutputTarget::Path(path) => std::fs::write(path, bytes)
+                .map_err(|e| format!("Failed to save workbook to '{}': {}", path, e)),
+            OutputTarget::Writer(writer) => writer
+                .bind(py)
+                .call_method1("write", (PyBytes::new(py, &bytes),))
+                .map(|_| ())
+                .map_err(|e| format!("Failed to write workbook to output object: {}", e)),
+            OutputTarget::Buffer(buffer) => {
+                buffer.borrow_mut(py).bytes = Some(bytes);
+                Ok(())
+            }
+        };
+    }
+    match output {
+        OutputTarget::Path(path) => workbook
+            .save(path)
+            .map_err(|e| format!("Failed to save workbook to '{}': {}", path, e)),
+        OutputTarget::Writer(writer) => {
+            let bytes = workbook
+                .save_to_buffer()
+                .map_err(|e| format!("Failed to write workbook to buffer: {}", e))?;
+            writer
+                .bind(py)
+                .call_method1("write", (PyBytes::new(py, &bytes),))
+                .map_err(|e| format!("Failed to write workbook to output object: {}", e))?;
+            Ok(())
+        }
+        OutputTarget::Buffer(buffer) => {
+            let bytes = workbook
+                .save_to_buffer()
+                .map_err(|e| format!("Failed to write workbook to buffer: {}", e))?;
+            buffer.borrow_mut(py).bytes = Some(bytes);
+            Ok(())
+        }
+    }
+}
+
+/// The `<calcPr>` element rust_xlsxwriter writes into `xl/workbook.xml`.
+const DEFAULT_CALC_PR: &str = r#"<calcPr calcId="124519" fullCalcOnLoad="1"/>"#;
+
+/// Twips (1/20 point) of a default 64-pixel column and 15-point row, and of
+/// the row and column headers, for placing split bars.
+const COL_TWIPS: u32 = 960;
+const ROW_TWIPS: u32 = 300;
+const ROW_HEADER_TWIPS: u32 = 390;
+const COL_HEADER_TWIPS: u32 = 300;
+
+/// Rewrite the parts of a saved workbook that rust_xlsxwriter can't write:
+/// the `<calcPr>` element for non-default `calc` settings, the panes of the
+/// `split_panes` sheets, and the sheet view of the `hidden_headers` sheets.
+/// Every other part is copied unchanged.
+fn patch_package(
+    bytes: &[u8],
+    calc: CalcSettings,
+    split_panes: &[(usize, (u32, u16))],
+    hidden_headers: &[usize],
+) -> Result<Vec<u8>, String> {
+    let fail = |e: &dyn std::fmt::Display| format!("Failed to write workbook package: {}", e);
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(|e| fail(&e))?;
+    let mut writer = ZipWriter::new(Cursor::new(Vec::with_capacity(bytes.len())));
+    for index in 0..archive.len() {
+        let file = archive.by_index_raw(index).map_err(|e| fail(&e))?;
+        let name = file.name().to_string();
+        let is_sheet = |sheet: usize| name == format!("xl/worksheets/sheet{}.xml", sheet + 1);
+        let split = split_panes
+            .iter()
+            .find(|&&(sheet, _)| is_sheet(sheet))
+            .map(|&(_, cell)| cell);
+        let hide_headers = hidden_headers.iter().any(|&sheet| is_sheet(sheet));
+        let set_calc = name == "xl/workbook.xml" && calc != CalcSettings::default();
+        if split.is_none() && !hide_headers && !set_calc {
+            writer.raw_copy_file(file).map_err(|e| fail(&e))?;
+            continue;
+        }
+        drop(file);
+        let mut xml = String::new();
+        archive
+            .by_index(index)
+            .and_then(|mut file| Ok(file.read_to_string(&mut xml)?))
+            .map_err(|e| fail(&e))?;
+        if let Some(cell) = split {
+            xml = split_frozen_pane(xml, cell);
+        }
+        if hide_headers {
+            xml = hide_row_col_headers(xml);
+        }
+        if set_calc {
+            xml = set_calc_pr(&xml, calc)?;
+        }
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        writer.start_file(name, options).map_err(|e| fail(&e))?;
+        writer.write_all(xml.as_bytes()).map_err(|e| fail(&e))?;
+    }
+    Ok(writer.finish().map_err(|e| fail(&e))?.into_inner())
+}
+
+/// Turn the frozen `<pane>` rust_xlsxwriter wrote at `(row, col)` into a
+/// split pane: the split positions become twips at default row heights and
+/// column widths, and the frozen state is dropped. A sheet without a pane
+/// (a `constant_memory` sheet) is returned unchanged.
+fn split_frozen_pane(xml: String, (row, col): (u32, u16)) -> String {
+    let Some(start) = xml.find("<pane ") else {
+        return xml;
+    };
+    let Some(len) = xml[start..].find("/>") else {
+        return xml;
+    };
+    let end = start + len + 2;
+    let pane = xml[start..end]
+        .replace(
+            &format!(r#"xSplit="{}""#, col),
+            &format!(
+                r#"xSplit="{}""#,
+                ROW_HEADER_TWIPS + COL_TWIPS * u32::from(col)
+            ),
+        )
+        .replace(
+            &format!(r#"ySplit="{}""#, row),
+            &format!(r#"ySplit="{}""#, COL_HEADER_TWIPS + ROW_TWIPS * row),
+        )
+        .replace(r#" state="frozen""#, "");
+    format!("{}{}{}", &xml[..start], pane, &xml[end..])
+}
+
+/// Hide the row and column headers in the `<sheetView>` rust_xlsxwriter
+/// wrote. A sheet without one is returned unchanged.
+fn hide_row_col_headers(xml: String) -> String {
+    const SHEET_VIEW: &str = "<sheetView ";
+    match xml.find(SHEET_VIEW) {
+        Some(start) => {
+            let at = start + SHEET_VIEW.len();
+            format!(r#"{}showRowColHeaders="0" {}"#, &xml[..at], &xml[at..])
+        }
+        None => xml,
+    }
+}
+
+/// Rewrite the `<calcPr>` element of `workbook.xml` for `calc`.
+fn set_calc_pr(xml: &str, calc: CalcSettings) -> Result<String, String> {
+    let mode = match calc.mode {
+        CalcMode::Auto => "",
+        CalcMode::Manual => r#" calcMode="manual""#,
+        CalcMode::AutoExceptTables => r#" calcMode="autoNoTable""#,
+    };
+    let full_calc = if calc.force_recalc {
+        r#" fullCalcOnLoad="1""#
+    } else {
+        ""
+    };
+    let calc_pr = format!(r#"<calcPr calcId="124519"{}{}/>"#, mode, full_calc);
+    if !xml.contains(DEFAULT_CALC_PR) {
+        return Err(
+            "Failed to set calculation settings: workbook.xml has no calcPr element".to_string(),
+        );
+    }
+    Ok(xml.replace(DEFAULT_CALC_PR, &calc_pr))
+}
+
+/// Valid keys for the `properties` dict.
+const DOC_PROPERTY_KEYS: &[&str] = &[
+    "title", "subject", "author", "manager", "company", "keywords", "comments", "category",
+    "created",
+];
+
+/// Build the document properties for the `properties` dict. `created` is an
+/// ISO 8601 date or datetime; one without a UTC offset is taken as UTC.
+pub(crate) fn parse_doc_properties(
+    properties: &HashMap<String, String>,
+) -> Result<DocProperties, String> {
+    reject_unknown_keys(
+        properties.keys().map(String::as_str),
+        "properties",
+        None,
+        DOC_PROPERTY_KEYS,
+    )?;
+    let mut doc = DocProperties::new();
+    for (key, value) in properties {
+        doc = match key.as_str() {
+            "title" => doc.set_title(value),
+            "subject" => doc.set_subject(value),
+            "author" => doc.set_author(value),
+            "manager" => doc.set_manager(value),
+            "company" => doc.set_company(value),
+            "keywords" => doc.set_keywords(value),
+            "comments" => doc.set_comment(value),
+            "category" => doc.set_category(value),
+            _ => doc.set_creation_datetime(&parse_created(value)?),
+        };
+    }
+    Ok(doc)
+}
+
+/// The `created` timestamp written with `deterministic=True` when `properties`
+/// has no `created` key: 1980-01-01, the date rust_xlsxwriter already gives
+/// every zip entry, so the whole file is independent of the time of writing.
+pub(crate) fn deterministic_created() -> Result<ExcelDateTime, String> {
+    ExcelDateTime::from_ymd(1980, 1, 1).map_err(|e| e.to_string())
+}
+
+/// Parse the `created` property into the UTC datetime Excel stores.
+fn parse_created(value: &str) -> Result<ExcelDateTime, String> {
+    let utc = parse_iso_datetime(value).ok_or_else(|| {
+        format!(
+            "properties: invalid 'created' value '{}'. Use an ISO 8601 date or datetime, e.g. '2024-01-15T09:30:00'",
+            value
+        )
+    })?;
+    utc_excel_datetime(utc)
+        .map_err(|e| format!("properties: invalid 'created' value '{}': {}", value, e))
+}
+
+/// Parse an ISO 8601 date or datetime as a UTC datetime. A value with a UTC
+/// offset is converted to UTC; one without is taken as UTC already.
+pub(crate) fn parse_iso_datetime(value: &str) -> Option<chrono::NaiveDateTime> {
+    let trimmed = value.trim();
+    chrono::DateTime::parse_from_rfc3339(trimmed)
+        .map(|dt| dt.naive_utc())
+        .ok()
+        .or_else(|| {
+            [
+                "%Y-%m-%dT%H:%M:%S%.f",
+                "%Y-%m-%d %H:%M:%S%.f",
+                "%Y-%m-%dT%H:%M",
+            ]
+            .iter()
+            .find_map(|pattern| chrono::NaiveDateTime::parse_from_str(trimmed, pattern).ok())
+        })
+        .or_else(|| {
+            chrono::NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+}
+
+/// Convert a UTC datetime for a document property, which Excel limits to
+/// the years 1900-9999.
+pub(crate) fn utc_excel_datetime(utc: chrono::NaiveDateTime) -> Result<ExcelDateTime, String> {
+    let year =
+        u16::try_from(utc.year()).map_err(|_| format!("year {} is out of range", utc.year()))?;
+    ExcelDateTime::from_ymd(year, utc.month() as u8, utc.day() as u8)
+        .and_then(|date| date.and_hms(utc.hour() as u16, utc.minute() as u8, utc.second()))
+        .map_err(|e| e.to_string())
+}
 
 pub(crate) fn apply_defined_names(
     workbook: &mut Workbook,
@@ -26,3 +325,73 @@ pub(crate) fn apply_defined_names(
     }
     Ok(())
 }
+
+/// Resolve `active_sheet` against the written sheet names (in workbook order).
+pub(crate) fn resolve_active_sheet(
+    selector: &SheetSelector,
+    sheet_names: &[String],
+) -> Result<usize, String> {
+    match selector {
+        SheetSelector::Index(index) if *index < sheet_names.len() => Ok(*index),
+        SheetSelector::Index(index) => Err(format!(
+            "active_sheet index {} is out of range for {} sheet(s)",
+            index,
+            sheet_names.len()
+        )),
+        SheetSelector::Name(name) => sheet_names.iter().position(|n| n == name).ok_or_else(|| {
+            format!(
+                "active_sheet '{}' does not match any sheet. Sheets: {}",
+                name,
+                sheet_names.join(", ")
+            )
+        }),
+    }
+}
+
+/// Hide sheets per their `visible` option (`visibility[i]` is worksheet `i`)
+/// and activate `active_sheet`, which must be one of the visible sheets.
+///
+/// Without an explicit `active_sheet`, rust_xlsxwriter falls back to
+/// activating the first sheet, which would silently unhide it, so the first
+/// visible sheet is made active instead.
+pub(crate) fn apply_sheet_states(
+    workbook: &mut Workbook,
+    visibility: &[SheetVisibility],
+    active_sheet: Option<usize>,
+) -> Result<(), String> {
+    let Some(first_visible) = visibility
+        .iter()
+        .position(|v| *v == SheetVisibility::Visible)
+    else {
+        return Err(
+            "At least one sheet must remain visible, but every sheet sets 'visible' to False or \"very_hidden\""
+                .to_string(),
+        );
+    };
+    for (index, state) in visibility.iter().enumerate() {
+        let worksheet = workbook
+            .worksheet_from_index(index)
+            .map_err(|e| format!("Failed to access worksheet {}: {}", index, e))?;
+        match state {
+            SheetVisibility::Visible => {}
+            SheetVisibility::Hidden => {
+                worksheet.set_hidden(true);
+            }
+            SheetVisibility::VeryHidden => {
+                worksheet.set_very_hidden(true);
+            }
+        }
+    }
+    let active = active_sheet.unwrap_or(first_visible);
+    let worksheet = workbook
+        .worksheet_from_index(active)
+        .map_err(|e| format!("Failed to access worksheet {}: {}", active, e))?;
+    if visibility[active] != SheetVisibility::Visible {
+        return Err(format!(
+            "active_sheet '{}' is hidden; the active sheet must be visible",
+            worksheet.name()
+        ));
+    }
+    worksheet.set_active(true);
+    Ok(())
+}
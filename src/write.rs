@@ -1,10 +1,17 @@
 //! Shared worksheet cell writers.
 
-use crate::parse::{naive_date_to_excel, naive_datetime_to_excel};
-use crate::types::CellValue;
+use crate::parse::{
+    naive_date_to_excel, naive_datetime_to_excel, naive_time_to_excel, parse_markup,
+    parse_num_format, parse_value, python_datetime_str, sanitize_string,
+};
+use crate::types::{
+    pytype_name, BoolMode, CellValue, DateOrder, LargeIntMode, LongStringMode, NanPolicy,
+    WriteConfig,
+};
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyDate, PyDateTime, PyFloat, PyInt, PyString};
-use rust_xlsxwriter::{Format, Worksheet, XlsxError};
+use pyo3::types::{PyBool, PyDate, PyDateTime, PyFloat, PyInt, PyString, PyTime};
+use rust_xlsxwriter::{Format, Formula, Note, Worksheet, XlsxError};
+use std::borrow::Cow;
 
 /// Maximum safe integer for lossless f64 representation (2^53).
 /// Integers beyond this range lose precision when cast to f64.
@@ -12,15 +19,159 @@ const MAX_SAFE_INT: i64 = 1 << 53;
 const MAX_SAFE_INT_U64: u64 = MAX_SAFE_INT as u64;
 
 /// Whether an integer of the given magnitude fits in an f64 without precision
-/// loss. Single source of the overflow-to-string policy shared by every integer
+/// loss. Single pivot for the `LargeIntMode` policy shared by every integer
 /// write path (`write_int`, `write_uint`, and the `CellValue::Integer` arm).
 fn int_fits_f64(magnitude: u64) -> bool {
     magnitude <= MAX_SAFE_INT_U64
 }
 
-/// Excel number format strings (shared with apply::apply_cells)
+/// Default Excel number format strings, used when no `date_format` /
+/// `datetime_format` / `time_format` override is given.
 pub(crate) const DATE_NUM_FORMAT: &str = "yyyy-mm-dd";
 pub(crate) const DATETIME_NUM_FORMAT: &str = "yyyy-mm-dd hh:mm:ss";
+pub(crate) const TIME_NUM_FORMAT: &str = "hh:mm:ss";
+
+/// Longest URL Excel accepts in a hyperlink. Longer `auto_hyperlinks`
+/// candidates are written as plain text.
+const MAX_URL_LEN: usize = 2079;
+
+/// Most characters Excel holds in one cell, and in a note once the 54
+/// characters rust_xlsxwriter reserves for an author prefix are taken off.
+/// `long_string_mode` applies to strings beyond the cell limit.
+const MAX_CELL_CHARS: usize = 32_767;
+const MAX_NOTE_CHARS: usize = MAX_CELL_CHARS - 54;
+
+/// Sheet-wide settings shared by every data cell write: the date, datetime,
+/// and time number formats, the NaN/Inf policy, and the large-integer and
+/// long-string modes.
+/// Built once per sheet (or CSV file) so the format strings are validated
+/// before any cell is written.
+pub(crate) struct CellWriteOptions {
+    pub(crate) date_format: Format,
+    pub(crate) datetime_format: Format,
+    pub(crate) time_format: Format,
+    pub(crate) nan_policy: NanPolicy,
+    pub(crate) large_int_mode: LargeIntMode,
+    pub(crate) long_string_mode: LongStringMode,
+    /// `@` (Text) number format for `LargeIntMode::Text` cells.
+    pub(crate) text_format: Format,
+    /// `0%` / `0.00%` number formats for whole and fractional `Percent` cells.
+    pub(crate) percent_format: Format,
+    pub(crate) percent_decimal_format: Format,
+    /// `#,##0.00` number format for `Currency` cells.
+    pub(crate) currency_format: Format,
+    /// `scientific_format` number format for `Scientific` cells, if set.
+    pub(crate) scientific_format: Option<Format>,
+    /// `0` number format for `integer_like_floats` cells.
+    pub(crate) integer_format: Format,
+    /// `cell_format` base style for data cells without a column format. Every
+    /// number format above is built on top of it.
+    pub(crate) cell_format: Option<Format>,
+    /// Whether URL and `mailto:` strings are written as hyperlinks
+    /// (`auto_hyperlinks`), styled with `link_format` when it is set.
+    pub(crate) auto_hyperlinks: bool,
+    pub(crate) link_format: Option<Format>,
+    /// Whether string values that read as numbers, booleans, dates, or times
+    /// are written as those types, as CSV fields are (`infer_string_types`).
+    pub(crate) infer_string_types: bool,
+    /// How Python and numpy booleans are written (`bool_mode`).
+    pub(crate) bool_mode: BoolMode,
+    /// Whether string values lose a leading BOM and illegal control
+    /// characters before they are written (`sanitize_strings`). CSV fields
+    /// are cleaned while parsing instead.
+    pub(crate) sanitize_strings: bool,
+}
+
+impl CellWriteOptions {
+    pub(crate) fn new(
+        date_format: &str,
+        datetime_format: &str,
+        time_format: &str,
+        nan_policy: NanPolicy,
+        large_int_mode: LargeIntMode,
+        cell_format: Option<Format>,
+    ) -> Result<Self, String> {
+        // Validate the user-supplied number formats, then layer every number
+        // format over the base style so typed cells keep its font and borders
+        parse_num_format("date_format", date_format)?;
+        parse_num_format("datetime_format", datetime_format)?;
+        parse_num_format("time_format", time_format)?;
+        let base = cell_format.clone().unwrap_or_default();
+        let with_num_format = |num_format: &str| base.clone().set_num_format(num_format);
+        Ok(Self {
+            date_format: with_num_format(date_format),
+            datetime_format: with_num_format(datetime_format),
+            time_format: with_num_format(time_format),
+            nan_policy,
+            large_int_mode,
+            long_string_mode: LongStringMode::Error,
+            text_format: with_num_format("@"),
+            percent_format: with_num_format("0%"),
+            percent_decimal_format: with_num_format("0.00%"),
+            currency_format: with_num_format("#,##0.00"),
+            scientific_format: None,
+            integer_format: with_num_format("0"),
+            cell_format,
+            auto_hyperlinks: false,
+            link_format: None,
+            infer_string_types: false,
+            bool_mode: BoolMode::Boolean,
+            sanitize_strings: false,
+        })
+    }
+
+    /// Turn on `auto_hyperlinks` for these options. `link_format` is the
+    /// parsed `link_format` option, if any.
+    pub(crate) fn with_auto_hyperlinks(mut self, link_format: Option<Format>) -> Self {
+        self.auto_hyperlinks = true;
+        self.link_format = link_format;
+        self
+    }
+
+    /// Build the options for a DataFrame sheet; `cell_format` is the parsed
+    /// `cell_format` option, if any.
+    pub(crate) fn from_config(
+        config: &WriteConfig<'_>,
+        cell_format: Option<Format>,
+    ) -> Result<Self, String> {
+        let options = Self::new(
+            config.date_format,
+            config.datetime_format,
+            config.time_format,
+            config.nan_policy,
+            config.large_int_mode,
+            cell_format,
+        )?;
+        Ok(Self {
+            long_string_mode: config.long_string_mode,
+            infer_string_types: config.infer_string_types,
+            bool_mode: config.bool_mode.clone(),
+            sanitize_strings: config.sanitize_strings,
+            ..options
+        })
+    }
+
+    /// The format for a value with no type-specific number format: the column
+    /// format when there is one, else the `cell_format` base style.
+    pub(crate) fn plain_format<'a>(
+        &'a self,
+        column_format: Option<&'a Format>,
+    ) -> Option<&'a Format> {
+        column_format.or(self.cell_format.as_ref())
+    }
+
+    /// A string value as it is written: cleaned by `sanitize_string` when
+    /// `sanitize_strings` is on, else unchanged.
+    pub(crate) fn clean_string(&self, s: String) -> String {
+        if !self.sanitize_strings {
+            return s;
+        }
+        match sanitize_string(&s) {
+            Cow::Borrowed(clean) if clean.len() == s.len() => s,
+            clean => clean.into_owned(),
+        }
+    }
+}
 
 /// Write a string to a cell, applying column format if provided.
 fn write_str(
@@ -40,6 +191,101 @@ fn write_str(
     .map_err(|e| format!("Failed to write text at row {}, col {}: {}", row, col, e))
 }
 
+/// Write a string of any length, applying `long_string_mode` when it is over
+/// Excel's cell limit: fail, keep the first 32,767 characters, or keep them
+/// and put the rest in a note on the cell.
+fn write_long_str(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    s: &str,
+    mode: LongStringMode,
+    fmt: Option<&Format>,
+) -> Result<(), String> {
+    // A string no longer in bytes than the limit can't be over it in chars
+    let cut = if s.len() > MAX_CELL_CHARS {
+        s.char_indices().nth(MAX_CELL_CHARS).map(|(i, _)| i)
+    } else {
+        None
+    };
+    let Some(cut) = cut else {
+        return write_str(worksheet, row, col, s, fmt);
+    };
+    let (head, rest) = s.split_at(cut);
+    match mode {
+        LongStringMode::Error => {
+            return Err(format!(
+                "String of {} characters at row {}, col {} exceeds Excel's 32,767-character \
+                 cell limit. Use long_string_mode='truncate' or 'split' to write it",
+                s.chars().count(),
+                row,
+                col
+            ))
+        }
+        LongStringMode::Truncate => {}
+        LongStringMode::Split => {
+            if rest.chars().count() > MAX_NOTE_CHARS {
+                return Err(format!(
+                    "String of {} characters at row {}, col {} is too long to split: a cell \
+                     and its note hold at most {} characters",
+                    s.chars().count(),
+                    row,
+                    col,
+                    MAX_CELL_CHARS + MAX_NOTE_CHARS
+                ));
+            }
+            let note = Note::new(rest).add_author_prefix(false);
+            worksheet
+                .insert_note(row, col, &note)
+                .map_err(|e| format!("Failed to write note at row {}, col {}: {}", row, col, e))?;
+        }
+    }
+    write_str(worksheet, row, col, head, fmt)
+}
+
+/// Whether `auto_hyperlinks` writes `s` as a link: an `http://`, `https://`,
+/// or `mailto:` prefix (any case) followed by an address, no whitespace, and
+/// no more than `MAX_URL_LEN` characters.
+fn is_auto_link(s: &str) -> bool {
+    let Some(prefix) = ["http://", "https://", "mailto:"]
+        .into_iter()
+        .find(|prefix| {
+            s.get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+        })
+    else {
+        return false;
+    };
+    s.len() > prefix.len() && !s.contains(char::is_whitespace) && s.chars().count() <= MAX_URL_LEN
+}
+
+/// Write a string as a hyperlink showing the string itself. `link_format`
+/// wins when set; otherwise the cell keeps `fmt` with the hyperlink style
+/// (blue, underlined) on top, or gets Excel's default hyperlink style.
+fn write_link(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    url: &str,
+    options: &CellWriteOptions,
+    fmt: Option<&Format>,
+) -> Result<(), String> {
+    match (&options.link_format, fmt) {
+        (Some(f), _) => worksheet.write_url_with_format(row, col, url, f),
+        (None, Some(f)) => {
+            worksheet.write_url_with_format(row, col, url, &f.clone().set_hyperlink())
+        }
+        (None, None) => worksheet.write_url(row, col, url),
+    }
+    .map(|_| ())
+    .map_err(|e| {
+        format!(
+            "Failed to write hyperlink at row {}, col {}: {}",
+            row, col, e
+        )
+    })
+}
+
 /// Write a number to a cell, applying column format if provided.
 fn write_num(
     worksheet: &mut Worksheet,
@@ -57,14 +303,24 @@ fn write_num(
     .map_err(|e| format!("Failed to write number at row {}, col {}: {}", row, col, e))
 }
 
-/// Write a boolean to a cell, applying column format if provided.
+/// Write a boolean to a cell as `bool_mode` asks (an Excel boolean, 1/0, or
+/// text), applying column format if provided.
 fn write_bool(
     worksheet: &mut Worksheet,
     row: u32,
     col: u16,
     val: bool,
+    bool_mode: &BoolMode,
     fmt: Option<&Format>,
 ) -> Result<(), String> {
+    match bool_mode {
+        BoolMode::Boolean => {}
+        BoolMode::OneZero => return write_num(worksheet, row, col, f64::from(u8::from(val)), fmt),
+        BoolMode::Text(true_text, false_text) => {
+            let text = if val { true_text } else { false_text };
+            return write_str(worksheet, row, col, text.as_str(), fmt);
+        }
+    }
     if let Some(f) = fmt {
         worksheet.write_boolean_with_format(row, col, val, f)
     } else {
@@ -74,18 +330,27 @@ fn write_bool(
     .map_err(|e| format!("Failed to write boolean at row {}, col {}: {}", row, col, e))
 }
 
-/// Write an integer, falling back to string for values beyond f64 precision.
+/// Write an integer, deferring to `write_large_int` for values beyond f64 precision.
 fn write_int(
     worksheet: &mut Worksheet,
     row: u32,
     col: u16,
     val: i64,
+    options: &CellWriteOptions,
     fmt: Option<&Format>,
 ) -> Result<(), String> {
     if int_fits_f64(val.unsigned_abs()) {
-        write_num(worksheet, row, col, val as f64, fmt)
+        write_num(worksheet, row, col, val as f64, options.plain_format(fmt))
     } else {
-        write_str(worksheet, row, col, val.to_string(), fmt)
+        write_large_int(
+            worksheet,
+            row,
+            col,
+            val.to_string(),
+            Some(val as f64),
+            options,
+            fmt,
+        )
     }
 }
 
@@ -94,38 +359,132 @@ fn write_uint(
     row: u32,
     col: u16,
     val: u64,
+    options: &CellWriteOptions,
     fmt: Option<&Format>,
 ) -> Result<(), String> {
     if int_fits_f64(val) {
-        write_num(worksheet, row, col, val as f64, fmt)
+        write_num(worksheet, row, col, val as f64, options.plain_format(fmt))
     } else {
-        write_str(worksheet, row, col, val.to_string(), fmt)
+        write_large_int(
+            worksheet,
+            row,
+            col,
+            val.to_string(),
+            Some(val as f64),
+            options,
+            fmt,
+        )
     }
 }
 
-/// Write a float, treating NaN/Inf as empty.
+/// Write an integer beyond f64 precision according to `large_int_mode`.
+/// `approx` is the nearest f64, or `None` for a Python int too large for any
+/// f64, which can only be written as text.
+fn write_large_int(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    digits: String,
+    approx: Option<f64>,
+    options: &CellWriteOptions,
+    fmt: Option<&Format>,
+) -> Result<(), String> {
+    match options.large_int_mode {
+        LargeIntMode::String => write_str(worksheet, row, col, digits, options.plain_format(fmt)),
+        LargeIntMode::Text => write_str(
+            worksheet,
+            row,
+            col,
+            digits,
+            Some(fmt.unwrap_or(&options.text_format)),
+        ),
+        LargeIntMode::Number => {
+            let val = approx.ok_or_else(|| {
+                format!(
+                    "Integer {} at row {}, col {} is too large to write as a number",
+                    digits, row, col
+                )
+            })?;
+            write_num(worksheet, row, col, val, options.plain_format(fmt))
+        }
+    }
+}
+
+/// Write a float, handing NaN/Inf to `write_non_finite`.
 fn write_float(
     worksheet: &mut Worksheet,
     row: u32,
     col: u16,
     val: f64,
+    nan_policy: NanPolicy,
     fmt: Option<&Format>,
 ) -> Result<(), String> {
     if val.is_nan() || val.is_infinite() {
-        write_str(worksheet, row, col, "", fmt)
+        write_non_finite(worksheet, row, col, val, nan_policy, fmt)
+            .map_err(|e| format!("Failed to write NaN/Inf at row {}, col {}: {}", row, col, e))
     } else {
         write_num(worksheet, row, col, val, fmt)
     }
 }
 
+/// Write a NaN or infinite value according to `nan_policy`: an empty string
+/// (the historical behavior), the text "NaN"/"Inf"/"-Inf", or an `=NA()`
+/// formula whose cached result is the `#N/A` error.
+fn write_non_finite(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    val: f64,
+    nan_policy: NanPolicy,
+    fmt: Option<&Format>,
+) -> Result<(), XlsxError> {
+    let text = match nan_policy {
+        NanPolicy::Empty => "",
+        NanPolicy::AsString if val.is_nan() => "NaN",
+        NanPolicy::AsString if val > 0.0 => "Inf",
+        NanPolicy::AsString => "-Inf",
+        NanPolicy::AsError => {
+            let formula = Formula::new("=NA()").set_result("#N/A");
+            match fmt {
+                Some(f) => worksheet.write_formula_with_format(row, col, formula, f)?,
+                None => worksheet.write_formula(row, col, formula)?,
+            };
+            return Ok(());
+        }
+    };
+    match fmt {
+        Some(f) => worksheet.write_string_with_format(row, col, text, f)?,
+        None => worksheet.write_string(row, col, text)?,
+    };
+    Ok(())
+}
+
 /// Write a cell value to the worksheet with appropriate formatting.
 pub(crate) fn write_cell(
     worksheet: &mut Worksheet,
     row: u32,
     col: u16,
     value: CellValue,
-    date_format: &Format,
-    datetime_format: &Format,
+    options: &CellWriteOptions,
+) -> Result<(), String> {
+    match &value {
+        // Strings that may be over the cell limit follow `long_string_mode`
+        CellValue::Text(v) | CellValue::String(v) if v.len() > MAX_CELL_CHARS => {
+            let fmt = matches!(value, CellValue::Text(_)).then_some(&options.text_format);
+            write_long_str(worksheet, row, col, v, options.long_string_mode, fmt)
+        }
+        _ => write_cell_value(worksheet, row, col, value, options)
+            .map_err(|e| format!("Write error at ({}, {}): {}", row, col, e)),
+    }
+}
+
+/// Write a cell value whose strings fit in a cell, for `write_cell`.
+fn write_cell_value(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    value: CellValue,
+    options: &CellWriteOptions,
 ) -> Result<(), XlsxError> {
     match value {
         CellValue::Empty => {
@@ -137,7 +496,16 @@ pub(crate) fn write_cell(
             if int_fits_f64(v.unsigned_abs()) {
                 worksheet.write_number(row, col, v as f64)?;
             } else {
-                worksheet.write_string(row, col, v.to_string())?;
+                match options.large_int_mode {
+                    LargeIntMode::String => worksheet.write_string(row, col, v.to_string())?,
+                    LargeIntMode::Number => worksheet.write_number(row, col, v as f64)?,
+                    LargeIntMode::Text => worksheet.write_string_with_format(
+                        row,
+                        col,
+                        v.to_string(),
+                        &options.text_format,
+                    )?,
+                };
             }
         }
         CellValue::Float(v) => {
@@ -147,10 +515,38 @@ pub(crate) fn write_cell(
             worksheet.write_boolean(row, col, v)?;
         }
         CellValue::Date(v) => {
-            worksheet.write_number_with_format(row, col, v, date_format)?;
+            worksheet.write_number_with_format(row, col, v, &options.date_format)?;
         }
         CellValue::DateTime(v) => {
-            worksheet.write_number_with_format(row, col, v, datetime_format)?;
+            worksheet.write_number_with_format(row, col, v, &options.datetime_format)?;
+        }
+        CellValue::Time(v) => {
+            worksheet.write_number_with_format(row, col, v, &options.time_format)?;
+        }
+        CellValue::NonFinite(v) => {
+            write_non_finite(worksheet, row, col, v, options.nan_policy, None)?;
+        }
+        CellValue::Percent(v) => {
+            // "45%" reads back as 45%, "12.5%" as 12.50%
+            let percent = v * 100.0;
+            let fmt = if (percent - percent.round()).abs() < 1e-9 {
+                &options.percent_format
+            } else {
+                &options.percent_decimal_format
+            };
+            worksheet.write_number_with_format(row, col, v, fmt)?;
+        }
+        CellValue::Currency(v) => {
+            worksheet.write_number_with_format(row, col, v, &options.currency_format)?;
+        }
+        CellValue::Scientific(v) => {
+            match &options.scientific_format {
+                Some(fmt) => worksheet.write_number_with_format(row, col, v, fmt)?,
+                None => worksheet.write_number(row, col, v)?,
+            };
+        }
+        CellValue::Text(v) => {
+            worksheet.write_string_with_format(row, col, &v, &options.text_format)?;
         }
         CellValue::String(v) => {
             worksheet.write_string(row, col, &v)?;
@@ -159,45 +555,296 @@ pub(crate) fn write_cell(
     Ok(())
 }
 
+/// Write a date decoded straight from a polars `Date` column, skipping the
+/// Python `date` object. Matches the `date` branch of
+/// `write_py_value_with_format`, including the text fallback before 1900-03-01;
+/// `None` (a null) is written as an empty cell.
+pub(crate) fn write_naive_date(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    date: Option<chrono::NaiveDate>,
+    options: &CellWriteOptions,
+    column_format: Option<&Format>,
+) -> Result<(), String> {
+    let plain = options.plain_format(column_format);
+    let Some(date) = date else {
+        return write_str(worksheet, row, col, "", plain);
+    };
+    let excel_date = naive_date_to_excel(date);
+    if excel_date < 61.0 {
+        return write_str(worksheet, row, col, date.to_string(), plain);
+    }
+    let fmt = column_format.unwrap_or(&options.date_format);
+    write_num(worksheet, row, col, excel_date, Some(fmt))
+}
+
+/// Write a datetime decoded straight from a naive polars `Datetime` column.
+/// Same rules as [`write_naive_date`], mirroring the `datetime` branch.
+pub(crate) fn write_naive_datetime(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    dt: Option<chrono::NaiveDateTime>,
+    options: &CellWriteOptions,
+    column_format: Option<&Format>,
+) -> Result<(), String> {
+    let plain = options.plain_format(column_format);
+    let Some(dt) = dt else {
+        return write_str(worksheet, row, col, "", plain);
+    };
+    let excel_dt = naive_datetime_to_excel(dt);
+    if excel_dt < 61.0 {
+        return write_str(worksheet, row, col, python_datetime_str(dt), plain);
+    }
+    let fmt = column_format.unwrap_or(&options.datetime_format);
+    write_num(worksheet, row, col, excel_dt, Some(fmt))
+}
+
+/// Write a categorical column's label as text, whatever the category values'
+/// own type; `None` (a missing value) is written as an empty cell.
+pub(crate) fn write_category(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    label: Option<&str>,
+    options: &CellWriteOptions,
+    column_format: Option<&Format>,
+) -> Result<(), String> {
+    let label = label.unwrap_or("");
+    let label = if options.sanitize_strings {
+        sanitize_string(label)
+    } else {
+        Cow::Borrowed(label)
+    };
+    write_str(
+        worksheet,
+        row,
+        col,
+        label.as_ref(),
+        options.plain_format(column_format),
+    )
+}
+
+/// Write a float column's value as a number with the `0` format when it's a
+/// finite whole number, so it displays without decimals. Returns `false`
+/// without writing for anything else (fractional, non-finite, or not a
+/// float at all), leaving it to `write_py_value_with_format`. A column
+/// format takes precedence over the integer format.
+pub(crate) fn write_integer_like_float(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    value: &Bound<'_, PyAny>,
+    options: &CellWriteOptions,
+    column_format: Option<&Format>,
+) -> Result<bool, String> {
+    if value.is_instance_of::<PyBool>() || value.is_instance_of::<PyInt>() {
+        return Ok(false);
+    }
+    match value.extract::<f64>() {
+        Ok(val) if val.is_finite() && val.fract() == 0.0 => {
+            let fmt = column_format.unwrap_or(&options.integer_format);
+            write_num(worksheet, row, col, val, Some(fmt))?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Write a value in a `text_columns` column as its `str()` text, with the `@`
+/// number format unless the column has its own format, so identifiers such as
+/// `"01234"` are never type-detected and stay text when edited in Excel.
+/// Returns the written character count, or `None` without writing for a null
+/// (None, NaN, pd.NA, NaT), leaving it to `write_py_value_with_format`.
+pub(crate) fn write_text_value(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    value: &Bound<'_, PyAny>,
+    options: &CellWriteOptions,
+    column_format: Option<&Format>,
+) -> Result<Option<usize>, String> {
+    if value.is_none() {
+        return Ok(None);
+    }
+    if let Ok(f) = value.cast::<PyFloat>() {
+        if f.value().is_nan() {
+            return Ok(None);
+        }
+    }
+    let type_name = pytype_name(value);
+    if type_name == "NAType" || type_name == "NaTType" {
+        return Ok(None);
+    }
+    let s = value
+        .str()
+        .map_err(|e| format!("Failed to convert text_columns value to string: {}", e))?
+        .to_string();
+    let s = options.clean_string(s);
+    let len = s.chars().count();
+    let fmt = column_format.unwrap_or(&options.text_format);
+    write_long_str(worksheet, row, col, &s, options.long_string_mode, Some(fmt))?;
+    Ok(Some(len))
+}
+
+/// Write a string value with `**bold**`/`*italic*` markup as a rich string
+/// (`auto_rich_text_columns`), each run styled over the cell's format so it
+/// keeps the column's font. Returns the displayed character count, or `None`
+/// without writing for a non-string or a string without markup, leaving it to
+/// `write_py_value_with_format`.
+pub(crate) fn write_markup_string(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    value: &Bound<'_, PyAny>,
+    options: &CellWriteOptions,
+    column_format: Option<&Format>,
+) -> Result<Option<usize>, String> {
+    let Ok(s) = value.cast::<PyString>() else {
+        return Ok(None);
+    };
+    let s = options.clean_string(s.to_string());
+    let runs = parse_markup(&s);
+    let plain = options.plain_format(column_format);
+    match runs.as_slice() {
+        [] => return Ok(None),
+        [run] if !run.bold && !run.italic => {
+            // Only escapes to undo, e.g. "5 \* 3"
+            if run.text == s {
+                return Ok(None);
+            }
+            write_str(worksheet, row, col, run.text.as_str(), plain)?;
+            return Ok(Some(run.text.chars().count()));
+        }
+        _ => {}
+    }
+
+    let base = plain.cloned().unwrap_or_default();
+    let formats: Vec<Format> = runs
+        .iter()
+        .map(|run| {
+            let mut format = base.clone();
+            if run.bold {
+                format = format.set_bold();
+            }
+            if run.italic {
+                format = format.set_italic();
+            }
+            format
+        })
+        .collect();
+    let segments: Vec<(&Format, &str)> = formats
+        .iter()
+        .zip(&runs)
+        .map(|(format, run)| (format, run.text.as_str()))
+        .collect();
+    match plain {
+        Some(f) => worksheet.write_rich_string_with_format(row, col, &segments, f),
+        None => worksheet.write_rich_string(row, col, &segments),
+    }
+    .map_err(|e| {
+        format!(
+            "Failed to write rich text at row {}, col {}: {}",
+            row, col, e
+        )
+    })?;
+    Ok(Some(runs.iter().map(|run| run.text.chars().count()).sum()))
+}
+
+/// Write a string as the value it reads as (`infer_string_types`), using the
+/// same detection as CSV fields: integers, floats, booleans, ISO datetimes,
+/// dates in any order `DateOrder::Auto` accepts, and times of day. Returns
+/// `false` without writing for a string that stays text (including an empty
+/// or pre-1900-03-01 date string), leaving it to the plain string path.
+fn write_inferred_string(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    value: &str,
+    options: &CellWriteOptions,
+    column_format: Option<&Format>,
+) -> Result<bool, String> {
+    let plain = options.plain_format(column_format);
+    match parse_value(value, DateOrder::Auto) {
+        CellValue::Integer(v) => write_int(worksheet, row, col, v, options, column_format)?,
+        CellValue::Float(v) | CellValue::NonFinite(v) => {
+            write_float(worksheet, row, col, v, options.nan_policy, plain)?
+        }
+        CellValue::Boolean(v) => write_bool(worksheet, row, col, v, &options.bool_mode, plain)?,
+        CellValue::Date(v) => {
+            let fmt = column_format.unwrap_or(&options.date_format);
+            write_num(worksheet, row, col, v, Some(fmt))?
+        }
+        CellValue::DateTime(v) => {
+            let fmt = column_format.unwrap_or(&options.datetime_format);
+            write_num(worksheet, row, col, v, Some(fmt))?
+        }
+        CellValue::Time(v) => {
+            let fmt = column_format.unwrap_or(&options.time_format);
+            write_num(worksheet, row, col, v, Some(fmt))?
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
 /// Write a Python value to the worksheet with optional column format.
 pub(crate) fn write_py_value_with_format(
     worksheet: &mut Worksheet,
     row: u32,
     col: u16,
     value: &Bound<'_, PyAny>,
-    date_format: &Format,
-    datetime_format: &Format,
+    options: &CellWriteOptions,
     column_format: Option<&Format>,
 ) -> Result<(), String> {
+    // Values with a type-specific number format (dates, times, large-int
+    // text) use `column_format` over their own format; everything else falls
+    // back to the cell_format base style.
+    let plain = options.plain_format(column_format);
+
     // Check for None first.
     if value.is_none() {
-        return write_str(worksheet, row, col, "", column_format);
+        return write_str(worksheet, row, col, "", plain);
     }
 
     // Boolean first (before int, since bool is subclass of int in Python).
     if let Ok(b) = value.cast::<PyBool>() {
-        return write_bool(worksheet, row, col, b.is_true(), column_format);
+        return write_bool(worksheet, row, col, b.is_true(), &options.bool_mode, plain);
     }
 
     if let Ok(i) = value.cast::<PyInt>() {
         if let Ok(val) = i.extract::<i64>() {
-            return write_int(worksheet, row, col, val, column_format);
+            return write_int(worksheet, row, col, val, options, column_format);
+        }
+        if let Ok(val) = i.extract::<u64>() {
+            return write_uint(worksheet, row, col, val, options, column_format);
         }
         let s = i
             .str()
             .map_err(|e| format!("Failed to convert Python int to string: {}", e))?
             .to_string();
-        return write_str(worksheet, row, col, s, column_format);
+        let approx = i.extract::<f64>().ok();
+        return write_large_int(worksheet, row, col, s, approx, options, column_format);
     }
 
     if let Ok(f) = value.cast::<PyFloat>() {
         if let Ok(val) = f.extract::<f64>() {
-            return write_float(worksheet, row, col, val, column_format);
+            return write_float(worksheet, row, col, val, options.nan_policy, plain);
         }
     }
 
     if let Ok(s) = value.cast::<PyString>() {
-        return write_str(worksheet, row, col, s.to_string(), column_format);
+        let s = options.clean_string(s.to_string());
+        if options.infer_string_types
+            && write_inferred_string(worksheet, row, col, &s, options, column_format)?
+        {
+            return Ok(());
+        }
+        if options.auto_hyperlinks && is_auto_link(&s) {
+            return write_link(worksheet, row, col, &s, options, plain);
+        }
+        return write_long_str(worksheet, row, col, &s, options.long_string_mode, plain);
     }
 
     let type_name = value
@@ -207,7 +854,7 @@ pub(crate) fn write_py_value_with_format(
         .to_string();
 
     if type_name == "NAType" || type_name == "NaTType" {
-        return write_str(worksheet, row, col, "", column_format);
+        return write_str(worksheet, row, col, "", plain);
     }
 
     // numpy scalar bool. Checked after the `PyBool` cast above (which only
@@ -217,7 +864,7 @@ pub(crate) fn write_py_value_with_format(
     // "bool_" on numpy 1.x and "bool" on numpy 2.x.
     if type_name == "bool_" || type_name == "bool" {
         if let Ok(val) = value.extract::<bool>() {
-            return write_bool(worksheet, row, col, val, column_format);
+            return write_bool(worksheet, row, col, val, &options.bool_mode, plain);
         }
     }
 
@@ -229,7 +876,7 @@ pub(crate) fn write_py_value_with_format(
             .and_then(|v| v.extract())
             .map_err(|e| format!("Failed to convert numpy datetime64 scalar: {}", e))?;
         if us_since_epoch == i64::MIN {
-            return write_str(worksheet, row, col, "", column_format);
+            return write_str(worksheet, row, col, "", plain);
         }
 
         let seconds = us_since_epoch.div_euclid(1_000_000);
@@ -247,9 +894,9 @@ pub(crate) fn write_py_value_with_format(
                 .str()
                 .map_err(|e| format!("Failed to convert numpy datetime64 to string: {}", e))?
                 .to_string();
-            return write_str(worksheet, row, col, s, column_format);
+            return write_str(worksheet, row, col, s, plain);
         }
-        let fmt = column_format.unwrap_or(datetime_format);
+        let fmt = column_format.unwrap_or(&options.datetime_format);
         return write_num(worksheet, row, col, excel_dt, Some(fmt));
     }
 
@@ -320,9 +967,9 @@ pub(crate) fn write_py_value_with_format(
                 .str()
                 .map_err(|e| format!("Failed to convert datetime to string: {}", e))?
                 .to_string();
-            return write_str(worksheet, row, col, s, column_format);
+            return write_str(worksheet, row, col, s, plain);
         }
-        let fmt = column_format.unwrap_or(datetime_format);
+        let fmt = column_format.unwrap_or(&options.datetime_format);
         return write_num(worksheet, row, col, excel_dt, Some(fmt));
     }
 
@@ -354,29 +1001,61 @@ pub(crate) fn write_py_value_with_format(
                 .str()
                 .map_err(|e| format!("Failed to convert date to string: {}", e))?
                 .to_string();
-            return write_str(worksheet, row, col, s, column_format);
+            return write_str(worksheet, row, col, s, plain);
         }
-        let fmt = column_format.unwrap_or(date_format);
+        let fmt = column_format.unwrap_or(&options.date_format);
         return write_num(worksheet, row, col, excel_date, Some(fmt));
     }
 
+    // Time of day. No date part, so there is no 1900 leap-year window to
+    // guard: the value is always a fraction in [0, 1). A tzinfo, if any, is
+    // ignored (wall-clock time), matching the datetime branch.
+    if value.cast::<PyTime>().is_ok() || type_name == "time" {
+        let hour: u32 = value
+            .getattr("hour")
+            .and_then(|v| v.extract())
+            .map_err(|e| format!("Failed to extract time hour: {}", e))?;
+        let minute: u32 = value
+            .getattr("minute")
+            .and_then(|v| v.extract())
+            .map_err(|e| format!("Failed to extract time minute: {}", e))?;
+        let second: u32 = value
+            .getattr("second")
+            .and_then(|v| v.extract())
+            .map_err(|e| format!("Failed to extract time second: {}", e))?;
+        let microsecond: u32 = value
+            .getattr("microsecond")
+            .and_then(|v| v.extract())
+            .unwrap_or(0);
+        let time = chrono::NaiveTime::from_hms_micro_opt(hour, minute, second, microsecond)
+            .ok_or_else(|| {
+                format!(
+                    "Invalid time: hour={}, minute={}, second={}, microsecond={}",
+                    hour, minute, second, microsecond
+                )
+            })?;
+        let fmt = column_format.unwrap_or(&options.time_format);
+        return write_num(worksheet, row, col, naive_time_to_excel(time), Some(fmt));
+    }
+
     // numpy scalar int (before f64 to avoid precision loss).
     if let Ok(val) = value.extract::<i64>() {
-        return write_int(worksheet, row, col, val, column_format);
+        return write_int(worksheet, row, col, val, options, column_format);
     }
 
     if let Ok(val) = value.extract::<u64>() {
-        return write_uint(worksheet, row, col, val, column_format);
+        return write_uint(worksheet, row, col, val, options, column_format);
     }
 
     // numpy scalar float.
     if let Ok(val) = value.extract::<f64>() {
-        return write_float(worksheet, row, col, val, column_format);
+        return write_float(worksheet, row, col, val, options.nan_policy, plain);
     }
 
     let s = value
         .str()
         .map_err(|e| format!("Failed to convert value to string: {}", e))?
         .to_string();
-    write_str(worksheet, row, col, s, column_format)
+    let s = options.clean_string(s);
+    write_long_str(worksheet, row, col, &s, options.long_string_mode, plain)
 }
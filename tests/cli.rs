@@ -146,7 +146,7 @@ fn sheet_name_flag_is_respected() {
         output.status
     );
     let stdout = String::from_utf8_lossy(&output.stdout);
-    // The CLI CSV reader treats every line as data (no header row), so "a,b\n1,2\n"
+    // Without --has-header the CLI CSV reader treats every line as data, so "a,b\n1,2\n"
     // is 2 rows x 2 cols.
     assert!(stdout.trim().ends_with("2 2"), "stdout was: {:?}", stdout);
     // --verbose echoes the sheet name to stderr, giving us a cheap way to
@@ -215,3 +215,446 @@ fn non_default_date_order_exits_zero() {
     let _ = fs::remove_file(&csv);
     let _ = fs::remove_file(&xlsx);
 }
+
+#[test]
+fn invalid_date_format_exits_nonzero_with_message() {
+    let csv = temp_path("baddatefmt", "csv");
+    let xlsx = temp_path("baddatefmt", "xlsx");
+    fs::write(&csv, "a\n2024-01-15\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--date-format")
+        .arg("\"yyyy-mm-dd")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert_eq!(output.status.code(), Some(1), "expected exit code 1");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("date_format"), "stderr was: {:?}", stderr);
+    assert!(!xlsx.exists(), "no xlsx should be written on failure");
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn invalid_nan_policy_exits_nonzero_with_message() {
+    let csv = temp_path("badnan", "csv");
+    let xlsx = temp_path("badnan", "xlsx");
+    fs::write(&csv, "a\nNaN\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--nan-policy")
+        .arg("zero")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert_eq!(output.status.code(), Some(1), "expected exit code 1");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid nan_policy"),
+        "stderr was: {:?}",
+        stderr
+    );
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn invalid_large_int_mode_exits_nonzero_with_message() {
+    let csv = temp_path("badlargeint", "csv");
+    let xlsx = temp_path("badlargeint", "xlsx");
+    fs::write(&csv, "a\n9007199254740993\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--large-int-mode")
+        .arg("float")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert_eq!(output.status.code(), Some(1), "expected exit code 1");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid large_int_mode"),
+        "stderr was: {:?}",
+        stderr
+    );
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn constant_memory_flag_exits_zero_and_produces_file() {
+    let csv = temp_path("constmem", "csv");
+    let xlsx = temp_path("constmem", "xlsx");
+    fs::write(&csv, "a,b,c\n1,2,3\n4,5,6\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--constant-memory")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "expected exit 0, got {:?}",
+        output.status
+    );
+    assert!(stdout.trim().ends_with("3 3"), "stdout was: {:?}", stdout);
+    assert!(xlsx.exists(), "output xlsx was not created");
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn constant_memory_with_parallel_exits_nonzero_with_message() {
+    let csv = temp_path("constmempar", "csv");
+    let xlsx = temp_path("constmempar", "xlsx");
+    fs::write(&csv, "a\n1\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--constant-memory")
+        .arg("--parallel")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert_eq!(output.status.code(), Some(1), "expected exit code 1");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("constant_memory"),
+        "stderr was: {:?}",
+        stderr
+    );
+    assert!(!xlsx.exists(), "no output should be written");
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn delimiter_flag_reads_semicolon_separated_file() {
+    let csv = temp_path("semicolon", "csv");
+    let xlsx = temp_path("semicolon", "xlsx");
+    fs::write(&csv, "a;b;c\n1;\"2;5\";3\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--delimiter")
+        .arg(";")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "expected exit 0, got {:?}",
+        output.status
+    );
+    // The quoted "2;5" stays one field, so every row has 3 columns.
+    assert!(stdout.trim().ends_with("2 3"), "stdout was: {:?}", stdout);
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn invalid_delimiter_exits_nonzero_with_message() {
+    let csv = temp_path("baddelim", "csv");
+    let xlsx = temp_path("baddelim", "xlsx");
+    fs::write(&csv, "a\n1\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--delimiter")
+        .arg("ab")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert_eq!(output.status.code(), Some(1), "expected exit code 1");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid delimiter 'ab'"),
+        "stderr was: {:?}",
+        stderr
+    );
+    assert!(!xlsx.exists(), "no output should be written");
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn has_header_flag_is_threaded_through() {
+    let csv = temp_path("has_header", "csv");
+    let xlsx = temp_path("has_header", "xlsx");
+    fs::write(&csv, "2024,2025\n1,2\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--has-header")
+        .arg("--verbose")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert!(
+        output.status.success(),
+        "expected exit 0, got {:?}",
+        output.status
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // The header row still counts toward the rows reported.
+    assert!(stdout.trim().ends_with("2 2"), "stdout was: {:?}", stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Header row: true"),
+        "stderr was: {:?}",
+        stderr
+    );
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn encoding_flag_decodes_windows_1252_input() {
+    let csv = temp_path("cp1252", "csv");
+    let xlsx = temp_path("cp1252", "xlsx");
+    // "José" in Windows-1252: the é is the single byte 0xE9, invalid as UTF-8
+    fs::write(&csv, b"name\nJos\xe9\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--encoding")
+        .arg("windows-1252")
+        .arg("--verbose")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert!(
+        output.status.success(),
+        "expected exit 0, got {:?}",
+        output.status
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Encoding: windows-1252"),
+        "stderr was: {:?}",
+        stderr
+    );
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn unknown_encoding_exits_nonzero_with_message() {
+    let csv = temp_path("badenc", "csv");
+    let xlsx = temp_path("badenc", "xlsx");
+    fs::write(&csv, "a\n1\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--encoding")
+        .arg("klingon")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert_eq!(output.status.code(), Some(1), "expected exit code 1");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Unknown encoding 'klingon'"),
+        "stderr was: {:?}",
+        stderr
+    );
+    assert!(!xlsx.exists(), "no output should be written");
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn null_values_flag_is_accepted() {
+    let csv = temp_path("null_values", "csv");
+    let xlsx = temp_path("null_values", "xlsx");
+    fs::write(&csv, "a,b\nNA,1\nN/A,2\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--null-values")
+        .arg("NA,N/A")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert!(
+        output.status.success(),
+        "expected exit 0, got {:?}",
+        output.status
+    );
+    assert!(xlsx.exists(), "output xlsx was not created");
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn equal_number_separators_exit_nonzero_with_message() {
+    let csv = temp_path("number_separators", "csv");
+    let xlsx = temp_path("number_separators", "xlsx");
+    fs::write(&csv, "a\n1.234\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--thousands-separator")
+        .arg(".")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert!(!output.status.success(), "expected non-zero exit");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("decimal_separator and thousands_separator must be different"),
+        "stderr was: {:?}",
+        stderr
+    );
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn text_columns_flag_is_accepted() {
+    let csv = temp_path("text_columns", "csv");
+    let xlsx = temp_path("text_columns", "xlsx");
+    fs::write(&csv, "zip,qty\n01234,1\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--text-columns")
+        .arg("0")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert!(
+        output.status.success(),
+        "expected exit 0, got {:?}",
+        output.status
+    );
+    assert!(xlsx.exists(), "output xlsx was not created");
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn long_string_mode_flag_controls_over_limit_fields() {
+    let csv = temp_path("long_string", "csv");
+    fs::write(&csv, format!("a\n{}\n", "x".repeat(40_000))).unwrap();
+
+    let xlsx = temp_path("long_string_error", "xlsx");
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .output()
+        .expect("failed to run xlsxturbo binary");
+    assert!(!output.status.success(), "expected non-zero exit");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("long_string_mode"),
+        "stderr was: {:?}",
+        stderr
+    );
+    let _ = fs::remove_file(&xlsx);
+
+    for mode in ["truncate", "split"] {
+        let xlsx = temp_path(&format!("long_string_{}", mode), "xlsx");
+        let output = Command::new(bin())
+            .arg(&csv)
+            .arg(&xlsx)
+            .arg("--long-string-mode")
+            .arg(mode)
+            .output()
+            .expect("failed to run xlsxturbo binary");
+        assert!(
+            output.status.success(),
+            "expected exit 0 for {}, got {:?}",
+            mode,
+            output.status
+        );
+        let _ = fs::remove_file(&xlsx);
+    }
+
+    let _ = fs::remove_file(&csv);
+}
+
+#[test]
+fn sanitize_strings_flag_is_accepted() {
+    let csv = temp_path("sanitize_strings", "csv");
+    let xlsx = temp_path("sanitize_strings", "xlsx");
+    fs::write(&csv, "name\nab\u{1}c\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--sanitize-strings")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert!(
+        output.status.success(),
+        "expected exit 0, got {:?}",
+        output.status
+    );
+    assert!(xlsx.exists(), "output xlsx was not created");
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
+
+#[test]
+fn comment_and_skip_empty_rows_flags_drop_rows() {
+    let csv = temp_path("comment", "csv");
+    let xlsx = temp_path("comment", "xlsx");
+    fs::write(&csv, "# exported\na,b\n,\n1,2\n").unwrap();
+
+    let output = Command::new(bin())
+        .arg(&csv)
+        .arg(&xlsx)
+        .arg("--comment")
+        .arg("#")
+        .arg("--skip-empty-rows")
+        .output()
+        .expect("failed to run xlsxturbo binary");
+
+    assert!(
+        output.status.success(),
+        "expected exit 0, got {:?}",
+        output.status
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // Only "a,b" and "1,2" remain.
+    assert!(stdout.trim().ends_with("2 2"), "stdout was: {:?}", stdout);
+
+    let _ = fs::remove_file(&csv);
+    let _ = fs::remove_file(&xlsx);
+}
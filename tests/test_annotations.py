@@ -1,7 +1,11 @@
-"""Tests for annotation features: merged ranges, hyperlinks, comments, and defined names."""
+"""Tests for annotation features: merged ranges, hyperlinks, comments, defined names, and document properties."""
 
 from __future__ import annotations
 
+import io
+import time
+import zipfile
+from datetime import datetime, timedelta, timezone
 from pathlib import Path
 
 import pandas as pd
@@ -83,6 +87,42 @@ class TestMergedRanges:
         assert "A1:B1" in merged
         wb.close()
 
+    def test_merge_keeps_number_type(self, tmp_xlsx: str) -> None:
+        """A numeric value is written as a number in the merged cell, with the format's num_format."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            merged_ranges=[("A4:C4", 1234.5, {"num_format": "#,##0.00", "bold": True}), ("A5:B5", True)],
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A4"].value == 1234.5
+        assert ws["A4"].number_format == "#,##0.00"
+        assert ws["A4"].font.b
+        assert ws["A5"].value is True
+        merged = [str(m) for m in ws.merged_cells.ranges]
+        assert "A4:C4" in merged
+        assert "A5:B5" in merged
+        wb.close()
+
+    def test_merge_datetime_uses_datetime_format(self, tmp_xlsx: str) -> None:
+        """A datetime value is written as a date serial with the sheet's datetime_format."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            datetime_format="dd/mm/yyyy hh:mm",
+            merged_ranges=[("A4:C4", datetime(2024, 1, 15, 9, 30))],
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A4"].value == datetime(2024, 1, 15, 9, 30)
+        assert ws["A4"].number_format == "dd/mm/yyyy hh:mm"
+        assert ws["A4"].alignment.horizontal == "center"
+        assert "A4:C4" in [str(m) for m in ws.merged_cells.ranges]
+        wb.close()
+
 
 class TestHyperlinks:
     """Tests for hyperlinks feature (v0.9.0)."""
@@ -152,6 +192,152 @@ class TestHyperlinks:
         assert ws["B1"].hyperlink is not None
         wb.close()
 
+    def test_hyperlink_tooltip(self, tmp_xlsx: str) -> None:
+        """A 4th tuple element sets the hover tooltip, with or without display text."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            hyperlinks=[
+                ("B1", "https://example.com", "Example", "Opens example.com"),
+                ("B2", "https://example.org", None, "No display text"),
+                ("B3", "https://example.net", "Plain"),
+            ],
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["B1"].hyperlink.tooltip == "Opens example.com"
+        assert ws["B1"].value == "Example"
+        assert ws["B2"].hyperlink.tooltip == "No display text"
+        assert ws["B2"].value == "https://example.org"
+        assert ws["B3"].hyperlink.tooltip is None
+        wb.close()
+
+    def test_internal_link_to_later_sheet(self, tmp_xlsx: str) -> None:
+        """An internal: link jumps to a cell on another sheet, quoting its name."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [
+                (
+                    df,
+                    "Summary",
+                    {"hyperlinks": [("B1", "internal:Raw Data!A1"), ("B2", "internal:raw data!A2:B3", "Go")]},
+                ),
+                (df, "Raw Data"),
+            ],
+            tmp_xlsx,
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = wb["Summary"]
+        assert ws["B1"].hyperlink.location == "'Raw Data'!A1"
+        assert ws["B1"].value == "Raw Data!A1"
+        assert ws["B2"].hyperlink.location == "'Raw Data'!A2:B3"
+        assert ws["B2"].value == "Go"
+        wb.close()
+
+    def test_internal_link_escapes_quotes_in_sheet_name(self, tmp_xlsx: str) -> None:
+        """A quote in the target sheet name is doubled, as Excel expects."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, sheet_name="O'Brien", hyperlinks=[("B1", "internal:O'Brien!$A$2")])
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["B1"].hyperlink.location == "'O''Brien'!$A$2"
+        wb.close()
+
+
+class TestAutoHyperlinks:
+    """Tests for the auto_hyperlinks and link_format options."""
+
+    def test_url_and_mailto_values_become_links(self, tmp_xlsx: str) -> None:
+        """http://, https://, and mailto: values are linked; other strings stay text."""
+        df = pd.DataFrame(
+            {"link": ["https://example.com/a?b=1", "HTTP://example.org", "mailto:sales@example.com", "example.com"]}
+        )
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, auto_hyperlinks=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].hyperlink.target == "https://example.com/a?b=1"
+        assert ws["A3"].hyperlink.target == "HTTP://example.org"
+        assert ws["A4"].hyperlink.target == "mailto:sales@example.com"
+        assert ws["A5"].hyperlink is None
+        assert ws["A2"].value == "https://example.com/a?b=1"
+        assert ws["A2"].font.u == "single"
+        assert ws["A1"].hyperlink is None
+        wb.close()
+
+    def test_off_by_default(self, tmp_xlsx: str) -> None:
+        """Without auto_hyperlinks, URL values are plain text."""
+        df = pd.DataFrame({"link": ["https://example.com"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].hyperlink is None
+        assert ws["A2"].value == "https://example.com"
+        wb.close()
+
+    def test_guards_keep_plain_text(self, tmp_xlsx: str) -> None:
+        """Whitespace, a bare prefix, and over-long URLs are not linked."""
+        too_long = "https://example.com/" + "a" * 2100
+        df = pd.DataFrame({"link": ["https://example.com is our site", "https://", too_long]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, auto_hyperlinks=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].hyperlink is None
+        assert ws["A3"].hyperlink is None
+        assert ws["A4"].hyperlink is None
+        assert ws["A4"].value == too_long
+        wb.close()
+
+    def test_link_format_styles_links(self, tmp_xlsx: str) -> None:
+        """link_format replaces the default hyperlink style for linked cells only."""
+        df = pd.DataFrame({"link": ["https://example.com", "plain"]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, auto_hyperlinks=True, link_format={"bold": True, "font_color": "#FF0000"}
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].font.b
+        assert ws["A2"].font.color.rgb == "FFFF0000"
+        assert not ws["A3"].font.b
+        wb.close()
+
+    def test_cell_format_kept_under_link_style(self, tmp_xlsx: str) -> None:
+        """Without link_format, a link keeps cell_format with the hyperlink style on top."""
+        df = pd.DataFrame({"link": ["https://example.com"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, auto_hyperlinks=True, cell_format={"italic": True})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].font.i
+        assert ws["A2"].font.u == "single"
+        wb.close()
+
+    def test_constant_memory(self, tmp_xlsx: str) -> None:
+        """auto_hyperlinks works while streaming rows."""
+        df = pd.DataFrame({"link": ["https://example.com"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, auto_hyperlinks=True, constant_memory=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].hyperlink.target == "https://example.com"
+        wb.close()
+
+    def test_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet auto_hyperlinks overrides the global one."""
+        df = pd.DataFrame({"link": ["https://example.com"]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"auto_hyperlinks": False})],
+            tmp_xlsx,
+            auto_hyperlinks=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].hyperlink is not None
+        assert wb["Local"]["A2"].hyperlink is None
+        wb.close()
+
+    def test_invalid_link_format_raises(self, tmp_xlsx: str) -> None:
+        """A non-dict link_format is rejected."""
+        df = pd.DataFrame({"link": ["https://example.com"]})
+        with pytest.raises(TypeError, match="expected dict for 'link_format'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, auto_hyperlinks=True, link_format=["bold"])  # type: ignore[arg-type]
+
 
 class TestComments:
     """Tests for comments/notes feature (v0.10.0)."""
@@ -205,6 +391,40 @@ class TestComments:
         assert "Column A" in ws["A1"].comment.text
         wb.close()
 
+    def test_comment_styling_options(self, tmp_xlsx: str) -> None:
+        """width, height, bg_color, font_size, and visible style the note box."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            comments={
+                "A1": {
+                    "text": "Check",
+                    "width": 200,
+                    "height": 90,
+                    "bg_color": "#FFCCCC",
+                    "font_size": 14,
+                    "visible": True,
+                }
+            },
+        )
+        with zipfile.ZipFile(tmp_xlsx) as zf:
+            vml = zf.read("xl/drawings/vmlDrawing1.vml").decode("utf-8")
+            comments_xml = zf.read("xl/comments1.xml").decode("utf-8")
+        # 200 x 90 pixels is 150 x 67.5 points
+        assert "width:150pt;height:67.5pt" in vml
+        assert 'fillcolor="#ffcccc"' in vml
+        assert "visibility:visible" in vml
+        assert '<sz val="14"/>' in comments_xml
+
+    def test_comment_invalid_styling_raises(self, tmp_xlsx: str) -> None:
+        """A zero size or non-positive font_size is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="'width' must be positive"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, comments={"A1": {"text": "x", "width": 0}})
+        with pytest.raises(ValueError, match="'font_size' must be a positive number"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, comments={"A1": {"text": "x", "font_size": 0}})
+
     def test_empty_per_sheet_comments_overrides_global(self, tmp_xlsx: str) -> None:
         """An explicitly empty per-sheet 'comments' dict disables the global default for that sheet.
 
@@ -323,3 +543,153 @@ class TestDefinedNamesVerification:
         wb = load_workbook(tmp_xlsx)
         assert "Total" in wb.defined_names
         wb.close()
+
+
+class TestDocumentProperties:
+    """Tests for the workbook-level properties parameter."""
+
+    def test_core_properties_written(self, tmp_xlsx: str) -> None:
+        """Title, author, keywords and the other core fields reach docProps/core.xml."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx,
+            properties={
+                "title": "Quarterly Report",
+                "subject": "Sales",
+                "author": "Finance",
+                "keywords": "sales, q1",
+                "comments": "Generated nightly",
+                "category": "Reports",
+            },
+        )
+        wb = load_workbook(tmp_xlsx)
+        props = wb.properties
+        assert props.title == "Quarterly Report"
+        assert props.subject == "Sales"
+        assert props.creator == "Finance"
+        assert props.keywords == "sales, q1"
+        assert props.description == "Generated nightly"
+        assert props.category == "Reports"
+        wb.close()
+
+    def test_company_and_manager(self, tmp_xlsx: str) -> None:
+        """company and manager are extended properties in docProps/app.xml."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Data")], tmp_xlsx,
+            properties={"company": "Acme", "manager": "Jo"},
+        )
+        with zipfile.ZipFile(tmp_xlsx) as zf:
+            app = zf.read("docProps/app.xml").decode("utf-8")
+        assert "<Company>Acme</Company>" in app
+        assert "<Manager>Jo</Manager>" in app
+
+    @pytest.mark.parametrize(
+        ("created", "expected"),
+        [
+            ("2024-01-15", datetime(2024, 1, 15)),
+            ("2024-01-15T09:30:00", datetime(2024, 1, 15, 9, 30)),
+            ("2024-01-15 09:30:00", datetime(2024, 1, 15, 9, 30)),
+            ("2024-01-15T09:30:00+02:00", datetime(2024, 1, 15, 7, 30)),
+        ],
+    )
+    def test_created_iso_string(self, tmp_xlsx: str, created: str, expected: datetime) -> None:
+        """created accepts ISO dates and datetimes, converted to UTC."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, properties={"created": created})
+        wb = load_workbook(tmp_xlsx)
+        assert wb.properties.created.replace(tzinfo=None) == expected
+        wb.close()
+
+    def test_invalid_created_raises(self, tmp_xlsx: str) -> None:
+        """A created value that is not an ISO date raises ValueError."""
+        with pytest.raises(ValueError, match="invalid 'created' value 'yesterday'"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, properties={"created": "yesterday"})
+
+    def test_unknown_key_raises(self, tmp_xlsx: str) -> None:
+        """An unknown properties key raises ValueError listing the valid keys."""
+        with pytest.raises(ValueError, match="properties: unknown option 'titel'"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, properties={"titel": "x"})
+
+
+class TestDeterministicOutput:
+    """Tests for the workbook-level deterministic parameter."""
+
+    def test_identical_inputs_give_identical_bytes(self) -> None:
+        """Writes a second apart are byte-identical, with created fixed at 1980-01-01."""
+        df = pd.DataFrame({"A": [1, 2], "B": ["x", "y"]})
+        first = xlsxturbo.df_to_xlsx_bytes(df, deterministic=True)
+        time.sleep(1.1)
+        assert xlsxturbo.df_to_xlsx_bytes(df, deterministic=True) == first
+        sheets = [(df, "One"), (df, "Two")]
+        assert xlsxturbo.dfs_to_xlsx_bytes(sheets, deterministic=True) == xlsxturbo.dfs_to_xlsx_bytes(
+            sheets, deterministic=True
+        )
+        with zipfile.ZipFile(io.BytesIO(first)) as zf:
+            core = zf.read("docProps/core.xml").decode("utf-8")
+        assert ">1980-01-01T00:00:00Z</dcterms:created>" in core
+
+    def test_explicit_created_takes_precedence(self, tmp_xlsx: str) -> None:
+        """A created property overrides the fixed timestamp."""
+        xlsxturbo.df_to_xlsx(
+            pd.DataFrame({"A": [1]}), tmp_xlsx, deterministic=True, properties={"created": "2024-01-15"}
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb.properties.created.replace(tzinfo=None) == datetime(2024, 1, 15)
+        wb.close()
+
+
+class TestCustomProperties:
+    """Tests for the workbook-level custom_properties parameter."""
+
+    def test_value_types(self, tmp_xlsx: str) -> None:
+        """str, int, float, bool and datetime values keep their Excel types."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx,
+            custom_properties={
+                "ReportId": "R-1",
+                "Confidential": True,
+                "Revision": 3,
+                "Score": 97.5,
+                "ApprovedOn": datetime(2024, 1, 15, 9, 30),
+            },
+        )
+        with zipfile.ZipFile(tmp_xlsx) as zf:
+            custom = zf.read("docProps/custom.xml").decode("utf-8")
+        assert 'name="ReportId"><vt:lpwstr>R-1</vt:lpwstr>' in custom
+        assert 'name="Confidential"><vt:bool>true</vt:bool>' in custom
+        assert 'name="Revision"><vt:i4>3</vt:i4>' in custom
+        assert 'name="Score"><vt:r8>97.5</vt:r8>' in custom
+        assert 'name="ApprovedOn"><vt:filetime>2024-01-15T09:30:00Z</vt:filetime>' in custom
+
+    def test_aware_datetime_converted_to_utc(self, tmp_xlsx: str) -> None:
+        """A datetime with a tzinfo is stored in UTC."""
+        when = datetime(2024, 1, 15, 9, 30, tzinfo=timezone(timedelta(hours=2)))
+        xlsxturbo.dfs_to_xlsx([(pd.DataFrame({"A": [1]}), "Data")], tmp_xlsx, custom_properties={"When": when})
+        with zipfile.ZipFile(tmp_xlsx) as zf:
+            custom = zf.read("docProps/custom.xml").decode("utf-8")
+        assert "<vt:filetime>2024-01-15T07:30:00Z</vt:filetime>" in custom
+
+    def test_combined_with_properties(self, tmp_xlsx: str) -> None:
+        """custom_properties and properties can be given together."""
+        xlsxturbo.df_to_xlsx(
+            pd.DataFrame({"A": [1]}), tmp_xlsx,
+            properties={"title": "Report"},
+            custom_properties={"ReportId": "R-1"},
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb.properties.title == "Report"
+        wb.close()
+        with zipfile.ZipFile(tmp_xlsx) as zf:
+            assert "ReportId" in zf.read("docProps/custom.xml").decode("utf-8")
+
+    def test_unsupported_value_type_raises(self, tmp_xlsx: str) -> None:
+        """A value that is not a str, number, bool or datetime raises TypeError."""
+        with pytest.raises(TypeError, match="'Tags' must be a str, int, float, bool, date, or datetime"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, custom_properties={"Tags": ["a", "b"]})
+
+    @pytest.mark.parametrize("value", [float("nan"), 2**60])
+    def test_unrepresentable_number_raises(self, tmp_xlsx: str, value: float) -> None:
+        """NaN and ints too large for a double raise ValueError."""
+        with pytest.raises(ValueError, match="custom_properties: 'X'"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, custom_properties={"X": value})
@@ -30,12 +30,42 @@ def test_df_to_xlsx_dfs_to_xlsx_parameter_parity() -> None:
     is rejected, or simply missing, on the multi-sheet path). The only
     expected differences are structural, not feature options: df_to_xlsx
     takes a single 'df' plus a top-level 'sheet_name', while dfs_to_xlsx takes
-    a 'sheets' list of (df, sheet_name[, options]) tuples instead.
+    a 'sheets' list of (df, sheet_name[, options]) tuples instead, plus
+    'active_sheet', which picks one of those sheets. df_to_xlsx's
+    'progress_callback'/'progress_every' report rows of its single write
+    and are not sheet options.
     """
     single_params = set(inspect.signature(xlsxturbo.df_to_xlsx).parameters.keys())
     multi_params = set(inspect.signature(xlsxturbo.dfs_to_xlsx).parameters.keys())
 
-    known_single_only = {"df", "sheet_name"}
-    known_multi_only = {"sheets"}
+    known_single_only = {"df", "sheet_name", "progress_callback", "progress_every"}
+    known_multi_only = {"sheets", "active_sheet"}
 
     assert single_params - known_single_only == multi_params - known_multi_only
+
+
+def test_csv_to_xlsx_csvs_to_xlsx_parameter_parity() -> None:
+    """Every csv_to_xlsx option must also exist on csvs_to_xlsx, and vice versa.
+
+    The only expected differences are structural: csv_to_xlsx takes an
+    'input_path' and 'sheet_name', while csvs_to_xlsx takes an 'inputs' list
+    of (csv_path, sheet_name[, options]) tuples instead.
+    """
+    single_params = set(inspect.signature(xlsxturbo.csv_to_xlsx).parameters.keys())
+    multi_params = set(inspect.signature(xlsxturbo.csvs_to_xlsx).parameters.keys())
+
+    assert single_params - {"input_path", "sheet_name"} == multi_params - {"inputs"}
+
+
+def test_bytes_variants_forward_all_options() -> None:
+    """df_to_xlsx_bytes / dfs_to_xlsx_bytes forward **kwargs instead of listing options.
+
+    They pass everything through to df_to_xlsx / dfs_to_xlsx, so a new write
+    option is available on the bytes variants without touching them. This
+    pins that shape: if either grows an explicit option list, the parity test
+    above would need to cover it too.
+    """
+    for func, first in ((xlsxturbo.df_to_xlsx_bytes, "df"), (xlsxturbo.dfs_to_xlsx_bytes, "sheets")):
+        params = list(inspect.signature(func).parameters.values())
+        assert [p.name for p in params[:1]] == [first]
+        assert [p.kind for p in params[1:]] == [inspect.Parameter.VAR_KEYWORD]
@@ -2,6 +2,8 @@
 
 from __future__ import annotations
 
+from datetime import date
+
 import pandas as pd
 import polars as pl
 import pytest
@@ -241,3 +243,83 @@ class TestCellsFormatting:
         assert cell.value == "test"
         assert cell.alignment.horizontal == "center"
         wb.close()
+
+
+class TestCellOverrides:
+    """Tests for the cell_overrides option."""
+
+    def test_value_and_format(self, tmp_xlsx: str) -> None:
+        """A value is written with its full format."""
+        df = pd.DataFrame({"a": [1, 2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, cell_overrides={
+            "D2": {"value": "Check", "format": {"bg_color": "#FFFF00", "bold": True}},
+        })
+        wb = load_workbook(tmp_xlsx)
+        cell = active_ws(wb)["D2"]
+        assert cell.value == "Check"
+        assert cell.font.bold is True
+        assert cell.fill.fgColor.rgb.endswith("FFFF00")
+        wb.close()
+
+    def test_format_only_keeps_data_value(self, tmp_xlsx: str) -> None:
+        """A format without a value restyles a DataFrame cell in place."""
+        df = pd.DataFrame({"a": [10, 20]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, cell_overrides={"A3": {"format": {"font_color": "#FF0000"}}})
+        wb = load_workbook(tmp_xlsx)
+        cell = active_ws(wb)["A3"]
+        assert cell.value == 20
+        assert cell.font.color.rgb.endswith("FF0000")
+        wb.close()
+
+    def test_overrides_data_and_cells(self, tmp_xlsx: str) -> None:
+        """Overrides are written last, after both the data and cells."""
+        df = pd.DataFrame({"a": [1]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx,
+            cells={"B1": "from cells"},
+            cell_overrides={"A2": {"value": "replaced"}, "B1": {"value": "from overrides"}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == "replaced"
+        assert ws["B1"].value == "from overrides"
+        wb.close()
+
+    def test_date_keeps_date_format(self, tmp_xlsx: str) -> None:
+        """A date value keeps the sheet's date format under a format without num_format."""
+        df = pd.DataFrame({"a": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, cell_overrides={
+            "C2": {"value": date(2024, 1, 15), "format": {"bold": True}},
+            "D2": {"value": 0.125, "format": {"num_format": "0.0%"}},
+        })
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["C2"].number_format == "yyyy-mm-dd"
+        assert ws["C2"].font.bold is True
+        assert ws["D2"].number_format == "0.0%"
+        wb.close()
+
+    def test_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet cell_overrides replaces the global one for that sheet."""
+        df = pl.DataFrame({"a": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "One", {"cell_overrides": {"C1": {"value": "sheet"}}}), (df, "Two")],
+            tmp_xlsx,
+            cell_overrides={"C1": {"value": "global"}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["One"]["C1"].value == "sheet"
+        assert wb["Two"]["C1"].value == "global"
+        wb.close()
+
+    def test_entry_without_value_or_format_raises(self, tmp_xlsx: str) -> None:
+        """An entry needs a value, a format, or both."""
+        with pytest.raises(ValueError, match=r"cell_overrides\['D2'\] needs a 'value', a 'format', or both"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"a": [1]}), tmp_xlsx, cell_overrides={"D2": {}})
+
+    def test_unknown_format_key_raises(self, tmp_xlsx: str) -> None:
+        """Format dicts reject unknown keys like column_formats does."""
+        with pytest.raises(ValueError, match=r"cell_overrides\['D2'\]: format: unknown option 'colour'"):
+            xlsxturbo.df_to_xlsx(
+                pd.DataFrame({"a": [1]}), tmp_xlsx, cell_overrides={"D2": {"format": {"colour": "red"}}}
+            )
@@ -62,6 +62,66 @@ class TestConditionalFormatting:
             assert "FFEB84" in xml
             assert "63BE7B" in xml
 
+    def test_3_color_scale_point_types(self, tmp_xlsx: str) -> None:
+        """min/mid/max types and values set the scale's points instead of the defaults."""
+        df = pd.DataFrame({"Value": [1, 5, 10, 1000]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            conditional_formats={
+                "Value": {
+                    "type": "3_color_scale",
+                    "min_type": "percentile",
+                    "min_value": 10,
+                    "mid_type": "number",
+                    "mid_value": 7.5,
+                    "max_type": "percent",
+                    "max_value": 90,
+                }
+            },
+        )
+        wb = load_workbook(tmp_xlsx)
+        cfvo = next(iter(active_ws(wb).conditional_formatting)).rules[0].colorScale.cfvo
+        assert [(c.type, float(c.val)) for c in cfvo] == [
+            ("percentile", 10.0),
+            ("num", 7.5),
+            ("percent", 90.0),
+        ]
+        wb.close()
+
+    def test_2_color_scale_explicit_min_max_types_keep_defaults(self, tmp_xlsx: str) -> None:
+        """min_type='min' / max_type='max' name the default ends and take no value."""
+        df = pd.DataFrame({"Score": [10, 50, 90]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            conditional_formats={"Score": {"type": "2_color_scale", "min_type": "min", "max_type": "max"}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        cfvo = next(iter(active_ws(wb).conditional_formatting)).rules[0].colorScale.cfvo
+        assert [c.type for c in cfvo] == ["min", "max"]
+        wb.close()
+
+    @pytest.mark.parametrize(
+        ("config", "match"),
+        [
+            pytest.param({"min_type": "percentile"}, "missing 'min_value' key", id="missing-value"),
+            pytest.param({"max_value": 5}, "'max_value' requires 'max_type'", id="value-without-type"),
+            pytest.param({"min_type": "percentile", "min_value": 150}, "between 0 and 100", id="out-of-range"),
+            pytest.param({"min_type": "max"}, "unknown min_type 'max'", id="wrong-end"),
+            pytest.param({"max_type": "max", "max_value": 5}, "not used with max_type", id="value-with-default"),
+        ],
+    )
+    def test_color_scale_point_errors(self, config: dict[str, object], match: str, tmp_xlsx: str) -> None:
+        """Invalid color-scale point settings raise ValueError instead of being silently ignored."""
+        df = pd.DataFrame({"Score": [1, 2]})
+        with pytest.raises(ValueError, match=match):
+            xlsxturbo.df_to_xlsx(
+                df,
+                tmp_xlsx,
+                conditional_formats={"Score": {"type": "2_color_scale", **config}},  # type: ignore[typeddict-item]
+            )
+
     def test_data_bar(self, tmp_xlsx: str) -> None:
         """Verify data bar conditional format."""
         df = pd.DataFrame({"Progress": [25, 50, 75, 100]})
@@ -153,6 +213,66 @@ class TestConditionalFormatting:
             )
 
 
+def _rule_types_by_range(ws: object) -> dict[str, list[str]]:
+    """Map each conditional-format range (e.g. "A2:A3") to its rule types."""
+    by_range: dict[str, list[str]] = {}
+    for cf in ws.conditional_formatting:  # type: ignore[attr-defined]
+        by_range.setdefault(str(cf.sqref), []).extend(rule.type for rule in cf.rules)
+    return by_range
+
+
+class TestConditionalFormatMode:
+    """Tests for conditional_format_mode with overlapping patterns."""
+
+    OVERLAPPING: dict[str, ConditionalFormat] = {
+        "score_*": {"type": "data_bar"},
+        "score_a": {"type": "2_color_scale"},
+    }
+
+    def test_all_matches_stacks_rules(self, tmp_xlsx: str) -> None:
+        """By default every matching pattern's rules apply to the column."""
+        df = pd.DataFrame({"score_a": [1, 2], "score_b": [3, 4]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, conditional_formats=self.OVERLAPPING)
+        wb = load_workbook(tmp_xlsx)
+        rules = _rule_types_by_range(active_ws(wb))
+        assert sorted(rules["A2:A3"]) == ["colorScale", "dataBar"]
+        assert rules["B2:B3"] == ["dataBar"]
+        wb.close()
+
+    def test_first_match_wins_skips_claimed_columns(self, tmp_xlsx: str) -> None:
+        """first_match_wins applies only the earliest matching pattern to each column."""
+        df = pd.DataFrame({"score_a": [1, 2], "score_b": [3, 4]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            conditional_formats=self.OVERLAPPING,
+            conditional_format_mode="first_match_wins",
+        )
+        wb = load_workbook(tmp_xlsx)
+        rules = _rule_types_by_range(active_ws(wb))
+        assert rules == {"A2:A3": ["dataBar"], "B2:B3": ["dataBar"]}
+        wb.close()
+
+    def test_per_sheet_mode_overrides_global(self, tmp_xlsx: str) -> None:
+        """A per-sheet conditional_format_mode overrides the global setting."""
+        df = pd.DataFrame({"score_a": [1, 2], "score_b": [3, 4]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Stacked"), (df, "First", {"conditional_format_mode": "first_match_wins"})],
+            tmp_xlsx,
+            conditional_formats=self.OVERLAPPING,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert len(_rule_types_by_range(wb["Stacked"])["A2:A3"]) == 2
+        assert _rule_types_by_range(wb["First"])["A2:A3"] == ["dataBar"]
+        wb.close()
+
+    def test_invalid_mode_raises(self, tmp_xlsx: str) -> None:
+        """An unknown conditional_format_mode raises ValueError listing the valid values."""
+        df = pd.DataFrame({"Score": [1]})
+        with pytest.raises(ValueError, match="conditional_format_mode.*all_matches, first_match_wins"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, conditional_format_mode="last")  # type: ignore[arg-type]
+
+
 class TestCellConditionalFormat:
     """Tests for rule-based conditional formatting (v0.12.0)."""
 
@@ -404,6 +524,38 @@ class TestCellConditionalFormat:
         assert cf.formula == ['"ERROR"']
         wb.close()
 
+    @pytest.mark.parametrize(
+        ("config", "operator", "formula"),
+        [
+            pytest.param({"rule": "greater_than", "value": 100}, "greaterThan", ["100"], id="rule-greater"),
+            pytest.param({"rule": "less_than", "value": 5}, "lessThan", ["5"], id="rule-less"),
+            pytest.param({"rule": "equal", "value": 42}, "equal", ["42"], id="rule-equal"),
+            pytest.param({"rule": "between", "min": 10, "max": 20}, "between", ["10", "20"], id="rule-between"),
+        ],
+    )
+    def test_cell_rule_aliases(
+        self, config: dict[str, object], operator: str, formula: list[str], tmp_xlsx: str
+    ) -> None:
+        """'rule', 'min', and 'max' work as aliases of 'criteria', 'min_value', and 'max_value'."""
+        df = pd.DataFrame({"score": [1, 50, 150]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, conditional_formats={
+            "score": {"type": "cell", "format": {"bg_color": "#FF0000"}, **config}  # type: ignore[typeddict-item]
+        })
+        wb = load_workbook(tmp_xlsx)
+        cf = next(iter(active_ws(wb).conditional_formatting)).rules[0]
+        assert cf.type == "cellIs"
+        assert cf.operator == operator
+        assert cf.formula == formula
+        wb.close()
+
+    def test_cell_rule_and_criteria_together_raises(self, tmp_xlsx: str) -> None:
+        """Passing both spellings of the same key is rejected rather than silently picking one."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="'criteria' and 'rule' are the same option"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, conditional_formats={
+                "A": {"type": "cell", "criteria": "greater_than", "rule": "less_than", "value": 1}
+            })
+
     def test_invalid_list_item_raises(self, tmp_xlsx: str) -> None:
         """Verify non-dict item in conditional format list raises TypeError."""
         df = pd.DataFrame({"A": [1]})
@@ -412,3 +564,58 @@ class TestCellConditionalFormat:
         }
         with pytest.raises(TypeError, match=r"list item .* must be a dict"):
             xlsxturbo.df_to_xlsx(df, tmp_xlsx, conditional_formats=invalid_formats)  # type: ignore[arg-type]
+
+
+class TestTextConditionalFormat:
+    """Tests for the 'text' conditional format type."""
+
+    @pytest.mark.parametrize(
+        ("rule", "text", "expected_type", "expected_formula"),
+        [
+            pytest.param("contains", "fail", "containsText", ['NOT(ISERROR(SEARCH("fail",B2)))'], id="contains"),
+            pytest.param("not_contains", "ok", "notContainsText", ['ISERROR(SEARCH("ok",B2))'], id="not_contains"),
+            pytest.param("begins_with", "ERR", "beginsWith", ['LEFT(B2,3)="ERR"'], id="begins_with"),
+            pytest.param("ends_with", ".pdf", "endsWith", ['RIGHT(B2,4)=".pdf"'], id="ends_with"),
+        ],
+    )
+    def test_text_rule_content(
+        self, rule: str, text: str, expected_type: str, expected_formula: list[str], tmp_xlsx: str
+    ) -> None:
+        """Each text rule produces the matching Excel rule over the pattern-selected column."""
+        df = pd.DataFrame({"id": [1, 2], "status_msg": ["ok", "ERR: fail.pdf"]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            conditional_formats={
+                "status_*": {"type": "text", "rule": rule, "text": text, "format": {"bg_color": "#FFC7CE"}}
+            },
+        )
+        wb = load_workbook(tmp_xlsx)
+        cf = next(iter(active_ws(wb).conditional_formatting))
+        assert str(cf.sqref) == "B2:B3"
+        assert cf.rules[0].type == expected_type
+        assert cf.rules[0].formula == expected_formula
+        assert cf.rules[0].dxf.fill is not None
+        wb.close()
+
+    def test_text_missing_rule_raises(self, tmp_xlsx: str) -> None:
+        """A 'text' config without 'rule' raises ValueError."""
+        df = pd.DataFrame({"A": ["x"]})
+        with pytest.raises(ValueError, match="'text' type requires 'rule'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, conditional_formats={"A": {"type": "text", "text": "x"}})
+
+    def test_text_unknown_rule_raises(self, tmp_xlsx: str) -> None:
+        """An unknown text rule raises ValueError naming the pattern and listing the valid rules."""
+        df = pd.DataFrame({"A": ["x"]})
+        with pytest.raises(ValueError, match=r"conditional_formats\['A'\]: Unknown text rule 'greater_than'"):
+            xlsxturbo.df_to_xlsx(
+                df,
+                tmp_xlsx,
+                conditional_formats={"A": {"type": "text", "rule": "greater_than", "text": "x"}},
+            )
+
+    def test_text_missing_text_raises(self, tmp_xlsx: str) -> None:
+        """A text rule without the 'text' string raises ValueError."""
+        df = pd.DataFrame({"A": ["x"]})
+        with pytest.raises(ValueError, match="missing 'text' key"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, conditional_formats={"A": {"type": "text", "rule": "contains"}})
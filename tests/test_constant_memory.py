@@ -3,6 +3,8 @@
 from __future__ import annotations
 
 import warnings
+from collections.abc import Callable
+from pathlib import Path
 
 import pandas as pd
 import pytest
@@ -71,6 +73,34 @@ class TestConstantMemoryMode:
         wb.close()
 
 
+class TestCsvConstantMemory:
+    """Tests for csv_to_xlsx(constant_memory=True)."""
+
+    def test_csv_constant_memory_writes_typed_values(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """Streamed CSV output keeps type detection and the (rows, cols) result."""
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        Path(csv_path).write_text("name,count,ratio\nalpha,1,0.5\nbeta,2,1.5\n")
+        rows, cols = xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, constant_memory=True)
+        assert (rows, cols) == (3, 3)
+        wb = load_workbook(xlsx_path)
+        ws = active_ws(wb)
+        assert ws["A1"].value == "name"
+        assert ws["A2"].value == "alpha"
+        assert ws["B3"].value == 2
+        assert ws["C2"].value == 0.5
+        wb.close()
+
+    def test_csv_constant_memory_with_parallel_raises(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """constant_memory and parallel are mutually exclusive."""
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        Path(csv_path).write_text("a\n1\n")
+        with pytest.raises(ValueError, match="constant_memory"):
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, constant_memory=True, parallel=True)
+        assert Path(xlsx_path).stat().st_size == 0
+
+
 class TestConstantMemoryWarning:
     """Tests for constant_memory warning emission."""
 
@@ -84,11 +114,13 @@ class TestConstantMemoryWarning:
                 constant_memory=True,
                 table_style="Medium2",
                 freeze_panes=True,
+                autofilter=True,
             )
             assert len(w) == 1
             assert issubclass(w[0].category, RuntimeWarning)
             assert "table_style" in str(w[0].message)
             assert "freeze_panes" in str(w[0].message)
+            assert "autofilter" in str(w[0].message)
 
     def test_constant_memory_no_warning_when_clean(self, tmp_xlsx: str) -> None:
         """constant_memory=True without incompatible options emits no warning."""
@@ -176,6 +208,13 @@ class TestFeatureConstantMemoryWarnings:
                 {"sparklines": {"D2": {"range": "Sheet1!A2:A3"}}},
                 id="sparklines",
             ),
+            pytest.param("row_groups", {"row_groups": [(1, 2, 1)]}, id="row_groups"),
+            pytest.param("col_groups", {"col_groups": [(0, 0, 1)]}, id="col_groups"),
+            pytest.param(
+                "categorical_validation",
+                {"categorical_validation": True},
+                id="categorical_validation",
+            ),
         ],
     )
     def test_feature_constant_memory_warns(
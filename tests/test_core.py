@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import io
 from collections.abc import Callable
 from pathlib import Path
 
@@ -89,6 +90,111 @@ class TestPolarsSupport:
         assert ws["A1"].font.bold is True
         wb.close()
 
+    def test_polars_date_and_datetime_dtypes(self, tmp_xlsx: str) -> None:
+        """Date and Datetime columns of every time unit write as Excel dates, nulls as empty cells.
+
+        These columns are decoded from their physical integers instead of
+        iter_rows() objects, so each time unit and null must land exactly
+        where the generic path would put it.
+        """
+        from datetime import date, datetime
+
+        stamps = [datetime(2024, 1, 15, 8, 30, 15, 250000), None]
+        df = pl.DataFrame(
+            {
+                "name": ["a", "b"],
+                "d": [date(2024, 1, 15), None],
+                "ms": pl.Series(stamps, dtype=pl.Datetime("ms")),
+                "us": pl.Series(stamps, dtype=pl.Datetime("us")),
+                "ns": pl.Series(stamps, dtype=pl.Datetime("ns")),
+            }
+        )
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == "a"
+        assert ws["B2"].value == datetime(2024, 1, 15)
+        assert ws["B2"].number_format == "yyyy-mm-dd"
+        for col in "CDE":
+            assert ws[f"{col}2"].value == datetime(2024, 1, 15, 8, 30, 15, 250000)
+            assert ws[f"{col}2"].number_format == "yyyy-mm-dd hh:mm:ss"
+            assert ws[f"{col}3"].value is None
+        assert ws["B3"].value is None
+        wb.close()
+
+    def test_polars_date_before_1900_march_written_as_text(self, tmp_xlsx: str) -> None:
+        """Polars dates before 1900-03-01 fall back to their str() text, as in the generic path."""
+        from datetime import date, datetime
+
+        df = pl.DataFrame({"d": [date(1899, 12, 31)], "t": [datetime(1899, 12, 31, 10, 30)]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == "1899-12-31"
+        assert ws["B2"].value == "1899-12-31 10:30:00"
+        wb.close()
+
+    def test_polars_date_column_formats_and_date_format(self, tmp_xlsx: str) -> None:
+        """column_formats and date_format still apply to polars Date columns."""
+        from datetime import date
+
+        df = pl.DataFrame({"a": [date(2024, 1, 15)], "b": [date(2024, 1, 15)]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, date_format="dd/mm/yyyy", column_formats={"b": {"num_format": "d mmm"}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].number_format == "dd/mm/yyyy"
+        assert ws["B2"].number_format == "d mmm"
+        wb.close()
+
+    def test_polars_tz_aware_datetime_keeps_wall_clock(self, tmp_xlsx: str) -> None:
+        """A time-zone-aware Datetime column is written as its local wall-clock time."""
+        from datetime import datetime
+
+        df = pl.DataFrame({"t": [datetime(2024, 1, 15, 12, 0)]}).with_columns(
+            pl.col("t").dt.replace_time_zone("UTC").dt.convert_time_zone("America/New_York")
+        )
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == datetime(2024, 1, 15, 7, 0)
+        wb.close()
+
+
+class TestCategoricalDtype:
+    """Categorical columns are decoded from their dtype and written as label text."""
+
+    def test_pandas_category_written_as_labels(self, tmp_xlsx: str) -> None:
+        """pandas category values, including non-string categories, are written as text."""
+        df = pd.DataFrame(
+            {
+                "status": pd.Categorical(["Open", None, "Closed"]),
+                "level": pd.Categorical([1, 2, 1]),
+            }
+        )
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, nan_policy="string")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [ws[f"A{r}"].value for r in (2, 3, 4)] == ["Open", None, "Closed"]
+        assert [ws[f"B{r}"].value for r in (2, 3, 4)] == ["1", "2", "1"]
+        wb.close()
+
+    def test_polars_categorical_and_enum_written_as_labels(self, tmp_xlsx: str) -> None:
+        """polars Categorical and Enum values are written as text, nulls as empty cells."""
+        df = pl.DataFrame(
+            {
+                "cat": pl.Series(["a", None, "b"], dtype=pl.Categorical),
+                "enum": pl.Series(["lo", "hi", None], dtype=pl.Enum(["lo", "hi"])),
+                "n": [1, 2, 3],
+            }
+        )
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [ws[f"A{r}"].value for r in (2, 3, 4)] == ["a", None, "b"]
+        assert [ws[f"B{r}"].value for r in (2, 3, 4)] == ["lo", "hi", None]
+        assert [ws[f"C{r}"].value for r in (2, 3, 4)] == [1, 2, 3]
+        wb.close()
+
 
 class TestBooleanDtype:
     """Tests for pure-bool-dtype columns (write.rs write_py_value_with_format).
@@ -126,6 +232,33 @@ class TestBooleanDtype:
         assert ws["A3"].value is False
         wb.close()
 
+    def test_polars_lazyframe_is_collected(self, tmp_xlsx: str) -> None:
+        """A polars LazyFrame is collected and written like the eager DataFrame."""
+        lf = pl.DataFrame({"x": [1, 2, 3], "y": ["a", "b", "c"]}).lazy().filter(pl.col("x") > 1)
+        rows, cols = xlsxturbo.df_to_xlsx(lf, tmp_xlsx, table_style="Medium2")
+        assert (rows, cols) == (3, 2)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A1"].value == "x"
+        assert ws["A2"].value == 2
+        assert ws["B3"].value == "c"
+        assert len(ws.tables) == 1
+        wb.close()
+
+    def test_polars_lazyframe_in_dfs_to_xlsx(self, tmp_xlsx: str) -> None:
+        """dfs_to_xlsx collects LazyFrame sheets alongside eager ones."""
+        df = pl.DataFrame({"x": [1, 2]})
+        xlsxturbo.dfs_to_xlsx([(df, "Eager"), (df.lazy(), "Lazy")], tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Lazy"]["A3"].value == 2
+        wb.close()
+
+    def test_polars_lazyframe_collect_failure_raises(self, tmp_xlsx: str) -> None:
+        """A LazyFrame whose collect() fails raises a ValueError saying so."""
+        lf = pl.LazyFrame({"x": [1]}).select(pl.col("missing"))
+        with pytest.raises(ValueError, match="Failed to collect polars LazyFrame"):
+            xlsxturbo.df_to_xlsx(lf, tmp_xlsx)
+
     def test_mixed_dtype_dataframe_bool_column_still_writes_booleans(self, tmp_xlsx: str) -> None:
         """A mixed-dtype DataFrame's bool column still writes real Excel booleans.
 
@@ -213,6 +346,53 @@ class TestEdgeCases:
         # other should have no background
         wb.close()
 
+    def test_column_formats_integer_key_by_position(self, tmp_xlsx: str) -> None:
+        """An integer column_formats key targets the column at that position."""
+        df = pd.DataFrame({"a": [0.1], "b": [0.2], "c": [0.3]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={2: {"num_format": "0.00%"}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["C2"].number_format == "0.00%"
+        assert ws["A2"].number_format == "General"
+        wb.close()
+
+    def test_column_formats_integer_key_with_duplicate_names(self, tmp_xlsx: str) -> None:
+        """Integer keys tell apart columns that share a name."""
+        df = pd.DataFrame([[1.0, 2.0]], columns=["x", "x"])
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={1: {"bold": True}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert not ws["A2"].font.bold
+        assert ws["B2"].font.bold
+        wb.close()
+
+    def test_column_formats_integer_key_beats_pattern(self, tmp_xlsx: str) -> None:
+        """An index key takes precedence over a name pattern, whatever the dict order."""
+        df = pd.DataFrame({"price_usd": [1.0], "price_eur": [2.0]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            column_formats={
+                "price_*": {"bg_color": "#0000FF"},
+                0: {"bg_color": "#FF0000"},
+            },
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].fill.fgColor.rgb == "FFFF0000"
+        assert ws["B2"].fill.fgColor.rgb == "FF0000FF"
+        wb.close()
+
+    def test_column_formats_string_digit_key_is_a_name(self, tmp_xlsx: str) -> None:
+        """A string key like "1" still matches a column named "1", not position 1."""
+        df = pd.DataFrame({"1": [1.0], "other": [2.0]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={"1": {"bold": True}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].font.bold
+        assert not ws["B2"].font.bold
+        wb.close()
+
     def test_empty_dataframe_no_header(self, tmp_xlsx: str) -> None:
         """Empty DataFrame with header=False."""
         df = pd.DataFrame({"A": [], "B": []})
@@ -221,18 +401,33 @@ class TestEdgeCases:
         assert cols == 2
         assert Path(tmp_xlsx).exists()
 
-    def test_table_style_skipped_when_header_false(self, tmp_xlsx: str) -> None:
-        """table_style is skipped when header=False (tables require headers)."""
+    def test_table_style_headerless_when_header_false(self, tmp_xlsx: str) -> None:
+        """table_style with header=False creates a headerless table over the data."""
         df = pd.DataFrame({"A": [1, 2]})
         xlsxturbo.df_to_xlsx(df, tmp_xlsx, header=False, table_style="Medium2")
         assert Path(tmp_xlsx).exists()
         wb = load_workbook(tmp_xlsx)
         ws = active_ws(wb)
         assert ws["A1"].value == 1  # data in row 1, no header
+        assert ws["A2"].value == 2
+        assert len(ws.tables) == 1
+        table = next(iter(ws.tables.values()))
+        assert table.ref == "A1:A2"
+        assert table.headerRowCount == 0
+        wb.close()
+
+    def test_headerless_table_respects_start_row(self, tmp_xlsx: str) -> None:
+        """A headerless table starts at start_row, the first data row."""
+        df = pd.DataFrame({"A": [1, 2], "B": [3, 4]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, header=False, table_style="Medium2", start_row=2)
+        wb = load_workbook(tmp_xlsx)
+        table = next(iter(active_ws(wb).tables.values()))
+        assert table.ref == "A3:B4"
+        assert table.headerRowCount == 0
         wb.close()
 
     def test_dfs_per_sheet_header_false_with_global_table_style(self, tmp_xlsx: str) -> None:
-        """Per-sheet header=False skips table even with global table_style."""
+        """Per-sheet header=False gets a headerless table with a global table_style."""
         df1 = pd.DataFrame({"A": [1]})
         df2 = pd.DataFrame({"B": [2]})
         xlsxturbo.dfs_to_xlsx([
@@ -243,8 +438,10 @@ class TestEdgeCases:
         wb = load_workbook(tmp_xlsx)
         # WithHeader sheet should have table
         assert wb["WithHeader"]["A1"].value == "A"
-        # NoHeader sheet should have data in row 1
+        # NoHeader sheet should have data in row 1, inside a headerless table
         assert wb["NoHeader"]["A1"].value == 2
+        no_header_table = next(iter(wb["NoHeader"].tables.values()))
+        assert no_header_table.headerRowCount == 0
         wb.close()
 
 
@@ -346,173 +543,1619 @@ class TestDateOrder:
         assert "european" in message.lower()
 
 
-class TestCsvConversion:
-    """Tests for csv_to_xlsx function."""
-
-    def test_basic_csv(self, tmp_xlsx_factory: Callable[..., str]) -> None:
-        """Basic CSV with header and data rows."""
-        import csv
+class TestDateFormats:
+    """Tests for the date_format/datetime_format parameters."""
 
-        csv_path = tmp_xlsx_factory(".csv")
-        xlsx_path = tmp_xlsx_factory()
-        with Path(csv_path).open("w", newline="") as f:
-            writer = csv.writer(f)
-            writer.writerow(["name", "age", "score"])
-            writer.writerow(["Alice", "30", "95.5"])
-            writer.writerow(["Bob", "25", "88.0"])
+    def test_df_custom_date_and_datetime_formats(self, tmp_xlsx: str) -> None:
+        """date_format/datetime_format replace the default number formats."""
+        from datetime import date, datetime
 
-        rows, cols = xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
-        assert rows == 3
-        assert cols == 3
-        wb = load_workbook(xlsx_path)
+        df = pd.DataFrame({
+            "d": [date(2024, 1, 15)],
+            "dt": [datetime(2024, 1, 15, 10, 30)],
+        })
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, date_format="dd/mm/yyyy", datetime_format="dd/mm/yyyy hh:mm")
+        wb = load_workbook(tmp_xlsx)
         ws = active_ws(wb)
-        assert ws["A1"].value == "name"
-        assert ws["B2"].value == 30  # should be detected as integer
-        assert ws["C2"].value == 95.5  # should be detected as float
+        assert ws["A2"].number_format == "dd/mm/yyyy"
+        assert ws["B2"].number_format == "dd/mm/yyyy hh:mm"
+        assert ws["A2"].value == datetime(2024, 1, 15)
         wb.close()
 
-    def test_csv_type_detection(self, tmp_xlsx_factory: Callable[..., str]) -> None:
-        """CSV type detection: int, float, bool, date, string."""
-        import csv
+    def test_df_default_formats_unchanged(self, tmp_xlsx: str) -> None:
+        """Omitting the parameters keeps the historical default formats."""
+        from datetime import date, datetime
 
-        csv_path = tmp_xlsx_factory(".csv")
-        xlsx_path = tmp_xlsx_factory()
-        with Path(csv_path).open("w", newline="") as f:
-            writer = csv.writer(f)
-            writer.writerow(["int", "float", "bool", "date", "text"])
-            writer.writerow(["42", "3.14", "true", "2024-01-15", "hello"])
+        df = pd.DataFrame({"d": [date(2024, 1, 15)], "dt": [datetime(2024, 1, 15, 10, 30)]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].number_format == "yyyy-mm-dd"
+        assert ws["B2"].number_format == "yyyy-mm-dd hh:mm:ss"
+        wb.close()
 
-        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
-        wb = load_workbook(xlsx_path)
+    def test_column_format_num_format_takes_precedence(self, tmp_xlsx: str) -> None:
+        """A column_formats num_format wins over date_format for its column."""
+        from datetime import date
+
+        df = pd.DataFrame({"a": [date(2024, 1, 15)], "b": [date(2024, 1, 15)]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, date_format="dd/mm/yyyy", column_formats={"b": {"num_format": "mmm yyyy"}}
+        )
+        wb = load_workbook(tmp_xlsx)
         ws = active_ws(wb)
-        assert ws["A2"].value == 42
-        assert abs(ws["B2"].value - 3.14) < 0.001
-        assert ws["C2"].value is True
-        # Date should be a datetime object in openpyxl
-        from datetime import datetime
+        assert ws["A2"].number_format == "dd/mm/yyyy"
+        assert ws["B2"].number_format == "mmm yyyy"
+        wb.close()
 
-        assert isinstance(ws["D2"].value, datetime)
-        assert ws["E2"].value == "hello"
+    def test_cells_use_date_format(self, tmp_xlsx: str) -> None:
+        """Date values written through cells use date_format too."""
+        from datetime import date
+
+        df = pd.DataFrame({"a": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, date_format="dd.mm.yyyy", cells={"C1": date(2024, 1, 15)})
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["C1"].number_format == "dd.mm.yyyy"
         wb.close()
 
-    def test_csv_datetime_fractional_seconds(self, tmp_xlsx_factory: Callable[..., str]) -> None:
-        """CSV datetime values preserve fractional seconds in Excel serials."""
-        import csv
-        from datetime import datetime
+    def test_dfs_per_sheet_date_format_overrides_global(self, tmp_xlsx: str) -> None:
+        """A per-sheet date_format overrides the global one; other sheets keep the global."""
+        from datetime import date
+
+        df = pd.DataFrame({"d": [date(2024, 1, 15)]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"date_format": "dd.mm.yyyy"})],
+            tmp_xlsx,
+            date_format="mm/dd/yyyy",
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].number_format == "mm/dd/yyyy"
+        assert wb["Local"]["A2"].number_format == "dd.mm.yyyy"
+        wb.close()
 
+    def test_csv_custom_formats(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """csv_to_xlsx applies date_format/datetime_format on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("2024-01-15,2024-01-15 10:30:00\n")
+
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(
+                csv_path,
+                xlsx_path,
+                parallel=parallel,
+                date_format="dd/mm/yyyy",
+                datetime_format="dd/mm/yyyy hh:mm",
+            )
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert ws["A1"].number_format == "dd/mm/yyyy"
+            assert ws["B1"].number_format == "dd/mm/yyyy hh:mm"
+            wb.close()
+
+    @pytest.mark.parametrize("bad_format", ["", "   ", '"unterminated', "[$-409yyyy", "0;0;0;0;0", "y" * 256])
+    def test_invalid_format_raises(self, tmp_xlsx: str, bad_format: str) -> None:
+        """An invalid number format string raises ValueError naming the parameter."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(ValueError, match="date_format"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, date_format=bad_format)
+        with pytest.raises(ValueError, match="datetime_format"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, datetime_format=bad_format)
+
+    def test_csv_invalid_format_raises(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """csv_to_xlsx rejects an invalid format before writing anything."""
         csv_path = tmp_xlsx_factory(".csv")
         xlsx_path = tmp_xlsx_factory()
-        with Path(csv_path).open("w", newline="") as f:
-            writer = csv.writer(f)
-            writer.writerow(["timestamp"])
-            writer.writerow(["2024-01-01T12:34:56.789"])
+        Path(csv_path).write_text("a\n1\n")
+        with pytest.raises(ValueError, match="date_format"):
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, date_format="")
+        assert Path(xlsx_path).stat().st_size == 0
 
-        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
-        wb = load_workbook(xlsx_path)
+    def test_per_sheet_date_format_wrong_type_raises(self, tmp_xlsx: str) -> None:
+        """A non-string per-sheet date_format raises TypeError."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(TypeError, match="date_format"):
+            xlsxturbo.dfs_to_xlsx([(df, "S1", {"date_format": 5})], tmp_xlsx)  # type: ignore[typeddict-item]
+
+
+class TestTimeValues:
+    """Tests for datetime.time values and the time_format parameter."""
+
+    def test_df_time_column_written_as_time(self, tmp_xlsx: str) -> None:
+        """datetime.time values become Excel times with the default hh:mm:ss format."""
+        from datetime import time
+
+        df = pd.DataFrame({"t": [time(14, 30), time(0, 0, 1)]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
         ws = active_ws(wb)
-        value = ws["A2"].value
-        assert isinstance(value, datetime)
-        assert value == datetime(2024, 1, 1, 12, 34, 56, 789000)
+        assert ws["A2"].number_format == "hh:mm:ss"
+        assert ws["A2"].value == time(14, 30)
+        assert ws["A3"].value == time(0, 0, 1)
         wb.close()
 
-    def test_csv_special_values(self, tmp_xlsx_factory: Callable[..., str]) -> None:
-        """CSV with NaN, Inf, empty cells."""
-        import csv
-
-        csv_path = tmp_xlsx_factory(".csv")
-        xlsx_path = tmp_xlsx_factory()
-        with Path(csv_path).open("w", newline="") as f:
-            writer = csv.writer(f)
-            writer.writerow(["a", "b", "c"])
-            writer.writerow(["NaN", "Inf", ""])
-            writer.writerow(["nan", "-Inf", "   "])
+    def test_polars_time_column(self, tmp_xlsx: str) -> None:
+        """Polars Time columns are written as Excel times."""
+        pl = pytest.importorskip("polars")
+        from datetime import time
 
-        rows, _cols = xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
-        assert rows == 3
-        wb = load_workbook(xlsx_path)
+        df = pl.DataFrame({"t": [time(8, 15, 30)]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
         ws = active_ws(wb)
-        # NaN/Inf/empty all become empty cells (write_cell -> CellValue::Empty
-        # writes an empty string, which openpyxl reads back as None or "").
-        for ref in ("A2", "B2", "C2", "A3", "B3", "C3"):
-            assert ws[ref].value in (None, ""), f"{ref} should be empty"
+        assert ws["A2"].number_format == "hh:mm:ss"
+        assert ws["A2"].value == time(8, 15, 30)
         wb.close()
 
-    def test_csv_string_cells_preserve_surrounding_whitespace(self, tmp_xlsx_factory: Callable[..., str]) -> None:
-        """String cells keep leading/trailing whitespace; type detection still trims to classify.
+    def test_custom_time_format(self, tmp_xlsx: str) -> None:
+        """time_format replaces the default number format for time cells."""
+        from datetime import time
 
-        Quoted fields are used so the padding survives however csv.writer
-        would otherwise decide to quote plain fields. A whitespace-padded
-        string stays padded; a whitespace-padded number is still detected and
-        written as a number (detection trims a private copy, but the original
-        untrimmed value is what a genuine string falls back to).
-        """
-        import csv
+        df = pd.DataFrame({"t": [time(14, 30)]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, time_format="h:mm AM/PM")
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A2"].number_format == "h:mm AM/PM"
+        wb.close()
+
+    def test_dfs_per_sheet_time_format_overrides_global(self, tmp_xlsx: str) -> None:
+        """A per-sheet time_format overrides the global one."""
+        from datetime import time
+
+        df = pd.DataFrame({"t": [time(14, 30)]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"time_format": "hh:mm"})],
+            tmp_xlsx,
+            time_format="h:mm:ss",
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].number_format == "h:mm:ss"
+        assert wb["Local"]["A2"].number_format == "hh:mm"
+        wb.close()
+
+    def test_csv_time_strings(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """HH:MM:SS strings in a CSV become Excel times on both code paths."""
+        from datetime import time
 
         csv_path = tmp_xlsx_factory(".csv")
-        xlsx_path = tmp_xlsx_factory()
-        with Path(csv_path).open("w", newline="") as f:
-            writer = csv.writer(f, quoting=csv.QUOTE_ALL)
-            writer.writerow(["text", "number"])
-            writer.writerow([" padded ", " 123 "])
+        Path(csv_path).write_text("14:30:00,14:30\n")
+
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, parallel=parallel, time_format="hh:mm")
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert ws["A1"].value == time(14, 30)
+            assert ws["A1"].number_format == "hh:mm"
+            assert ws["B1"].value == "14:30"
+            wb.close()
+
+    def test_invalid_time_format_raises(self, tmp_xlsx: str) -> None:
+        """An invalid time_format raises ValueError naming the parameter."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(ValueError, match="time_format"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, time_format="")
+
+
+class TestNanPolicy:
+    """Tests for the nan_policy parameter."""
+
+    def test_default_writes_empty(self, tmp_xlsx: str) -> None:
+        """The default policy keeps NaN/Inf as empty cells."""
+        df = pd.DataFrame({"a": [float("nan"), float("inf")]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value in (None, "")
+        assert ws["A3"].value in (None, "")
+        wb.close()
 
-        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
-        wb = load_workbook(xlsx_path)
+    def test_string_policy(self, tmp_xlsx: str) -> None:
+        """nan_policy='string' writes the literal text NaN/Inf/-Inf."""
+        df = pd.DataFrame({"a": [float("nan"), float("inf"), float("-inf"), 1.5]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, nan_policy="string")
+        wb = load_workbook(tmp_xlsx)
         ws = active_ws(wb)
-        assert ws["A2"].value == " padded "
-        assert ws["B2"].value == 123
+        assert [ws[f"A{r}"].value for r in range(2, 6)] == ["NaN", "Inf", "-Inf", 1.5]
         wb.close()
 
-    def test_csv_int_min_writes_as_string_without_overflow(self, tmp_xlsx_factory: Callable[..., str]) -> None:
-        """i64::MIN via the CSV path (write_cell) is written as text, no overflow.
+    def test_error_policy(self, tmp_xlsx: str) -> None:
+        """nan_policy='error' writes an =NA() formula cached as #N/A."""
+        df = pd.DataFrame({"a": [float("nan")]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, nan_policy="error")
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A2"].value == "=NA()"
+        wb.close()
+        wb = load_workbook(tmp_xlsx, data_only=True)
+        assert active_ws(wb)["A2"].value == "#N/A"
+        wb.close()
 
-        Complements test_i64_min_writes_as_string_without_overflow, which covers
-        the distinct DataFrame write_int path.
-        """
-        import csv as _csv
+    def test_polars_nan(self, tmp_xlsx: str) -> None:
+        """Polars NaN follows the policy; nulls stay empty."""
+        pl = pytest.importorskip("polars")
 
-        int_min = -9223372036854775808  # i64::MIN
-        csv_path = tmp_xlsx_factory(".csv")
-        xlsx_path = tmp_xlsx_factory()
-        with Path(csv_path).open("w", newline="") as f:
-            w = _csv.writer(f)
-            w.writerow(["n"])
-            w.writerow([str(int_min)])
-        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
-        wb = load_workbook(xlsx_path)
+        df = pl.DataFrame({"a": [float("nan"), None]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, nan_policy="string")
+        wb = load_workbook(tmp_xlsx)
         ws = active_ws(wb)
-        assert ws["A2"].value == str(int_min)
+        assert ws["A2"].value == "NaN"
+        assert ws["A3"].value in (None, "")
         wb.close()
 
-    def test_csv_parallel(self, tmp_xlsx_factory: Callable[..., str]) -> None:
-        """CSV parallel mode produces same output."""
-        import csv
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet nan_policy overrides the global one."""
+        df = pd.DataFrame({"a": [float("nan")]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"nan_policy": "empty"})],
+            tmp_xlsx,
+            nan_policy="string",
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].value == "NaN"
+        assert wb["Local"]["A2"].value in (None, "")
+        wb.close()
 
+    def test_csv_policies(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """csv_to_xlsx applies nan_policy on both code paths."""
         csv_path = tmp_xlsx_factory(".csv")
-        xlsx_seq = tmp_xlsx_factory()
-        xlsx_par = tmp_xlsx_factory()
-        with Path(csv_path).open("w", newline="") as f:
-            writer = csv.writer(f)
-            writer.writerow(["num", "text"])
-            for i in range(100):
-                writer.writerow([str(i), f"row_{i}"])
+        Path(csv_path).write_text("nan,inf,-Infinity\n")
+
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, parallel=parallel, nan_policy="string")
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert [ws["A1"].value, ws["B1"].value, ws["C1"].value] == ["NaN", "Inf", "-Inf"]
+            wb.close()
+
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, parallel=parallel, nan_policy="error")
+            wb = load_workbook(xlsx_path, data_only=True)
+            assert active_ws(wb)["A1"].value == "#N/A"
+            wb.close()
+
+    def test_invalid_policy_raises(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """An unknown nan_policy raises ValueError listing the valid values."""
+        df = pd.DataFrame({"a": [1.0]})
+        with pytest.raises(ValueError, match="Invalid nan_policy 'zero'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx_factory(), nan_policy="zero")  # type: ignore[arg-type]
+        with pytest.raises(ValueError, match="sheet 'S1': Invalid nan_policy"):
+            xlsxturbo.dfs_to_xlsx([(df, "S1", {"nan_policy": "zero"})], tmp_xlsx_factory())  # type: ignore[typeddict-item]
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a\n")
+        with pytest.raises(ValueError, match="Invalid nan_policy"):
+            xlsxturbo.csv_to_xlsx(csv_path, tmp_xlsx_factory(), nan_policy="zero")  # type: ignore[arg-type]
 
-        rows_s, cols_s = xlsxturbo.csv_to_xlsx(csv_path, xlsx_seq, parallel=False)
-        rows_p, cols_p = xlsxturbo.csv_to_xlsx(csv_path, xlsx_par, parallel=True)
-        assert rows_s == rows_p
-        assert cols_s == cols_p
-        wb_s = load_workbook(xlsx_seq)
-        wb_p = load_workbook(xlsx_par)
-        ws_s = active_ws(wb_s)
-        ws_p = active_ws(wb_p)
-        # Spot check some cells match
-        for row in [1, 2, 50, 101]:
-            assert ws_s[f"A{row}"].value == ws_p[f"A{row}"].value
-            assert ws_s[f"B{row}"].value == ws_p[f"B{row}"].value
-        wb_s.close()
-        wb_p.close()
 
-    def test_csv_with_sheet_name(self, tmp_xlsx_factory: Callable[..., str]) -> None:
-        """CSV conversion with custom sheet name."""
+class TestLargeIntMode:
+    """Tests for the large_int_mode parameter."""
+
+    LARGE = 9007199254740993  # 2^53 + 1, not exactly representable as f64
+
+    def test_default_writes_plain_string(self, tmp_xlsx: str) -> None:
+        """The default mode keeps every digit in a General-formatted text cell."""
+        df = pd.DataFrame({"id": [self.LARGE]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        cell = active_ws(wb)["A2"]
+        assert cell.value == str(self.LARGE)
+        assert cell.number_format == "General"
+        wb.close()
+
+    def test_number_mode(self, tmp_xlsx: str) -> None:
+        """large_int_mode='number' writes a (rounded) Excel number."""
+        df = pd.DataFrame({"id": [self.LARGE, 42]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, large_int_mode="number")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert isinstance(ws["A2"].value, (int, float))
+        assert ws["A2"].value == float(self.LARGE)
+        assert ws["A3"].value == 42
+        wb.close()
+
+    def test_text_mode(self, tmp_xlsx: str) -> None:
+        """large_int_mode='text' keeps every digit with the '@' number format."""
+        df = pd.DataFrame({"id": [self.LARGE, 42]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, large_int_mode="text")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == str(self.LARGE)
+        assert ws["A2"].number_format == "@"
+        # Values below the 2^53 pivot are unaffected.
+        assert ws["A3"].value == 42
+        wb.close()
+
+    def test_python_int_beyond_u64(self, tmp_xlsx: str) -> None:
+        """Python ints wider than 64 bits follow the mode too."""
+        huge = 2**70
+        df = pd.DataFrame({"id": [huge]}, dtype=object)
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, large_int_mode="number")
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A2"].value == float(huge)
+        wb.close()
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet large_int_mode overrides the global one."""
+        df = pd.DataFrame({"id": [self.LARGE]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"large_int_mode": "string"})],
+            tmp_xlsx,
+            large_int_mode="number",
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].value == float(self.LARGE)
+        assert wb["Local"]["A2"].value == str(self.LARGE)
+        wb.close()
+
+    def test_csv_modes(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """csv_to_xlsx applies large_int_mode on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text(f"{self.LARGE}\n")
+
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, parallel=parallel, large_int_mode="text")
+            wb = load_workbook(xlsx_path)
+            cell = active_ws(wb)["A1"]
+            assert cell.value == str(self.LARGE)
+            assert cell.number_format == "@"
+            wb.close()
+
+    def test_invalid_mode_raises(self, tmp_xlsx: str) -> None:
+        """An unknown large_int_mode raises ValueError listing the valid values."""
+        df = pd.DataFrame({"id": [1]})
+        with pytest.raises(ValueError, match="Invalid large_int_mode 'float'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, large_int_mode="float")  # type: ignore[arg-type]
+
+
+class TestLongStringMode:
+    """Tests for the long_string_mode parameter."""
+
+    LIMIT = 32767
+    LONG = "a" * LIMIT + "b" * 100
+
+    def test_default_raises_naming_cell(self, tmp_xlsx: str) -> None:
+        """Strings over the cell limit raise ValueError by default."""
+        df = pd.DataFrame({"log": ["short", self.LONG]})
+        with pytest.raises(ValueError, match=r"row 2, col 0 exceeds Excel's 32,767-character"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+
+    def test_limit_itself_is_written(self, tmp_xlsx: str) -> None:
+        """A string of exactly 32,767 characters needs no mode."""
+        df = pd.DataFrame({"log": ["é" * self.LIMIT]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A2"].value == "é" * self.LIMIT
+        wb.close()
+
+    def test_truncate_mode(self, tmp_xlsx: str) -> None:
+        """long_string_mode='truncate' keeps the first 32,767 characters."""
+        df = pd.DataFrame({"log": [self.LONG, "short"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, long_string_mode="truncate")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == "a" * self.LIMIT
+        assert ws["A2"].comment is None
+        assert ws["A3"].value == "short"
+        wb.close()
+
+    def test_split_mode_puts_rest_in_note(self, tmp_xlsx: str) -> None:
+        """long_string_mode='split' moves the overflow into a note on the cell."""
+        df = pd.DataFrame({"log": [self.LONG]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, long_string_mode="split")
+        wb = load_workbook(tmp_xlsx)
+        cell = active_ws(wb)["A2"]
+        assert cell.value == "a" * self.LIMIT
+        assert cell.comment is not None
+        assert cell.comment.text == "b" * 100
+        wb.close()
+
+    def test_split_mode_too_long_raises(self, tmp_xlsx: str) -> None:
+        """A string that overflows the cell and its note still raises."""
+        df = pd.DataFrame({"log": ["x" * 70000]})
+        with pytest.raises(ValueError, match="too long to split"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, long_string_mode="split")
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet long_string_mode overrides the global one."""
+        df = pd.DataFrame({"log": [self.LONG]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"long_string_mode": "split"})],
+            tmp_xlsx,
+            long_string_mode="truncate",
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].comment is None
+        assert wb["Local"]["A2"].comment is not None
+        wb.close()
+
+    def test_csv_modes(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """csv_to_xlsx applies long_string_mode on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text(f"{self.LONG}\n")
+
+        for parallel in (False, True):
+            with pytest.raises(ValueError, match="long_string_mode"):
+                xlsxturbo.csv_to_xlsx(csv_path, tmp_xlsx_factory(), parallel=parallel)
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(
+                csv_path, xlsx_path, parallel=parallel, long_string_mode="truncate"
+            )
+            wb = load_workbook(xlsx_path)
+            assert active_ws(wb)["A1"].value == "a" * self.LIMIT
+            wb.close()
+
+    def test_invalid_mode_raises(self, tmp_xlsx: str) -> None:
+        """An unknown long_string_mode raises ValueError listing the valid values."""
+        df = pd.DataFrame({"log": ["x"]})
+        with pytest.raises(ValueError, match="Invalid long_string_mode 'wrap'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, long_string_mode="wrap")  # type: ignore[arg-type]
+
+
+class TestSanitizeStrings:
+    """Tests for the sanitize_strings parameter."""
+
+    def test_strips_bom_and_control_characters(self, tmp_xlsx: str) -> None:
+        """A leading BOM is dropped and illegal control characters become spaces."""
+        df = pd.DataFrame({"s": ["\ufeffid", "a\x00b\x0bc", "tab\tand\nnewline"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, sanitize_strings=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == "id"
+        assert ws["A3"].value == "a b c"
+        assert ws["A4"].value == "tab\tand\nnewline"
+        wb.close()
+
+    def test_off_by_default(self, tmp_xlsx: str) -> None:
+        """Without the option a leading BOM is written as-is."""
+        df = pd.DataFrame({"s": ["\ufeffid"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A2"].value == "\ufeffid"
+        wb.close()
+
+    def test_categorical_and_text_columns(self, tmp_xlsx: str) -> None:
+        """Categorical labels and text_columns values are cleaned too."""
+        df = pd.DataFrame(
+            {
+                "cat": pd.Categorical(["x\x01y"]),
+                "code": ["\ufeff007"],
+            }
+        )
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, sanitize_strings=True, text_columns=["code"])
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == "x y"
+        assert ws["B2"].value == "007"
+        wb.close()
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet sanitize_strings overrides the global one."""
+        df = pd.DataFrame({"s": ["\ufeffid"]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"sanitize_strings": False})],
+            tmp_xlsx,
+            sanitize_strings=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].value == "id"
+        assert wb["Local"]["A2"].value == "\ufeffid"
+        wb.close()
+
+    def test_csv_fields(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """CSV fields are cleaned before type detection on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a\x01b,\ufeff42\n", encoding="utf-8")
+
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, parallel=parallel, sanitize_strings=True)
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert ws["A1"].value == "a b"
+            assert ws["B1"].value == 42
+            wb.close()
+
+    def test_dataframe_header_labels(self, tmp_xlsx: str) -> None:
+        """Column names are cleaned like the values, in flat and transposed headers."""
+        df = pd.DataFrame({"\ufeffid": [1], "na\x01me": ["x"]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Flat"), (df, "Transposed", {"transpose": True})],
+            tmp_xlsx,
+            sanitize_strings=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Flat"]["A1"].value == "id"
+        assert wb["Flat"]["B1"].value == "na me"
+        assert wb["Transposed"]["A1"].value == "id"
+        assert wb["Transposed"]["A2"].value == "na me"
+        wb.close()
+
+    def test_csv_header_row(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """The CSV header row is cleaned on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("co\x02de,name\n1,a\n", encoding="utf-8")
+
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(
+                csv_path, xlsx_path, parallel=parallel, has_header=True, sanitize_strings=True
+            )
+            wb = load_workbook(xlsx_path)
+            assert active_ws(wb)["A1"].value == "co de"
+            wb.close()
+
+
+class TestIntegerLikeFloats:
+    """Tests for the integer_like_floats parameter."""
+
+    def test_whole_floats_get_integer_format(self, tmp_xlsx: str) -> None:
+        """Whole values of a float column get the '0' format; fractional ones don't."""
+        df = pd.DataFrame({"qty": [3.0, 2.5, float("nan"), 12.0]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, integer_like_floats=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == 3
+        assert ws["A2"].number_format == "0"
+        assert ws["A3"].value == 2.5
+        assert ws["A3"].number_format == "General"
+        assert ws["A4"].value in (None, "")
+        assert ws["A5"].number_format == "0"
+        wb.close()
+
+    def test_default_leaves_floats_unformatted(self, tmp_xlsx: str) -> None:
+        """Without the option, whole floats keep the General format."""
+        df = pd.DataFrame({"qty": [3.0]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A2"].number_format == "General"
+        wb.close()
+
+    def test_only_float_dtype_columns(self, tmp_xlsx: str) -> None:
+        """Whole floats in an object column are left alone."""
+        df = pd.DataFrame({"f": [1.0], "o": pd.Series([2.0], dtype=object)})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, integer_like_floats=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].number_format == "0"
+        assert ws["B2"].number_format == "General"
+        wb.close()
+
+    def test_column_format_wins(self, tmp_xlsx: str) -> None:
+        """A column_formats entry keeps its own number format."""
+        df = pd.DataFrame({"price": [4.0]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, integer_like_floats=True, column_formats={"price": {"num_format": "0.00"}}
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A2"].number_format == "0.00"
+        wb.close()
+
+    def test_polars_float_column(self, tmp_xlsx: str) -> None:
+        """Polars Float64 columns are recognized; nulls stay empty."""
+        df = pl.DataFrame({"qty": [7.0, None, 1.5]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, integer_like_floats=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == 7
+        assert ws["A2"].number_format == "0"
+        assert ws["A3"].value in (None, "")
+        assert ws["A4"].number_format == "General"
+        wb.close()
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet integer_like_floats overrides the global one."""
+        df = pd.DataFrame({"qty": [3.0]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"integer_like_floats": False})],
+            tmp_xlsx,
+            integer_like_floats=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].number_format == "0"
+        assert wb["Local"]["A2"].number_format == "General"
+        wb.close()
+
+
+class TestInferStringTypes:
+    """Tests for the infer_string_types parameter."""
+
+    def test_strings_become_typed_cells(self, tmp_xlsx: str) -> None:
+        """Numeric, boolean, and date strings are written as those types; other text stays."""
+        df = pd.DataFrame(
+            {
+                "n": ["42", "2.5", "007"],
+                "when": ["2024-01-15", "2024-01-15 10:30:00", "14:30:00"],
+                "other": ["true", "hello", "1899-12-31"],
+            }
+        )
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, infer_string_types=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == 42
+        assert ws["A3"].value == 2.5
+        assert ws["A4"].value == 7
+        assert ws["B2"].number_format == "yyyy-mm-dd"
+        assert ws["B3"].number_format == "yyyy-mm-dd hh:mm:ss"
+        assert ws["B4"].number_format == "hh:mm:ss"
+        assert ws["C2"].value is True
+        assert ws["C3"].value == "hello"
+        assert ws["C4"].value == "1899-12-31"
+        wb.close()
+
+    def test_default_keeps_strings(self, tmp_xlsx: str) -> None:
+        """Without the option, numeric strings stay text."""
+        df = pd.DataFrame({"n": ["42"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A2"].value == "42"
+        wb.close()
+
+    def test_column_format_applies(self, tmp_xlsx: str) -> None:
+        """A column_formats entry styles inferred numbers and replaces the date format."""
+        df = pl.DataFrame({"amount": ["1234.5"], "day": ["2024-01-15"]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            infer_string_types=True,
+            column_formats={"amount": {"num_format": "#,##0.00"}, "day": {"num_format": "dd/mm/yyyy"}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == 1234.5
+        assert ws["A2"].number_format == "#,##0.00"
+        assert ws["B2"].number_format == "dd/mm/yyyy"
+        wb.close()
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet infer_string_types overrides the global one."""
+        df = pd.DataFrame({"n": ["42"]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"infer_string_types": False})],
+            tmp_xlsx,
+            infer_string_types=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].value == 42
+        assert wb["Local"]["A2"].value == "42"
+        wb.close()
+
+
+class TestBoolMode:
+    """Tests for the bool_mode parameter."""
+
+    @pytest.mark.parametrize(
+        ("bool_mode", "expected"),
+        [
+            pytest.param("boolean", [True, False], id="boolean"),
+            pytest.param("yes_no", ["Yes", "No"], id="yes-no"),
+            pytest.param("one_zero", [1, 0], id="one-zero"),
+            pytest.param(("Y", "N"), ["Y", "N"], id="custom"),
+        ],
+    )
+    def test_modes(self, bool_mode: object, expected: list[object], tmp_xlsx: str) -> None:
+        """Python and numpy booleans are written as each mode asks; missing values stay empty."""
+        df = pd.DataFrame({"py": [True, False, None], "np": np.array([True, False, True])})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, bool_mode=bool_mode)  # type: ignore[arg-type]
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [ws["A2"].value, ws["A3"].value] == expected
+        assert [ws["B2"].value, ws["B3"].value] == expected
+        assert ws["A4"].value is None
+        wb.close()
+
+    def test_polars_and_column_format(self, tmp_xlsx: str) -> None:
+        """A polars Boolean column takes the mode and keeps its column format."""
+        df = pl.DataFrame({"flag": [True, False]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, bool_mode="yes_no", column_formats={"flag": {"bold": True}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == "Yes"
+        assert ws["A2"].font.bold
+        wb.close()
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet bool_mode overrides the global one."""
+        df = pd.DataFrame({"flag": [True]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"bool_mode": "boolean"})],
+            tmp_xlsx,
+            bool_mode="one_zero",
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].value == 1
+        assert wb["Local"]["A2"].value is True
+        wb.close()
+
+    @pytest.mark.parametrize(
+        ("bool_mode", "error", "match"),
+        [
+            pytest.param("custom", ValueError, r"\(true_text, false_text\) pair", id="custom-without-labels"),
+            pytest.param("true_false", ValueError, "invalid mode 'true_false'", id="unknown-mode"),
+            pytest.param(("Y",), TypeError, "must be 'boolean'", id="one-label"),
+            pytest.param(("Y", 1), TypeError, "must be 'boolean'", id="non-string-label"),
+        ],
+    )
+    def test_invalid_values_raise(
+        self, bool_mode: object, error: type[Exception], match: str, tmp_xlsx: str
+    ) -> None:
+        """An unknown mode or a malformed label pair raises before anything is written."""
+        df = pd.DataFrame({"flag": [True]})
+        with pytest.raises(error, match=match):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, bool_mode=bool_mode)  # type: ignore[arg-type]
+
+
+class TestTextColumns:
+    """Tests for the text_columns parameter."""
+
+    def test_names_and_indices(self, tmp_xlsx: str) -> None:
+        """Selected columns are written as "@" text, skipping infer_string_types; nulls stay empty."""
+        df = pd.DataFrame({"zip": ["01234", "98765", None], "sku": [7, 8, 9], "qty": ["05", "10", "15"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, text_columns=["zip", 1], infer_string_types=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [ws["A2"].value, ws["B2"].value, ws["C2"].value] == ["01234", "7", 5]
+        assert ws["A2"].number_format == "@"
+        assert ws["B3"].number_format == "@"
+        assert ws["A4"].value is None
+        wb.close()
+
+    def test_polars_date_column(self, tmp_xlsx: str) -> None:
+        """A polars Date column in text_columns is written as its ISO text."""
+        from datetime import date
+
+        df = pl.DataFrame({"day": [date(2024, 1, 15)], "code": ["007"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, text_columns=["day", "code"])
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [ws["A2"].value, ws["B2"].value] == ["2024-01-15", "007"]
+        wb.close()
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet text_columns overrides the global one."""
+        df = pd.DataFrame({"a": [1], "b": [2]})
+        xlsxturbo.dfs_to_xlsx([(df, "Global"), (df, "Local", {"text_columns": ["b"]})], tmp_xlsx, text_columns=["a"])
+        wb = load_workbook(tmp_xlsx)
+        assert [wb["Global"]["A2"].value, wb["Global"]["B2"].value] == ["1", 2]
+        assert [wb["Local"]["A2"].value, wb["Local"]["B2"].value] == [1, "2"]
+        wb.close()
+
+    @pytest.mark.parametrize(
+        ("text_columns", "match"),
+        [
+            pytest.param(["missing"], "'missing' is not a written column", id="unknown-name"),
+            pytest.param([5], "5 is out of range", id="out-of-range"),
+        ],
+    )
+    def test_unknown_columns_raise(self, text_columns: list[object], match: str, tmp_xlsx: str) -> None:
+        """An unknown name or out-of-range index raises ValueError."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(ValueError, match=match):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, text_columns=text_columns)  # type: ignore[arg-type]
+
+
+class TestProgressCallback:
+    """Tests for df_to_xlsx's progress_callback and progress_every."""
+
+    def test_called_every_n_rows(self, tmp_xlsx: str) -> None:
+        """The callback gets the running row count at each multiple of progress_every."""
+        seen: list[int] = []
+        df = pd.DataFrame({"a": range(10)})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, progress_callback=seen.append, progress_every=3)
+        assert seen == [3, 6, 9]
+
+    def test_polars(self, tmp_xlsx: str) -> None:
+        """The polars row loop reports progress too."""
+        seen: list[int] = []
+        df = pl.DataFrame({"a": range(4), "d": [None] * 4}, schema={"a": pl.Int64, "d": pl.Date})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, progress_callback=seen.append, progress_every=2)
+        assert seen == [2, 4]
+
+    def test_callback_exception_warns_and_continues(self, tmp_xlsx: str) -> None:
+        """An exception from the callback becomes a RuntimeWarning; the file is still written."""
+
+        def fail(_rows: int) -> None:
+            raise KeyError("boom")
+
+        df = pd.DataFrame({"a": range(4)})
+        with pytest.warns(RuntimeWarning, match="progress_callback raised at row 2"):
+            rows, _ = xlsxturbo.df_to_xlsx(df, tmp_xlsx, progress_callback=fail, progress_every=2)
+        assert rows == 5
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A5"].value == 3
+        wb.close()
+
+    @pytest.mark.parametrize("engine", ["pandas", "polars"])
+    def test_false_cancels_write(self, engine: str, tmp_xlsx: str) -> None:
+        """Returning False stops the row loop, saves nothing, and raises RuntimeError."""
+        data = {"a": list(range(10))}
+        df = pd.DataFrame(data) if engine == "pandas" else pl.DataFrame(data)
+        seen: list[int] = []
+
+        def cancel_after_four(rows: int) -> bool:
+            seen.append(rows)
+            return rows < 4
+
+        with pytest.raises(RuntimeError, match="write cancelled"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, progress_callback=cancel_after_four, progress_every=2)
+        assert seen == [2, 4]
+        # The fixture's empty placeholder file is left as it was: no workbook was saved.
+        assert Path(tmp_xlsx).stat().st_size == 0
+
+    def test_cancel_writes_nothing_to_file_like(self) -> None:
+        """A cancelled write never calls the output object's write()."""
+        buffer = io.BytesIO()
+        with pytest.raises(RuntimeError, match="write cancelled"):
+            xlsxturbo.df_to_xlsx(
+                pd.DataFrame({"a": [1, 2]}), buffer, progress_callback=lambda _rows: False, progress_every=1
+            )
+        assert buffer.getvalue() == b""
+
+    def test_only_false_cancels(self, tmp_xlsx: str) -> None:
+        """Falsy non-bool returns (None, 0) don't cancel."""
+        df = pd.DataFrame({"a": range(4)})
+        rows, _ = xlsxturbo.df_to_xlsx(df, tmp_xlsx, progress_callback=lambda _rows: 0, progress_every=1)
+        assert rows == 5
+
+    def test_invalid_arguments_raise(self, tmp_xlsx: str) -> None:
+        """A non-callable callback raises TypeError and progress_every=0 raises ValueError."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(TypeError, match="progress_callback must be callable, got int"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, progress_callback=3)  # type: ignore[arg-type]
+        with pytest.raises(ValueError, match="progress_every must be at least 1"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, progress_callback=print, progress_every=0)
+
+
+class TestColumnSelection:
+    """Tests for the columns parameter."""
+
+    def test_selects_and_reorders(self, tmp_xlsx: str) -> None:
+        """Names and positions pick the written columns, in the listed order."""
+        df = pd.DataFrame({"id": [1, 2], "name": ["a", "b"], "total": [3.5, 4.5]})
+        rows, cols = xlsxturbo.df_to_xlsx(df, tmp_xlsx, columns=["total", 0])
+        assert (rows, cols) == (3, 2)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [c.value for c in ws[1]] == ["total", "id"]
+        assert [c.value for c in ws[2]] == [3.5, 1]
+        wb.close()
+
+    def test_polars(self, tmp_xlsx: str) -> None:
+        """Polars DataFrames are projected the same way."""
+        df = pl.DataFrame({"id": [1], "name": ["a"], "total": [3.5]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, columns=[2, "name"])
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [c.value for c in ws[1]] == ["total", "name"]
+        assert [c.value for c in ws[2]] == [3.5, "a"]
+        wb.close()
+
+    def test_other_options_see_selected_columns(self, tmp_xlsx: str) -> None:
+        """column_formats index keys and formula_columns refer to the written columns."""
+        df = pd.DataFrame({"id": [1], "price": [2.0], "qty": [3]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            columns=["qty", "price"],
+            column_formats={0: {"num_format": "0.00"}},
+            formula_columns={"Total": "=A{row}*B{row}"},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].number_format == "0.00"
+        assert ws["C1"].value == "Total"
+        assert ws["C2"].value == "=A2*B2"
+        wb.close()
+
+    def test_empty_list_writes_all_columns(self, tmp_xlsx: str) -> None:
+        """An empty selection disables the option."""
+        df = pd.DataFrame({"a": [1], "b": [2]})
+        _, cols = xlsxturbo.df_to_xlsx(df, tmp_xlsx, columns=[])
+        assert cols == 2
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet columns selection overrides the global one."""
+        df = pd.DataFrame({"a": [1], "b": [2]})
+        xlsxturbo.dfs_to_xlsx([(df, "Global"), (df, "Local", {"columns": ["a"]})], tmp_xlsx, columns=["b"])
+        wb = load_workbook(tmp_xlsx)
+        assert [c.value for c in wb["Global"][1]] == ["b"]
+        assert [c.value for c in wb["Local"][1]] == ["a"]
+        wb.close()
+
+    @pytest.mark.parametrize(
+        ("columns", "error", "match"),
+        [
+            (["missing"], ValueError, "'missing' is not a DataFrame column. Available columns: a, b"),
+            ([2], ValueError, "2 is out of range \\(DataFrame has 2 columns\\)"),
+            (["a", 0], ValueError, "column 'a' is selected more than once"),
+            ([-1], ValueError, "must be a non-negative column index"),
+            ([1.5], TypeError, "expected a column name or index, got float"),
+            ("a", TypeError, "must be a list of column names or indices, got str"),
+        ],
+    )
+    def test_invalid_selection_raises(
+        self, columns: object, error: type[Exception], match: str, tmp_xlsx: str
+    ) -> None:
+        """Bad selections raise before anything is written."""
+        df = pd.DataFrame({"a": [1], "b": [2]})
+        with pytest.raises(error, match=match):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, columns=columns)  # type: ignore[arg-type]
+
+
+class TestRowWindow:
+    """Tests for the skip_rows and max_rows parameters."""
+
+    @pytest.mark.parametrize("engine", ["pandas", "polars"])
+    def test_window_bounds_rows_and_ranges(self, engine: str, tmp_xlsx: str) -> None:
+        """Only the window is written, and the table and returned rows follow it."""
+        data = {"a": list(range(10)), "b": [str(i) for i in range(10)]}
+        df = pd.DataFrame(data) if engine == "pandas" else pl.DataFrame(data)
+        rows, cols = xlsxturbo.df_to_xlsx(df, tmp_xlsx, skip_rows=2, max_rows=3, table_style="Medium2")
+        assert (rows, cols) == (4, 2)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [ws[f"A{r}"].value for r in range(2, 6)] == [2, 3, 4, None]
+        assert next(iter(ws.tables.values())).ref == "A1:B4"
+        wb.close()
+
+    def test_validation_range_follows_window(self, tmp_xlsx: str) -> None:
+        """Validation ranges cover only the written rows."""
+        df = pd.DataFrame({"a": range(10)})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, max_rows=4, validations={"a": {"type": "whole_number", "min": 0, "max": 9}}
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert str(active_ws(wb).data_validations.dataValidation[0].sqref) == "A2:A5"
+        wb.close()
+
+    def test_window_past_end_writes_header_only(self, tmp_xlsx: str) -> None:
+        """Skipping every row leaves just the header."""
+        df = pd.DataFrame({"a": [1, 2]})
+        rows, _ = xlsxturbo.df_to_xlsx(df, tmp_xlsx, skip_rows=5)
+        assert rows == 1
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """Per-sheet values override the global ones; empty windows don't claim table names."""
+        df = pd.DataFrame({"a": range(5)})
+        result = xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"max_rows": 0}), (df, "Skip", {"skip_rows": 5})],
+            tmp_xlsx,
+            max_rows=2,
+            table_style="Medium2",
+            table_name="Data",
+        )
+        assert result == [(3, 1), (1, 1), (1, 1)]
+
+    def test_negative_per_sheet_value_raises(self, tmp_xlsx: str) -> None:
+        """A negative per-sheet value raises TypeError naming the option."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(TypeError, match="sheet option 'max_rows' must be a non-negative int"):
+            xlsxturbo.dfs_to_xlsx([(df, "S", {"max_rows": -1})], tmp_xlsx)
+
+
+class TestTranspose:
+    """Tests for the transpose parameter."""
+
+    @pytest.mark.parametrize("engine", ["pandas", "polars"])
+    def test_rows_become_columns(self, engine: str, tmp_xlsx: str) -> None:
+        """Column names run down column A and each row fills the next column, keeping types."""
+        data = {"name": ["Alice", "Bob"], "score": [10, 20]}
+        df = pd.DataFrame(data) if engine == "pandas" else pl.DataFrame(data)
+        rows, cols = xlsxturbo.df_to_xlsx(df, tmp_xlsx, transpose=True)
+        assert (rows, cols) == (2, 3)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [[c.value for c in row] for row in ws.iter_rows()] == [["name", "Alice", "Bob"], ["score", 10, 20]]
+        wb.close()
+
+    def test_without_header_at_offset(self, tmp_xlsx: str) -> None:
+        """Without a header the first row lands in start_col; the return value is absolute."""
+        df = pd.DataFrame({"a": [1, 2], "b": [3, 4]})
+        rows, cols = xlsxturbo.df_to_xlsx(df, tmp_xlsx, transpose=True, header=False, start_row=2, start_col=1)
+        assert (rows, cols) == (4, 3)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [ws["B3"].value, ws["C3"].value, ws["B4"].value, ws["C4"].value] == [1, 2, 3, 4]
+        wb.close()
+
+    def test_column_formats_follow_original_column(self, tmp_xlsx: str) -> None:
+        """A column's format applies along its sheet row."""
+        df = pd.DataFrame({"ratio": [0.5, 0.25], "n": [1, 2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, transpose=True, column_formats={"ratio": {"num_format": "0.00%"}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["B1"].number_format == "0.00%"
+        assert ws["C1"].number_format == "0.00%"
+        assert ws["B2"].number_format == "General"
+        wb.close()
+
+    def test_freeze_panes_freezes_name_column(self, tmp_xlsx: str) -> None:
+        """freeze_panes=True freezes the column of names instead of the top row."""
+        df = pd.DataFrame({"a": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, transpose=True, freeze_panes=True)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).freeze_panes == "B1"
+        wb.close()
+
+    def test_column_keyed_features_warn_and_skip(self, tmp_xlsx: str) -> None:
+        """Tables and conditional formats are skipped with a RuntimeWarning naming them."""
+        df = pd.DataFrame({"a": [1, 2]})
+        with pytest.warns(RuntimeWarning, match="transpose=True skips .*table_style, conditional_formats"):
+            xlsxturbo.df_to_xlsx(
+                df,
+                tmp_xlsx,
+                transpose=True,
+                table_style="Medium2",
+                conditional_formats={"a": {"type": "2_color_scale"}},
+            )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert not ws.tables
+        assert len(ws.conditional_formatting) == 0
+        wb.close()
+
+    def test_constant_memory_raises(self, tmp_xlsx: str) -> None:
+        """Transposed writes fill columns out of row order, so constant_memory is rejected."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(ValueError, match="transpose cannot be combined with constant_memory"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, transpose=True, constant_memory=True)
+
+    def test_dfs_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """Per-sheet transpose applies to its own sheet only."""
+        df = pd.DataFrame({"a": [1, 2, 3]})
+        result = xlsxturbo.dfs_to_xlsx([(df, "Wide", {"transpose": True}), (df, "Long")], tmp_xlsx)
+        assert result == [(1, 4), (4, 1)]
+
+
+class TestMultiIndexHeader:
+    """Tests for multi-row headers from a pandas column MultiIndex."""
+
+    @staticmethod
+    def _df() -> pd.DataFrame:
+        columns = pd.MultiIndex.from_tuples([("Sales", "Q1"), ("Sales", "Q2"), ("Cost", "Q1"), ("Total", "")])
+        return pd.DataFrame([[1, 2, 3, 4], [5, 6, 7, 8]], columns=columns)
+
+    def test_one_header_row_per_level(self, tmp_xlsx: str) -> None:
+        """Each level gets a row and repeated upper labels are merged; data starts below."""
+        rows, cols = xlsxturbo.df_to_xlsx(self._df(), tmp_xlsx)
+        assert (rows, cols) == (4, 4)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [ws.cell(1, c).value for c in (1, 3, 4)] == ["Sales", "Cost", "Total"]
+        assert [ws.cell(2, c).value for c in range(1, 4)] == ["Q1", "Q2", "Q1"]
+        assert [str(r) for r in ws.merged_cells.ranges] == ["A1:B1"]
+        assert [ws.cell(3, c).value for c in range(1, 5)] == [1, 2, 3, 4]
+        wb.close()
+
+    def test_merge_respects_parent_labels(self, tmp_xlsx: str) -> None:
+        """A lower label repeated under different parents is not merged."""
+        columns = pd.MultiIndex.from_tuples([("A", "x", 1), ("A", "x", 2), ("B", "x", 3)])
+        df = pd.DataFrame([[1, 2, 3]], columns=columns)
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert sorted(str(r) for r in ws.merged_cells.ranges) == ["A1:B1", "A2:B2"]
+        assert ws["C2"].value == "x"
+        wb.close()
+
+    def test_features_anchor_on_last_header_row(self, tmp_xlsx: str) -> None:
+        """Freeze panes, autofilter, and offsets account for every header row."""
+        xlsxturbo.df_to_xlsx(self._df(), tmp_xlsx, start_row=1, freeze_panes=True, autofilter=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.freeze_panes == "A4"
+        assert ws.auto_filter.ref == "A3:D5"
+        wb.close()
+
+    def test_header_false_writes_data_only(self, tmp_xlsx: str) -> None:
+        """Without a header the MultiIndex writes no header rows at all."""
+        rows, _ = xlsxturbo.df_to_xlsx(self._df(), tmp_xlsx, header=False)
+        assert rows == 2
+
+
+class TestIncludeIndex:
+    """Tests for the include_index parameter."""
+
+    def test_named_index_is_first_column(self, tmp_xlsx: str) -> None:
+        """The index is written leftmost under its name, and the columns shift right."""
+        df = pd.DataFrame({"a": [1, 2]}, index=pd.Index([10, 20], name="id"))
+        rows, cols = xlsxturbo.df_to_xlsx(df, tmp_xlsx, include_index=True)
+        assert (rows, cols) == (3, 2)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [[c.value for c in row] for row in ws.iter_rows()] == [["id", "a"], [10, 1], [20, 2]]
+        wb.close()
+
+    def test_multiindex_expands_to_columns(self, tmp_xlsx: str) -> None:
+        """Each index level becomes its own column."""
+        index = pd.MultiIndex.from_tuples([("x", 1), ("y", 2)], names=["group", "n"])
+        df = pd.DataFrame({"v": [5, 6]}, index=index)
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, include_index=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [c.value for c in ws[1]] == ["group", "n", "v"]
+        assert [c.value for c in ws[3]] == ["y", 2, 6]
+        wb.close()
+
+    def test_feature_ranges_shift_right(self, tmp_xlsx: str) -> None:
+        """Name-keyed features land on the shifted column and can target the index."""
+        df = pd.DataFrame({"a": [1, 2]}, index=pd.Index([10, 20], name="id"))
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            include_index=True,
+            column_formats={"id": {"bold": True}},
+            validations={"a": {"type": "whole_number", "min": 0, "max": 9}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].font.bold
+        assert str(ws.data_validations.dataValidation[0].sqref) == "B2:B3"
+        wb.close()
+
+    def test_index_follows_row_window(self, tmp_xlsx: str) -> None:
+        """skip_rows keeps each written row's own index label."""
+        df = pd.DataFrame({"a": [1, 2, 3]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, include_index=True, skip_rows=2)
+        wb = load_workbook(tmp_xlsx)
+        assert [c.value for c in active_ws(wb)[2]] == [2, 3]
+        wb.close()
+
+    def test_ignored_for_polars(self, tmp_xlsx: str) -> None:
+        """polars DataFrames have no index, so nothing extra is written."""
+        rows, cols = xlsxturbo.df_to_xlsx(pl.DataFrame({"a": [1]}), tmp_xlsx, include_index=True)
+        assert (rows, cols) == (2, 1)
+
+
+class TestStartOffset:
+    """Tests for the start_row/start_col parameters."""
+
+    def test_data_and_return_value_are_offset(self, tmp_xlsx: str) -> None:
+        """Header and data move to the offset; the return value is absolute."""
+        df = pd.DataFrame({"Name": ["Alice", "Bob"], "Score": [10, 20]})
+        rows, cols = xlsxturbo.df_to_xlsx(df, tmp_xlsx, start_row=4, start_col=1)
+        assert (rows, cols) == (7, 3)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A1"].value is None
+        assert ws["B5"].value == "Name"
+        assert ws["C5"].value == "Score"
+        assert ws["B6"].value == "Alice"
+        assert ws["C7"].value == 20
+        wb.close()
+
+    def test_table_freeze_panes_and_formula_columns(self, tmp_xlsx: str) -> None:
+        """The table range, frozen header row, and formula columns follow the offset."""
+        df = pd.DataFrame({"Name": ["Alice", "Bob"], "Score": [10, 20]})
+        rows, cols = xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            start_row=2,
+            start_col=1,
+            table_style="Medium2",
+            freeze_panes=True,
+            formula_columns={"Double": "=C{row}*2"},
+        )
+        assert (rows, cols) == (5, 4)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [t.ref for t in ws.tables.values()] == ["B3:C5"]
+        assert ws.freeze_panes == "A4"
+        assert ws["D3"].value == "Double"
+        assert ws["D4"].value == "=C4*2"
+        wb.close()
+
+    def test_conditional_formats_and_validations_are_offset(self, tmp_xlsx: str) -> None:
+        """Column-name-keyed rules target the offset data range."""
+        df = pd.DataFrame({"Name": ["Alice", "Bob"], "Score": [10, 20]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            start_row=2,
+            start_col=1,
+            conditional_formats={"Score": {"type": "data_bar"}},
+            validations={"Name": {"type": "text_length", "min": 1, "max": 50}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [str(cf.sqref) for cf in ws.conditional_formatting] == ["C4:C5"]
+        assert [str(dv.sqref) for dv in ws.data_validations.dataValidation] == ["B4:B5"]
+        wb.close()
+
+    def test_without_header(self, tmp_xlsx: str) -> None:
+        """With header=False the first data row lands on start_row."""
+        df = pd.DataFrame({"a": [1, 2]})
+        rows, cols = xlsxturbo.df_to_xlsx(df, tmp_xlsx, header=False, start_row=3)
+        assert (rows, cols) == (5, 1)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A4"].value == 1
+        wb.close()
+
+    def test_dfs_per_sheet_offset(self, tmp_xlsx: str) -> None:
+        """A per-sheet start_row/start_col overrides the global offset."""
+        df = pd.DataFrame({"a": [1]})
+        stats = xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"start_row": 0, "start_col": 3})],
+            tmp_xlsx,
+            start_row=2,
+        )
+        assert stats == [(4, 1), (2, 4)]
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A3"].value == "a"
+        assert wb["Local"]["D1"].value == "a"
+        wb.close()
+
+    def test_offset_beyond_grid_raises(self, tmp_xlsx: str) -> None:
+        """An offset that pushes columns past XFD raises ValueError."""
+        df = pd.DataFrame({"a": [1], "b": [2]})
+        with pytest.raises(ValueError, match="start_col 16383"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, start_col=16383)
+
+
+class TestDfSections:
+    """Tests for df_sections_to_xlsx (several DataFrames stacked on shared sheets)."""
+
+    def test_sections_share_sheet_with_titles(self, tmp_xlsx: str) -> None:
+        """Sections naming one sheet stack at their rows, each below its title."""
+        summary = pd.DataFrame({"region": ["N", "S"], "total": [10, 20]})
+        detail = pd.DataFrame({"item": ["a", "b", "c"], "qty": [1, 2, 3]})
+        stats = xlsxturbo.df_sections_to_xlsx(
+            [(summary, "Report", 0, "Summary"), (detail, "Report", 5, "Detail")],
+            tmp_xlsx,
+            title_format={"bold": True},
+        )
+        assert stats == [(4, 2), (10, 2)]
+        wb = load_workbook(tmp_xlsx)
+        assert wb.sheetnames == ["Report"]
+        ws = wb["Report"]
+        assert ws["A1"].value == "Summary"
+        assert ws["A1"].font.bold
+        assert ws["A2"].value == "region"
+        assert ws["B4"].value == 20
+        assert ws["A6"].value == "Detail"
+        assert ws["A7"].value == "item"
+        assert ws["B10"].value == 3
+        wb.close()
+
+    def test_sections_create_sheets_in_first_appearance_order(self, tmp_xlsx: str) -> None:
+        """Sheets are created as first named; untitled sections start at start_row."""
+        df = pd.DataFrame({"a": [1]})
+        xlsxturbo.df_sections_to_xlsx(
+            [(df, "First", 0), (df, "Second", 3), (df, "First", 2)],
+            tmp_xlsx,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb.sheetnames == ["First", "Second"]
+        assert wb["First"]["A3"].value == "a"
+        assert wb["First"]["A4"].value == 1
+        assert wb["Second"]["A4"].value == "a"
+        wb.close()
+
+    def test_sections_each_get_a_table(self, tmp_xlsx: str) -> None:
+        """table_style makes one table per section, even on a shared sheet."""
+        df = pd.DataFrame({"a": [1, 2]})
+        xlsxturbo.df_sections_to_xlsx(
+            [(df, "S", 0), (df, "S", 4)],
+            tmp_xlsx,
+            table_style="Medium2",
+        )
+        wb = load_workbook(tmp_xlsx)
+        refs = sorted(table.ref for table in wb["S"].tables.values())
+        assert refs == ["A1:A3", "A5:A7"]
+        wb.close()
+
+    def test_overlapping_sections_raise(self, tmp_xlsx: str) -> None:
+        """A section starting inside the previous one on the same sheet raises ValueError."""
+        df = pd.DataFrame({"a": [1, 2]})
+        with pytest.raises(ValueError, match=r"section 1 \(sheet 'S'\): start_row 2 overlaps.*row 3 or later"):
+            xlsxturbo.df_sections_to_xlsx([(df, "S", 0), (df, "S", 2)], tmp_xlsx)
+
+    @pytest.mark.parametrize(
+        ("sections", "error", "match"),
+        [
+            pytest.param([], ValueError, "at least one section", id="empty"),
+            pytest.param([(pd.DataFrame({"a": [1]}), "S")], ValueError, "3 or 4 elements", id="short-tuple"),
+            pytest.param([(pd.DataFrame({"a": [1]}), "S", -1)], TypeError, "start_row", id="negative-row"),
+            pytest.param([(pd.DataFrame({"a": [1]}), "S", 0, 5)], TypeError, "title", id="bad-title"),
+        ],
+    )
+    def test_invalid_sections_raise(
+        self, sections: list[tuple[object, ...]], error: type[Exception], match: str, tmp_xlsx: str
+    ) -> None:
+        """Malformed section lists raise with a message naming the problem."""
+        with pytest.raises(error, match=match):
+            xlsxturbo.df_sections_to_xlsx(sections, tmp_xlsx)  # type: ignore[arg-type]
+
+
+class TestBytesOutput:
+    """Tests for df_to_xlsx_bytes / dfs_to_xlsx_bytes (workbook returned in memory)."""
+
+    def test_df_to_xlsx_bytes_returns_workbook(self) -> None:
+        """The returned bytes are a complete workbook honoring the forwarded options."""
+        df = pd.DataFrame({"name": ["Alice", "Bob"], "age": [30, 25]})
+        data = xlsxturbo.df_to_xlsx_bytes(df, sheet_name="People", table_style="Medium2")
+        assert isinstance(data, bytes)
+        assert data[:2] == b"PK"
+        wb = load_workbook(io.BytesIO(data))
+        assert wb.sheetnames == ["People"]
+        ws = wb["People"]
+        assert ws["A1"].value == "name"
+        assert ws["B3"].value == 25
+        assert [table.ref for table in ws.tables.values()] == ["A1:B3"]
+        wb.close()
+
+    def test_df_to_xlsx_bytes_matches_file_output(self, tmp_xlsx: str) -> None:
+        """Writing to bytes and to a file produce the same cell contents."""
+        df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, start_row=2)
+        data = xlsxturbo.df_to_xlsx_bytes(df, start_row=2)
+        from_file = load_workbook(tmp_xlsx)
+        from_bytes = load_workbook(io.BytesIO(data))
+        file_values = list(active_ws(from_file).iter_rows(values_only=True))
+        bytes_values = list(active_ws(from_bytes).iter_rows(values_only=True))
+        assert file_values == bytes_values
+        from_file.close()
+        from_bytes.close()
+
+    def test_dfs_to_xlsx_bytes_with_per_sheet_options(self) -> None:
+        """dfs_to_xlsx_bytes writes every sheet and honors per-sheet options."""
+        df = pd.DataFrame({"a": [1, 2]})
+        data = xlsxturbo.dfs_to_xlsx_bytes([(df, "One"), (df, "Two", {"header": False})], autofit=True)
+        wb = load_workbook(io.BytesIO(data))
+        assert wb.sheetnames == ["One", "Two"]
+        assert wb["One"]["A1"].value == "a"
+        assert wb["Two"]["A1"].value == 1
+        wb.close()
+
+    def test_bytes_rejects_output_path(self) -> None:
+        """output_path cannot be forwarded; the bytes functions choose the destination."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(TypeError, match="output_path"):
+            xlsxturbo.df_to_xlsx_bytes(df, output_path="out.xlsx")
+
+    def test_bytes_propagates_option_errors(self) -> None:
+        """Invalid options raise the same errors as the file-writing functions."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(ValueError, match="Invalid nan_policy"):
+            xlsxturbo.df_to_xlsx_bytes(df, nan_policy="bogus")
+        with pytest.raises(TypeError, match="unexpected keyword argument"):
+            xlsxturbo.dfs_to_xlsx_bytes([(df, "S")], bogus=True)
+
+
+class TestFileLikeOutput:
+    """Tests for passing a binary file-like object as output_path."""
+
+    def test_df_to_xlsx_writes_to_bytesio(self) -> None:
+        """A BytesIO output receives the complete workbook."""
+        df = pd.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+        buffer = io.BytesIO()
+        assert xlsxturbo.df_to_xlsx(df, buffer, sheet_name="Data") == (3, 2)
+        wb = load_workbook(io.BytesIO(buffer.getvalue()))
+        assert wb.sheetnames == ["Data"]
+        assert wb["Data"]["B3"].value == "y"
+        wb.close()
+
+    def test_dfs_to_xlsx_writes_to_open_file(self, tmp_xlsx: str) -> None:
+        """An open binary file handle is written through its write() method."""
+        df = pd.DataFrame({"a": [1]})
+        with open(tmp_xlsx, "wb") as handle:
+            xlsxturbo.dfs_to_xlsx([(df, "One"), (df, "Two")], handle)
+        wb = load_workbook(tmp_xlsx)
+        assert wb.sheetnames == ["One", "Two"]
+        wb.close()
+
+    def test_df_sections_to_xlsx_writes_to_bytesio(self) -> None:
+        """df_sections_to_xlsx accepts a file-like output as well."""
+        df = pd.DataFrame({"a": [1]})
+        buffer = io.BytesIO()
+        xlsxturbo.df_sections_to_xlsx([(df, "S", 0), (df, "S", 3)], buffer)
+        assert buffer.getvalue()[:2] == b"PK"
+
+    def test_write_is_called_once_with_bytes(self) -> None:
+        """Any object with write() works; it receives the workbook in a single bytes call."""
+
+        class Recorder:
+            """Collects the chunks passed to write()."""
+
+            def __init__(self) -> None:
+                """Start with no recorded chunks."""
+                self.chunks: list[bytes] = []
+
+            def write(self, data: bytes) -> int:
+                """Record one chunk.
+
+                Args:
+                    data: The bytes being written.
+
+                Returns:
+                    The number of bytes recorded.
+                """
+                self.chunks.append(data)
+                return len(data)
+
+        recorder = Recorder()
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"a": [1]}), recorder)
+        assert len(recorder.chunks) == 1
+        assert isinstance(recorder.chunks[0], bytes)
+        assert recorder.chunks[0][:2] == b"PK"
+
+    def test_text_mode_output_raises(self) -> None:
+        """A text stream's write() rejects bytes; the failure surfaces as ValueError."""
+        with pytest.raises(ValueError, match="Failed to write workbook to output object"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"a": [1]}), io.StringIO())  # type: ignore[arg-type]
+
+    def test_object_without_write_raises(self) -> None:
+        """Neither a path nor a writer raises TypeError naming both accepted forms."""
+        with pytest.raises(TypeError, match="file-like object with a write"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"a": [1]}), 123)  # type: ignore[arg-type]
+
+
+class TestCsvConversion:
+    """Tests for csv_to_xlsx function."""
+
+    def test_basic_csv(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """Basic CSV with header and data rows."""
+        import csv
+
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        with Path(csv_path).open("w", newline="") as f:
+            writer = csv.writer(f)
+            writer.writerow(["name", "age", "score"])
+            writer.writerow(["Alice", "30", "95.5"])
+            writer.writerow(["Bob", "25", "88.0"])
+
+        rows, cols = xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
+        assert rows == 3
+        assert cols == 3
+        wb = load_workbook(xlsx_path)
+        ws = active_ws(wb)
+        assert ws["A1"].value == "name"
+        assert ws["B2"].value == 30  # should be detected as integer
+        assert ws["C2"].value == 95.5  # should be detected as float
+        wb.close()
+
+    def test_csv_type_detection(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """CSV type detection: int, float, bool, date, string."""
+        import csv
+
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        with Path(csv_path).open("w", newline="") as f:
+            writer = csv.writer(f)
+            writer.writerow(["int", "float", "bool", "date", "text"])
+            writer.writerow(["42", "3.14", "true", "2024-01-15", "hello"])
+
+        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
+        wb = load_workbook(xlsx_path)
+        ws = active_ws(wb)
+        assert ws["A2"].value == 42
+        assert abs(ws["B2"].value - 3.14) < 0.001
+        assert ws["C2"].value is True
+        # Date should be a datetime object in openpyxl
+        from datetime import datetime
+
+        assert isinstance(ws["D2"].value, datetime)
+        assert ws["E2"].value == "hello"
+        wb.close()
+
+    def test_csv_datetime_fractional_seconds(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """CSV datetime values preserve fractional seconds in Excel serials."""
+        import csv
+        from datetime import datetime
+
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        with Path(csv_path).open("w", newline="") as f:
+            writer = csv.writer(f)
+            writer.writerow(["timestamp"])
+            writer.writerow(["2024-01-01T12:34:56.789"])
+
+        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
+        wb = load_workbook(xlsx_path)
+        ws = active_ws(wb)
+        value = ws["A2"].value
+        assert isinstance(value, datetime)
+        assert value == datetime(2024, 1, 1, 12, 34, 56, 789000)
+        wb.close()
+
+    def test_csv_special_values(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """CSV with NaN, Inf, empty cells."""
+        import csv
+
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        with Path(csv_path).open("w", newline="") as f:
+            writer = csv.writer(f)
+            writer.writerow(["a", "b", "c"])
+            writer.writerow(["NaN", "Inf", ""])
+            writer.writerow(["nan", "-Inf", "   "])
+
+        rows, _cols = xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
+        assert rows == 3
+        wb = load_workbook(xlsx_path)
+        ws = active_ws(wb)
+        # NaN/Inf/empty all become empty cells under the default nan_policy
+        # (an empty string is written, which openpyxl reads back as None or "").
+        for ref in ("A2", "B2", "C2", "A3", "B3", "C3"):
+            assert ws[ref].value in (None, ""), f"{ref} should be empty"
+        wb.close()
+
+    def test_csv_string_cells_preserve_surrounding_whitespace(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """String cells keep leading/trailing whitespace; type detection still trims to classify.
+
+        Quoted fields are used so the padding survives however csv.writer
+        would otherwise decide to quote plain fields. A whitespace-padded
+        string stays padded; a whitespace-padded number is still detected and
+        written as a number (detection trims a private copy, but the original
+        untrimmed value is what a genuine string falls back to).
+        """
+        import csv
+
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        with Path(csv_path).open("w", newline="") as f:
+            writer = csv.writer(f, quoting=csv.QUOTE_ALL)
+            writer.writerow(["text", "number"])
+            writer.writerow([" padded ", " 123 "])
+
+        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
+        wb = load_workbook(xlsx_path)
+        ws = active_ws(wb)
+        assert ws["A2"].value == " padded "
+        assert ws["B2"].value == 123
+        wb.close()
+
+    def test_csv_int_min_writes_as_string_without_overflow(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """i64::MIN via the CSV path (write_cell) is written as text, no overflow.
+
+        Complements test_i64_min_writes_as_string_without_overflow, which covers
+        the distinct DataFrame write_int path.
+        """
+        import csv as _csv
+
+        int_min = -9223372036854775808  # i64::MIN
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        with Path(csv_path).open("w", newline="") as f:
+            w = _csv.writer(f)
+            w.writerow(["n"])
+            w.writerow([str(int_min)])
+        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
+        wb = load_workbook(xlsx_path)
+        ws = active_ws(wb)
+        assert ws["A2"].value == str(int_min)
+        wb.close()
+
+    def test_csv_parallel(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """CSV parallel mode produces same output."""
+        import csv
+
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_seq = tmp_xlsx_factory()
+        xlsx_par = tmp_xlsx_factory()
+        with Path(csv_path).open("w", newline="") as f:
+            writer = csv.writer(f)
+            writer.writerow(["num", "text"])
+            for i in range(100):
+                writer.writerow([str(i), f"row_{i}"])
+
+        rows_s, cols_s = xlsxturbo.csv_to_xlsx(csv_path, xlsx_seq, parallel=False)
+        rows_p, cols_p = xlsxturbo.csv_to_xlsx(csv_path, xlsx_par, parallel=True)
+        assert rows_s == rows_p
+        assert cols_s == cols_p
+        wb_s = load_workbook(xlsx_seq)
+        wb_p = load_workbook(xlsx_par)
+        ws_s = active_ws(wb_s)
+        ws_p = active_ws(wb_p)
+        # Spot check some cells match
+        for row in [1, 2, 50, 101]:
+            assert ws_s[f"A{row}"].value == ws_p[f"A{row}"].value
+            assert ws_s[f"B{row}"].value == ws_p[f"B{row}"].value
+        wb_s.close()
+        wb_p.close()
+
+    def test_csv_with_sheet_name(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """CSV conversion with custom sheet name."""
         import csv
 
         csv_path = tmp_xlsx_factory(".csv")
@@ -527,6 +2170,303 @@ class TestCsvConversion:
         assert "MySheet" in wb.sheetnames
         wb.close()
 
+    def test_csv_smart_numeric(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """smart_numeric writes currency/percent/grouped strings as formatted numbers on both code paths."""
+        import csv
+
+        csv_path = tmp_xlsx_factory(".csv")
+        with Path(csv_path).open("w", newline="", encoding="utf-8") as f:
+            writer = csv.writer(f)
+            writer.writerow(["price", "rate", "count", "note"])
+            writer.writerow(["$1,234.50", "45%", "1,234,567", "12,34"])
+            writer.writerow(["-€9.99", "12.5%", "1,000", "5 USD"])
+
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, parallel=parallel, smart_numeric=True)
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert ws["A2"].value == 1234.5
+            assert ws["A2"].number_format == "#,##0.00"
+            assert ws["A3"].value == -9.99
+            assert ws["B2"].value == pytest.approx(0.45)
+            assert ws["B2"].number_format == "0%"
+            assert ws["B3"].value == pytest.approx(0.125)
+            assert ws["B3"].number_format == "0.00%"
+            assert ws["C2"].value == 1234567
+            assert ws["C3"].value == 1000
+            # Malformed grouping and trailing units stay text
+            assert ws["D2"].value == "12,34"
+            assert ws["D3"].value == "5 USD"
+            wb.close()
+
+    def test_csv_smart_numeric_off_by_default(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """Without smart_numeric, currency and percent strings are written unchanged."""
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        Path(csv_path).write_text('"$1,234.50",45%\n')
+        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
+        wb = load_workbook(xlsx_path)
+        ws = active_ws(wb)
+        assert ws["A1"].value == "$1,234.50"
+        assert ws["B1"].value == "45%"
+        wb.close()
+
+    def test_csv_delimiter_quote_and_escape(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """delimiter, quote, and escape configure the CSV dialect on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("name;note\n'Smith; J';'say \\'hi\\''\n")
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            rows, cols = xlsxturbo.csv_to_xlsx(
+                csv_path, xlsx_path, parallel=parallel, delimiter=";", quote="'", escape="\\"
+            )
+            assert (rows, cols) == (2, 2)
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert ws["A2"].value == "Smith; J"
+            assert ws["B2"].value == "say 'hi'"
+            wb.close()
+
+    def test_csv_tab_delimiter(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """A tab delimiter reads TSV files, given as a real tab or the two characters backslash-t."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a\tb\n1\t2\n")
+        for delimiter in ("\t", "\\t"):
+            xlsx_path = tmp_xlsx_factory()
+            assert xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, delimiter=delimiter) == (2, 2)
+
+    def test_csv_invalid_dialect_raises(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """A multi-character delimiter or clashing dialect characters are rejected."""
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        Path(csv_path).write_text("a\n1\n")
+        with pytest.raises(ValueError, match="Invalid delimiter ';;'"):
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, delimiter=";;")
+        with pytest.raises(ValueError, match="delimiter and quote must be different"):
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, quote=",")
+
+    def test_csv_encoding(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """Non-UTF-8 input is decoded with the given encoding on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_bytes("name,city\nJosé,Zürich €\n".encode("cp1252"))
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, parallel=parallel, encoding="windows-1252")
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert ws["A2"].value == "José"
+            assert ws["B2"].value == "Zürich €"
+            wb.close()
+
+    def test_csv_utf16_with_bom(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """A UTF-16 byte-order mark is honored even with the default encoding."""
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        Path(csv_path).write_bytes("a,b\nü,1\n".encode("utf-16"))
+        assert xlsxturbo.csv_to_xlsx(csv_path, xlsx_path) == (2, 2)
+        wb = load_workbook(xlsx_path)
+        assert active_ws(wb)["A2"].value == "ü"
+        wb.close()
+
+    def test_csv_unknown_encoding_raises(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """An unrecognized encoding name is a ValueError."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a\n1\n")
+        with pytest.raises(ValueError, match="Unknown encoding 'klingon'"):
+            xlsxturbo.csv_to_xlsx(csv_path, tmp_xlsx_factory(), encoding="klingon")
+
+    def test_csv_comment_and_skip_empty_rows(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """Comment lines and blank rows are skipped without leaving gaps, on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("# exported 2024-01-15\nname,qty\n,,\n  \nx,1\n# end\ny,2\n")
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            rows, cols = xlsxturbo.csv_to_xlsx(
+                csv_path, xlsx_path, parallel=parallel, comment="#", skip_empty_rows=True
+            )
+            assert (rows, cols) == (3, 2)
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert [row[0] for row in ws.iter_rows(values_only=True)] == ["name", "x", "y"]
+            wb.close()
+
+    def test_csv_null_values(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """null_values markers become empty cells, ignoring case and padding, on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("id,score,note\n1,NA,ok\n2, null ,-\n3,4.5,NAN-free\n")
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(
+                csv_path, xlsx_path, parallel=parallel, has_header=True, null_values=["na", "NULL", "-"]
+            )
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert list(ws.iter_rows(min_row=2, values_only=True)) == [
+                (1, None, "ok"),
+                (2, None, None),
+                (3, 4.5, "NAN-free"),
+            ]
+            wb.close()
+
+        # Without null_values the markers stay text
+        xlsx_path = tmp_xlsx_factory()
+        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
+        wb = load_workbook(xlsx_path)
+        assert active_ws(wb)["B2"].value == "NA"
+        wb.close()
+
+    def test_csv_number_separators(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """European decimal and thousands separators are normalized, on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("amount;qty;day\n1.234,56;1.000;15.01.2024\n-0,5;12.34,5;x\n")
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(
+                csv_path,
+                xlsx_path,
+                parallel=parallel,
+                delimiter=";",
+                decimal_separator=",",
+                thousands_separator=".",
+            )
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert ws["A2"].value == pytest.approx(1234.56)
+            assert ws["B2"].value == 1000
+            assert ws["A3"].value == pytest.approx(-0.5)
+            # Misplaced groups stay text
+            assert ws["B3"].value == "12.34,5"
+            wb.close()
+
+    @pytest.mark.parametrize(
+        "kwargs, match",
+        [
+            ({"decimal_separator": ",,"}, "Invalid decimal_separator"),
+            ({"thousands_separator": "1"}, "Invalid thousands_separator"),
+            ({"thousands_separator": "."}, "must be different"),
+        ],
+    )
+    def test_csv_number_separators_invalid(
+        self, tmp_xlsx_factory: Callable[..., str], kwargs: dict[str, str], match: str
+    ) -> None:
+        """Bad or clashing separators are rejected."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a\n1\n")
+        with pytest.raises(ValueError, match=match):
+            xlsxturbo.csv_to_xlsx(csv_path, tmp_xlsx_factory(), **kwargs)
+
+    def test_csv_scientific_format(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """Exponent-notation numbers get scientific_format and keep their value, on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a,b\n1.5e10,2.5\n-3E-4,7\n")
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, parallel=parallel, scientific_format="0.00E+00")
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert ws["A2"].value == pytest.approx(1.5e10)
+            assert ws["A2"].number_format == "0.00E+00"
+            assert ws["A3"].value == pytest.approx(-3e-4)
+            assert ws["A3"].number_format == "0.00E+00"
+            assert ws["B2"].number_format == "General"
+            wb.close()
+
+    def test_csv_scientific_format_invalid(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """An empty scientific_format is rejected."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("1e3\n")
+        with pytest.raises(ValueError, match="scientific_format"):
+            xlsxturbo.csv_to_xlsx(csv_path, tmp_xlsx_factory(), scientific_format="")
+
+    def test_csv_text_columns(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """text_columns fields are written as "@" text with leading zeros kept, on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("zip,qty\n01234,007\nNA,2\n")
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            xlsxturbo.csv_to_xlsx(csv_path, xlsx_path, parallel=parallel, text_columns=[0], null_values=["NA"])
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert [ws["A2"].value, ws["B2"].value] == ["01234", 7]
+            assert ws["A2"].number_format == "@"
+            assert ws["A3"].value is None
+            wb.close()
+
+    def test_csv_comment_must_differ_from_delimiter(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """A comment character equal to the delimiter is rejected."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a\n1\n")
+        with pytest.raises(ValueError, match="CSV comment must differ"):
+            xlsxturbo.csv_to_xlsx(csv_path, tmp_xlsx_factory(), comment=",")
+
+    def test_csv_has_header(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """has_header writes the first row as styled text and freezes it, on both code paths."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("2024,when\n1,2024-01-02\n")
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            rows, cols = xlsxturbo.csv_to_xlsx(
+                csv_path, xlsx_path, parallel=parallel, has_header=True, header_format={"bold": True}
+            )
+            assert (rows, cols) == (2, 2)
+            wb = load_workbook(xlsx_path)
+            ws = active_ws(wb)
+            assert ws["A1"].value == "2024"
+            assert ws["A1"].font.bold
+            assert ws["A2"].value == 1
+            assert ws.freeze_panes == "A2"
+            wb.close()
+
+    def test_csv_header_format_requires_has_header(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """header_format without has_header has no row to style and is rejected."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a\n1\n")
+        with pytest.raises(ValueError, match="header_format requires has_header=True"):
+            xlsxturbo.csv_to_xlsx(csv_path, tmp_xlsx_factory(), header_format={"bold": True})
+
+    def test_csvs_to_xlsx_one_sheet_per_file(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """csvs_to_xlsx writes each file to its own sheet, with per-sheet option overrides."""
+        from datetime import datetime
+
+        first = tmp_xlsx_factory(".csv")
+        second = tmp_xlsx_factory(".csv")
+        Path(first).write_text("name,qty\nx,1\ny,2\n")
+        Path(second).write_text("when;qty\n02-01-2024;3\n")
+        for parallel in (False, True):
+            xlsx_path = tmp_xlsx_factory()
+            dims = xlsxturbo.csvs_to_xlsx(
+                [(first, "First"), (Path(second), "Second", {"delimiter": ";", "date_order": "eu"})],
+                xlsx_path,
+                parallel=parallel,
+                has_header=True,
+                header_format={"bold": True},
+            )
+            assert dims == [(3, 2), (2, 2)]
+            wb = load_workbook(xlsx_path)
+            assert wb.sheetnames == ["First", "Second"]
+            assert wb["First"]["A1"].font.bold
+            assert wb["First"]["B3"].value == 2
+            assert wb["Second"]["A2"].value == datetime(2024, 1, 2)
+            assert wb["Second"].freeze_panes == "A2"
+            wb.close()
+
+    def test_csvs_to_xlsx_rejects_bad_inputs(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """Empty input lists, unknown option keys, and per-sheet errors are ValueErrors naming the problem."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a\n1\n")
+        xlsx_path = tmp_xlsx_factory()
+        with pytest.raises(ValueError, match="at least one input"):
+            xlsxturbo.csvs_to_xlsx([], xlsx_path)
+        with pytest.raises(ValueError, match="Unknown sheet option 'sheet_name'"):
+            xlsxturbo.csvs_to_xlsx([(csv_path, "A", {"sheet_name": "B"})], xlsx_path)
+        with pytest.raises(ValueError, match="sheet 'B': Invalid delimiter"):
+            xlsxturbo.csvs_to_xlsx([(csv_path, "A"), (csv_path, "B", {"delimiter": ";;"})], xlsx_path)
+        with pytest.raises(ValueError, match="sheet 'A': header_format requires has_header=True"):
+            xlsxturbo.csvs_to_xlsx([(csv_path, "A", {"header_format": {"bold": True}})], xlsx_path)
+        with pytest.raises(ValueError, match="sheet 'Missing': Failed to open input file"):
+            xlsxturbo.csvs_to_xlsx([(csv_path, "A"), (csv_path + ".gone", "Missing")], xlsx_path)
+
 
 class TestUnicodeAndSpecialData:
     """Tests for Unicode, mixed types, nulls, and CSV edge cases."""
@@ -2,13 +2,14 @@
 
 from __future__ import annotations
 
+import base64
 from pathlib import Path
 
 import pandas as pd
 import pytest
 import xlsxturbo
 
-from tests.helpers import HAS_OPENPYXL, load_workbook
+from tests.helpers import HAS_OPENPYXL, TINY_PNG_B64, load_workbook
 
 pytestmark = pytest.mark.skipif(not HAS_OPENPYXL, reason="openpyxl required for content verification")
 
@@ -24,6 +25,41 @@ class TestErrorPaths:
         message = str(exc_info.value)
         assert "Failed to load image" in message or "image" in message.lower()
 
+    def test_unrecognized_image_bytes_raise_error(self, tmp_xlsx: str) -> None:
+        """Image bytes that aren't a supported format raise instead of panicking."""
+        df = pd.DataFrame({"A": [1]})
+        for data in (b"", b"not an image"):
+            with pytest.raises(ValueError, match=r"images\['B1'\]: unrecognized image data"):
+                xlsxturbo.df_to_xlsx(df, tmp_xlsx, images={"B1": data})
+
+    def test_image_type_mismatch_raises_error(self, tmp_xlsx: str) -> None:
+        """A declared image_type must match the format read from the data."""
+        df = pd.DataFrame({"A": [1]})
+        png_data = base64.b64decode(TINY_PNG_B64)
+        with pytest.raises(ValueError, match="image_type is 'jpeg' but the data is png"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, images={"B1": {"data": png_data, "image_type": "jpg"}})
+
+    def test_background_image_wrong_type_raises_error(self, tmp_xlsx: str) -> None:
+        """background_image takes a path or bytes."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(TypeError, match="background_image must be an image path"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, background_image=3)  # type: ignore[arg-type]
+
+    def test_image_dpi_must_be_positive(self, tmp_xlsx: str) -> None:
+        """A zero or negative image dpi is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        png_data = base64.b64decode(TINY_PNG_B64)
+        with pytest.raises(ValueError, match="'dpi' must be a positive number"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, images={"B1": {"data": png_data, "dpi": 0}})
+
+    def test_image_path_and_data_are_exclusive(self, tmp_xlsx: str) -> None:
+        """An image config needs exactly one of 'path' and 'data'."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="both 'path' and 'data'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, images={"B1": {"path": "a.png", "data": b"x"}})
+        with pytest.raises(ValueError, match="missing 'path' or 'data' key"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, images={"B1": {"scale_width": 2.0}})
+
     def test_validation_list_exceeds_255_chars_raises_error(self, tmp_xlsx: str) -> None:
         """Validation list exceeding 255 chars raises clear error."""
         df = pd.DataFrame({"Status": ["A"]})
@@ -37,6 +73,40 @@ class TestErrorPaths:
         assert "255" in message
         assert "character" in message.lower()
 
+    def test_validation_values_and_source_range_are_exclusive(self, tmp_xlsx: str) -> None:
+        """A list validation takes inline values or a source_range, not both."""
+        df = pd.DataFrame({"Status": ["A"]})
+        with pytest.raises(ValueError, match="'values' or 'source_range', not both"):
+            xlsxturbo.df_to_xlsx(
+                df,
+                tmp_xlsx,
+                validations={"Status": {"type": "list", "values": ["A"], "source_range": "=Lists!$A$1:$A$5"}},
+            )
+
+    def test_date_validation_bad_bound_raises_error(self, tmp_xlsx: str) -> None:
+        """A date bound that isn't ISO 8601 names the bound and the value."""
+        df = pd.DataFrame({"Due": ["x"]})
+        with pytest.raises(ValueError, match=r"validations\['Due'\]: invalid 'min' date '31/01/2024'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, validations={"Due": {"type": "date", "min": "31/01/2024"}})
+        with pytest.raises(ValueError, match="invalid 'max' time '25:00'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, validations={"Due": {"type": "time", "max": "25:00"}})
+
+    def test_validation_unknown_error_style_raises_error(self, tmp_xlsx: str) -> None:
+        """An unknown error_style lists the valid ones."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="Unknown error_style 'soft'. Valid: stop, warning, information"):
+            xlsxturbo.df_to_xlsx(
+                df,
+                tmp_xlsx,
+                validations={"A": {"type": "decimal", "error_style": "soft"}},  # type: ignore[arg-type]
+            )
+
+    def test_auto_rich_text_columns_unmatched_pattern_raises_error(self, tmp_xlsx: str) -> None:
+        """An auto_rich_text_columns pattern matching no column is rejected."""
+        df = pd.DataFrame({"A": ["**x**"]})
+        with pytest.raises(ValueError, match=r"auto_rich_text_columns\['B'\]: pattern matched no columns"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, auto_rich_text_columns=["B"])
+
     def test_invalid_validation_config_raises_error(self, tmp_xlsx: str) -> None:
         """Invalid validation config (not a dict) raises clear error."""
         df = pd.DataFrame({"A": [1]})
@@ -81,6 +151,30 @@ class TestErrorPaths:
                 column_formats={"Missing": {"bold": True}},
             )
 
+    def test_column_format_index_out_of_range(self, tmp_xlsx: str) -> None:
+        """An integer column_formats key past the last column raises an error."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match=r"column_formats\[1\]: column index out of range"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={1: {"bold": True}})
+
+    def test_column_format_negative_index(self, tmp_xlsx: str) -> None:
+        """A negative integer column_formats key raises an error."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match=r"column_formats\[-1\]: must be a non-negative"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={-1: {"bold": True}})
+
+    def test_column_format_key_wrong_type(self, tmp_xlsx: str) -> None:
+        """A column_formats key that is neither str nor int raises TypeError."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(TypeError, match=r"column_formats\[1.5\]: must be a column name pattern"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={1.5: {"bold": True}})  # type: ignore[dict-item]  # float key is intentionally invalid
+
+    def test_column_format_bool_key_raises(self, tmp_xlsx: str) -> None:
+        """A bool column_formats key is rejected rather than treated as column index 0 or 1."""
+        df = pd.DataFrame({"A": [1], "B": [2]})
+        with pytest.raises(TypeError, match=r"column_formats\[True\]: must be a column name pattern"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={True: {"bold": True}})
+
     def test_invalid_rich_text_segment_raises_error(self, tmp_xlsx: str) -> None:
         """Invalid rich_text segment (not string or tuple) raises clear error."""
         df = pd.DataFrame({"A": [1]})
@@ -139,6 +233,31 @@ class TestErrorPaths:
                 merged_ranges=[("A1:B1", "Title", {"bold": True}, "ignored")],  # type: ignore[list-item]
             )
 
+    def test_overlapping_merged_ranges_raise_error(self, tmp_xlsx: str) -> None:
+        """Every pair of overlapping merged ranges is listed in one error."""
+        df = pd.DataFrame({"A": [1]})
+        ranges = [("D1:E2", "a"), ("E2:F3", "b"), ("H1:H2", "c"), ("F3:G3", "d")]
+        with pytest.raises(ValueError) as exc_info:
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, merged_ranges=ranges)
+        message = str(exc_info.value)
+        assert "'D1:E2' overlaps 'E2:F3'" in message
+        assert "'E2:F3' overlaps 'F3:G3'" in message
+        assert "H1:H2" not in message
+
+    def test_merged_range_inside_table_raises_error(self, tmp_xlsx: str) -> None:
+        """A merged range may not intersect the table range."""
+        df = pd.DataFrame({"A": [1, 2], "B": [3, 4]})
+        with pytest.raises(ValueError, match=r"'B2:C3' intersects the table range A1:B3"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_style="Medium2", merged_ranges=[("B2:C3", "x")])
+
+    def test_merged_range_inside_autofilter_raises_error(self, tmp_xlsx: str) -> None:
+        """A merged range may not intersect the autofilter range."""
+        df = pd.DataFrame({"A": [1, 2], "B": [3, 4]})
+        with pytest.raises(ValueError, match=r"'A3:B3' intersects the autofilter range A1:B3"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofilter=True, merged_ranges=[("A3:B3", "x")])
+        # Just below the filtered block is fine
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofilter=True, merged_ranges=[("A4:B4", "x")])
+
     def test_wrong_type_hyperlinks_raises_error(self, tmp_xlsx: str) -> None:
         """Passing a dict instead of list for hyperlinks raises TypeError."""
         df = pd.DataFrame({"A": [1]})
@@ -151,13 +270,31 @@ class TestErrorPaths:
     def test_hyperlink_tuple_rejects_extra_items(self, tmp_xlsx: str) -> None:
         """Hyperlink tuples require their documented exact arity."""
         df = pd.DataFrame({"A": [1]})
-        with pytest.raises(ValueError, match="exactly 2 or 3 elements"):
+        with pytest.raises(ValueError, match="2 to 4 elements"):
             xlsxturbo.df_to_xlsx(
                 df,
                 tmp_xlsx,
-                hyperlinks=[("A1", "https://example.com", "Example", "ignored")],  # type: ignore[list-item]
+                hyperlinks=[("A1", "https://example.com", "Example", "Tip", "ignored")],  # type: ignore[list-item]
             )
 
+    def test_hyperlink_tooltip_too_long_raises(self, tmp_xlsx: str) -> None:
+        """A tooltip beyond Excel's 255-character limit is rejected up front."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="255-character limit"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, hyperlinks=[("A1", "https://example.com", None, "x" * 256)])
+
+    def test_internal_hyperlink_to_missing_sheet_raises(self, tmp_xlsx: str) -> None:
+        """An internal: link must target a sheet of the workbook."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="target sheet 'Nope' does not exist. Sheets: Data"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, sheet_name="Data", hyperlinks=[("B1", "internal:Nope!A1")])
+
+    def test_internal_hyperlink_invalid_cell_raises(self, tmp_xlsx: str) -> None:
+        """An internal: link's cell part must be a valid reference."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="invalid internal link target"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, sheet_name="Data", hyperlinks=[("B1", "internal:Data!1A")])
+
     def test_invalid_rich_text_not_list_raises_error(self, tmp_xlsx: str) -> None:
         """Invalid rich_text value (not a list) raises clear error."""
         df = pd.DataFrame({"A": [1]})
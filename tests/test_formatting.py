@@ -2,7 +2,10 @@
 
 from __future__ import annotations
 
+import base64
 import zipfile
+from collections.abc import Callable
+from datetime import date
 from pathlib import Path
 
 import pandas as pd
@@ -10,7 +13,7 @@ import polars as pl
 import pytest
 import xlsxturbo
 
-from tests.helpers import HAS_OPENPYXL, active_ws, load_workbook
+from tests.helpers import HAS_OPENPYXL, TINY_PNG_B64, active_ws, load_workbook
 
 pytestmark = pytest.mark.skipif(not HAS_OPENPYXL, reason="openpyxl required for content verification")
 
@@ -126,11 +129,12 @@ class TestColumnWidthCap:
         wb.close()
 
     def test_autofit_with_explicit_width_for_one_column(self, tmp_xlsx: str) -> None:
-        """autofit=True with a column_widths override for one column still autofits the rest.
+        """autofit=True with a column_widths entry for one column still autofits the rest.
 
         Regression test: `column_widths={0: 25}` with no '_all' key used to
-        drop autofit for every other column entirely (W1). Column A keeps the
-        explicit width; column B is autofitted to its (longer) content.
+        drop autofit for every other column entirely (W1). Column A's entry
+        is only a cap, so its short content keeps it narrow; column B is
+        autofitted to its (longer) content.
         """
         df = pd.DataFrame({
             "A": ["x"],
@@ -142,22 +146,46 @@ class TestColumnWidthCap:
         w_a = ws.column_dimensions["A"].width
         w_b = ws.column_dimensions["B"].width
         assert w_a is not None
-        assert abs(w_a - 25) < 1, f"Explicit column A width {w_a} should be ~25"
+        assert w_a < 15, f"Column A width {w_a} should fit its content, below the 25 cap"
         assert w_b is not None
-        assert w_b > w_a, f"Autofitted column B width {w_b} should exceed column A's explicit width"
+        assert w_b > 25, f"Autofitted column B width {w_b} should fit its 60-character content"
+        wb.close()
+
+    def test_per_column_caps_with_autofit(self, tmp_xlsx: str) -> None:
+        """Each column is autofitted, then capped at its own width, else at '_all'."""
+        df = pd.DataFrame({
+            "Description": ["d" * 120],
+            "Notes": ["n" * 120],
+            "Short": ["x"],
+        })
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofit=True, column_widths={0: 80, "_all": 30})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert abs(ws.column_dimensions["A"].width - 80) < 1
+        assert abs(ws.column_dimensions["B"].width - 30) < 1
+        assert ws.column_dimensions["C"].width < 15
+        wb.close()
+
+    def test_autofit_caps_still_size_formula_columns(self, tmp_xlsx: str) -> None:
+        """Formula columns are autofitted when column_widths caps only the data columns."""
+        df = pd.DataFrame({"A": ["y" * 60], "B": [2]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            autofit=True,
+            column_widths={0: 10},
+            formula_columns={"Quarterly revenue total": "=B{row}*2"},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert abs(ws.column_dimensions["A"].width - 10) < 1
+        w_c = ws.column_dimensions["C"].width
+        assert w_c is not None
+        assert w_c > 15, f"Formula column C width {w_c} should fit its header"
         wb.close()
 
     def test_explicit_width_smaller_than_autofit_wins(self, tmp_xlsx: str) -> None:
-        """An explicit width narrower than the autofit width is kept, not enlarged.
-
-        Pins the apply order in `apply_worksheet_features` (convert.rs):
-        `worksheet.autofit()` runs first and the explicit `column_widths` are
-        applied on top. rust_xlsxwriter lets an autofit width override a user
-        width when the autofit width is greater, so applying autofit *after*
-        the explicit widths would silently widen this column to ~60 and only
-        this ordering (explicit-last) keeps it at 10. The sibling test above
-        passes under either order; this one fails if the calls are swapped.
-        """
+        """A cap narrower than the autofit width is kept, not enlarged."""
         df = pd.DataFrame({
             "A": ["y" * 60],
             "B": ["x"],
@@ -233,6 +261,104 @@ class TestColumnWidthCap:
         wb.close()
 
 
+class TestAutofitColumns:
+    """Tests for autofit given a list of columns."""
+
+    def test_only_listed_columns_are_sized(self, tmp_xlsx: str) -> None:
+        """Listed columns, by name or position, are autofitted; the rest keep the default."""
+        df = pd.DataFrame({"A": ["x" * 40], "B": ["y" * 40], "C": ["z" * 40]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofit=["A", 2])
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        for letter in ("A", "C"):
+            width = ws.column_dimensions[letter].width
+            assert width is not None and width > 35, f"column {letter} width {width} should fit 40 chars"
+        assert "B" not in ws.column_dimensions, "unlisted column B should keep the default width"
+        wb.close()
+
+    def test_explicit_width_and_cap(self, tmp_xlsx: str) -> None:
+        """Widths cap the listed columns and set the rest; a column's own entry beats '_all'."""
+        df = pd.DataFrame({"A": ["x" * 40], "B": ["y" * 40], "C": ["z" * 40]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofit=["A", "B"], column_widths={0: 12, "_all": 25})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert abs(ws.column_dimensions["A"].width - 12) < 1
+        assert abs(ws.column_dimensions["B"].width - 25) < 1
+        assert abs(ws.column_dimensions["C"].width - 25) < 1
+        wb.close()
+
+    def test_per_sheet_column_list(self, tmp_xlsx: str) -> None:
+        """A per-sheet list overrides the global autofit=True."""
+        df = pd.DataFrame({"A": ["x" * 40], "B": ["y" * 40]})
+        xlsxturbo.dfs_to_xlsx([(df, "All"), (df, "Some", {"autofit": ["B"]})], tmp_xlsx, autofit=True)
+        wb = load_workbook(tmp_xlsx)
+        assert wb["All"].column_dimensions["A"].width > 35
+        assert wb["Some"].column_dimensions["B"].width > 35
+        assert "A" not in wb["Some"].column_dimensions
+        wb.close()
+
+    @pytest.mark.parametrize(
+        ("autofit", "match"),
+        [
+            pytest.param(["Missing"], "not a written column", id="unknown-name"),
+            pytest.param([5], "out of range", id="index-out-of-range"),
+        ],
+    )
+    def test_invalid_column_raises(self, autofit: list[str | int], match: str, tmp_xlsx: str) -> None:
+        """Unknown names and out-of-range positions raise ValueError."""
+        with pytest.raises(ValueError, match=match):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, autofit=autofit)
+
+    def test_wrong_type_raises_type_error(self, tmp_xlsx: str) -> None:
+        """A value that is neither a bool nor a list raises TypeError."""
+        with pytest.raises(TypeError, match="autofit"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, autofit="A")  # type: ignore[arg-type]
+
+
+class TestAutofitHeaderWidth:
+    """Tests for the header-width floor under autofitted columns."""
+
+    HEADER = "Quarterly Revenue Total"
+
+    @pytest.mark.parametrize(
+        "kwargs",
+        [
+            pytest.param({"autofit": True}, id="autofit"),
+            pytest.param({"autofit": True, "column_widths": {"_all": 60}}, id="autofit-with-cap"),
+            pytest.param({"autofit": [0]}, id="column-list"),
+        ],
+    )
+    def test_header_wider_than_data_is_not_clipped(self, kwargs: dict[str, object], tmp_xlsx: str) -> None:
+        """A column is at least as wide as its header label plus padding."""
+        df = pd.DataFrame({self.HEADER: [1, 2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, header_format={"bold": True}, **kwargs)  # type: ignore[arg-type]
+        wb = load_workbook(tmp_xlsx)
+        width = active_ws(wb).column_dimensions["A"].width
+        assert width is not None
+        assert width >= len(self.HEADER) + 2, f"width {width} should fit the header plus padding"
+        wb.close()
+
+    def test_cap_wins_over_header_floor(self, tmp_xlsx: str) -> None:
+        """An explicit column_widths cap still limits the column."""
+        df = pd.DataFrame({self.HEADER: [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofit=True, column_widths={0: 10})
+        wb = load_workbook(tmp_xlsx)
+        width = active_ws(wb).column_dimensions["A"].width
+        assert width is not None
+        assert abs(width - 10) < 1
+        wb.close()
+
+    def test_no_header_no_floor(self, tmp_xlsx: str) -> None:
+        """Without a header row, only the data sizes the column."""
+        df = pd.DataFrame({self.HEADER: [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofit=True, header=False)
+        wb = load_workbook(tmp_xlsx)
+        width = active_ws(wb).column_dimensions["A"].width
+        assert width is not None
+        assert width < len(self.HEADER)
+        wb.close()
+
+
 class TestTableName:
     """Tests for table_name parameter."""
 
@@ -242,69 +368,1108 @@ class TestTableName:
         xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_style="Medium2", table_name="MyTable")
         wb = load_workbook(tmp_xlsx)
         ws = active_ws(wb)
-        tables = list(ws.tables.keys())
-        assert "MyTable" in tables
+        tables = list(ws.tables.keys())
+        assert "MyTable" in tables
+        wb.close()
+
+    def test_table_name_sanitization(self, tmp_xlsx: str) -> None:
+        """Sanitize invalid characters in table names."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, table_style="Medium2", table_name="My Table-2024!"
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        tables = list(ws.tables.keys())
+        assert len(tables) == 1
+        assert "_" in tables[0]  # Some characters replaced with underscore
+        wb.close()
+
+    def test_table_name_starts_with_digit(self, tmp_xlsx: str) -> None:
+        """Prefix table names starting with a digit with an underscore."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_style="Medium2", table_name="123Data")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        tables = list(ws.tables.keys())
+        assert tables[0].startswith("_")
+        wb.close()
+
+    def test_per_sheet_table_names(self, tmp_xlsx: str) -> None:
+        """Apply different table names per sheet."""
+        df1 = pd.DataFrame({"A": [1]})
+        df2 = pd.DataFrame({"B": [2]})
+        xlsxturbo.dfs_to_xlsx(
+            [
+                (df1, "Sheet1", {"table_style": "Medium2", "table_name": "Table1"}),
+                (df2, "Sheet2", {"table_style": "Medium2", "table_name": "Table2"}),
+            ],
+            tmp_xlsx,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert "Table1" in wb["Sheet1"].tables
+        assert "Table2" in wb["Sheet2"].tables
+        wb.close()
+
+    def test_no_table_name_without_table_style(self, tmp_xlsx: str) -> None:
+        """Ignore table_name when table_style is None."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_name="Ignored")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert len(ws.tables) == 0
+        wb.close()
+
+    def test_duplicate_global_table_name_raises_before_save(self, tmp_xlsx: str) -> None:
+        """Reject a global table name reused by multiple table-bearing sheets."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match=r"Duplicate table name.*Sheet1.*Sheet2"):
+            xlsxturbo.dfs_to_xlsx(
+                [(df, "Sheet1"), (df, "Sheet2")],
+                tmp_xlsx,
+                table_style="Medium2",
+                table_name="Shared Table",
+            )
+
+
+class TestTableColumns:
+    """Tests for the table_columns per-column table overrides."""
+
+    def test_totals_row_functions_and_label(self, tmp_xlsx: str) -> None:
+        """Add a totals row with a label and per-column functions."""
+        df = pd.DataFrame({"Item": ["a", "b"], "Qty": [1, 2], "Price": [1.5, 2.5]})
+        result = xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            table_style="Medium2",
+            table_columns=[
+                {"total_label": "Total"},
+                {"total_function": "sum"},
+                {"total_function": "average"},
+            ],
+        )
+        assert result == (4, 3)  # header + 2 data rows + totals row
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        table = next(iter(ws.tables.values()))
+        assert table.ref == "A1:C4"
+        assert table.totalsRowCount == 1
+        assert [c.totalsRowFunction for c in table.tableColumns] == [None, "sum", "average"]
+        assert ws["A4"].value == "Total"
+        assert ws["B4"].value == "=SUBTOTAL(109,[Qty])"
+        wb.close()
+
+    def test_header_override_without_totals(self, tmp_xlsx: str) -> None:
+        """Rename a table column header without adding a totals row."""
+        df = pd.DataFrame({"qty": [1, 2], "price": [3, 4]})
+        result = xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, table_style="Medium2", table_columns=[{}, {"header": "Unit Price"}]
+        )
+        assert result == (3, 2)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        table = next(iter(ws.tables.values()))
+        assert table.ref == "A1:B3"
+        assert not table.totalsRowCount
+        assert ws["A1"].value == "qty"
+        assert ws["B1"].value == "Unit Price"
+        wb.close()
+
+    def test_per_sheet_totals_row(self, tmp_xlsx: str) -> None:
+        """Apply table_columns per sheet, with the totals row in each sheet's count."""
+        df = pd.DataFrame({"A": [1, 2, 3]})
+        result = xlsxturbo.dfs_to_xlsx(
+            [
+                (df, "Totals", {"table_columns": [{"total_function": "count"}]}),
+                (df, "Plain"),
+            ],
+            tmp_xlsx,
+            table_style="Medium2",
+        )
+        assert result == [(5, 1), (4, 1)]
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Totals"]["A5"].value == "=SUBTOTAL(103,[A])"
+        assert wb["Plain"]["A5"].value is None
+        wb.close()
+
+    def test_ignored_without_table_style(self, tmp_xlsx: str) -> None:
+        """Ignore table_columns when no table is created."""
+        df = pd.DataFrame({"A": [1, 2]})
+        result = xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_columns=[{"total_function": "sum"}])
+        assert result == (3, 1)
+        wb = load_workbook(tmp_xlsx)
+        assert len(active_ws(wb).tables) == 0
+        wb.close()
+
+    def test_more_entries_than_columns_raises(self, tmp_xlsx: str) -> None:
+        """Reject more table_columns entries than the table has columns."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match=r"table_columns has 2 entries but the table has only 1 columns"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_style="Medium2", table_columns=[{}, {}])
+
+    def test_invalid_total_function_raises(self, tmp_xlsx: str) -> None:
+        """Reject an unknown total_function name."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match=r"table_columns\[0\]: invalid total_function 'median'"):
+            xlsxturbo.df_to_xlsx(
+                df, tmp_xlsx, table_style="Medium2", table_columns=[{"total_function": "median"}]
+            )
+
+    def test_function_and_label_together_raise(self, tmp_xlsx: str) -> None:
+        """Reject total_function and total_label on the same column."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="cannot both be set"):
+            xlsxturbo.df_to_xlsx(
+                df,
+                tmp_xlsx,
+                table_style="Medium2",
+                table_columns=[{"total_function": "sum", "total_label": "Total"}],
+            )
+
+    def test_unknown_key_raises(self, tmp_xlsx: str) -> None:
+        """Reject unknown keys in a table_columns entry."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match=r"unknown option 'total'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_style="Medium2", table_columns=[{"total": "sum"}])
+
+
+class TestTableOptions:
+    """Tests for the table_options banding and emphasis toggles."""
+
+    def test_toggles_set_table_style_flags(self, tmp_xlsx: str) -> None:
+        """Write each toggle to the table's style info."""
+        df = pd.DataFrame({"A": [1, 2], "B": [3, 4]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            table_style="Medium9",
+            table_options={"banded_rows": False, "banded_columns": True, "first_column": True, "last_column": True},
+        )
+        wb = load_workbook(tmp_xlsx)
+        info = next(iter(active_ws(wb).tables.values())).tableStyleInfo
+        assert info.name == "TableStyleMedium9"
+        assert not info.showRowStripes
+        assert info.showColumnStripes
+        assert info.showFirstColumn
+        assert info.showLastColumn
+        wb.close()
+
+    def test_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A sheet's table_options replaces the global one; omitted keys keep their defaults."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Own", {"table_options": {"last_column": True}})],
+            tmp_xlsx,
+            table_style="Medium2",
+            table_options={"first_column": True},
+        )
+        wb = load_workbook(tmp_xlsx)
+        own = next(iter(wb["Own"].tables.values())).tableStyleInfo
+        glob = next(iter(wb["Global"].tables.values())).tableStyleInfo
+        assert glob.showFirstColumn and not glob.showLastColumn
+        assert own.showLastColumn and not own.showFirstColumn
+        assert own.showRowStripes
+        wb.close()
+
+    def test_unknown_key_raises(self, tmp_xlsx: str) -> None:
+        """Reject unknown keys in table_options."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match=r"unknown option 'banded'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_style="Medium2", table_options={"banded": True})
+
+
+class TestExtraTables:
+    """Tests for the tables option adding tables over explicit ranges."""
+
+    def test_two_tables_on_one_sheet(self, tmp_xlsx: str) -> None:
+        """Add one table per range, with headers from the cells and each entry's style and name."""
+        df = pd.DataFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            tables=[
+                {"range": "A1:B3", "style": "Light9", "name": "Left Part"},
+                {"range": "C1:C3", "total_row": True},
+            ],
+        )
+        wb = load_workbook(tmp_xlsx)
+        tables = {t.ref: t for t in active_ws(wb).tables.values()}
+        assert set(tables) == {"A1:B3", "C1:C3"}
+        left = tables["A1:B3"]
+        assert left.displayName == "Left_Part"
+        assert left.tableStyleInfo.name == "TableStyleLight9"
+        assert [c.name for c in left.tableColumns] == ["a", "b"]
+        assert tables["C1:C3"].tableStyleInfo.name == "TableStyleMedium9"
+        assert tables["C1:C3"].totalsRowCount == 1
+        wb.close()
+
+    def test_alongside_table_style(self, tmp_xlsx: str) -> None:
+        """Add extra tables next to the table_style table when the ranges stay clear of it."""
+        df = pd.DataFrame({"a": [1, 2]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            table_style="Medium2",
+            cells={"C1": "extra", "C2": 1, "C3": 2},
+            tables=[{"range": "C1:C3"}],
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert sorted(t.ref for t in active_ws(wb).tables.values()) == ["A1:A3", "C1:C3"]
+        wb.close()
+
+    def test_per_sheet_tables(self, tmp_xlsx: str) -> None:
+        """A sheet's tables replace the global list, and an empty list adds none."""
+        df = pd.DataFrame({"a": [1, 2], "b": [3, 4]})
+        xlsxturbo.dfs_to_xlsx(
+            [
+                (df, "Global"),
+                (df, "Own", {"tables": [{"range": "B1:B3"}]}),
+                (df, "NoTables", {"tables": []}),
+            ],
+            tmp_xlsx,
+            tables=[{"range": "A1:A3"}],
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert [t.ref for t in wb["Global"].tables.values()] == ["A1:A3"]
+        assert [t.ref for t in wb["Own"].tables.values()] == ["B1:B3"]
+        assert len(wb["NoTables"].tables) == 0
+        wb.close()
+
+    def test_overlapping_ranges_raise(self, tmp_xlsx: str) -> None:
+        """Report every overlap, including with the table_style table, in one error."""
+        df = pd.DataFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
+        with pytest.raises(ValueError, match=r"'B1:C3' overlaps 'C1:C3'.*intersects the table range A1:C3"):
+            xlsxturbo.df_to_xlsx(
+                df,
+                tmp_xlsx,
+                table_style="Medium2",
+                tables=[{"range": "B1:C3"}, {"range": "C1:C3"}],
+            )
+
+    def test_duplicate_names_raise(self, tmp_xlsx: str) -> None:
+        """Reject a name repeated on one sheet, or across sheets, case-insensitively."""
+        df = pd.DataFrame({"a": [1, 2], "b": [3, 4]})
+        with pytest.raises(ValueError, match=r"table name 'sales' for 'B1:B3' is already used by 'A1:A3'"):
+            xlsxturbo.df_to_xlsx(
+                df, tmp_xlsx, tables=[{"range": "A1:A3", "name": "Sales"}, {"range": "B1:B3", "name": "sales"}]
+            )
+        with pytest.raises(ValueError, match=r"Duplicate table name 'Sales' for sheets 'S1' and 'S2'"):
+            xlsxturbo.dfs_to_xlsx([(df, "S1"), (df, "S2")], tmp_xlsx, tables=[{"range": "A1:A3", "name": "Sales"}])
+
+    def test_invalid_entries_raise(self, tmp_xlsx: str) -> None:
+        """Reject a missing or malformed range, an unknown style, and unknown keys."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(ValueError, match=r"tables\[0\]: missing 'range' key"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, tables=[{"style": "Light1"}])
+        with pytest.raises(ValueError, match=r"tables\[0\]: Invalid cell range 'A1'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, tables=[{"range": "A1"}])
+        with pytest.raises(ValueError, match=r"tables\[0\]: Unknown table_style 'Bright'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, tables=[{"range": "A1:A2", "style": "Bright"}])
+        with pytest.raises(ValueError, match=r"unknown option 'header'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, tables=[{"range": "A1:A2", "header": False}])
+
+
+class TestAutofilter:
+    """Tests for autofilter without a table."""
+
+    def test_autofilter_covers_header_and_data(self, tmp_xlsx: str) -> None:
+        """autofilter=True filters the header row through the last data row, with no table."""
+        df = pd.DataFrame({"A": [1, 2, 3], "B": ["x", "y", "z"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofilter=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.auto_filter.ref == "A1:B4"
+        assert len(ws.tables) == 0
+        wb.close()
+
+    def test_autofilter_respects_offset_and_formula_columns(self, tmp_xlsx: str) -> None:
+        """The filter range follows start_row/start_col and includes formula columns."""
+        df = pd.DataFrame({"A": [1, 2], "B": [3, 4]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            autofilter=True,
+            start_row=2,
+            start_col=1,
+            formula_columns={"Sum": "=B{row}+C{row}"},
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).auto_filter.ref == "B3:D5"
+        wb.close()
+
+    def test_autofilter_with_freeze_panes_and_widths(self, tmp_xlsx: str) -> None:
+        """autofilter combines with freeze_panes and column_widths."""
+        df = pd.DataFrame({"A": [1, 2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofilter=True, freeze_panes=True, column_widths={0: 20})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.auto_filter.ref == "A1:A3"
+        assert ws.freeze_panes == "A2"
+        assert ws.column_dimensions["A"].width == pytest.approx(20, abs=1)
+        wb.close()
+
+    def test_autofilter_skipped_with_table(self, tmp_xlsx: str) -> None:
+        """A table brings its own filter, so no worksheet autofilter is added."""
+        df = pd.DataFrame({"A": [1, 2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, autofilter=True, table_style="Medium2")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.auto_filter.ref is None
+        assert len(ws.tables) == 1
+        wb.close()
+
+    def test_per_sheet_autofilter_overrides_global(self, tmp_xlsx: str) -> None:
+        """A per-sheet autofilter overrides the global setting."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "On"), (df, "Off", {"autofilter": False})],
+            tmp_xlsx,
+            autofilter=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["On"].auto_filter.ref == "A1:A2"
+        assert wb["Off"].auto_filter.ref is None
+        wb.close()
+
+
+class TestFreezePanes:
+    """Tests for freeze_panes given as a bool, a cell reference, a (row, col) tuple, or a rows/cols dict."""
+
+    @pytest.mark.parametrize(
+        ("freeze_panes", "expected"),
+        [
+            pytest.param(True, "A2", id="header-row"),
+            pytest.param("C2", "C2", id="cell-ref"),
+            pytest.param((1, 2), "C2", id="tuple"),
+            pytest.param((0, 1), "B1", id="columns-only"),
+            pytest.param({"rows": 3, "cols": 2}, "C4", id="rows-cols-dict"),
+            pytest.param({"cols": 1}, "B1", id="dict-columns-only"),
+        ],
+    )
+    def test_freeze_panes_position(self, freeze_panes: object, expected: str, tmp_xlsx: str) -> None:
+        """Each accepted form freezes at the expected top-left scrollable cell."""
+        df = pd.DataFrame({"A": [1, 2], "B": [3, 4], "C": [5, 6]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_panes=freeze_panes)  # type: ignore[arg-type]
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).freeze_panes == expected
+        wb.close()
+
+    def test_freeze_panes_cell_ref_ignores_header_and_offset(self, tmp_xlsx: str) -> None:
+        """An explicit position is absolute: header=False and start_row don't move it."""
+        df = pd.DataFrame({"A": [1, 2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_panes="B3", header=False, start_row=5)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).freeze_panes == "B3"
+        wb.close()
+
+    def test_freeze_panes_dict_follows_offset(self, tmp_xlsx: str) -> None:
+        """A rows/cols dict counts from start_row/start_col, unlike an explicit position."""
+        df = pd.DataFrame({"A": [1, 2], "B": [3, 4]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_panes={"rows": 1, "cols": 1}, start_row=2, start_col=1)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).freeze_panes == "C4"
+        wb.close()
+
+    @pytest.mark.parametrize(
+        ("freeze_panes", "offset"),
+        [
+            pytest.param({"rows": 10}, {"start_row": 1_048_570}, id="rows-past-last-row"),
+            pytest.param({"cols": 10}, {"start_col": 16_380}, id="cols-past-last-col"),
+        ],
+    )
+    def test_freeze_panes_dict_past_grid_with_offset_raises(
+        self, freeze_panes: dict[str, int], offset: dict[str, int], tmp_xlsx: str
+    ) -> None:
+        """A rows/cols dict that the start offset pushes past Excel's grid raises ValueError."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="freeze_panes: .* outside Excel's grid"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_panes=freeze_panes, **offset)  # type: ignore[arg-type]
+
+    def test_per_sheet_freeze_panes_overrides_global(self, tmp_xlsx: str) -> None:
+        """A per-sheet cell reference, tuple, or dict overrides the global setting."""
+        df = pd.DataFrame({"A": [1], "B": [2]})
+        xlsxturbo.dfs_to_xlsx(
+            [
+                (df, "Global"),
+                (df, "Ref", {"freeze_panes": "B2"}),
+                (df, "Tuple", {"freeze_panes": (0, 1)}),
+                (df, "Dict", {"freeze_panes": {"rows": 2}}),
+            ],
+            tmp_xlsx,
+            freeze_panes=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"].freeze_panes == "A2"
+        assert wb["Ref"].freeze_panes == "B2"
+        assert wb["Tuple"].freeze_panes == "B1"
+        assert wb["Dict"].freeze_panes == "A3"
+        wb.close()
+
+    @pytest.mark.parametrize(
+        "freeze_panes",
+        [
+            pytest.param("not a cell", id="bad-ref"),
+            pytest.param((1_048_576, 0), id="row-out-of-range"),
+            pytest.param((0, 16_384), id="col-out-of-range"),
+            pytest.param((-1, 0), id="negative"),
+            pytest.param({"row": 1}, id="dict-unknown-key"),
+            pytest.param({"rows": -1}, id="dict-negative"),
+            pytest.param({"cols": 16_384}, id="dict-col-out-of-range"),
+        ],
+    )
+    def test_invalid_freeze_panes_raises_value_error(self, freeze_panes: object, tmp_xlsx: str) -> None:
+        """Unparseable references and positions outside the grid raise ValueError."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="freeze_panes"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_panes=freeze_panes)  # type: ignore[arg-type]
+
+    def test_freeze_top_cell_sets_scroll_position(self, tmp_xlsx: str) -> None:
+        """freeze_top_cell moves the scrollable pane's top-left cell; the freeze stays put."""
+        df = pd.DataFrame({"A": range(200)})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_panes=True, freeze_top_cell="A100")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.freeze_panes == "A2"
+        assert ws.sheet_view.pane is not None
+        assert ws.sheet_view.pane.topLeftCell == "A100"
+        wb.close()
+
+    def test_per_sheet_freeze_top_cell(self, tmp_xlsx: str) -> None:
+        """A per-sheet freeze_top_cell overrides the global one."""
+        df = pd.DataFrame({"A": range(50)})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Own", {"freeze_top_cell": "A40"})],
+            tmp_xlsx,
+            freeze_panes=True,
+            freeze_top_cell="A20",
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"].sheet_view.pane is not None
+        assert wb["Global"].sheet_view.pane.topLeftCell == "A20"
+        assert wb["Own"].sheet_view.pane is not None
+        assert wb["Own"].sheet_view.pane.topLeftCell == "A40"
+        wb.close()
+
+    def test_freeze_top_cell_without_freeze_is_ignored(self, tmp_xlsx: str) -> None:
+        """Without frozen panes there is no pane to scroll, so nothing is written."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_top_cell="A100")
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_view.pane is None
+        wb.close()
+
+    def test_invalid_freeze_top_cell_raises(self, tmp_xlsx: str) -> None:
+        """An unparseable freeze_top_cell raises ValueError naming the option."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="freeze_top_cell"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_panes=True, freeze_top_cell="nope")
+
+    def test_split_panes_writes_unfrozen_split(self, tmp_xlsx: str) -> None:
+        """split_panes writes a pane without the frozen state, sized in twips."""
+        df = pd.DataFrame({"A": [1], "B": [2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, split_panes="B3")
+        wb = load_workbook(tmp_xlsx)
+        pane = active_ws(wb).sheet_view.pane
+        assert pane is not None
+        assert pane.state is None or pane.state == "split"
+        assert pane.topLeftCell == "B3"
+        assert pane.xSplit == 390 + 960
+        assert pane.ySplit == 300 + 2 * 300
+        wb.close()
+
+    def test_split_panes_with_freeze_panes_raises(self, tmp_xlsx: str) -> None:
+        """Freezing and splitting the same sheet is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="mutually exclusive"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_panes=True, split_panes="B2")
+
+    def test_split_panes_at_a1_raises(self, tmp_xlsx: str) -> None:
+        """A split at A1 has no panes to create."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="split_panes"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, split_panes="A1")
+
+    def test_per_sheet_split_panes_replaces_global_freeze(self, tmp_xlsx: str) -> None:
+        """A sheet's own split_panes wins over the global freeze_panes."""
+        df = pd.DataFrame({"A": [1, 2]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Frozen"), (df, "Split", {"split_panes": "A2"})],
+            tmp_xlsx,
+            freeze_panes=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        frozen = wb["Frozen"].sheet_view.pane
+        split = wb["Split"].sheet_view.pane
+        assert frozen is not None and frozen.state == "frozen"
+        assert split is not None and split.state != "frozen"
+        assert split.ySplit == 600
+        wb.close()
+
+    def test_wrong_type_freeze_panes_raises_type_error(self, tmp_xlsx: str) -> None:
+        """A value that is not a bool, str, or (row, col) tuple raises TypeError."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(TypeError, match="freeze_panes"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, freeze_panes=1.5)  # type: ignore[arg-type]
+
+
+class TestSheetVisibility:
+    """Tests for the per-sheet 'visible' option in dfs_to_xlsx."""
+
+    def test_hidden_and_very_hidden_sheets(self, tmp_xlsx: str) -> None:
+        """visible=False hides a sheet and "very_hidden" very-hides it; others stay visible."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Main"), (df, "Lookup", {"visible": False}), (df, "Secret", {"visible": "very_hidden"})],
+            tmp_xlsx,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Main"].sheet_state == "visible"
+        assert wb["Lookup"].sheet_state == "hidden"
+        assert wb["Secret"].sheet_state == "veryHidden"
+        wb.close()
+
+    def test_hidden_first_sheet_activates_first_visible(self, tmp_xlsx: str) -> None:
+        """Hiding the first sheet keeps it hidden and opens on the first visible sheet."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx([(df, "Lookup", {"visible": False}), (df, "Main")], tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Lookup"].sheet_state == "hidden"
+        assert active_ws(wb).title == "Main"
+        wb.close()
+
+    def test_hiding_every_sheet_raises(self, tmp_xlsx: str) -> None:
+        """At least one sheet must remain visible."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="At least one sheet must remain visible"):
+            xlsxturbo.dfs_to_xlsx(
+                [(df, "A", {"visible": False}), (df, "B", {"visible": "very_hidden"})],
+                tmp_xlsx,
+            )
+
+    @pytest.mark.parametrize(
+        ("visible", "error"),
+        [
+            pytest.param("hidden", ValueError, id="unknown-string"),
+            pytest.param(0, TypeError, id="int"),
+        ],
+    )
+    def test_invalid_visible_raises(self, visible: object, error: type[Exception], tmp_xlsx: str) -> None:
+        """Only True, False, and "very_hidden" are accepted."""
+        df = pd.DataFrame({"A": [1]})
+        sheets = [(df, "Main", {}), (df, "Other", {"visible": visible})]
+        with pytest.raises(error, match="visible"):
+            xlsxturbo.dfs_to_xlsx(sheets, tmp_xlsx)  # type: ignore[arg-type]
+
+
+class TestActiveSheet:
+    """Tests for dfs_to_xlsx's active_sheet parameter and the per-sheet active_cell option."""
+
+    @pytest.mark.parametrize("active_sheet", [pytest.param(1, id="index"), pytest.param("Summary", id="name")])
+    def test_active_sheet(self, active_sheet: int | str, tmp_xlsx: str) -> None:
+        """The chosen sheet, by index or name, is active when the workbook opens."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx([(df, "Data"), (df, "Summary")], tmp_xlsx, active_sheet=active_sheet)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).title == "Summary"
+        wb.close()
+
+    def test_active_cell_sets_selection(self, tmp_xlsx: str) -> None:
+        """active_cell selects that cell on its sheet only."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx([(df, "Data", {"active_cell": "C5"}), (df, "Other")], tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Data"].active_cell == "C5"
+        assert wb["Other"].active_cell == "A1"
+        wb.close()
+
+    @pytest.mark.parametrize(
+        ("active_sheet", "match"),
+        [
+            pytest.param(2, "out of range", id="index-out-of-range"),
+            pytest.param("Missing", "does not match any sheet", id="unknown-name"),
+            pytest.param("Hidden", "is hidden", id="hidden-sheet"),
+        ],
+    )
+    def test_invalid_active_sheet_raises(self, active_sheet: int | str, match: str, tmp_xlsx: str) -> None:
+        """Unknown, out-of-range, or hidden active sheets raise ValueError."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match=match):
+            xlsxturbo.dfs_to_xlsx(
+                [(df, "Data"), (df, "Hidden", {"visible": False})],
+                tmp_xlsx,
+                active_sheet=active_sheet,
+            )
+
+    def test_wrong_type_active_sheet_raises(self, tmp_xlsx: str) -> None:
+        """A bool is not accepted as a sheet index."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(TypeError, match="active_sheet"):
+            xlsxturbo.dfs_to_xlsx([(df, "Data")], tmp_xlsx, active_sheet=True)
+
+    def test_invalid_active_cell_raises(self, tmp_xlsx: str) -> None:
+        """An unparseable active_cell raises ValueError naming the option."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="active_cell"):
+            xlsxturbo.dfs_to_xlsx([(df, "Data", {"active_cell": "not a cell"})], tmp_xlsx)
+
+
+class TestShowGridlines:
+    """Tests for the show_gridlines option."""
+
+    def test_gridlines_shown_by_default(self, tmp_xlsx: str) -> None:
+        """Without the option the sheet keeps Excel's default gridlines."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_view.showGridLines in (None, True)
+        wb.close()
+
+    def test_hide_gridlines_with_table_style(self, tmp_xlsx: str) -> None:
+        """show_gridlines=False works independently of a table style."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, show_gridlines=False, table_style="Medium2")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.sheet_view.showGridLines is False
+        assert len(ws.tables) == 1
+        wb.close()
+
+    def test_per_sheet_show_gridlines(self, tmp_xlsx: str) -> None:
+        """A per-sheet value overrides the global default."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Dashboard"), (df, "Data", {"show_gridlines": True})],
+            tmp_xlsx,
+            show_gridlines=False,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Dashboard"].sheet_view.showGridLines is False
+        assert wb["Data"].sheet_view.showGridLines in (None, True)
+        wb.close()
+
+    def test_hide_gridlines_under_constant_memory(self, tmp_xlsx: str) -> None:
+        """Gridlines are a view flag, so constant_memory still honors them."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, show_gridlines=False, constant_memory=True)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_view.showGridLines is False
+        wb.close()
+
+
+class TestShowRowColHeaders:
+    """Tests for the show_row_col_headers option."""
+
+    def test_headers_shown_by_default(self, tmp_xlsx: str) -> None:
+        """Without the option the sheet keeps Excel's row and column headers."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_view.showRowColHeaders in (None, True)
+        wb.close()
+
+    def test_hide_headers_with_gridlines(self, tmp_xlsx: str) -> None:
+        """Hidden headers combine with hidden gridlines and a frozen header row."""
+        xlsxturbo.df_to_xlsx(
+            pd.DataFrame({"A": [1], "B": [2]}),
+            tmp_xlsx,
+            show_row_col_headers=False,
+            show_gridlines=False,
+            freeze_panes=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.sheet_view.showRowColHeaders is False
+        assert ws.sheet_view.showGridLines is False
+        assert ws.freeze_panes == "A2"
+        wb.close()
+
+    def test_per_sheet_show_row_col_headers(self, tmp_xlsx: str) -> None:
+        """A per-sheet value overrides the global default."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Dashboard"), (df, "Data", {"show_row_col_headers": True})],
+            tmp_xlsx,
+            show_row_col_headers=False,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Dashboard"].sheet_view.showRowColHeaders is False
+        assert wb["Data"].sheet_view.showRowColHeaders in (None, True)
+        wb.close()
+
+    def test_hide_headers_under_constant_memory(self, tmp_xlsx: str) -> None:
+        """The headers flag is patched into the saved file, so constant_memory still honors it."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, show_row_col_headers=False, constant_memory=True)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_view.showRowColHeaders is False
+        wb.close()
+
+    def test_non_bool_per_sheet_value_raises(self, tmp_xlsx: str) -> None:
+        """A non-bool per-sheet value raises TypeError naming the option."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(TypeError, match="show_row_col_headers"):
+            xlsxturbo.dfs_to_xlsx([(df, "Data", {"show_row_col_headers": 1})], tmp_xlsx)
+
+
+class TestZoomAndView:
+    """Tests for the zoom and view options."""
+
+    @pytest.mark.parametrize(
+        ("view", "expected"),
+        [
+            ("normal", "normal"),
+            ("page_layout", "pageLayout"),
+            ("page_break", "pageBreakPreview"),
+        ],
+    )
+    def test_view(self, view: str, expected: str, tmp_xlsx: str) -> None:
+        """Each view name maps to Excel's sheet view."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, view=view)  # type: ignore[arg-type]
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_view.view == expected
+        wb.close()
+
+    def test_zoom(self, tmp_xlsx: str) -> None:
+        """zoom sets the sheet's zoom scale."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, zoom=150)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_view.zoomScale == 150
+        wb.close()
+
+    def test_per_sheet_zoom_and_view(self, tmp_xlsx: str) -> None:
+        """Per-sheet values override the global defaults."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Own", {"zoom": 80, "view": "page_break"})],
+            tmp_xlsx,
+            zoom=120,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"].sheet_view.zoomScale == 120
+        assert wb["Own"].sheet_view.zoomScale == 80
+        assert wb["Own"].sheet_view.view == "pageBreakPreview"
+        wb.close()
+
+    def test_zoom_and_view_under_constant_memory(self, tmp_xlsx: str) -> None:
+        """Zoom and view touch no cells, so constant_memory still honors them."""
+        xlsxturbo.df_to_xlsx(
+            pd.DataFrame({"A": [1]}), tmp_xlsx, zoom=150, view="page_layout", constant_memory=True
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_view.zoomScale == 150
+        assert active_ws(wb).sheet_view.view == "pageLayout"
+        wb.close()
+
+    @pytest.mark.parametrize("zoom", [5, 401])
+    def test_zoom_out_of_range_raises(self, zoom: int, tmp_xlsx: str) -> None:
+        """Zoom outside Excel's 10-400 range raises ValueError."""
+        with pytest.raises(ValueError, match="zoom"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, zoom=zoom)
+
+    def test_invalid_view_raises(self, tmp_xlsx: str) -> None:
+        """An unknown view name raises ValueError listing the valid ones."""
+        with pytest.raises(ValueError, match="page_layout"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, view="zoomed")  # type: ignore[arg-type]
+
+
+class TestRightToLeft:
+    """Tests for the right_to_left option."""
+
+    def test_left_to_right_by_default(self, tmp_xlsx: str) -> None:
+        """Without the option the sheet reads left to right."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_view.rightToLeft in (None, False)
+        wb.close()
+
+    def test_flips_view_not_data(self, tmp_xlsx: str) -> None:
+        """The view is mirrored while columns and frozen panes keep their positions."""
+        df = pd.DataFrame({"First": [1], "Second": [2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, right_to_left=True, freeze_panes="B2")
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.sheet_view.rightToLeft is True
+        assert ws["A1"].value == "First"
+        assert ws["B1"].value == "Second"
+        assert ws.freeze_panes == "B2"
+        wb.close()
+
+    def test_per_sheet_right_to_left(self, tmp_xlsx: str) -> None:
+        """A per-sheet value overrides the global default."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Arabic"), (df, "English", {"right_to_left": False})],
+            tmp_xlsx,
+            right_to_left=True,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Arabic"].sheet_view.rightToLeft is True
+        assert wb["English"].sheet_view.rightToLeft in (None, False)
+        wb.close()
+
+
+class TestTabColor:
+    """Tests for the tab_color option."""
+
+    def test_per_sheet_tab_colors(self, tmp_xlsx: str) -> None:
+        """Per-sheet colors override the global one; hex and named colors both work."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Income", {"tab_color": "#2E7D32"}), (df, "Costs", {"tab_color": "red"}), (df, "Other")],
+            tmp_xlsx,
+            tab_color="navy",
+        )
+        wb = load_workbook(tmp_xlsx)
+        for name, rgb in (("Income", "FF2E7D32"), ("Costs", "FFFF0000"), ("Other", "FF000080")):
+            tab_color = wb[name].sheet_properties.tabColor
+            assert tab_color is not None
+            assert tab_color.rgb == rgb
+        wb.close()
+
+    def test_no_tab_color_by_default(self, tmp_xlsx: str) -> None:
+        """Without the option no tab color is written."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).sheet_properties.tabColor is None
+        wb.close()
+
+    def test_invalid_tab_color_names_sheet(self, tmp_xlsx: str) -> None:
+        """An unknown color raises ValueError naming the sheet and the option."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match=r"sheet 'Sales': Invalid tab_color: Unknown color: blurple"):
+            xlsxturbo.dfs_to_xlsx([(df, "Main"), (df, "Sales", {"tab_color": "blurple"})], tmp_xlsx)
+
+
+class TestPageSetup:
+    """Tests for the page_setup option."""
+
+    def test_page_setup_applied(self, tmp_xlsx: str) -> None:
+        """Orientation, fit-to-pages, paper size, and margins reach the print settings."""
+        xlsxturbo.df_to_xlsx(
+            pd.DataFrame({"A": [1, 2]}),
+            tmp_xlsx,
+            page_setup={
+                "orientation": "landscape",
+                "fit_to_pages": (1, 0),
+                "paper_size": 9,
+                "margins": {"left": 0.5, "top": 1.0},
+            },
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.page_setup.orientation == "landscape"
+        assert int(ws.page_setup.paperSize) == 9
+        assert ws.sheet_properties.pageSetUpPr is not None
+        assert ws.sheet_properties.pageSetUpPr.fitToPage is True
+        assert int(ws.page_setup.fitToHeight) == 0
+        assert ws.page_margins.left == 0.5
+        assert ws.page_margins.top == 1.0
+        # Unset edges keep Excel's defaults
+        assert ws.page_margins.right == 0.7
+        assert ws.page_margins.footer == 0.3
+        assert ws["A2"].value == 1
+        wb.close()
+
+    def test_per_sheet_page_setup_overrides_global(self, tmp_xlsx: str) -> None:
+        """A per-sheet page_setup replaces the global one for that sheet."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Wide"), (df, "Tall", {"page_setup": {"orientation": "portrait"}})],
+            tmp_xlsx,
+            page_setup={"orientation": "landscape"},
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Wide"].page_setup.orientation == "landscape"
+        assert wb["Tall"].page_setup.orientation == "portrait"
+        wb.close()
+
+    def test_page_setup_under_constant_memory(self, tmp_xlsx: str) -> None:
+        """Print settings touch no cells, so constant_memory still honors them."""
+        xlsxturbo.df_to_xlsx(
+            pd.DataFrame({"A": [1]}), tmp_xlsx, constant_memory=True, page_setup={"orientation": "landscape"}
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb).page_setup.orientation == "landscape"
+        wb.close()
+
+    def test_invalid_orientation_raises(self, tmp_xlsx: str) -> None:
+        """An unknown orientation lists the valid values."""
+        with pytest.raises(ValueError, match=r"page_setup: invalid orientation 'sideways'"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, page_setup={"orientation": "sideways"})
+
+    def test_unknown_key_raises(self, tmp_xlsx: str) -> None:
+        """A misspelled key is rejected instead of silently ignored."""
+        with pytest.raises(ValueError, match=r"page_setup: unknown option 'orientaton'"):
+            xlsxturbo.df_to_xlsx(
+                pd.DataFrame({"A": [1]}),
+                tmp_xlsx,
+                page_setup={"orientaton": "landscape"},  # type: ignore[typeddict-unknown-key]
+            )
+
+    def test_negative_margin_raises(self, tmp_xlsx: str) -> None:
+        """A negative margin is an error, not a silent fallback to the default."""
+        with pytest.raises(ValueError, match=r"page_setup: margins: 'left' must be a non-negative number"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, page_setup={"margins": {"left": -1.0}})
+
+    def test_header_and_footer(self, tmp_xlsx: str) -> None:
+        """header/footer format strings reach the printed header and footer."""
+        xlsxturbo.df_to_xlsx(
+            pd.DataFrame({"A": [1]}),
+            tmp_xlsx,
+            header=True,
+            page_setup={
+                "header": "&CAcme Corp",
+                "footer": "&CPage &P of &N",
+                "header_footer_scale_with_doc": False,
+            },
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.oddHeader.center.text == "Acme Corp"
+        assert ws.oddFooter.center.text == "Page &P of &N"
+        assert ws.HeaderFooter.scaleWithDoc is False
+        assert ws["A1"].value == "A"
+        wb.close()
+
+    def test_header_image(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """An image path fills the &[Picture] placeholder in its section."""
+        out_path = tmp_xlsx_factory()
+        img_path = tmp_xlsx_factory(".png")
+        Path(img_path).write_bytes(base64.b64decode(TINY_PNG_B64))
+        xlsxturbo.df_to_xlsx(
+            pd.DataFrame({"A": [1]}),
+            out_path,
+            page_setup={"header": "&L&[Picture]&CReport", "header_images": {"left": img_path}},
+        )
+        with zipfile.ZipFile(out_path) as zf:
+            sheet = zf.read("xl/worksheets/sheet1.xml").decode("utf-8")
+            assert "<oddHeader>&amp;L&amp;G&amp;CReport</oddHeader>" in sheet
+            assert "<legacyDrawingHF" in sheet
+            assert any(name.startswith("xl/media/") for name in zf.namelist())
+
+    def test_header_image_without_placeholder_raises(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """An image needs a &[Picture] placeholder in its section of the text."""
+        img_path = tmp_xlsx_factory(".png")
+        Path(img_path).write_bytes(base64.b64decode(TINY_PNG_B64))
+        with pytest.raises(ValueError, match=r"No &\[Picture\] or &\[G\] variable"):
+            xlsxturbo.df_to_xlsx(
+                pd.DataFrame({"A": [1]}),
+                tmp_xlsx_factory(),
+                page_setup={"header": "&CReport", "header_images": {"left": img_path}},
+            )
+
+    def test_header_too_long_raises(self, tmp_xlsx: str) -> None:
+        """Excel's 255-character limit is an error rather than a silently dropped header."""
+        with pytest.raises(ValueError, match=r"page_setup: 'header' exceeds Excel's limit of 255 characters"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, page_setup={"header": "x" * 256})
+
+
+class TestPrintArea:
+    """Tests for the print_area option."""
+
+    def test_print_area_beyond_data(self, tmp_xlsx: str) -> None:
+        """The explicit range is kept even when it extends past the written data."""
+        df = pd.DataFrame({"A": [1, 2]})
+        xlsxturbo.dfs_to_xlsx([(df, "Data", {"print_area": "A1:F50"}), (df, "Other")], tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Data"].print_area is not None
+        assert str(wb["Data"].print_area).endswith("$A$1:$F$50")
+        assert wb["Other"].print_area is None
+        wb.close()
+
+    def test_invalid_print_area_raises(self, tmp_xlsx: str) -> None:
+        """A malformed range raises ValueError naming the option."""
+        with pytest.raises(ValueError, match=r"Invalid print_area: Invalid cell range 'A1'"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, print_area="A1")
+
+
+class TestOutlineGroups:
+    """Tests for the row_groups and col_groups options."""
+
+    def test_row_groups_levels_and_collapse(self, tmp_xlsx: str) -> None:
+        """Each row gets its explicit level; a collapsed group hides its rows."""
+        df = pd.DataFrame({"A": list(range(12))})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, row_groups=[(1, 10, 1), (2, 4, 2), (6, 9, 2, True)])
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        # openpyxl row_dimensions are 1-based
+        assert ws.row_dimensions[2].outlineLevel == 1
+        assert ws.row_dimensions[3].outlineLevel == 2
+        assert ws.row_dimensions[3].hidden is False
+        assert ws.row_dimensions[7].outlineLevel == 2
+        assert ws.row_dimensions[7].hidden is True
+        assert ws.row_dimensions[11].outlineLevel == 1
+        assert ws.row_dimensions[12].outlineLevel == 0
         wb.close()
 
-    def test_table_name_sanitization(self, tmp_xlsx: str) -> None:
-        """Sanitize invalid characters in table names."""
-        df = pd.DataFrame({"A": [1]})
-        xlsxturbo.df_to_xlsx(
-            df, tmp_xlsx, table_style="Medium2", table_name="My Table-2024!"
-        )
+    def test_level_without_outer_group(self, tmp_xlsx: str) -> None:
+        """An explicit level is honored even without an enclosing lower-level group."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": list(range(6))}), tmp_xlsx, row_groups=[(3, 4, 2)])
         wb = load_workbook(tmp_xlsx)
         ws = active_ws(wb)
-        tables = list(ws.tables.keys())
-        assert len(tables) == 1
-        assert "_" in tables[0]  # Some characters replaced with underscore
+        assert ws.row_dimensions[4].outlineLevel == 2
+        assert ws.row_dimensions[5].outlineLevel == 2
+        assert ws.row_dimensions[3].outlineLevel == 0
         wb.close()
 
-    def test_table_name_starts_with_digit(self, tmp_xlsx: str) -> None:
-        """Prefix table names starting with a digit with an underscore."""
-        df = pd.DataFrame({"A": [1]})
-        xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_style="Medium2", table_name="123Data")
+    def test_col_groups(self, tmp_xlsx: str) -> None:
+        """Column groups outline absolute sheet columns, collapsed ones hidden."""
+        df = pd.DataFrame({"A": [1], "B": [2], "C": [3], "D": [4]})
+        xlsxturbo.dfs_to_xlsx([(df, "Data", {"col_groups": [(1, 2, 1, True)]})], tmp_xlsx)
         wb = load_workbook(tmp_xlsx)
-        ws = active_ws(wb)
-        tables = list(ws.tables.keys())
-        assert tables[0].startswith("_")
+        dims = wb["Data"].column_dimensions["B"]
+        assert dims.outlineLevel == 1
+        assert dims.hidden is True
         wb.close()
 
-    def test_per_sheet_table_names(self, tmp_xlsx: str) -> None:
-        """Apply different table names per sheet."""
-        df1 = pd.DataFrame({"A": [1]})
-        df2 = pd.DataFrame({"B": [2]})
-        xlsxturbo.dfs_to_xlsx(
-            [
-                (df1, "Sheet1", {"table_style": "Medium2", "table_name": "Table1"}),
-                (df2, "Sheet2", {"table_style": "Medium2", "table_name": "Table2"}),
-            ],
+    @pytest.mark.parametrize(
+        ("groups", "match"),
+        [
+            ([(1, 2)], r"row_groups\[0\]: tuple must have 3 or 4 elements, got 2"),
+            ([(3, 1, 1)], r"row_groups\[0\]: first \(3\) must not be greater than last \(1\)"),
+            ([(1, 2, 8)], r"row_groups\[0\]: level must be an integer from 1 to 7, got 8"),
+        ],
+    )
+    def test_invalid_row_groups_raise(self, groups: list[tuple[int, ...]], match: str, tmp_xlsx: str) -> None:
+        """Malformed groups raise ValueError naming the entry."""
+        with pytest.raises(ValueError, match=match):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"A": [1]}), tmp_xlsx, row_groups=groups)  # type: ignore[arg-type]
+
+    def test_outline_buttons_default_below_and_right(self, tmp_xlsx: str) -> None:
+        """Without the options, group buttons stay at Excel's default positions."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"A": range(5)}), tmp_xlsx, row_groups=[(1, 3, 1)])
+        wb = load_workbook(tmp_xlsx)
+        outline = active_ws(wb).sheet_properties.outlinePr
+        assert outline is None or outline.summaryBelow in (None, True)
+        assert outline is None or outline.summaryRight in (None, True)
+        wb.close()
+
+    def test_outline_buttons_above_and_left(self, tmp_xlsx: str) -> None:
+        """outline_below/outline_right=False move the buttons above and left."""
+        xlsxturbo.df_to_xlsx(
+            pd.DataFrame({"A": range(5), "B": range(5)}),
             tmp_xlsx,
+            row_groups=[(1, 3, 1)],
+            col_groups=[(1, 1, 1)],
+            outline_below=False,
+            outline_right=False,
         )
         wb = load_workbook(tmp_xlsx)
-        assert "Table1" in wb["Sheet1"].tables
-        assert "Table2" in wb["Sheet2"].tables
+        outline = active_ws(wb).sheet_properties.outlinePr
+        assert outline is not None
+        assert outline.summaryBelow is False
+        assert outline.summaryRight is False
         wb.close()
 
-    def test_no_table_name_without_table_style(self, tmp_xlsx: str) -> None:
-        """Ignore table_name when table_style is None."""
-        df = pd.DataFrame({"A": [1]})
-        xlsxturbo.df_to_xlsx(df, tmp_xlsx, table_name="Ignored")
+    def test_per_sheet_outline_below(self, tmp_xlsx: str) -> None:
+        """A per-sheet value overrides the global default."""
+        df = pd.DataFrame({"A": range(5)})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Above", {"outline_below": False}), (df, "Below")],
+            tmp_xlsx,
+            row_groups=[(1, 3, 1)],
+        )
         wb = load_workbook(tmp_xlsx)
-        ws = active_ws(wb)
-        assert len(ws.tables) == 0
+        assert wb["Above"].sheet_properties.outlinePr.summaryBelow is False
+        below = wb["Below"].sheet_properties.outlinePr
+        assert below is None or below.summaryBelow in (None, True)
         wb.close()
 
-    def test_duplicate_global_table_name_raises_before_save(self, tmp_xlsx: str) -> None:
-        """Reject a global table name reused by multiple table-bearing sheets."""
-        df = pd.DataFrame({"A": [1]})
-        with pytest.raises(ValueError, match=r"Duplicate table name.*Sheet1.*Sheet2"):
-            xlsxturbo.dfs_to_xlsx(
-                [(df, "Sheet1"), (df, "Sheet2")],
-                tmp_xlsx,
-                table_style="Medium2",
-                table_name="Shared Table",
-            )
-
 
 class TestHeaderFormat:
     """Tests for header_format parameter."""
@@ -387,6 +1552,83 @@ class TestHeaderFormat:
         assert wb["Sheet2"]["A1"].fill.fgColor.rgb == "FFFF0000"
         wb.close()
 
+    def test_font_strikethrough(self, tmp_xlsx: str) -> None:
+        """font_strikethrough strikes through header and column text."""
+        df = pd.DataFrame({"A": [1], "B": [2]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, header_format={"font_strikethrough": True}, column_formats={"B": {"font_strikethrough": True}}
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A1"].font.strike is True
+        assert ws["B2"].font.strike is True
+        assert not ws["A2"].font.strike
+        wb.close()
+
+    def test_font_name_family_and_scheme(self, tmp_xlsx: str) -> None:
+        """font_name sets the typeface; font_family and font_scheme are written alongside it."""
+        df = pd.DataFrame({"A": [1], "B": [2]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            header_format={"font_name": "Arial", "font_family": 2},
+            column_formats={"B": {"font_name": "Cambria", "font_scheme": "major"}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A1"].font.name == "Arial"
+        assert ws["A1"].font.family == 2
+        assert ws["A1"].font.scheme is None
+        assert ws["B2"].font.name == "Cambria"
+        assert ws["B2"].font.scheme == "major"
+        assert ws["A2"].font.name == "Calibri"
+        wb.close()
+
+    def test_invalid_font_options_raise(self, tmp_xlsx: str) -> None:
+        """An empty font_name, out-of-range font_family, or unknown font_scheme is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="'font_name' must not be empty"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, header_format={"font_name": " "})
+        with pytest.raises(ValueError, match="'font_family' must be between 0 and 5"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, header_format={"font_family": 9})
+        with pytest.raises(ValueError, match="Unknown font_scheme"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, header_format={"font_scheme": "headline"})
+
+    def test_underline_styles(self, tmp_xlsx: str) -> None:
+        """underline=True stays single; a string picks double or accounting styles."""
+        df = pd.DataFrame({"A": [1], "B": [2], "C": [3]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            header_format={"underline": True},
+            column_formats={"B": {"underline": "double"}, "C": {"underline": "single_accounting"}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A1"].font.underline == "single"
+        assert ws["A2"].font.underline is None
+        assert ws["B2"].font.underline == "double"
+        assert ws["C2"].font.underline == "singleAccounting"
+        wb.close()
+
+    def test_invalid_underline_style_raises(self, tmp_xlsx: str) -> None:
+        """An unknown underline style is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="Unknown underline style 'wavy'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, header_format={"underline": "wavy"})
+
+    def test_font_script(self, tmp_xlsx: str) -> None:
+        """font_script raises or lowers column text; an unknown value is rejected."""
+        df = pd.DataFrame({"A": [1], "B": [2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={"B": {"font_script": "superscript"}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["B2"].font.vertAlign == "superscript"
+        assert ws["A2"].font.vertAlign is None
+        wb.close()
+        with pytest.raises(ValueError, match="Unknown font_script 'raised'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, header_format={"font_script": "raised"})
+
 
 class TestRichText:
     """Tests for rich text feature (v0.10.0)."""
@@ -436,6 +1678,52 @@ class TestRichText:
         )
         assert Path(tmp_xlsx).exists()
 
+    def test_rich_text_script_segments(self, tmp_xlsx: str) -> None:
+        """font_script raises or lowers individual runs, e.g. H2O and a footnote marker."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            rich_text={"B1": ["H", ("2", {"font_script": "subscript"}), "O", ("1", {"font_script": "superscript"})]},
+        )
+        with zipfile.ZipFile(tmp_xlsx) as zf:
+            shared = zf.read("xl/sharedStrings.xml").decode("utf-8")
+            assert '<vertAlign val="subscript"/>' in shared
+            assert '<vertAlign val="superscript"/>' in shared
+
+    def test_auto_rich_text_columns(self, tmp_xlsx: str) -> None:
+        """Markup in a listed column becomes bold/italic runs over the column's font; other values stay as given."""
+        df = pd.DataFrame({"note": ["**Blocked** until *Friday*", "2 * 3", r"5 \* 4"], "raw": ["**kept**"] * 3})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, auto_rich_text_columns=["no*"], column_formats={"note": {"font_color": "#FF0000"}}
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].value == "Blocked until Friday"
+        assert ws["A3"].value == "2 * 3"
+        assert ws["A4"].value == "5 * 4"
+        assert ws["B2"].value == "**kept**"
+        wb.close()
+        with zipfile.ZipFile(tmp_xlsx) as zf:
+            shared = zf.read("xl/sharedStrings.xml").decode("utf-8")
+            assert "<b/>" in shared
+            assert "<i/>" in shared
+            # Every run keeps the column's font color
+            assert shared.count('<color rgb="FFFF0000"/>') == 3
+
+    def test_auto_rich_text_columns_per_sheet(self, tmp_xlsx: str) -> None:
+        """A per-sheet auto_rich_text_columns overrides the global list; an empty list turns it off."""
+        df = pd.DataFrame({"note": ["**bold**"]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Off", {"auto_rich_text_columns": []})],
+            tmp_xlsx,
+            auto_rich_text_columns=["note"],
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].value == "bold"
+        assert wb["Off"]["A2"].value == "**bold**"
+        wb.close()
+
 
 class TestRowHeights:
     """Tests for row_heights parameter (v0.4.0)."""
@@ -481,6 +1769,61 @@ class TestRowHeights:
         wb.close()
 
 
+class TestDefaultDimensions:
+    """Tests for default_col_width and default_row_height."""
+
+    def test_defaults_apply_to_whole_sheet(self, tmp_xlsx: str) -> None:
+        """Every column and row gets the defaults, including those past the data."""
+        df = pd.DataFrame({"A": [1, 2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, default_col_width=20, default_row_height=30)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert abs(ws.column_dimensions["A"].width - 20) < 1
+        assert ws.sheet_format.defaultRowHeight == 30
+        assert abs(ws.row_dimensions[3].height - 30) < 1
+        wb.close()
+
+    def test_explicit_sizes_override_defaults(self, tmp_xlsx: str) -> None:
+        """column_widths and row_heights win for the columns and rows they set."""
+        df = pd.DataFrame({"A": [1], "B": [2], "C": [3]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            default_col_width=20,
+            default_row_height=30,
+            column_widths={1: 5},
+            row_heights={0: 40},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert abs(ws.column_dimensions["A"].width - 20) < 1
+        assert abs(ws.column_dimensions["B"].width - 5) < 1
+        assert abs(ws.column_dimensions["C"].width - 20) < 1
+        assert abs(ws.row_dimensions[1].height - 40) < 1
+        assert abs(ws.row_dimensions[2].height - 30) < 1
+        wb.close()
+
+    def test_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet default replaces the global one on its sheet."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"default_col_width": 12})],
+            tmp_xlsx,
+            default_col_width=30,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert abs(wb["Global"].column_dimensions["A"].width - 30) < 1
+        assert abs(wb["Local"].column_dimensions["A"].width - 12) < 1
+        wb.close()
+
+    def test_invalid_type_raises(self, tmp_xlsx: str) -> None:
+        """A non-numeric per-sheet default is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(TypeError, match="default_col_width"):
+            xlsxturbo.dfs_to_xlsx([(df, "S", {"default_col_width": "wide"})], tmp_xlsx)
+
+
+
 class TestBorderStyles:
     """Tests for per-side border styles (v0.12.0)."""
 
@@ -726,6 +2069,71 @@ class TestBorderStyles:
         assert wb["S2"]["A2"].border.right.style == "thick"
         wb.close()
 
+    def test_border_style_applies_to_true_flags(self, tmp_xlsx: str) -> None:
+        """border_style replaces thin for True flags; a named side style still wins."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={
+            "A": {"border": True, "border_style": "dashed", "border_bottom": "double"}
+        })
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        cell = ws["A2"]
+        assert cell.border.left.style == "dashed"
+        assert cell.border.top.style == "dashed"
+        assert cell.border.bottom.style == "double"
+        wb.close()
+
+    def test_per_side_border_colors(self, tmp_xlsx: str) -> None:
+        """Per-side colors override border_color for their side."""
+        df = pd.DataFrame({"A": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, header_format={
+            "border": "medium", "border_color": "#FF0000", "border_top_color": "#0000FF"
+        })
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        header = ws["A1"]
+        assert header.border.left.color.rgb == "FFFF0000"
+        assert header.border.top.color.rgb == "FF0000FF"
+        wb.close()
+
+    def test_border_style_without_border_raises(self, tmp_xlsx: str) -> None:
+        """border_style alone draws nothing and is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="'border_style' needs 'border'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={"A": {"border_style": "thick"}})
+
+    def test_diagonal_border(self, tmp_xlsx: str) -> None:
+        """diagonal_border draws a corner-to-corner line in the chosen direction and color."""
+        df = pd.DataFrame({"A": [1], "B": [2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={
+            "A": {"diagonal_border": True},
+            "B": {"diagonal_border": "dashed", "diagonal_type": "both", "diagonal_color": "#FF0000"},
+        })
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        up = ws["A2"].border
+        assert up.diagonal.style == "thin"
+        assert up.diagonalUp and not up.diagonalDown
+        both = ws["B2"].border
+        assert both.diagonal.style == "dashed"
+        assert both.diagonalUp and both.diagonalDown
+        assert both.diagonal.color.rgb == "FFFF0000"
+        wb.close()
+
+    def test_diagonal_type_without_diagonal_border_raises(self, tmp_xlsx: str) -> None:
+        """diagonal_type alone draws nothing and is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="'diagonal_type' needs 'diagonal_border'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={"A": {"diagonal_type": "down"}})
+
+    def test_invalid_diagonal_type_raises(self, tmp_xlsx: str) -> None:
+        """An unknown diagonal direction is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="Unknown diagonal_type"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={
+                "A": {"diagonal_border": True, "diagonal_type": "sideways"}
+            })
+
 
 class TestTextAlignment:
     """Tests for text alignment (v0.12.0)."""
@@ -901,3 +2309,232 @@ class TestTextAlignment:
         ws = active_ws(wb)
         assert ws["A2"].alignment.horizontal == "center"
         wb.close()
+
+    def test_short_spellings(self, tmp_xlsx: str) -> None:
+        """align, valign, and text_wrap work in header, column, and merged-range formats."""
+        df = pd.DataFrame({"A": ["hello"], "B": [1]})
+        short = {"align": "right", "valign": "top", "text_wrap": True}
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            header_format=short,
+            column_formats={"A": short},
+            merged_ranges=[("D1:E2", "Title", short)],
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        for ref in ("A1", "A2", "D1"):
+            assert ws[ref].alignment.horizontal == "right"
+            assert ws[ref].alignment.vertical == "top"
+            assert ws[ref].alignment.wrapText is True
+        wb.close()
+
+    def test_valign_middle_centers_vertically(self, tmp_xlsx: str) -> None:
+        """valign='middle' is Excel's vertical center."""
+        df = pd.DataFrame({"A": ["hello"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, column_formats={"A": {"valign": "middle"}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].alignment.vertical == "center"
+        wb.close()
+
+    def test_short_and_long_spelling_together_raises(self, tmp_xlsx: str) -> None:
+        """Setting both spellings of one option is rejected."""
+        df = pd.DataFrame({"A": [1]})
+        with pytest.raises(ValueError, match="'wrap_text' and 'text_wrap' are the same option"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, header_format={"wrap_text": True, "text_wrap": False})
+
+
+class TestCellFormat:
+    """Tests for the cell_format base style of data cells."""
+
+    def test_styles_every_data_cell_but_not_header(self, tmp_xlsx: str) -> None:
+        """Every data cell gets the base style; the header keeps its own."""
+        df = pd.DataFrame({"a": [1, 2], "b": ["x", None]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, cell_format={"italic": True, "border": True})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        for ref in ("A2", "A3", "B2", "B3"):
+            assert ws[ref].font.italic
+            assert ws[ref].border.left.style == "thin"
+        assert not ws["A1"].font.italic
+        wb.close()
+
+    def test_typed_cells_keep_number_format(self, tmp_xlsx: str) -> None:
+        """Dates and whole floats combine the base style with their number format."""
+        df = pd.DataFrame({"d": [date(2024, 1, 2)], "f": [3.0]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, cell_format={"bold": True}, integer_like_floats=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].number_format == "yyyy-mm-dd"
+        assert ws["B2"].number_format == "0"
+        assert ws["A2"].font.bold and ws["B2"].font.bold
+        wb.close()
+
+    def test_column_format_takes_precedence(self, tmp_xlsx: str) -> None:
+        """A matching column_formats entry replaces the base style for its column."""
+        df = pd.DataFrame({"a": [1], "b": [2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, cell_format={"italic": True}, column_formats={"b": {"bold": True}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A2"].font.italic
+        assert ws["B2"].font.bold
+        assert not ws["B2"].font.italic
+        wb.close()
+
+    def test_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet cell_format replaces the global one on its sheet."""
+        df = pd.DataFrame({"a": [1]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"cell_format": {"bold": True}})],
+            tmp_xlsx,
+            cell_format={"italic": True},
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A2"].font.italic
+        assert wb["Local"]["A2"].font.bold
+        assert not wb["Local"]["A2"].font.italic
+        wb.close()
+
+    def test_unknown_key_raises(self, tmp_xlsx: str) -> None:
+        """Unknown keys are rejected with the option name."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(ValueError, match="cell_format: unknown option 'colour'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, cell_format={"colour": "red"})
+
+
+class TestBandedRows:
+    """Tests for banded_rows alternating row fills."""
+
+    def test_alternates_fill_on_data_rows(self, tmp_xlsx: str) -> None:
+        """Data rows alternate between the two colors; the header is not banded."""
+        df = pd.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, banded_rows=("#FFFFFF", "#F2F2F2"))
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A1"].fill.fill_type is None
+        for ref in ("A2", "B2", "A4", "B4"):
+            assert ws[ref].fill.fgColor.rgb == "FFFFFFFF"
+        assert ws["A3"].fill.fgColor.rgb == "FFF2F2F2"
+        assert ws["B3"].fill.fgColor.rgb == "FFF2F2F2"
+        wb.close()
+
+    def test_formats_layer_on_top_of_fill(self, tmp_xlsx: str) -> None:
+        """cell_format, column_formats, and number formats keep the band fill."""
+        df = pd.DataFrame({"a": [1, 2], "b": [1.5, 2.5], "d": [date(2024, 1, 2), date(2024, 1, 3)]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            banded_rows=("#FFFFFF", "#F2F2F2"),
+            cell_format={"italic": True},
+            column_formats={"b": {"bold": True}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A3"].font.italic
+        assert ws["A3"].fill.fgColor.rgb == "FFF2F2F2"
+        assert ws["B3"].font.bold
+        assert ws["B3"].fill.fgColor.rgb == "FFF2F2F2"
+        assert ws["C3"].number_format == "yyyy-mm-dd"
+        assert ws["C3"].fill.fgColor.rgb == "FFF2F2F2"
+        wb.close()
+
+    def test_column_bg_color_wins(self, tmp_xlsx: str) -> None:
+        """A column format's own bg_color overrides the band fill."""
+        df = pd.DataFrame({"a": [1, 2], "b": [3, 4]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, banded_rows=("#FFFFFF", "#F2F2F2"), column_formats={"b": {"bg_color": "#FF0000"}}
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A3"].fill.fgColor.rgb == "FFF2F2F2"
+        assert ws["B3"].fill.fgColor.rgb == "FFFF0000"
+        wb.close()
+
+    def test_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet banded_rows replaces the global colors on its sheet."""
+        df = pd.DataFrame({"a": [1, 2]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"banded_rows": ["#FFFF00", "#00FF00"]})],
+            tmp_xlsx,
+            banded_rows=("#FFFFFF", "#F2F2F2"),
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A3"].fill.fgColor.rgb == "FFF2F2F2"
+        assert wb["Local"]["A3"].fill.fgColor.rgb == "FF00FF00"
+        wb.close()
+
+    def test_polars_constant_memory(self, tmp_xlsx: str) -> None:
+        """Bands are applied while rows stream out in constant_memory mode."""
+        df = pl.DataFrame({"a": [1, 2, 3]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, banded_rows=("#FFFFFF", "#F2F2F2"), constant_memory=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A3"].fill.fgColor.rgb == "FFF2F2F2"
+        assert ws["A4"].fill.fgColor.rgb == "FFFFFFFF"
+        wb.close()
+
+    def test_invalid_value_raises(self, tmp_xlsx: str) -> None:
+        """A single color is a TypeError; an unknown color a ValueError."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(TypeError, match="banded_rows must be a pair of colors"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, banded_rows="#FFFFFF")
+        with pytest.raises(ValueError, match="Invalid banded_rows color"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, banded_rows=("#FFFFFF", "nope"))
+
+
+class TestSheetProtection:
+    """Tests for the protect option."""
+
+    def test_protect_true_locks_sheet(self, tmp_xlsx: str) -> None:
+        """protect=True protects the sheet with every cell locked."""
+        df = pd.DataFrame({"a": [1], "b": ["x"]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, protect=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.protection.sheet
+        assert not ws.protection.password
+        assert ws["A2"].protection.locked is True
+        wb.close()
+
+    def test_password_and_unlocked_columns(self, tmp_xlsx: str) -> None:
+        """Only data cells of unlocked_columns are editable; headers and formulas stay locked."""
+        df = pd.DataFrame({"price": [1.5, 2.0], "qty": [3, 4], "day": [date(2024, 1, 2), date(2024, 1, 3)]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            formula_columns={"total": "=A{row}*B{row}"},
+            column_formats={"price": {"bold": True}},
+            protect={"password": "secret", "unlocked_columns": ["price", "d*"]},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws.protection.sheet
+        assert ws.protection.password
+        assert ws["A2"].protection.locked is False
+        assert ws["A2"].font.bold is True
+        assert ws["C2"].protection.locked is False
+        assert ws["C2"].number_format == "yyyy-mm-dd"
+        assert ws["B2"].protection.locked is True
+        assert ws["A1"].protection.locked is True
+        assert ws["D2"].protection.locked is True
+        wb.close()
+
+    def test_per_sheet_false_overrides_global(self, tmp_xlsx: str) -> None:
+        """A per-sheet protect=False leaves that sheet unprotected."""
+        df = pd.DataFrame({"a": [1]})
+        xlsxturbo.dfs_to_xlsx([(df, "Locked"), (df, "Open", {"protect": False})], tmp_xlsx, protect=True)
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Locked"].protection.sheet
+        assert not wb["Open"].protection.sheet
+        wb.close()
+
+    def test_invalid_protect_raises(self, tmp_xlsx: str) -> None:
+        """A non-bool/dict value, an unknown key, or an unmatched column is rejected."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(TypeError, match="protect must be a bool or a dict"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, protect="secret")
+        with pytest.raises(ValueError, match="unknown option 'pass'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, protect={"pass": "secret"})
+        with pytest.raises(ValueError, match="pattern matched no columns"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, protect={"unlocked_columns": ["missing"]})
@@ -1,8 +1,13 @@
-"""Tests for the formula_columns feature."""
+"""Tests for the formula_columns and summary_row features and workbook calculation settings."""
 
 from __future__ import annotations
 
+import io
+import re
+import zipfile
+
 import pandas as pd
+import polars as pl
 import pytest
 import xlsxturbo
 
@@ -91,6 +96,258 @@ class TestFormulaColumns:
         wb.close()
 
 
+class TestFormulaColumnNames:
+    """Tests for {col_name} references in formula templates."""
+
+    def test_column_names_resolve_to_cells(self, tmp_xlsx: str) -> None:
+        """Data and formula columns can be referenced by name, following start_col."""
+        df = pd.DataFrame({"price": [1.5, 2.0], "qty": [3, 4]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            start_col=1,
+            formula_columns={"Sub": "={price}*{qty}", "Total": "={Sub}*1.2"},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["D2"].value == "=B2*C2"
+        assert ws["E3"].value == "=D3*1.2"
+        wb.close()
+
+    def test_array_constant_left_untouched(self, tmp_xlsx: str) -> None:
+        """A {1,2} array constant is not mistaken for a column name."""
+        df = pd.DataFrame({"a": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, formula_columns={"S": "=SUM({a}*{1,2})"})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["B2"].value == "=SUM(A2*{1,2})"
+        wb.close()
+
+    def test_unknown_column_raises(self, tmp_xlsx: str) -> None:
+        """A name that is neither a data nor a formula column is rejected."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(ValueError, match=r"formula_columns\['S'\]: unknown column 'b'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, formula_columns={"S": "={a}+{b}"})
+
+
+def _sheet_xml(path: str) -> str:
+    with zipfile.ZipFile(path) as zf:
+        return zf.read("xl/worksheets/sheet1.xml").decode("utf-8")
+
+
+class TestFormulaTypes:
+    """Tests for formula_type: regular, dynamic, and array formula columns."""
+
+    def test_dict_regular_matches_string(self, tmp_xlsx: str) -> None:
+        """A dict without formula_type writes one formula per row, like a string."""
+        df = pd.DataFrame({"a": [1, 2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, formula_columns={"D": {"formula": "={a}*2"}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["B2"].value == "=A2*2"
+        assert ws["B3"].value == "=A3*2"
+        wb.close()
+
+    def test_dynamic_formula_written_once(self, tmp_xlsx: str) -> None:
+        """A dynamic formula sits in the first data row only, as a spilling array."""
+        df = pd.DataFrame({"a": [3, 1, 2]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, formula_columns={"Sorted": {"formula": "=SORT(A2:A4)", "formula_type": "dynamic"}}
+        )
+        xml = _sheet_xml(tmp_xlsx)
+        assert re.search(r'<c r="B2"[^>]*cm="1"[^>]*><f t="array" ref="B2">', xml)
+        assert 'r="B3"' not in xml
+
+    def test_array_formula_defaults_to_data_rows(self, tmp_xlsx: str) -> None:
+        """Without a range, an array formula covers the column's data rows."""
+        df = pd.DataFrame({"a": [1, 2, 3]})
+        xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, formula_columns={"D": {"formula": "=A2:A4*2", "formula_type": "array"}}
+        )
+        xml = _sheet_xml(tmp_xlsx)
+        assert '<f t="array" ref="B2:B4">A2:A4*2</f>' in xml
+        assert "cm=" not in xml
+
+    def test_array_formula_with_range(self, tmp_xlsx: str) -> None:
+        """An explicit range places the array formula there."""
+        df = pd.DataFrame({"a": [1, 2], "b": [3, 4]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            formula_columns={"Dot": {"formula": "=SUM(A2:A3*B2:B3)", "formula_type": "array", "range": "E2"}},
+        )
+        xml = _sheet_xml(tmp_xlsx)
+        assert '<f t="array" ref="E2">SUM(A2:A3*B2:B3)</f>' in xml
+
+    def test_invalid_options_raise(self, tmp_xlsx: str) -> None:
+        """Bad formula_type, a range on a non-array formula, and unknown keys are rejected."""
+        df = pd.DataFrame({"a": [1]})
+        bad: list[tuple[dict[str, str], str]] = [
+            ({"formula": "=1", "formula_type": "cse"}, "invalid formula_type 'cse'"),
+            ({"formula": "=1", "range": "B2"}, "'range' needs formula_type 'array'"),
+            ({"formula": "=1", "formula_type": "array", "range": "B2:"}, "invalid range"),
+            ({"formula_type": "dynamic"}, "missing 'formula' key"),
+            ({"formula": "=1", "type": "array"}, "type"),
+        ]
+        for options, message in bad:
+            with pytest.raises(ValueError, match=re.escape(message)):
+                xlsxturbo.df_to_xlsx(df, tmp_xlsx, formula_columns={"F": options})
+
+
+class TestFormulaColumnPosition:
+    """Tests for formula columns inserted among the data columns."""
+
+    def test_insert_after_named_column(self, tmp_xlsx: str) -> None:
+        """'after:col' inserts the formula column and shifts later data columns right."""
+        df = pd.DataFrame({"price": [2.0, 3.0], "qty": [4, 5], "tax": [0.1, 0.2]})
+        _rows, cols = xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            formula_columns={
+                "Sub": {"formula": "={price}*{qty}", "position": "after:qty"},
+                "Total": "={Sub}*(1+{tax})",
+            },
+        )
+        assert cols == 5
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [c.value for c in ws[1]] == ["price", "qty", "Sub", "tax", "Total"]
+        assert ws["C2"].value == "=A2*B2"
+        assert ws["D2"].value == 0.1
+        assert ws["E3"].value == "=C3*(1+D3)"
+        wb.close()
+
+    def test_insert_by_index_follows_start_col(self, tmp_xlsx: str) -> None:
+        """An int position counts DataFrame columns from the left, after start_col."""
+        df = pl.DataFrame({"a": [1], "b": [2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, start_col=1, formula_columns={"F": {"formula": "={b}", "position": 0}})
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert [ws.cell(row=1, column=c).value for c in (2, 3, 4)] == ["F", "a", "b"]
+        assert ws["B2"].value == "=D2"
+        assert ws["C2"].value == 1
+        wb.close()
+
+    def test_table_and_features_follow_shift(self, tmp_xlsx: str) -> None:
+        """The table spans inserted columns; validations and summary rows move with the data."""
+        df = pd.DataFrame({"a": [1, 2], "b": [3, 4]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            table_style="Medium2",
+            formula_columns={"Mid": {"formula": "={a}+{b}", "position": 1}},
+            validations={"b": {"type": "whole_number", "min": 0, "max": 10}},
+            summary_row={"b": "sum"},
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        table = next(iter(ws.tables.values()))
+        assert table.ref == "A1:C3"
+        assert [c.name for c in table.tableColumns] == ["a", "Mid", "b"]
+        assert str(ws.data_validations.dataValidation[0].sqref) == "C2:C3"
+        assert ws["C4"].value == "=SUM(C2:C3)"
+        wb.close()
+
+    def test_invalid_position_raises(self, tmp_xlsx: str) -> None:
+        """Positions past the data or naming an unknown column are rejected."""
+        df = pd.DataFrame({"a": [1]})
+        bad: list[tuple[int | str, str]] = [
+            (2, "position 2 is past the last of 1 columns"),
+            ("after:z", "'after:z' names no DataFrame column"),
+            ("before:a", "invalid position 'before:a'"),
+        ]
+        for position, message in bad:
+            with pytest.raises(ValueError, match=re.escape(message)):
+                xlsxturbo.df_to_xlsx(df, tmp_xlsx, formula_columns={"F": {"formula": "=1", "position": position}})
+
+
+class TestFormulaResult:
+    """Tests for cached formula_result values."""
+
+    def test_constant_and_callable_results(self, tmp_xlsx: str) -> None:
+        """Readers that don't recalculate see the cached values."""
+        df = pd.DataFrame({"a": [1, 2, 3]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            formula_columns={
+                "Double": {"formula": "={a}*2", "formula_result": lambda i: int(df["a"][i]) * 2},
+                "Label": {"formula": '="x"', "formula_result": "x"},
+                "Flag": {"formula": "=TRUE", "formula_result": True},
+            },
+        )
+        wb = load_workbook(tmp_xlsx, data_only=True)
+        ws = active_ws(wb)
+        assert [ws.cell(row=r, column=2).value for r in (2, 3, 4)] == [2, 4, 6]
+        assert ws["C2"].value == "x"
+        assert ws["D4"].value is True
+        wb.close()
+
+    def test_formula_is_still_written(self, tmp_xlsx: str) -> None:
+        """The cached value sits alongside the formula, not instead of it."""
+        df = pd.DataFrame({"a": [1]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, formula_columns={"F": {"formula": "={a}+1", "formula_result": 2}})
+        assert "<f>A2+1</f><v>2</v>" in _sheet_xml(tmp_xlsx)
+
+    def test_invalid_results_raise(self, tmp_xlsx: str) -> None:
+        """Unsupported values and exceptions from the callable are reported."""
+        df = pd.DataFrame({"a": [1]})
+
+        def fail(_row: int) -> float:
+            raise RuntimeError("boom")
+
+        bad: list[tuple[object, str]] = [
+            ([1], "formula_result must be a number, string, bool, or callable, got list"),
+            (float("nan"), "formula_result must be finite"),
+            (fail, "formula_result raised at row 0"),
+        ]
+        for result, message in bad:
+            with pytest.raises(ValueError, match=re.escape(message)):
+                xlsxturbo.df_to_xlsx(df, tmp_xlsx, formula_columns={"F": {"formula": "=1", "formula_result": result}})
+
+
+def _calc_pr(source: str | io.BytesIO) -> str:
+    with zipfile.ZipFile(source) as zf:
+        xml = zf.read("xl/workbook.xml").decode("utf-8")
+    match = re.search(r"<calcPr[^>]*/>", xml)
+    assert match is not None
+    return match.group(0)
+
+
+class TestCalculationSettings:
+    """Tests for the workbook-level calc_mode and force_recalc parameters."""
+
+    def test_default_recalculates_on_load(self, tmp_xlsx: str) -> None:
+        """By default Excel recalculates everything on open, in automatic mode."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"a": [1]}), tmp_xlsx)
+        calc_pr = _calc_pr(tmp_xlsx)
+        assert 'fullCalcOnLoad="1"' in calc_pr
+        assert "calcMode" not in calc_pr
+
+    def test_manual_without_recalc(self, tmp_xlsx: str) -> None:
+        """force_recalc=False keeps cached values; calc_mode sets the mode."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"a": [1]}), tmp_xlsx, calc_mode="manual", force_recalc=False)
+        calc_pr = _calc_pr(tmp_xlsx)
+        assert 'calcMode="manual"' in calc_pr
+        assert "fullCalcOnLoad" not in calc_pr
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A2"].value == 1
+        wb.close()
+
+    def test_dfs_and_bytes_output(self) -> None:
+        """The settings apply to multi-sheet and in-memory writes too."""
+        df = pd.DataFrame({"a": [1]})
+        data = xlsxturbo.dfs_to_xlsx_bytes([(df, "One"), (df, "Two")], calc_mode="auto_except_tables")
+        calc_pr = _calc_pr(io.BytesIO(data))
+        assert 'calcMode="autoNoTable"' in calc_pr
+        assert 'fullCalcOnLoad="1"' in calc_pr
+
+    def test_invalid_calc_mode_raises(self, tmp_xlsx: str) -> None:
+        """An unknown calc_mode is rejected."""
+        with pytest.raises(ValueError, match="Invalid calc_mode 'semi'"):
+            xlsxturbo.df_to_xlsx(pd.DataFrame({"a": [1]}), tmp_xlsx, calc_mode="semi")
+
+
 class TestFormulaColumnsHeaderFalse:
     """Regression tests for formula_columns with header=False (v0.10.5 fix)."""
 
@@ -128,3 +385,80 @@ class TestFormulaColumnsHeaderFalse:
         # Row 2 should have data
         assert ws["A2"].value == 10
         wb.close()
+
+
+class TestSummaryRow:
+    """Tests for the summary_row aggregate row below the data."""
+
+    def test_writes_bold_aggregates_below_data(self, tmp_xlsx: str) -> None:
+        """Each listed column gets an aggregate formula; the row count includes it."""
+        df = pd.DataFrame({"price": [10, 20, 30], "qty": [1, 2, 3], "name": ["a", "b", None]})
+        rows, cols = xlsxturbo.df_to_xlsx(
+            df, tmp_xlsx, summary_row={"price": "mean", "qty": "sum", "name": "count"}
+        )
+        assert (rows, cols) == (5, 3)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["A5"].value == "=AVERAGE(A2:A4)"
+        assert ws["B5"].value == "=SUM(B2:B4)"
+        assert ws["C5"].value == "=COUNTA(C2:C4)"
+        assert ws["A5"].font.bold
+        wb.close()
+
+    def test_caches_dataframe_values(self, tmp_xlsx: str) -> None:
+        """Readers that don't recalculate see the DataFrame's aggregates."""
+        df = pd.DataFrame({"a": [1.5, 2.5, 4.0], "b": [3, 1, 2]})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, summary_row={"a": "max", "b": "min"})
+        wb = load_workbook(tmp_xlsx, data_only=True)
+        ws = active_ws(wb)
+        assert ws["A5"].value == 4
+        assert ws["B5"].value == 1
+        wb.close()
+
+    def test_follows_offset_and_table_totals_row(self, tmp_xlsx: str) -> None:
+        """The row sits below a table's totals row and follows start_row/start_col."""
+        df = pd.DataFrame({"a": [1, 2]})
+        rows, _cols = xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            start_row=2,
+            start_col=1,
+            table_style="Medium2",
+            table_columns=[{"total_function": "sum"}],
+            summary_row={"a": "sum"},
+        )
+        assert rows == 7
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        assert ws["B7"].value == "=SUM(B4:B5)"
+        wb.close()
+
+    def test_polars_constant_memory(self, tmp_xlsx: str) -> None:
+        """The row is written after streamed polars data in constant_memory mode."""
+        df = pl.DataFrame({"n": [1, 2, 3]})
+        rows, _cols = xlsxturbo.df_to_xlsx(df, tmp_xlsx, summary_row={"n": "sum"}, constant_memory=True)
+        assert rows == 5
+        wb = load_workbook(tmp_xlsx)
+        assert active_ws(wb)["A5"].value == "=SUM(A2:A4)"
+        wb.close()
+
+    def test_per_sheet_override(self, tmp_xlsx: str) -> None:
+        """A per-sheet summary_row replaces the global one on its sheet."""
+        df = pd.DataFrame({"a": [1, 2]})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Global"), (df, "Local", {"summary_row": {"a": "max"}})],
+            tmp_xlsx,
+            summary_row={"a": "sum"},
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert wb["Global"]["A4"].value == "=SUM(A2:A3)"
+        assert wb["Local"]["A4"].value == "=MAX(A2:A3)"
+        wb.close()
+
+    def test_invalid_values_raise(self, tmp_xlsx: str) -> None:
+        """Unknown aggregates and unknown columns are rejected."""
+        df = pd.DataFrame({"a": [1]})
+        with pytest.raises(ValueError, match="invalid aggregate 'median'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, summary_row={"a": "median"})
+        with pytest.raises(ValueError, match="summary_row: unknown column 'b'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, summary_row={"b": "sum"})
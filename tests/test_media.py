@@ -66,6 +66,80 @@ class TestImages:
             # A drawing relationship must anchor the image to the sheet.
             assert any(n.startswith("xl/drawings/") for n in zf.namelist())
 
+    def test_image_offset_and_anchor(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """Pixel offsets and an absolute anchor reach the drawing XML."""
+        df = pd.DataFrame({"A": [1]})
+        path = tmp_xlsx_factory()
+        img_path = tmp_xlsx_factory(".png")
+        Path(img_path).write_bytes(base64.b64decode(TINY_PNG_B64))
+
+        image: ImageOptions = {"path": img_path, "x_offset": 10, "y_offset": 5, "anchor": "absolute"}
+        xlsxturbo.df_to_xlsx(df, path, images={"C2": image})
+        with zipfile.ZipFile(path) as zf:
+            drawing = zf.read("xl/drawings/drawing1.xml").decode()
+        assert 'editAs="absolute"' in drawing
+        # Offsets are stored in EMUs (9525 per pixel) on the anchor cell
+        assert "<xdr:colOff>95250</xdr:colOff>" in drawing
+        assert "<xdr:rowOff>47625</xdr:rowOff>" in drawing
+
+    def test_image_dpi_and_move_and_size_anchor(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """dpi resizes relative to the file's own 96 DPI; anchor sets how the image follows its cells."""
+        df = pd.DataFrame({"A": [1]})
+        path = tmp_xlsx_factory()
+        png_data = base64.b64decode(TINY_PNG_B64)
+
+        image: ImageOptions = {"data": png_data, "dpi": 48, "anchor": "move_and_size"}
+        xlsxturbo.df_to_xlsx(df, path, images={"C2": image})
+        with zipfile.ZipFile(path) as zf:
+            drawing = zf.read("xl/drawings/drawing1.xml").decode()
+        # The 1x1 pixel image at half its native DPI is 2x2 pixels (9525 EMU per pixel)
+        assert '<a:ext cx="19050" cy="19050"/>' in drawing
+        assert 'editAs="oneCell"' not in drawing
+
+    def test_image_from_bytes(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """Raw bytes and a config dict with 'data' embed without touching the filesystem."""
+        df = pd.DataFrame({"A": [1]})
+        path = tmp_xlsx_factory()
+        png_data = base64.b64decode(TINY_PNG_B64)
+
+        image: ImageOptions = {"data": png_data, "image_type": "png", "scale_width": 2.0}
+        xlsxturbo.df_to_xlsx(df, path, images={"C2": png_data, "E5": image})
+        with zipfile.ZipFile(path) as zf:
+            assert "xl/media/image1.png" in zf.namelist()
+            drawing = zf.read("xl/drawings/drawing1.xml").decode()
+        # Identical data is stored once but anchored at both cells
+        assert drawing.count("<xdr:pic>") == 2
+
+    def test_background_image_per_sheet(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """A global background image applies to every sheet; a per-sheet path replaces it."""
+        df = pd.DataFrame({"A": [1]})
+        path = tmp_xlsx_factory()
+        png_data = base64.b64decode(TINY_PNG_B64)
+        img_path = tmp_xlsx_factory(".png")
+        Path(img_path).write_bytes(png_data)
+
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "Draft"), (df, "Final", {"background_image": img_path})],
+            path,
+            background_image=png_data,
+        )
+        with zipfile.ZipFile(path) as zf:
+            for index in (1, 2):
+                sheet_xml = zf.read(f"xl/worksheets/sheet{index}.xml").decode()
+                assert "<picture " in sheet_xml
+
+    def test_image_unknown_anchor_raises(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """An unknown anchor mode lists the valid ones."""
+        df = pd.DataFrame({"A": [1]})
+        img_path = tmp_xlsx_factory(".png")
+        Path(img_path).write_bytes(base64.b64decode(TINY_PNG_B64))
+        with pytest.raises(ValueError, match=r"images\['C2'\]: Unknown anchor 'floating'"):
+            xlsxturbo.df_to_xlsx(
+                df,
+                tmp_xlsx_factory(),
+                images={"C2": {"path": img_path, "anchor": "floating"}},  # type: ignore[typeddict-item]
+            )
+
 
 class TestCheckboxes:
     """Tests for checkboxes feature (v0.13.0)."""
@@ -522,6 +596,40 @@ class TestCharts:
         with pytest.raises(ValueError, match="must include a sheet name"):
             xlsxturbo.df_to_xlsx(df, tmp_xlsx, charts=charts)
 
+    def test_chart_from_column_names(self, tmp_xlsx: str) -> None:
+        """value_cols/categories_col resolve to the written ranges, following offsets and formula columns."""
+        df = pd.DataFrame({"Month": ["Jan", "Feb", "Mar"], "Sales": [120, 145, 160], "Costs": [80, 90, 95]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            sheet_name="Report",
+            start_row=2,
+            start_col=1,
+            formula_columns={"Net": "=C{row}-D{row}"},
+            charts={"H2": {"type": "column", "categories_col": "Month", "value_cols": ["Sales", "Net"]}},
+        )
+        with zipfile.ZipFile(tmp_xlsx) as zf:
+            chart_xml = zf.read("xl/charts/chart1.xml").decode("utf-8")
+        assert "Report!$B$4:$B$6" in chart_xml  # categories
+        assert "Report!$C$4:$C$6" in chart_xml  # Sales values
+        assert "Report!$E$4:$E$6" in chart_xml  # Net formula column values
+        assert "Report!$C$3" in chart_xml  # series named by its header cell
+
+    def test_chart_unknown_value_col_raises(self, tmp_xlsx: str) -> None:
+        """An unknown column name lists the written columns."""
+        df = pd.DataFrame({"Month": ["Jan"], "Sales": [1]})
+        with pytest.raises(ValueError, match=r"value_cols 'Profit' is not a written column. Columns: Month, Sales"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, charts={"D2": {"type": "pie", "value_cols": "Profit"}})
+
+    def test_chart_value_cols_with_range_raises(self, tmp_xlsx: str) -> None:
+        """value_cols cannot be mixed with an explicit values range."""
+        df = pd.DataFrame({"Month": ["Jan"], "Sales": [1]})
+        charts: dict[str, ChartOptions] = {
+            "D2": {"type": "line", "value_cols": ["Sales"], "values_range": "Sheet1!$B$2:$B$2"}
+        }
+        with pytest.raises(ValueError, match="'value_cols' cannot be combined with 'values_range'"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, charts=charts)
+
 
 class TestSparklines:
     """Tests for native Excel sparklines (mini in-cell charts)."""
@@ -23,6 +23,7 @@ import base64
 import inspect
 import zipfile
 from collections.abc import Callable
+from datetime import date, datetime, time
 from pathlib import Path
 
 import pandas as pd
@@ -34,10 +35,27 @@ from tests.helpers import HAS_OPENPYXL, TINY_PNG_B64, active_ws, load_workbook
 pytestmark = pytest.mark.skipif(not HAS_OPENPYXL, reason="openpyxl required for content verification")
 
 # kwargs of df_to_xlsx that are not per-sheet "options": the DataFrame, the
-# output path, the (single-sheet) sheet name, and defined_names, which is a
-# workbook-level feature applied once regardless of which sheet(s) exist,
-# not a per-sheet option accepted by dfs_to_xlsx's options dict.
-NON_SHEET_PARAMS = frozenset({"df", "output_path", "sheet_name", "defined_names"})
+# output path, the (single-sheet) sheet name, defined_names, properties,
+# custom_properties, deterministic and the calc_mode/force_recalc pair, which are
+# workbook-level features applied once
+# regardless of which sheet(s) exist, not per-sheet options accepted by
+# dfs_to_xlsx's options dict, and the progress_callback/progress_every pair,
+# which reports on the write itself.
+NON_SHEET_PARAMS = frozenset(
+    {
+        "df",
+        "output_path",
+        "sheet_name",
+        "defined_names",
+        "calc_mode",
+        "force_recalc",
+        "properties",
+        "custom_properties",
+        "deterministic",
+        "progress_callback",
+        "progress_every",
+    }
+)
 
 # A factory that creates and tracks a new temporary file path (see
 # conftest.py's `tmp_xlsx_factory` fixture); used by checks that need an
@@ -89,10 +107,16 @@ def _check_table_name(path: str, _factory: PathFactory) -> None:
 
 
 def _check_freeze_panes(path: str, _factory: PathFactory) -> None:
-    """freeze_panes=True must freeze the header row."""
+    """freeze_panes=True must freeze the header row; a cell reference or rows/cols dict freezes at that cell."""
     xlsxturbo.df_to_xlsx(_base_df(), path, freeze_panes=True)
     ws = active_ws(load_workbook(path))
     assert ws.freeze_panes == "A2"
+    xlsxturbo.df_to_xlsx(_base_df(), path, freeze_panes="B2")
+    ws = active_ws(load_workbook(path))
+    assert ws.freeze_panes == "B2"
+    xlsxturbo.df_to_xlsx(_base_df(), path, freeze_panes={"rows": 2, "cols": 1})
+    ws = active_ws(load_workbook(path))
+    assert ws.freeze_panes == "B3"
 
 
 def _check_constant_memory(path: str, _factory: PathFactory) -> None:
@@ -250,6 +274,399 @@ def _check_cells(path: str, _factory: PathFactory) -> None:
     assert ws["D1"].value == "Coverage label"
 
 
+def _check_date_format(path: str, _factory: PathFactory) -> None:
+    """date_format must set the number format of date cells."""
+    df = pd.DataFrame({"Day": [date(2024, 1, 15)]})
+    xlsxturbo.df_to_xlsx(df, path, date_format="dd/mm/yyyy")
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].number_format == "dd/mm/yyyy"
+
+
+def _check_datetime_format(path: str, _factory: PathFactory) -> None:
+    """datetime_format must set the number format of datetime cells."""
+    df = pd.DataFrame({"When": [datetime(2024, 1, 15, 10, 30)]})
+    xlsxturbo.df_to_xlsx(df, path, datetime_format="dd/mm/yyyy hh:mm")
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].number_format == "dd/mm/yyyy hh:mm"
+
+
+def _check_time_format(path: str, _factory: PathFactory) -> None:
+    """time_format must set the number format of time cells."""
+    df = pd.DataFrame({"When": [time(14, 30)]})
+    xlsxturbo.df_to_xlsx(df, path, time_format="h:mm AM/PM")
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].number_format == "h:mm AM/PM"
+
+
+def _check_nan_policy(path: str, _factory: PathFactory) -> None:
+    """nan_policy must change how NaN cells are written."""
+    df = pd.DataFrame({"Value": [float("nan")]})
+    xlsxturbo.df_to_xlsx(df, path, nan_policy="string")
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].value == "NaN"
+
+
+def _check_large_int_mode(path: str, _factory: PathFactory) -> None:
+    """large_int_mode must change how integers beyond 2^53 are written."""
+    df = pd.DataFrame({"Id": [2**53 + 1]})
+    xlsxturbo.df_to_xlsx(df, path, large_int_mode="text")
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].number_format == "@"
+
+
+def _check_start_row(path: str, _factory: PathFactory) -> None:
+    """start_row must move the header down."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, start_row=2)
+    ws = active_ws(load_workbook(path))
+    assert ws["A3"].value == "Name"
+
+
+def _check_start_col(path: str, _factory: PathFactory) -> None:
+    """start_col must move the header right."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, start_col=2)
+    ws = active_ws(load_workbook(path))
+    assert ws["C1"].value == "Name"
+
+
+def _check_autofilter(path: str, _factory: PathFactory) -> None:
+    """autofilter=True must add a worksheet autofilter over the data."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, autofilter=True)
+    ws = active_ws(load_workbook(path))
+    assert ws.auto_filter.ref == "A1:B4"
+
+
+def _check_conditional_format_mode(path: str, _factory: PathFactory) -> None:
+    """first_match_wins must drop a later pattern's rules on an already-formatted column."""
+    xlsxturbo.df_to_xlsx(
+        _base_df(),
+        path,
+        conditional_formats={"*": {"type": "data_bar"}, "Score": {"type": "2_color_scale"}},
+        conditional_format_mode="first_match_wins",
+    )
+    ws = active_ws(load_workbook(path))
+    rule_types = [rule.type for cf in ws.conditional_formatting for rule in cf.rules]
+    assert rule_types == ["dataBar", "dataBar"]
+
+
+def _check_freeze_top_cell(path: str, _factory: PathFactory) -> None:
+    """freeze_top_cell must set the frozen pane's top-left scroll cell."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, freeze_panes=True, freeze_top_cell="A3")
+    ws = active_ws(load_workbook(path))
+    pane = ws.sheet_view.pane
+    assert pane is not None
+    assert pane.topLeftCell == "A3"
+
+
+def _check_show_gridlines(path: str, _factory: PathFactory) -> None:
+    """show_gridlines=False must hide the on-screen gridlines."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, show_gridlines=False)
+    ws = active_ws(load_workbook(path))
+    assert ws.sheet_view.showGridLines is False
+
+
+def _check_show_row_col_headers(path: str, _factory: PathFactory) -> None:
+    """show_row_col_headers=False must hide the on-screen row and column headers."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, show_row_col_headers=False)
+    ws = active_ws(load_workbook(path))
+    assert ws.sheet_view.showRowColHeaders is False
+
+
+def _check_tab_color(path: str, _factory: PathFactory) -> None:
+    """tab_color must color the sheet tab."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, tab_color="#FF0000")
+    ws = active_ws(load_workbook(path))
+    assert ws.sheet_properties.tabColor is not None
+    assert ws.sheet_properties.tabColor.rgb == "FFFF0000"
+
+
+def _check_page_setup(path: str, _factory: PathFactory) -> None:
+    """page_setup must reach the sheet's print settings."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, page_setup={"orientation": "landscape", "paper_size": 9})
+    ws = active_ws(load_workbook(path))
+    assert ws.page_setup.orientation == "landscape"
+    assert int(ws.page_setup.paperSize) == 9
+
+
+def _check_print_area(path: str, _factory: PathFactory) -> None:
+    """print_area must define the sheet's print range."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, print_area="A1:B3")
+    ws = active_ws(load_workbook(path))
+    assert ws.print_area is not None
+    assert str(ws.print_area).endswith("$A$1:$B$3")
+
+
+def _check_row_groups(path: str, _factory: PathFactory) -> None:
+    """row_groups must set the outline level of the grouped rows."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, row_groups=[(1, 2, 1)])
+    ws = active_ws(load_workbook(path))
+    assert ws.row_dimensions[2].outlineLevel == 1
+
+
+def _check_col_groups(path: str, _factory: PathFactory) -> None:
+    """col_groups must set the outline level of the grouped columns."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, col_groups=[(1, 1, 1)])
+    ws = active_ws(load_workbook(path))
+    assert ws.column_dimensions["B"].outlineLevel == 1
+
+
+def _check_table_columns(path: str, _factory: PathFactory) -> None:
+    """table_columns must add a totals row to the table."""
+    xlsxturbo.df_to_xlsx(
+        _base_df(), path, table_style="Medium9", table_columns=[{"total_label": "Total"}]
+    )
+    ws = active_ws(load_workbook(path))
+    assert next(iter(ws.tables.values())).totalsRowCount == 1
+
+
+def _check_table_options(path: str, _factory: PathFactory) -> None:
+    """table_options must set the table's first column emphasis."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, table_style="Medium9", table_options={"first_column": True})
+    with zipfile.ZipFile(path) as zf:
+        assert 'showFirstColumn="1"' in zf.read("xl/tables/table1.xml").decode()
+
+
+def _check_tables(path: str, _factory: PathFactory) -> None:
+    """tables must add a table over the given range."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, tables=[{"range": "A1:A4"}])
+    ws = active_ws(load_workbook(path))
+    assert [t.ref for t in ws.tables.values()] == ["A1:A4"]
+
+
+def _check_categorical_validation(path: str, _factory: PathFactory) -> None:
+    """categorical_validation must add a dropdown over a categorical column."""
+    df = pd.DataFrame({"status": pd.Categorical(["Open", "Closed"])})
+    xlsxturbo.df_to_xlsx(df, path, categorical_validation=True)
+    ws = active_ws(load_workbook(path))
+    assert ws.data_validations.dataValidation[0].type == "list"
+
+
+def _check_integer_like_floats(path: str, _factory: PathFactory) -> None:
+    """integer_like_floats must give whole float values the integer format."""
+    df = pd.DataFrame({"qty": [3.0]})
+    xlsxturbo.df_to_xlsx(df, path, integer_like_floats=True)
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].number_format == "0"
+
+
+def _check_columns(path: str, _factory: PathFactory) -> None:
+    """columns must select and order the written columns."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, columns=["Score"])
+    ws = active_ws(load_workbook(path))
+    assert ws["A1"].value == "Score"
+    assert ws["B1"].value is None
+
+
+def _check_skip_rows(path: str, _factory: PathFactory) -> None:
+    """skip_rows must leave out the leading data rows."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, skip_rows=1)
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].value == "Bob"
+
+
+def _check_max_rows(path: str, _factory: PathFactory) -> None:
+    """max_rows must cap the written data rows."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, max_rows=1)
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].value == "Alice"
+    assert ws["A3"].value is None
+
+
+def _check_transpose(path: str, _factory: PathFactory) -> None:
+    """transpose must write the column names down column A and each row as a column."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, transpose=True)
+    ws = active_ws(load_workbook(path))
+    assert ws["A1"].value == "Name"
+    assert ws["B1"].value == "Alice"
+    assert ws["C1"].value == "Bob"
+
+
+def _check_include_index(path: str, _factory: PathFactory) -> None:
+    """include_index must write the pandas index as the first column."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, include_index=True)
+    ws = active_ws(load_workbook(path))
+    assert ws["A1"].value == "index"
+    assert ws["A3"].value == 1
+    assert ws["B1"].value == "Name"
+
+
+def _check_cell_format(path: str, _factory: PathFactory) -> None:
+    """cell_format must style the data cells."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, cell_format={"bold": True})
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].font.bold is True
+    assert ws["B2"].font.bold is True
+
+
+def _check_banded_rows(path: str, _factory: PathFactory) -> None:
+    """banded_rows must alternate the fill of the data rows."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, banded_rows=("#FFFFFF", "#F2F2F2"))
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].fill.fgColor.rgb == "FFFFFFFF"
+    assert ws["A3"].fill.fgColor.rgb == "FFF2F2F2"
+
+
+def _check_summary_row(path: str, _factory: PathFactory) -> None:
+    """summary_row must write an aggregate row below the data."""
+    rows, _cols = xlsxturbo.df_to_xlsx(_base_df(), path, summary_row={"Score": "sum"})
+    assert rows == 5
+    ws = active_ws(load_workbook(path))
+    assert ws["B5"].value == "=SUM(B2:B4)"
+
+
+def _check_default_col_width(path: str, _factory: PathFactory) -> None:
+    """default_col_width must size the sheet's columns."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, default_col_width=25)
+    ws = active_ws(load_workbook(path))
+    assert abs(ws.column_dimensions["A"].width - 25) < 1
+
+
+def _check_default_row_height(path: str, _factory: PathFactory) -> None:
+    """default_row_height must size the sheet's rows."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, default_row_height=28)
+    ws = active_ws(load_workbook(path))
+    assert ws.sheet_format.defaultRowHeight == 28
+
+
+def _check_protect(path: str, _factory: PathFactory) -> None:
+    """protect must lock the sheet and leave unlocked_columns editable."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, protect={"unlocked_columns": ["Name"]})
+    ws = active_ws(load_workbook(path))
+    assert ws.protection.sheet
+    assert ws["A2"].protection.locked is False
+    assert ws["B2"].protection.locked is True
+
+
+def _check_cell_overrides(path: str, _factory: PathFactory) -> None:
+    """cell_overrides must write the value and format to the given cell."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, cell_overrides={"A2": {"value": "Note", "format": {"bold": True}}})
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].value == "Note"
+    assert ws["A2"].font.bold is True
+
+
+def _check_auto_hyperlinks(path: str, _factory: PathFactory) -> None:
+    """auto_hyperlinks must turn URL string values into hyperlinks."""
+    df = pd.DataFrame({"site": ["https://example.com", "plain"]})
+    xlsxturbo.df_to_xlsx(df, path, auto_hyperlinks=True)
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].hyperlink is not None
+    assert ws["A2"].hyperlink.target == "https://example.com"
+    assert ws["A3"].hyperlink is None
+
+
+def _check_link_format(path: str, _factory: PathFactory) -> None:
+    """link_format must style the auto_hyperlinks cells."""
+    df = pd.DataFrame({"site": ["https://example.com"]})
+    xlsxturbo.df_to_xlsx(df, path, auto_hyperlinks=True, link_format={"bold": True})
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].hyperlink is not None
+    assert ws["A2"].font.b
+
+
+def _check_background_image(path: str, _factory: PathFactory) -> None:
+    """background_image must attach the image to the sheet as its background picture."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, background_image=base64.b64decode(TINY_PNG_B64))
+    with zipfile.ZipFile(path) as zf:
+        sheet_xml = zf.read("xl/worksheets/sheet1.xml").decode()
+        assert "<picture " in sheet_xml
+        assert any(n.startswith("xl/media/") for n in zf.namelist())
+
+
+def _check_auto_rich_text_columns(path: str, _factory: PathFactory) -> None:
+    """auto_rich_text_columns must write markup in the listed columns as rich text runs."""
+    df = pd.DataFrame({"note": ["**Blocked** until *Friday*"], "raw": ["**as is**"]})
+    xlsxturbo.df_to_xlsx(df, path, auto_rich_text_columns=["note"])
+    with zipfile.ZipFile(path) as zf:
+        shared = zf.read("xl/sharedStrings.xml").decode("utf-8")
+    assert "<b/>" in shared
+    assert "<i/>" in shared
+    assert "**as is**" in shared
+
+
+
+def _check_infer_string_types(path: str, _factory: PathFactory) -> None:
+    """infer_string_types must write numeric strings as numbers."""
+    df = pd.DataFrame({"n": ["42"]})
+    xlsxturbo.df_to_xlsx(df, path, infer_string_types=True)
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].value == 42
+
+
+def _check_bool_mode(path: str, _factory: PathFactory) -> None:
+    """bool_mode must write booleans as the chosen labels."""
+    xlsxturbo.df_to_xlsx(pd.DataFrame({"flag": [True]}), path, bool_mode="yes_no")
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].value == "Yes"
+
+
+
+def _check_text_columns(path: str, _factory: PathFactory) -> None:
+    """text_columns must keep leading zeros by writing the column as text."""
+    xlsxturbo.df_to_xlsx(pd.DataFrame({"zip": [1234]}), path, text_columns=["zip"])
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].value == "1234"
+    assert ws["A2"].number_format == "@"
+
+
+def _check_long_string_mode(path: str, _factory: PathFactory) -> None:
+    """long_string_mode must let strings over the cell limit be written."""
+    df = pd.DataFrame({"Log": ["x" * 40000]})
+    xlsxturbo.df_to_xlsx(df, path, long_string_mode="truncate")
+    ws = active_ws(load_workbook(path))
+    assert len(ws["A2"].value) == 32767
+
+
+def _check_sanitize_strings(path: str, _factory: PathFactory) -> None:
+    """sanitize_strings must replace illegal control characters in strings."""
+    df = pd.DataFrame({"Name": ["a\x00b"]})
+    xlsxturbo.df_to_xlsx(df, path, sanitize_strings=True)
+    ws = active_ws(load_workbook(path))
+    assert ws["A2"].value == "a b"
+
+
+def _check_split_panes(path: str, _factory: PathFactory) -> None:
+    """split_panes must write a pane that is not frozen."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, split_panes="B2")
+    pane = active_ws(load_workbook(path)).sheet_view.pane
+    assert pane is not None
+    assert pane.state != "frozen"
+    assert pane.topLeftCell == "B2"
+
+
+def _check_zoom(path: str, _factory: PathFactory) -> None:
+    """zoom must set the sheet's zoom scale."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, zoom=150)
+    assert active_ws(load_workbook(path)).sheet_view.zoomScale == 150
+
+
+def _check_view(path: str, _factory: PathFactory) -> None:
+    """view must set the sheet's view."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, view="page_layout")
+    assert active_ws(load_workbook(path)).sheet_view.view == "pageLayout"
+
+
+def _check_right_to_left(path: str, _factory: PathFactory) -> None:
+    """right_to_left must mirror the sheet view."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, right_to_left=True)
+    assert active_ws(load_workbook(path)).sheet_view.rightToLeft is True
+
+
+def _check_outline_below(path: str, _factory: PathFactory) -> None:
+    """outline_below=False must put row group buttons above their groups."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, row_groups=[(1, 2, 1)], outline_below=False)
+    outline = active_ws(load_workbook(path)).sheet_properties.outlinePr
+    assert outline is not None
+    assert outline.summaryBelow is False
+
+
+def _check_outline_right(path: str, _factory: PathFactory) -> None:
+    """outline_right=False must put column group buttons left of their groups."""
+    xlsxturbo.df_to_xlsx(_base_df(), path, col_groups=[(1, 1, 1)], outline_right=False)
+    outline = active_ws(load_workbook(path)).sheet_properties.outlinePr
+    assert outline is not None
+    assert outline.summaryRight is False
+
+
 # Option name -> a callable that writes a minimal workbook exercising that
 # option and asserts its observable effect. Every df_to_xlsx sheet-option
 # kwarg must have exactly one entry here (see test_coverage_map_is_complete).
@@ -277,6 +694,55 @@ COVERAGE: dict[str, Callable[[str, PathFactory], None]] = {
     "charts": _check_charts,
     "sparklines": _check_sparklines,
     "cells": _check_cells,
+    "cell_overrides": _check_cell_overrides,
+    "date_format": _check_date_format,
+    "datetime_format": _check_datetime_format,
+    "time_format": _check_time_format,
+    "nan_policy": _check_nan_policy,
+    "large_int_mode": _check_large_int_mode,
+    "start_row": _check_start_row,
+    "start_col": _check_start_col,
+    "autofilter": _check_autofilter,
+    "conditional_format_mode": _check_conditional_format_mode,
+    "freeze_top_cell": _check_freeze_top_cell,
+    "show_gridlines": _check_show_gridlines,
+    "show_row_col_headers": _check_show_row_col_headers,
+    "tab_color": _check_tab_color,
+    "page_setup": _check_page_setup,
+    "print_area": _check_print_area,
+    "background_image": _check_background_image,
+    "row_groups": _check_row_groups,
+    "col_groups": _check_col_groups,
+    "table_columns": _check_table_columns,
+    "table_options": _check_table_options,
+    "tables": _check_tables,
+    "categorical_validation": _check_categorical_validation,
+    "integer_like_floats": _check_integer_like_floats,
+    "columns": _check_columns,
+    "skip_rows": _check_skip_rows,
+    "max_rows": _check_max_rows,
+    "transpose": _check_transpose,
+    "include_index": _check_include_index,
+    "cell_format": _check_cell_format,
+    "banded_rows": _check_banded_rows,
+    "summary_row": _check_summary_row,
+    "default_col_width": _check_default_col_width,
+    "default_row_height": _check_default_row_height,
+    "protect": _check_protect,
+    "auto_hyperlinks": _check_auto_hyperlinks,
+    "auto_rich_text_columns": _check_auto_rich_text_columns,
+    "infer_string_types": _check_infer_string_types,
+    "bool_mode": _check_bool_mode,
+    "text_columns": _check_text_columns,
+    "link_format": _check_link_format,
+    "long_string_mode": _check_long_string_mode,
+    "sanitize_strings": _check_sanitize_strings,
+    "split_panes": _check_split_panes,
+    "zoom": _check_zoom,
+    "view": _check_view,
+    "right_to_left": _check_right_to_left,
+    "outline_below": _check_outline_below,
+    "outline_right": _check_outline_right,
 }
 
 
@@ -0,0 +1,74 @@
+"""Tests for read_xlsx, reading written workbooks back into rows."""
+
+from __future__ import annotations
+
+from collections.abc import Callable
+from datetime import date, datetime, time
+from pathlib import Path
+
+import pandas as pd
+import pytest
+import xlsxturbo
+
+
+class TestReadXlsx:
+    """read_xlsx returns typed cell values positioned from A1."""
+
+    def test_round_trips_typed_values(self, tmp_xlsx: str) -> None:
+        """Numbers, bools, strings, dates, datetimes, times, and empty cells come back typed."""
+        df = pd.DataFrame(
+            {
+                "n": [1, 2],
+                "f": [1.5, None],
+                "b": [True, False],
+                "s": ["a", "b"],
+                "d": [date(2024, 1, 2), date(2024, 3, 4)],
+                "ts": [datetime(2024, 1, 2, 3, 4, 5, 123000), None],
+                "t": [time(14, 30), time(0, 0, 1)],
+            }
+        )
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        assert xlsxturbo.read_xlsx(tmp_xlsx) == [
+            ["n", "f", "b", "s", "d", "ts", "t"],
+            [1.0, 1.5, True, "a", datetime(2024, 1, 2), datetime(2024, 1, 2, 3, 4, 5, 123000), time(14, 30)],
+            [2.0, None, False, "b", datetime(2024, 3, 4), None, time(0, 0, 1)],
+        ]
+
+    def test_rows_are_indexed_from_a1(self, tmp_xlsx: str) -> None:
+        """Leading empty rows and columns are kept, so rows[r][c] matches the sheet position."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"a": [1]}), tmp_xlsx, start_row=1, start_col=2)
+        assert xlsxturbo.read_xlsx(tmp_xlsx) == [
+            [None, None, None],
+            [None, None, "a"],
+            [None, None, 1.0],
+        ]
+
+    def test_sheet_name_selects_sheet(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """sheet_name picks a sheet, the first is the default, and paths may be Path objects."""
+        xlsx_path = tmp_xlsx_factory()
+        xlsxturbo.dfs_to_xlsx(
+            [(pd.DataFrame({"a": [1]}), "First"), (pd.DataFrame({"b": ["x"]}), "Second")], xlsx_path
+        )
+        assert xlsxturbo.read_xlsx(xlsx_path) == [["a"], [1.0]]
+        assert xlsxturbo.read_xlsx(Path(xlsx_path), sheet_name="Second") == [["b"], ["x"]]
+
+    def test_reads_csv_conversion_output(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """A csv_to_xlsx result reads back with its detected types."""
+        csv_path = tmp_xlsx_factory(".csv")
+        xlsx_path = tmp_xlsx_factory()
+        Path(csv_path).write_text("name,when,ok\nx,2024-01-15,true\n")
+        xlsxturbo.csv_to_xlsx(csv_path, xlsx_path)
+        assert xlsxturbo.read_xlsx(xlsx_path)[1] == ["x", datetime(2024, 1, 15), True]
+
+    def test_unknown_sheet_raises(self, tmp_xlsx: str) -> None:
+        """A sheet_name that matches no sheet lists the sheets."""
+        xlsxturbo.df_to_xlsx(pd.DataFrame({"a": [1]}), tmp_xlsx, sheet_name="Data")
+        with pytest.raises(ValueError, match="sheet_name 'Nope' does not match any sheet. Sheets: Data"):
+            xlsxturbo.read_xlsx(tmp_xlsx, sheet_name="Nope")
+
+    def test_non_xlsx_file_raises(self, tmp_xlsx_factory: Callable[..., str]) -> None:
+        """A file that is not an XLSX workbook is a ValueError."""
+        csv_path = tmp_xlsx_factory(".csv")
+        Path(csv_path).write_text("a,b\n")
+        with pytest.raises(ValueError, match="Failed to open"):
+            xlsxturbo.read_xlsx(csv_path)
@@ -2,9 +2,11 @@
 
 from __future__ import annotations
 
+from datetime import date, time
 from pathlib import Path
 
 import pandas as pd
+import polars as pl
 import pytest
 import xlsxturbo
 
@@ -35,6 +37,61 @@ class TestValidations:
         assert "Pending" in dv.formula1
         wb.close()
 
+    def test_list_validation_from_source_range(self, tmp_xlsx: str) -> None:
+        """A source_range dropdown references the cells instead of inlining values."""
+        df = pd.DataFrame({"Status": ["Open", "Closed"]})
+        options = pd.DataFrame({"Option": [f"Status {i:03d}" for i in range(100)]})
+        xlsxturbo.dfs_to_xlsx(
+            [
+                (df, "Data", {"validations": {"Status": {"type": "list", "source_range": "=Lists!$A$2:$A$101"}}}),
+                (options, "Lists"),
+            ],
+            tmp_xlsx,
+        )
+        wb = load_workbook(tmp_xlsx)
+        dv = wb["Data"].data_validations.dataValidation[0]
+        assert dv.type == "list"
+        assert dv.formula1 == "Lists!$A$2:$A$101"
+        assert str(dv.sqref) == "A2:A3"
+        wb.close()
+
+    def test_list_validation_without_dropdown_or_blanks(self, tmp_xlsx: str) -> None:
+        """show_dropdown=False hides the arrow and ignore_blank=False rejects empty cells."""
+        df = pd.DataFrame({"Status": ["Open"]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            validations={
+                "Status": {"type": "list", "values": ["Open", "Closed"], "show_dropdown": False, "ignore_blank": False}
+            },
+        )
+        wb = load_workbook(tmp_xlsx)
+        dv = active_ws(wb).data_validations.dataValidation[0]
+        # Excel's showDropDown attribute is inverted: set means the arrow is hidden
+        assert dv.showDropDown is True
+        assert not dv.allow_blank
+        wb.close()
+
+    def test_date_and_time_validation(self, tmp_xlsx: str) -> None:
+        """date/time bounds from ISO strings or Python objects become Excel serials."""
+        df = pd.DataFrame({"Due": ["2024-03-01"], "Start": ["09:00"]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            validations={
+                "Due": {"type": "date", "min": "2024-01-01", "max": date(2024, 12, 31)},
+                "Start": {"type": "time", "min": time(9, 0), "max": "17:30"},
+            },
+        )
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        by_type = {dv.type: dv for dv in ws.data_validations.dataValidation}
+        assert by_type["date"].formula1 == "45292"
+        assert by_type["date"].formula2 == "45657"
+        assert float(by_type["time"].formula1) == pytest.approx(0.375)
+        assert float(by_type["time"].formula2) == pytest.approx(17.5 / 24)
+        wb.close()
+
     def test_number_validation(self, tmp_xlsx: str) -> None:
         """Verify whole number range validation."""
         df = pd.DataFrame({"Score": [85, 90]})
@@ -81,6 +138,22 @@ class TestValidations:
         assert dv.errorTitle == "Invalid"
         wb.close()
 
+    def test_validation_warning_error_style(self, tmp_xlsx: str) -> None:
+        """error_style='warning' soft-warns instead of blocking the entry."""
+        df = pd.DataFrame({"Value": [50]})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            validations={
+                "Value": {"type": "decimal", "max": 100, "error_message": "Unusually high", "error_style": "warning"}
+            },
+        )
+        wb = load_workbook(tmp_xlsx)
+        dv = active_ws(wb).data_validations.dataValidation[0]
+        assert dv.errorStyle == "warning"
+        assert dv.error == "Unusually high"
+        wb.close()
+
     def test_whole_number_max_outside_i32_range_raises(self, tmp_xlsx: str) -> None:
         """A whole_number max outside the i32 range raises a clear, actionable error."""
         df = pd.DataFrame({"Score": [85]})
@@ -128,3 +201,74 @@ class TestValidations:
                 tmp_xlsx,
                 validations={"Missing": {"type": "whole_number", "min": 0, "max": 100}},
             )
+
+
+class TestCategoricalValidation:
+    """Tests for categorical_validation dropdowns over categorical columns."""
+
+    def test_pandas_category_dropdown_in_declared_order(self, tmp_xlsx: str) -> None:
+        """A pandas category column gets a list validation of all its categories, in order."""
+        df = pd.DataFrame(
+            {
+                "status": pd.Categorical(["Open", "Closed"], categories=["Pending", "Open", "Closed"]),
+                "n": [1, 2],
+            }
+        )
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, categorical_validation=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        (dv,) = ws.data_validations.dataValidation
+        assert dv.type == "list"
+        assert dv.formula1 == '"Pending,Open,Closed"'
+        assert str(dv.sqref) == "A2:A3"
+        wb.close()
+
+    def test_polars_enum_dropdown(self, tmp_xlsx: str) -> None:
+        """A polars Enum column lists its declared categories."""
+        df = pl.DataFrame({"size": pl.Series(["M", "S"], dtype=pl.Enum(["S", "M", "L"]))})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx, categorical_validation=True)
+        wb = load_workbook(tmp_xlsx)
+        ws = active_ws(wb)
+        (dv,) = ws.data_validations.dataValidation
+        assert dv.formula1 == '"S,M,L"'
+        wb.close()
+
+    def test_off_by_default(self, tmp_xlsx: str) -> None:
+        """Without the flag, categorical columns get no validation."""
+        df = pd.DataFrame({"status": pd.Categorical(["Open", "Closed"])})
+        xlsxturbo.df_to_xlsx(df, tmp_xlsx)
+        wb = load_workbook(tmp_xlsx)
+        assert len(active_ws(wb).data_validations.dataValidation) == 0
+        wb.close()
+
+    def test_explicit_validation_wins(self, tmp_xlsx: str) -> None:
+        """A column matched by validations keeps that validation instead of the dropdown."""
+        df = pd.DataFrame({"status": pd.Categorical(["Open", "Closed"])})
+        xlsxturbo.df_to_xlsx(
+            df,
+            tmp_xlsx,
+            categorical_validation=True,
+            validations={"stat*": {"type": "text_length", "min": 1, "max": 10}},
+        )
+        wb = load_workbook(tmp_xlsx)
+        (dv,) = active_ws(wb).data_validations.dataValidation
+        assert dv.type == "textLength"
+        wb.close()
+
+    def test_per_sheet_flag(self, tmp_xlsx: str) -> None:
+        """categorical_validation can be enabled for a single sheet."""
+        df = pd.DataFrame({"status": pd.Categorical(["Open", "Closed"])})
+        xlsxturbo.dfs_to_xlsx(
+            [(df, "On", {"categorical_validation": True}), (df, "Off")],
+            tmp_xlsx,
+        )
+        wb = load_workbook(tmp_xlsx)
+        assert len(wb["On"].data_validations.dataValidation) == 1
+        assert len(wb["Off"].data_validations.dataValidation) == 0
+        wb.close()
+
+    def test_categories_over_255_chars_raise(self, tmp_xlsx: str) -> None:
+        """A category list beyond Excel's 255-character limit raises ValueError."""
+        df = pd.DataFrame({"c": pd.Categorical(["x" * 200, "y" * 100])})
+        with pytest.raises(ValueError, match="categories of column 'c' exceed Excel's 255 character"):
+            xlsxturbo.df_to_xlsx(df, tmp_xlsx, categorical_validation=True)